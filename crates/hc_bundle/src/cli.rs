@@ -3,7 +3,7 @@
 
 use clap::{Parser, Subcommand};
 use holochain_types::dna::DnaBundle;
-use holochain_types::prelude::{AppManifest, DnaManifest, ValidatedDnaManifest};
+use holochain_types::prelude::{AgentPubKey, AppManifest, DnaManifest, ValidatedDnaManifest};
 use holochain_types::web_app::WebAppManifest;
 use holochain_util::ffs;
 use mr_bundle::{Location, Manifest};
@@ -106,6 +106,56 @@ pub enum HcDnaBundleSubcommand {
         /// The path to the dna file.
         path: std::path::PathBuf,
     },
+
+    /// Print a summary of a `.dna` bundle without unpacking it: the manifest,
+    /// the bundled wasms with their sizes and hashes, the DNA hash, and the
+    /// functions each zome exports and the host functions it imports.
+    ///
+    /// e.g.:
+    ///
+    /// $ hc dna inspect ./some/dir/my-dna.dna
+    #[cfg(feature = "inspect")]
+    Inspect {
+        /// The path to the bundle to inspect.
+        path: PathBuf,
+    },
+
+    /// Sign a `.dna` bundle with a publisher key.
+    ///
+    /// e.g.:
+    ///
+    /// $ hc dna sign ./some/dir/my-dna.dna --key ./publisher.key
+    ///
+    /// A bundle can be signed by several publishers. Signing it again with the
+    /// same key replaces that publisher's signature.
+    Sign {
+        /// The path to the bundle to sign.
+        path: PathBuf,
+
+        /// The publisher key file, created with `hc app keygen`.
+        #[arg(short = 'k', long)]
+        key: PathBuf,
+
+        /// Specify the output path for the signed bundle.
+        ///
+        /// If not specified, the bundle is signed in place.
+        #[arg(short = 'o', long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Check the publisher signatures on a `.dna` bundle and print the
+    /// publishers that signed it.
+    ///
+    /// Fails if the bundle is unsigned or has been modified since it was signed.
+    Verify {
+        /// The path to the bundle to verify.
+        path: PathBuf,
+
+        /// Also fail unless the bundle is signed by this publisher.
+        /// May be given more than once to accept any of several publishers.
+        #[arg(short = 'p', long = "publisher", value_parser = parse_publisher)]
+        publishers: Vec<AgentPubKey>,
+    },
 }
 
 /// Work with Holochain hApp bundles.
@@ -188,6 +238,70 @@ pub enum HcAppBundleSubcommand {
 
     /// Print the schema for a hApp manifest
     Schema,
+
+    /// Print a summary of a `.happ` bundle without unpacking it: the manifest,
+    /// the bundled DNAs with their sizes, and for each role the DNA hash with
+    /// the modifiers of the role applied, and the functions each zome exports
+    /// and the host functions it imports.
+    ///
+    /// e.g.:
+    ///
+    /// $ hc app inspect ./some/dir/my-app.happ
+    #[cfg(feature = "inspect")]
+    Inspect {
+        /// The path to the bundle to inspect.
+        path: PathBuf,
+    },
+
+    /// Generate a new publisher key for signing bundles, and print the public
+    /// key that identifies the publisher.
+    ///
+    /// e.g.:
+    ///
+    /// $ hc app keygen ./publisher.key
+    ///
+    /// The key file is used by `hc app sign` and `hc dna sign` and must be kept secret.
+    Keygen {
+        /// The path of the key file to create.
+        path: PathBuf,
+    },
+
+    /// Sign a `.happ` bundle with a publisher key.
+    ///
+    /// e.g.:
+    ///
+    /// $ hc app sign ./some/dir/my-app.happ --key ./publisher.key
+    ///
+    /// A bundle can be signed by several publishers. Signing it again with the
+    /// same key replaces that publisher's signature.
+    Sign {
+        /// The path to the bundle to sign.
+        path: PathBuf,
+
+        /// The publisher key file, created with `hc app keygen`.
+        #[arg(short = 'k', long)]
+        key: PathBuf,
+
+        /// Specify the output path for the signed bundle.
+        ///
+        /// If not specified, the bundle is signed in place.
+        #[arg(short = 'o', long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Check the publisher signatures on a `.happ` bundle and print the
+    /// publishers that signed it.
+    ///
+    /// Fails if the bundle is unsigned or has been modified since it was signed.
+    Verify {
+        /// The path to the bundle to verify.
+        path: PathBuf,
+
+        /// Also fail unless the bundle is signed by this publisher.
+        /// May be given more than once to accept any of several publishers.
+        #[arg(short = 'p', long = "publisher", value_parser = parse_publisher)]
+        publishers: Vec<AgentPubKey>,
+    },
 }
 
 /// Work with Holochain web-hApp bundles.
@@ -237,6 +351,13 @@ pub enum HcWebAppBundleSubcommand {
         /// as each of the DNA files specified in the hApps' manifests.
         #[arg(short, long)]
         recursive: bool,
+
+        /// Write an indexed bundle, whose resources are packed one at a time
+        /// and can be read without loading the whole bundle into memory.
+        ///
+        /// Holochain and `hc` read either kind of bundle.
+        #[arg(long)]
+        indexed: bool,
     },
 
     /// Unpack parts of the `.webhapp` bundle file into a specific directory.
@@ -354,6 +475,27 @@ impl HcDnaBundleSubcommand {
                 let dna_hash_b64 = bundle.to_dna_file().await?.0.dna_hash().to_string();
                 println!("{}", dna_hash_b64);
             }
+            #[cfg(feature = "inspect")]
+            Self::Inspect { path } => {
+                let inspection = crate::inspect::inspect_dna(&path).await?;
+                print!("{}", serde_yaml::to_string(&inspection)?);
+            }
+            Self::Sign { path, key, output } => {
+                let (bundle_path, publisher) =
+                    crate::signing::sign::<ValidatedDnaManifest>(&path, &key, output).await?;
+                println!(
+                    "Signed bundle {} as publisher {}",
+                    bundle_path.to_string_lossy(),
+                    publisher
+                );
+            }
+            Self::Verify { path, publishers } => {
+                for publisher in
+                    crate::signing::verify::<ValidatedDnaManifest>(&path, &publishers).await?
+                {
+                    println!("Signed by publisher {}", publisher);
+                }
+            }
         }
         Ok(())
     }
@@ -405,6 +547,33 @@ impl HcAppBundleSubcommand {
             Self::Schema => {
                 println!("{}", include_str!("../schema/happ-manifest.schema.json"));
             }
+            #[cfg(feature = "inspect")]
+            Self::Inspect { path } => {
+                let inspection = crate::inspect::inspect_app(&path).await?;
+                print!("{}", serde_yaml::to_string(&inspection)?);
+            }
+            Self::Keygen { path } => {
+                let publisher = crate::signing::generate_key(&path)?;
+                println!(
+                    "Wrote publisher key {} for publisher {}",
+                    path.to_string_lossy(),
+                    publisher
+                );
+            }
+            Self::Sign { path, key, output } => {
+                let (bundle_path, publisher) =
+                    crate::signing::sign::<AppManifest>(&path, &key, output).await?;
+                println!(
+                    "Signed bundle {} as publisher {}",
+                    bundle_path.to_string_lossy(),
+                    publisher
+                );
+            }
+            Self::Verify { path, publishers } => {
+                for publisher in crate::signing::verify::<AppManifest>(&path, &publishers).await? {
+                    println!("Signed by publisher {}", publisher);
+                }
+            }
         }
         Ok(())
     }
@@ -421,6 +590,7 @@ impl HcWebAppBundleSubcommand {
                 path,
                 output,
                 recursive,
+                indexed,
             } => {
                 let name = get_web_app_name(&path).await?;
 
@@ -428,8 +598,13 @@ impl HcWebAppBundleSubcommand {
                     web_app_pack_recursive(&path).await?;
                 }
 
-                let (bundle_path, _) =
-                    crate::packing::pack::<WebAppManifest>(&path, output, name, false).await?;
+                let bundle_path = if indexed {
+                    crate::packing::pack_indexed::<WebAppManifest>(&path, output, name).await?
+                } else {
+                    crate::packing::pack::<WebAppManifest>(&path, output, name, false)
+                        .await?
+                        .0
+                };
                 println!("Wrote bundle {}", bundle_path.to_string_lossy());
             }
             Self::Unpack {
@@ -469,6 +644,11 @@ impl HcWebAppBundleSubcommand {
     }
 }
 
+/// Parse a publisher key given in the same base64 form as an agent key.
+fn parse_publisher(s: &str) -> Result<AgentPubKey, String> {
+    AgentPubKey::try_from(s).map_err(|e| e.to_string())
+}
+
 /// Load a [ValidatedDnaManifest] manifest from the given path and return its `name` field.
 pub async fn get_dna_name(manifest_path: &Path) -> HcBundleResult<String> {
     let manifest_path = manifest_path.to_path_buf();
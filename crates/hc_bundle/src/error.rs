@@ -18,6 +18,15 @@ pub enum HcBundleError {
     #[error("DNA error: {0}")]
     DnaError(#[from] holochain_types::dna::DnaError),
 
+    /// AppBundleError
+    #[error("hApp bundle error: {0}")]
+    AppBundleError(#[from] holochain_types::app::AppBundleError),
+
+    /// RibosomeError
+    #[cfg(feature = "inspect")]
+    #[error("Ribosome error: {0}")]
+    RibosomeError(#[from] holochain::core::ribosome::error::RibosomeError),
+
     /// MrBundleError
     #[error(transparent)]
     MrBundleError(#[from] mr_bundle::error::MrBundleError),
@@ -42,6 +51,22 @@ pub enum HcBundleError {
 
     #[error(transparent)]
     ModuleCompileError(#[from] CompileError),
+
+    /// A publisher key file that isn't a 32 byte ed25519 seed
+    #[error("The publisher key file should hold exactly 32 bytes: {0}")]
+    InvalidSigningKey(PathBuf),
+
+    /// A bundle without any publisher signature
+    #[error("The bundle is not signed: {0}")]
+    UnsignedBundle(PathBuf),
+
+    /// A bundle that isn't signed by any of the trusted publishers
+    #[error("The bundle is not signed by any of the trusted publishers: {0}")]
+    UntrustedBundle(PathBuf),
+
+    /// A zome whose wasm can't be parsed
+    #[error("The wasm of zome '{0}' is invalid: {1}")]
+    InvalidWasm(holochain_types::prelude::ZomeName, String),
 }
 
 /// HcBundle Result type.
@@ -0,0 +1,207 @@
+#![forbid(missing_docs)]
+
+//! Defines the CLI commands for summarising DNA and hApp bundles without unpacking them.
+
+use crate::error::{HcBundleError, HcBundleResult};
+use holochain::core::ribosome::real_ribosome::{ModuleCacheLock, RealRibosome};
+use holochain_types::prelude::*;
+use holochain_wasmer_host::module::ModuleCache;
+use mr_bundle::{Bundle, Manifest};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// A summary of a DNA bundle, as printed by `hc dna inspect`.
+#[derive(Debug, serde::Serialize)]
+pub struct DnaInspection {
+    /// The manifest of the bundle.
+    pub manifest: DnaManifest,
+    /// The hash of the DNA.
+    pub dna_hash: DnaHashB64,
+    /// The resources in the bundle.
+    pub resources: Vec<ResourceInspection>,
+    /// The integrity zomes of the DNA, in order.
+    pub integrity_zomes: Vec<ZomeInspection>,
+    /// The coordinator zomes of the DNA, in order.
+    pub coordinator_zomes: Vec<ZomeInspection>,
+}
+
+/// A summary of a hApp bundle, as printed by `hc app inspect`.
+#[derive(Debug, serde::Serialize)]
+pub struct AppInspection {
+    /// The manifest of the bundle.
+    pub manifest: AppManifest,
+    /// The resources in the bundle.
+    pub resources: Vec<ResourceInspection>,
+    /// The DNA of each role that has one.
+    pub roles: BTreeMap<RoleName, RoleInspection>,
+}
+
+/// A resource in a bundle.
+#[derive(Debug, serde::Serialize)]
+pub struct ResourceInspection {
+    /// The path of the resource in the bundle.
+    pub path: PathBuf,
+    /// The size of the resource in bytes.
+    pub size: usize,
+    /// The hash of the resource, if it is a wasm.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wasm_hash: Option<WasmHashB64>,
+}
+
+/// The DNA of a role in a hApp bundle.
+#[derive(Debug, serde::Serialize)]
+pub struct RoleInspection {
+    /// The hash of the DNA with the modifiers of the role applied.
+    pub dna_hash: DnaHashB64,
+    /// The hash of the DNA as bundled, if the modifiers of the role change it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_dna_hash: Option<DnaHashB64>,
+    /// The integrity zomes of the DNA, in order.
+    pub integrity_zomes: Vec<ZomeInspection>,
+    /// The coordinator zomes of the DNA, in order.
+    pub coordinator_zomes: Vec<ZomeInspection>,
+}
+
+/// A wasm zome of a DNA.
+#[derive(Debug, serde::Serialize)]
+pub struct ZomeInspection {
+    /// The name of the zome.
+    pub name: ZomeName,
+    /// The hash of the zome's wasm.
+    pub wasm_hash: WasmHashB64,
+    /// The integrity zomes this zome depends on.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<ZomeName>,
+    /// The functions the wasm exports, other than those the host uses to
+    /// manage the memory and types of the zome.
+    pub exports: Vec<String>,
+    /// The host functions the wasm imports.
+    pub host_fn_imports: Vec<String>,
+    /// The imports which this version of Holochain doesn't provide, so the
+    /// zome can't be run.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unsupported_imports: Vec<String>,
+}
+
+/// Summarise a DNA bundle file.
+pub async fn inspect_dna(bundle_path: &Path) -> HcBundleResult<DnaInspection> {
+    let bundle = DnaBundle::read_from_file(bundle_path).await?;
+    let manifest = bundle.manifest().0.clone();
+    let resources = inspect_resources(&bundle, true).await?;
+    let (dna_file, _) = bundle.to_dna_file().await?;
+    let (integrity_zomes, coordinator_zomes) = inspect_zomes(&dna_file).await?;
+    Ok(DnaInspection {
+        manifest,
+        dna_hash: dna_file.dna_hash().clone().into(),
+        resources,
+        integrity_zomes,
+        coordinator_zomes,
+    })
+}
+
+/// Summarise a hApp bundle file, resolving the DNA of each role with the
+/// modifiers of the role applied.
+pub async fn inspect_app(bundle_path: &Path) -> HcBundleResult<AppInspection> {
+    let bundle = AppBundle::from(Bundle::<AppManifest>::read_from_file(bundle_path).await?);
+    let resources = inspect_resources(&bundle, false).await?;
+    let mut roles = BTreeMap::new();
+    for (role_name, (dna_file, original_hash)) in bundle.resolve_role_dnas().await? {
+        let (integrity_zomes, coordinator_zomes) = inspect_zomes(&dna_file).await?;
+        let dna_hash = dna_file.dna_hash().clone();
+        roles.insert(
+            role_name,
+            RoleInspection {
+                original_dna_hash: (original_hash != dna_hash).then(|| original_hash.into()),
+                dna_hash: dna_hash.into(),
+                integrity_zomes,
+                coordinator_zomes,
+            },
+        );
+    }
+    Ok(AppInspection {
+        manifest: bundle.manifest().clone(),
+        resources,
+        roles,
+    })
+}
+
+async fn inspect_resources<M: Manifest>(
+    bundle: &Bundle<M>,
+    wasm: bool,
+) -> HcBundleResult<Vec<ResourceInspection>> {
+    let mut resources = Vec::new();
+    for (path, bytes) in bundle.bundled_resources()?.iter() {
+        let wasm_hash = if wasm {
+            Some(DnaWasm::from(bytes.inner().to_vec()).to_hash().await.into())
+        } else {
+            None
+        };
+        resources.push(ResourceInspection {
+            path: path.clone(),
+            size: bytes.inner().len(),
+            wasm_hash,
+        });
+    }
+    Ok(resources)
+}
+
+async fn inspect_zomes(
+    dna_file: &DnaFile,
+) -> HcBundleResult<(Vec<ZomeInspection>, Vec<ZomeInspection>)> {
+    let ribosome = RealRibosome::new(
+        dna_file.clone(),
+        Arc::new(ModuleCacheLock::new(ModuleCache::new(None))),
+    )
+    .await?;
+    let host_fns: HashSet<String> = RealRibosome::tooling_imports().await?.into_iter().collect();
+    let dna_def = dna_file.dna_def();
+    let mut integrity = Vec::new();
+    for (name, def) in dna_def.integrity_zomes.iter() {
+        integrity.push(inspect_zome(&ribosome, &host_fns, name, def.as_any_zome_def()).await?);
+    }
+    let mut coordinator = Vec::new();
+    for (name, def) in dna_def.coordinator_zomes.iter() {
+        coordinator.push(inspect_zome(&ribosome, &host_fns, name, def.as_any_zome_def()).await?);
+    }
+    Ok((integrity, coordinator))
+}
+
+/// Summarise a zome, listing its exports the way the conductor does and
+/// checking its imports against the host functions the conductor provides.
+async fn inspect_zome(
+    ribosome: &RealRibosome,
+    host_fns: &HashSet<String>,
+    name: &ZomeName,
+    def: &ZomeDef,
+) -> HcBundleResult<ZomeInspection> {
+    let module = ribosome
+        .build_module(name)
+        .await
+        .map_err(|e| HcBundleError::InvalidWasm(name.clone(), e.to_string()))?;
+    // The `__hc__` memory functions are exported by every zome and are of no
+    // interest here.
+    let exports = ribosome
+        .get_extern_fns_for_wasm(module.clone())
+        .into_iter()
+        .map(|function_name| function_name.0)
+        .filter(|function_name| !function_name.starts_with("__hc__"))
+        .collect();
+    let mut imports: Vec<String> = module
+        .imports()
+        .functions()
+        .map(|import| import.name().to_string())
+        .collect();
+    imports.sort();
+    let (host_fn_imports, unsupported_imports) = imports
+        .into_iter()
+        .partition(|import| host_fns.contains(import));
+    Ok(ZomeInspection {
+        name: name.clone(),
+        wasm_hash: def.wasm_hash(name).map_err(DnaError::from)?.into(),
+        dependencies: def.dependencies().to_vec(),
+        exports,
+        host_fn_imports,
+        unsupported_imports,
+    })
+}
@@ -1,13 +1,22 @@
 mod cli;
 mod error;
 mod init;
+#[cfg(feature = "inspect")]
+mod inspect;
 mod packing;
+mod signing;
 
 pub use cli::{
     app_pack_recursive, bundled_dnas_workdir_locations, get_app_name, get_dna_name,
     get_web_app_name, web_app_pack_recursive, HcAppBundle, HcDnaBundle, HcWebAppBundle,
 };
-pub use packing::{pack, unpack, unpack_raw};
+#[cfg(feature = "inspect")]
+pub use inspect::{
+    inspect_app, inspect_dna, AppInspection, DnaInspection, ResourceInspection, RoleInspection,
+    ZomeInspection,
+};
+pub use packing::{pack, pack_indexed, unpack, unpack_raw};
+pub use signing::{generate_key, read_key, sign, verify};
 
 // Temporarily include a fork of wasmer from the git branch 'wamr', until it is officially released in wasmer v5
 #[cfg(feature = "wasmer_wamr")]
@@ -91,16 +91,7 @@ pub async fn pack<M: Manifest>(
     let dir_path = ffs::canonicalize(dir_path).await?;
     let manifest_path = dir_path.join(M::path());
     let bundle: Bundle<M> = Bundle::pack_yaml(&manifest_path).await?;
-    let target_path = match target_path {
-        Some(target_path) => {
-            if target_path.is_dir() {
-                dir_to_bundle_path(&target_path, name, M::bundle_extension())?
-            } else {
-                target_path
-            }
-        }
-        None => dir_to_bundle_path(&dir_path, name, M::bundle_extension())?,
-    };
+    let target_path = resolve_target_path::<M>(&dir_path, target_path, name)?;
     bundle.write_to_file(&target_path).await?;
     if serialize_wasm {
         eprintln!("DEPRECATED: Bundling precompiled and preserialized wasm for iOS is deprecated. Please use the wasm interpreter instead.");
@@ -110,6 +101,44 @@ pub async fn pack<M: Manifest>(
     Ok((target_path, bundle))
 }
 
+/// Pack a directory containing a YAML manifest into an indexed bundle, returning
+/// the path to which the bundle file was written.
+///
+/// The resources are streamed into the bundle file one at a time, and the
+/// manifest and each resource of the bundle can then be read without loading
+/// the rest, which suits large bundles such as web-hApps with big UIs.
+pub async fn pack_indexed<M: Manifest + Send + 'static>(
+    dir_path: &std::path::Path,
+    target_path: Option<PathBuf>,
+    name: String,
+) -> HcBundleResult<PathBuf> {
+    let dir_path = ffs::canonicalize(dir_path).await?;
+    let manifest_path = dir_path.join(M::path());
+    let target_path = resolve_target_path::<M>(&dir_path, target_path, name)?;
+    let output = target_path.clone();
+    tokio::task::spawn_blocking(move || Bundle::<M>::pack_yaml_indexed(&manifest_path, &output))
+        .await
+        .expect("packing task panicked")?;
+    Ok(target_path)
+}
+
+fn resolve_target_path<M: Manifest>(
+    dir_path: &Path,
+    target_path: Option<PathBuf>,
+    name: String,
+) -> HcBundleResult<PathBuf> {
+    match target_path {
+        Some(target_path) => {
+            if target_path.is_dir() {
+                dir_to_bundle_path(&target_path, name, M::bundle_extension())
+            } else {
+                Ok(target_path)
+            }
+        }
+        None => dir_to_bundle_path(dir_path, name, M::bundle_extension()),
+    }
+}
+
 fn dir_to_bundle_path(dir_path: &Path, name: String, extension: &str) -> HcBundleResult<PathBuf> {
     Ok(dir_path.join(format!("{}.{}", name, extension)))
 }
@@ -0,0 +1,87 @@
+#![forbid(missing_docs)]
+
+//! Defines the CLI commands for signing bundles and verifying their signatures.
+
+use crate::error::{HcBundleError, HcBundleResult};
+use holochain_types::prelude::AgentPubKey;
+use holochain_util::ffs;
+use mr_bundle::{Bundle, Manifest, SigningKey};
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Write a new publisher signing key to a file that must not exist yet,
+/// returning the public key that identifies the publisher.
+///
+/// The file holds the raw 32 byte ed25519 seed and should be kept secret.
+pub fn generate_key(key_path: &Path) -> HcBundleResult<AgentPubKey> {
+    let key = SigningKey::from_bytes(&rand::random());
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(key_path)?.write_all(key.as_bytes())?;
+    Ok(publisher_key(key.verifying_key().to_bytes()))
+}
+
+/// Read a publisher signing key written by [`generate_key`].
+pub async fn read_key(key_path: &Path) -> HcBundleResult<SigningKey> {
+    let bytes = ffs::read(key_path).await?;
+    let seed: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| HcBundleError::InvalidSigningKey(key_path.to_path_buf()))?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+/// Sign a bundle with a publisher key, returning the path of the signed bundle
+/// and the publisher.
+///
+/// The signed bundle replaces the original unless an output path is given.
+pub async fn sign<M: Manifest>(
+    bundle_path: &Path,
+    key_path: &Path,
+    output: Option<PathBuf>,
+) -> HcBundleResult<(PathBuf, AgentPubKey)> {
+    let key = read_key(key_path).await?;
+    let bytes = ffs::read(bundle_path).await?;
+    let bundle: Bundle<M> = Bundle::decode(&bytes)?;
+    // Don't add a signature alongside one that is already broken.
+    bundle.verify_signatures()?;
+    let bundle = bundle.sign(&key)?;
+    let output = output.unwrap_or_else(|| bundle_path.to_path_buf());
+    // Keep the encoding the bundle was packed with.
+    if mr_bundle::is_indexed(&bytes) {
+        bundle.write_indexed_to_file(&output).await?;
+    } else {
+        bundle.write_to_file(&output).await?;
+    }
+    Ok((output, publisher_key(key.verifying_key().to_bytes())))
+}
+
+/// Check the signatures on a bundle, returning the publishers that signed it.
+///
+/// It is an error if the bundle isn't signed, if any signature doesn't match,
+/// or if `trusted` publishers are given and none of them signed the bundle.
+pub async fn verify<M: Manifest>(
+    bundle_path: &Path,
+    trusted: &[AgentPubKey],
+) -> HcBundleResult<Vec<AgentPubKey>> {
+    let bundle: Bundle<M> = Bundle::read_from_file(bundle_path).await?;
+    let publishers: Vec<AgentPubKey> = bundle
+        .verify_signatures()?
+        .into_iter()
+        .map(publisher_key)
+        .collect();
+    if publishers.is_empty() {
+        return Err(HcBundleError::UnsignedBundle(bundle_path.to_path_buf()));
+    }
+    if !trusted.is_empty() && !publishers.iter().any(|p| trusted.contains(p)) {
+        return Err(HcBundleError::UntrustedBundle(bundle_path.to_path_buf()));
+    }
+    Ok(publishers)
+}
+
+/// Publishers are shown and configured in the same form as agent keys.
+fn publisher_key(public_key: [u8; 32]) -> AgentPubKey {
+    AgentPubKey::from_raw_32(public_key.to_vec())
+}
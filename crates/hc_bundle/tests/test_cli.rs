@@ -305,6 +305,270 @@ async fn test_hash_dna_function() {
     }
 }
 
+#[tokio::test]
+async fn test_sign_and_verify_app_bundle() {
+    for dna in [
+        "tests/fixtures/my-app/dnas/dna1",
+        "tests/fixtures/my-app/dnas/dna2",
+    ] {
+        let mut cmd = Command::cargo_bin("hc-dna").unwrap();
+        cmd.args(["pack", dna]).assert().success();
+    }
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let unsigned_path = tmp_dir.path().join("unsigned.happ");
+    let signed_path = tmp_dir.path().join("signed.happ");
+    {
+        let mut cmd = Command::cargo_bin("hc-app").unwrap();
+        let cmd = cmd
+            .args(["pack", "tests/fixtures/my-app/", "-o"])
+            .arg(&unsigned_path);
+        cmd.assert().success();
+    }
+
+    // The publisher is the last word printed by keygen.
+    let keygen = |name: &str| {
+        let key_path = tmp_dir.path().join(name);
+        let mut cmd = Command::cargo_bin("hc-app").unwrap();
+        let stdout = cmd
+            .arg("keygen")
+            .arg(&key_path)
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let publisher = String::from_utf8_lossy(&stdout)
+            .split_whitespace()
+            .last()
+            .unwrap()
+            .to_string();
+        (key_path, publisher)
+    };
+    let (alice_key, alice) = keygen("alice.key");
+    let (_, bob) = keygen("bob.key");
+    assert_ne!(alice, bob);
+
+    // An existing key is never overwritten.
+    Command::cargo_bin("hc-app")
+        .unwrap()
+        .arg("keygen")
+        .arg(&alice_key)
+        .assert()
+        .failure();
+
+    Command::cargo_bin("hc-app")
+        .unwrap()
+        .arg("sign")
+        .arg(&unsigned_path)
+        .arg("--key")
+        .arg(&alice_key)
+        .arg("-o")
+        .arg(&signed_path)
+        .assert()
+        .success();
+
+    let signed = read_app(&signed_path).unwrap();
+    assert_eq!(1, signed.signatures().len());
+    assert_eq!(
+        read_app(&unsigned_path).unwrap().manifest(),
+        signed.manifest()
+    );
+
+    let verify = |path: &Path, publishers: &[&str]| {
+        let mut cmd = Command::cargo_bin("hc-app").unwrap();
+        cmd.arg("verify").arg(path);
+        for publisher in publishers {
+            cmd.args(["--publisher", publisher]);
+        }
+        cmd.assert()
+    };
+    let stdout = verify(&signed_path, &[])
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert!(String::from_utf8_lossy(&stdout).contains(&alice));
+    verify(&signed_path, &[&bob, &alice]).success();
+    verify(&signed_path, &[&bob]).failure();
+    verify(&unsigned_path, &[]).failure();
+}
+
+#[tokio::test]
+async fn test_pack_indexed_web_app() {
+    for dna in [
+        "tests/fixtures/my-app/dnas/dna1",
+        "tests/fixtures/my-app/dnas/dna2",
+    ] {
+        let mut cmd = Command::cargo_bin("hc-dna").unwrap();
+        cmd.args(["pack", dna]).assert().success();
+    }
+    Command::cargo_bin("hc-app")
+        .unwrap()
+        .args(["pack", "tests/fixtures/my-app/"])
+        .assert()
+        .success();
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let packed_path = tmp_dir.path().join("packed.webhapp");
+    let indexed_path = tmp_dir.path().join("indexed.webhapp");
+    for (path, extra_args) in [(&packed_path, &[][..]), (&indexed_path, &["--indexed"][..])] {
+        let mut cmd = Command::cargo_bin("hc-web-app").unwrap();
+        cmd.args(["pack", "tests/fixtures/web-app/", "-o"])
+            .arg(path)
+            .args(extra_args)
+            .assert()
+            .success();
+    }
+
+    let indexed_bytes = ffs::sync::read(&indexed_path).unwrap();
+    assert!(mr_bundle::is_indexed(&indexed_bytes));
+    assert!(!mr_bundle::is_indexed(
+        &ffs::sync::read(&packed_path).unwrap()
+    ));
+
+    // Both encodings hold the same bundle.
+    let packed = read_web_app(&packed_path).unwrap();
+    let indexed = read_web_app(&indexed_path).unwrap();
+    assert_eq!(packed.manifest(), indexed.manifest());
+    assert_eq!(
+        packed.bundled_resources().unwrap(),
+        indexed.bundled_resources().unwrap()
+    );
+
+    // And an indexed bundle can be unpacked like any other.
+    let unpacked = tmp_dir.path().join("unpacked");
+    Command::cargo_bin("hc-web-app")
+        .unwrap()
+        .arg("unpack")
+        .arg(&indexed_path)
+        .arg("-o")
+        .arg(&unpacked)
+        .assert()
+        .success();
+    assert!(unpacked.join("web-happ.yaml").is_file());
+}
+
+/// A module which imports `__hc__agent_info_1` and exports `init` and
+/// `__hc__allocate_1`.
+#[cfg(feature = "inspect")]
+const TEST_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+    0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type: () -> ()
+    0x02, 0x1a, 0x01, 0x03, b'e', b'n', b'v', 0x12, b'_', b'_', b'h', b'c', b'_', b'_', b'a', b'g',
+    b'e', b'n', b't', b'_', b'i', b'n', b'f', b'o', b'_', b'1', 0x00, 0x00, // import
+    0x03, 0x02, 0x01, 0x00, // function
+    0x07, 0x1b, 0x02, 0x04, b'i', b'n', b'i', b't', 0x00, 0x01, 0x10, b'_', b'_', b'h', b'c', b'_',
+    b'_', b'a', b'l', b'l', b'o', b'c', b'a', b't', b'e', b'_', b'1', 0x00, 0x01, // export
+    0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b, // code
+];
+
+#[cfg(feature = "inspect")]
+#[tokio::test]
+async fn test_inspect_dna_and_app() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let app_dir = tmp_dir.path().join("app");
+    std::fs::create_dir(&app_dir).unwrap();
+    std::fs::write(app_dir.join("integrity.wasm"), TEST_WASM).unwrap();
+    std::fs::write(app_dir.join("coordinator.wasm"), TEST_WASM).unwrap();
+    std::fs::write(
+        app_dir.join("dna.yaml"),
+        r#"
+manifest_version: "1"
+name: inspected
+integrity:
+  network_seed: ~
+  origin_time: 2022-02-11T23:29:00.789576Z
+  properties: ~
+  zomes:
+    - name: integrity
+      bundled: integrity.wasm
+coordinator:
+  zomes:
+    - name: coordinator
+      bundled: coordinator.wasm
+      dependencies:
+        - name: integrity
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        app_dir.join("happ.yaml"),
+        r#"
+manifest_version: "1"
+name: inspected
+roles:
+  - name: as-bundled
+    dna:
+      bundled: inspected.dna
+  - name: modified
+    dna:
+      bundled: inspected.dna
+      modifiers:
+        network_seed: modified
+"#,
+    )
+    .unwrap();
+    Command::cargo_bin("hc-dna")
+        .unwrap()
+        .arg("pack")
+        .arg(&app_dir)
+        .assert()
+        .success();
+    let app_path = tmp_dir.path().join("inspected.happ");
+    Command::cargo_bin("hc-app")
+        .unwrap()
+        .arg("pack")
+        .arg(&app_dir)
+        .arg("-o")
+        .arg(&app_path)
+        .assert()
+        .success();
+
+    let inspect = |bin: &str, path: &Path| -> serde_yaml::Value {
+        let stdout = Command::cargo_bin(bin)
+            .unwrap()
+            .arg("inspect")
+            .arg(path)
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        serde_yaml::from_slice(&stdout).unwrap()
+    };
+
+    let dna = inspect("hc-dna", &app_dir.join("inspected.dna"));
+    assert_eq!(dna["manifest"]["name"], "inspected");
+    assert_eq!(dna["resources"][0]["path"], "coordinator.wasm");
+    assert_eq!(dna["resources"][0]["size"], TEST_WASM.len() as u64);
+    let wasm_hash = &dna["resources"][0]["wasm_hash"];
+    let coordinator = &dna["coordinator_zomes"][0];
+    assert_eq!(coordinator["name"], "coordinator");
+    assert_eq!(wasm_hash, &coordinator["wasm_hash"]);
+    assert_eq!(coordinator["dependencies"][0], "integrity");
+    assert_eq!(
+        serde_yaml::Value::from(vec!["init"]),
+        coordinator["exports"]
+    );
+    assert_eq!(
+        serde_yaml::Value::from(vec!["__hc__agent_info_1"]),
+        coordinator["host_fn_imports"]
+    );
+    assert!(coordinator.get("unsupported_imports").is_none());
+    assert_eq!(wasm_hash, &dna["integrity_zomes"][0]["wasm_hash"]);
+
+    // The DNA hash of each role has the modifiers of the role applied.
+    let app = inspect("hc-app", &app_path);
+    assert_eq!(app["manifest"]["name"], "inspected");
+    assert_eq!(app["resources"][0]["path"], "inspected.dna");
+    let as_bundled = &app["roles"]["as-bundled"];
+    assert_eq!(dna["dna_hash"], as_bundled["dna_hash"]);
+    assert!(as_bundled.get("original_dna_hash").is_none());
+    let modified = &app["roles"]["modified"];
+    assert_eq!(dna["dna_hash"], modified["original_dna_hash"]);
+    assert_ne!(dna["dna_hash"], modified["dna_hash"]);
+    assert_eq!(dna["coordinator_zomes"], modified["coordinator_zomes"]);
+}
+
 #[test]
 fn test_all_dna_manifests_match_schema() {
     let schema = load_schema("dna-manifest");
@@ -65,6 +65,7 @@ async fn main() -> anyhow::Result<()> {
             network_seed: None,
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         };
 
         let r = AdminRequest::InstallApp(Box::new(payload));
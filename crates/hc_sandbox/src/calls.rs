@@ -566,6 +566,7 @@ pub async fn install_app_bundle(cmd: &mut CmdRunner, args: InstallApp) -> anyhow
         network_seed,
         ignore_genesis_failure: false,
         allow_throwaway_random_agent_key: true,
+        wasm_limits: None,
     };
 
     let r = AdminRequest::InstallApp(Box::new(payload));
@@ -87,8 +87,20 @@ pub struct Network {
 pub enum NetworkType {
     /// A transport that uses the local memory transport protocol.
     Mem,
-    // /// A transport that uses the QUIC protocol.
-    // Quic(Quic),
+    /// A transport that uses direct QUIC connections, without a signal server.
+    Quic {
+        /// The local address to bind, e.g. `kitsune-quic://0.0.0.0:5788`.
+        #[arg(long, value_parser = try_parse_url2)]
+        bind_to: Option<Url2>,
+
+        /// Advertise this host to peers instead of the bound one.
+        #[arg(long)]
+        override_host: Option<String>,
+
+        /// Advertise this port to peers instead of the bound one.
+        #[arg(long)]
+        override_port: Option<u16>,
+    },
     // /// A transport that uses the MDNS protocol.
     // Mdns,
     /// A transport that uses the WebRTC protocol.
@@ -217,6 +229,17 @@ impl Network {
                 };
                 kit.transport_pool = vec![transport];
             }
+            NetworkType::Quic {
+                bind_to,
+                override_host,
+                override_port,
+            } => {
+                kit.transport_pool = vec![TransportConfig::Quic {
+                    bind_to,
+                    override_host,
+                    override_port,
+                }];
+            }
         }
         Some(kit)
     }
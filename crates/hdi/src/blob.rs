@@ -0,0 +1,234 @@
+use crate::prelude::*;
+
+/// The chunk size used when none is given.
+pub const BLOB_CHUNK_SIZE_DEFAULT: u32 = 1024 * 1024;
+
+/// The largest chunk a blob may be split into.
+///
+/// This stays well under [`ENTRY_SIZE_LIMIT`] so that a chunk plus its
+/// serialization overhead always fits in a single entry.
+pub const BLOB_CHUNK_SIZE_MAX: u32 = 3 * 1000 * 1000;
+
+/// One chunk of a blob.
+///
+/// Chunks are plain content so the entry hash of a chunk is the address of its
+/// bytes. Identical chunks, in the same or in different blobs, share an entry.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, SerializedBytes)]
+pub struct BlobChunk(#[serde(with = "serde_bytes")] pub Vec<u8>);
+
+impl std::fmt::Debug for BlobChunk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BlobChunk")
+            .field(&format_args!("{} bytes", self.0.len()))
+            .finish()
+    }
+}
+
+crate::app_entry!(BlobChunk);
+
+/// Describes a blob as an ordered list of chunk entries.
+///
+/// The entry hash of the manifest is the handle used to read the blob back.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, SerializedBytes)]
+pub struct BlobManifest {
+    /// Total size of the blob in bytes.
+    pub size: u64,
+    /// Size of every chunk but the last, which holds the remainder.
+    pub chunk_size: u32,
+    /// Entry hashes of the [`BlobChunk`]s in order.
+    pub chunks: Vec<EntryHash>,
+    /// Optional media type of the content, e.g. `image/png`.
+    pub content_type: Option<String>,
+}
+
+crate::app_entry!(BlobManifest);
+
+impl BlobManifest {
+    /// The number of chunks a blob of `size` bytes splits into.
+    pub fn chunk_count(size: u64, chunk_size: u32) -> u64 {
+        if chunk_size == 0 {
+            return 0;
+        }
+        size.div_ceil(chunk_size as u64)
+    }
+
+    /// The length the chunk at `index` must have.
+    pub fn chunk_len(&self, index: usize) -> u64 {
+        let start = index as u64 * self.chunk_size as u64;
+        std::cmp::min(self.chunk_size as u64, self.size.saturating_sub(start))
+    }
+}
+
+/// Split `data` into chunks of `chunk_size` bytes and build the manifest
+/// that addresses them.
+///
+/// Nothing is committed, the caller creates the entries. See `hdk::blob`
+/// for a function that does both.
+pub fn chunk_blob(
+    data: &[u8],
+    chunk_size: u32,
+    content_type: Option<String>,
+) -> ExternResult<(BlobManifest, Vec<BlobChunk>)> {
+    if chunk_size == 0 || chunk_size > BLOB_CHUNK_SIZE_MAX {
+        return Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "Blob chunk size must be between 1 and {} bytes, got {}",
+            BLOB_CHUNK_SIZE_MAX, chunk_size
+        ))));
+    }
+    let chunks: Vec<BlobChunk> = data
+        .chunks(chunk_size as usize)
+        .map(|c| BlobChunk(c.to_vec()))
+        .collect();
+    let chunk_hashes = chunks
+        .iter()
+        .map(hash_entry)
+        .collect::<ExternResult<Vec<EntryHash>>>()?;
+    Ok((
+        BlobManifest {
+            size: data.len() as u64,
+            chunk_size,
+            chunks: chunk_hashes,
+            content_type,
+        },
+        chunks,
+    ))
+}
+
+/// Check a [`BlobChunk`] on its own.
+///
+/// A chunk must not be empty and must not exceed [`BLOB_CHUNK_SIZE_MAX`].
+pub fn validate_blob_chunk(chunk: &BlobChunk) -> ValidateCallbackResult {
+    if chunk.0.is_empty() {
+        ValidateCallbackResult::Invalid("Blob chunks must not be empty".to_string())
+    } else if chunk.0.len() > BLOB_CHUNK_SIZE_MAX as usize {
+        ValidateCallbackResult::Invalid(format!(
+            "Blob chunk of {} bytes is larger than the maximum of {}",
+            chunk.0.len(),
+            BLOB_CHUNK_SIZE_MAX
+        ))
+    } else {
+        ValidateCallbackResult::Valid
+    }
+}
+
+/// Check a [`BlobManifest`] is internally consistent.
+///
+/// This does not fetch the chunks, use [`validate_blob_manifest_chunks`] for that.
+pub fn validate_blob_manifest(manifest: &BlobManifest) -> ValidateCallbackResult {
+    if manifest.chunk_size == 0 || manifest.chunk_size > BLOB_CHUNK_SIZE_MAX {
+        return ValidateCallbackResult::Invalid(format!(
+            "Blob chunk size must be between 1 and {} bytes, got {}",
+            BLOB_CHUNK_SIZE_MAX, manifest.chunk_size
+        ));
+    }
+    let expected = BlobManifest::chunk_count(manifest.size, manifest.chunk_size);
+    if manifest.chunks.len() as u64 != expected {
+        return ValidateCallbackResult::Invalid(format!(
+            "Blob of {} bytes in chunks of {} should have {} chunks but the manifest lists {}",
+            manifest.size,
+            manifest.chunk_size,
+            expected,
+            manifest.chunks.len()
+        ));
+    }
+    ValidateCallbackResult::Valid
+}
+
+/// Check that every chunk of a [`BlobManifest`] exists and has the length the
+/// manifest says it should.
+///
+/// This uses [`must_get_entry`] on every chunk, so it is only worth doing when
+/// the app needs to guarantee that a manifest is complete. Missing chunks
+/// are returned as [`ValidateCallbackResult::UnresolvedDependencies`].
+pub fn validate_blob_manifest_chunks(
+    manifest: &BlobManifest,
+) -> ExternResult<ValidateCallbackResult> {
+    match validate_blob_manifest(manifest) {
+        ValidateCallbackResult::Valid => (),
+        invalid => return Ok(invalid),
+    }
+    for (index, chunk_hash) in manifest.chunks.iter().enumerate() {
+        let entry = match must_get_entry(chunk_hash.clone()) {
+            Ok(entry) => entry,
+            Err(_) => {
+                return Ok(ValidateCallbackResult::UnresolvedDependencies(
+                    UnresolvedDependencies::Hashes(vec![chunk_hash.clone().into()]),
+                ))
+            }
+        };
+        let chunk = BlobChunk::try_from(entry)?;
+        if chunk.0.len() as u64 != manifest.chunk_len(index) {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "Blob chunk {} has {} bytes but the manifest expects {}",
+                index,
+                chunk.0.len(),
+                manifest.chunk_len(index)
+            )));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(size: u64, chunk_size: u32, chunk_count: usize) -> BlobManifest {
+        BlobManifest {
+            size,
+            chunk_size,
+            chunks: vec![EntryHash::from_raw_36(vec![0; 36]); chunk_count],
+            content_type: None,
+        }
+    }
+
+    #[test]
+    fn chunk_lengths() {
+        let m = manifest(10, 4, 3);
+        assert_eq!(3, BlobManifest::chunk_count(10, 4));
+        assert_eq!(4, m.chunk_len(0));
+        assert_eq!(4, m.chunk_len(1));
+        assert_eq!(2, m.chunk_len(2));
+        assert_eq!(0, BlobManifest::chunk_count(0, 4));
+    }
+
+    #[test]
+    fn manifest_validation() {
+        assert_eq!(
+            ValidateCallbackResult::Valid,
+            validate_blob_manifest(&manifest(10, 4, 3))
+        );
+        assert_eq!(
+            ValidateCallbackResult::Valid,
+            validate_blob_manifest(&manifest(0, 4, 0))
+        );
+        assert!(matches!(
+            validate_blob_manifest(&manifest(10, 4, 2)),
+            ValidateCallbackResult::Invalid(_)
+        ));
+        assert!(matches!(
+            validate_blob_manifest(&manifest(10, 0, 0)),
+            ValidateCallbackResult::Invalid(_)
+        ));
+        assert!(matches!(
+            validate_blob_manifest(&manifest(10, BLOB_CHUNK_SIZE_MAX + 1, 1)),
+            ValidateCallbackResult::Invalid(_)
+        ));
+    }
+
+    #[test]
+    fn chunk_validation() {
+        assert_eq!(
+            ValidateCallbackResult::Valid,
+            validate_blob_chunk(&BlobChunk(vec![1, 2, 3]))
+        );
+        assert!(matches!(
+            validate_blob_chunk(&BlobChunk(vec![])),
+            ValidateCallbackResult::Invalid(_)
+        ));
+        assert!(matches!(
+            validate_blob_chunk(&BlobChunk(vec![0; BLOB_CHUNK_SIZE_MAX as usize + 1])),
+            ValidateCallbackResult::Invalid(_)
+        ));
+    }
+}
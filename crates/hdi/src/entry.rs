@@ -64,6 +64,25 @@ pub fn must_get_action(action_hash: ActionHash) -> ExternResult<SignedActionHash
     })
 }
 
+/// MUST get many [`SignedActionHashed`]s, like calling [`must_get_action`]
+/// for each hash.
+///
+/// Actions that have to be fetched from the network are grouped by authority,
+/// so each authority is sent a single request rather than one per action.
+/// The actions are returned in the same order as the hashes passed in.
+///
+/// If any of the actions fails to be returned:
+///
+/// - Callbacks will return early with [`UnresolvedDependencies`] for all the
+///   actions that could not be found
+/// - Zome calls will receive a [`WasmError`] from the host
+pub fn must_get_actions(action_hashes: Vec<ActionHash>) -> ExternResult<Vec<SignedActionHashed>> {
+    HDI.with(|h| {
+        h.borrow()
+            .must_get_actions(MustGetActionsInput::new(action_hashes))
+    })
+}
+
 /// MUST get a VALID [`Record`] at a given [`ActionHash`].
 ///
 /// The [`Record`] is guaranteed to be valid.
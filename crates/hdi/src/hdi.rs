@@ -28,6 +28,18 @@ pub trait HdiT: Send + Sync {
         &self,
         must_get_action_input: MustGetActionInput,
     ) -> ExternResult<SignedActionHashed>;
+    /// Defaults to getting each action in turn, for implementations
+    /// written before this method existed.
+    fn must_get_actions(
+        &self,
+        must_get_actions_input: MustGetActionsInput,
+    ) -> ExternResult<Vec<SignedActionHashed>> {
+        must_get_actions_input
+            .into_inner()
+            .into_iter()
+            .map(|action_hash| self.must_get_action(MustGetActionInput::new(action_hash)))
+            .collect()
+    }
     fn must_get_valid_record(
         &self,
         must_get_valid_record_input: MustGetValidRecordInput,
@@ -167,6 +179,15 @@ impl HdiT for HostHdi {
             must_get_action_input,
         )
     }
+    fn must_get_actions(
+        &self,
+        must_get_actions_input: MustGetActionsInput,
+    ) -> ExternResult<Vec<SignedActionHashed>> {
+        host_call::<MustGetActionsInput, Vec<SignedActionHashed>>(
+            __hc__must_get_actions_1,
+            must_get_actions_input,
+        )
+    }
     fn must_get_valid_record(
         &self,
         must_get_valid_record_input: MustGetValidRecordInput,
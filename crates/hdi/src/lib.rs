@@ -181,6 +181,27 @@ pub mod entry;
 
 pub mod hash;
 
+/// Splitting blobs that are too large for a single entry into content-addressed chunks.
+///
+/// A blob is stored as a series of [`blob::BlobChunk`] entries plus one [`blob::BlobManifest`]
+/// entry listing the chunk hashes in order. Chunks are addressed by their content, so identical
+/// chunks are only stored once.
+///
+/// Both types are app entries. To use them add them to the entry types of an integrity zome:
+///
+/// ```ignore
+/// #[hdk_entry_types]
+/// #[unit_enum(UnitEntryTypes)]
+/// pub enum EntryTypes {
+///     BlobChunk(BlobChunk),
+///     BlobManifest(BlobManifest),
+/// }
+/// ```
+///
+/// and check them in the validation callback with [`blob::validate_blob_chunk`] and
+/// [`blob::validate_blob_manifest`].
+pub mod blob;
+
 /// Distributed Hash Tables (DHTs) are fundamentally all key/value stores (content addressable).
 ///
 /// This has lots of benefits but can make discoverability difficult.
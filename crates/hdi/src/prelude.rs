@@ -1,10 +1,12 @@
 #[cfg(feature = "unstable-functions")]
 pub use crate::agent::is_same_agent;
 pub use crate::app_entry;
+pub use crate::blob::*;
 pub use crate::chain::must_get_agent_activity;
 pub use crate::ed25519::verify_signature;
 pub use crate::ed25519::verify_signature_raw;
 pub use crate::entry::must_get_action;
+pub use crate::entry::must_get_actions;
 pub use crate::entry::must_get_entry;
 pub use crate::entry::must_get_valid_record;
 pub use crate::entry_types;
@@ -91,6 +93,7 @@ macro_rules! holochain_externs {
             must_get_entry:1,
             must_get_valid_record:1,
             must_get_action:1,
+            must_get_actions:1,
             must_get_agent_activity:1,
             x_salsa20_poly1305_decrypt:1,
             x_25519_x_salsa20_poly1305_decrypt:1,
@@ -0,0 +1,252 @@
+use crate::prelude::*;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+pub use hdi::blob::*;
+
+/// The number of chunks a [`BlobReader`] fetches with each [`get_many`] by default.
+pub const BLOB_READ_WINDOW_DEFAULT: usize = 8;
+
+/// The hashes of a newly created blob.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlobCreated {
+    /// The action that created the manifest entry.
+    pub manifest_action_hash: ActionHash,
+    /// The manifest entry hash, used to read the blob back.
+    pub manifest_hash: EntryHash,
+}
+
+/// Chunk `data` and commit each distinct chunk and then the manifest.
+///
+/// `chunk` and `manifest` wrap the blob types in the app's entry types, so an
+/// integrity zome with `BlobChunk` and `BlobManifest` variants can pass the
+/// variant constructors directly:
+///
+/// ```ignore
+/// let created = create_blob(
+///     &file_bytes,
+///     None,
+///     Some("image/png".to_string()),
+///     EntryTypes::BlobChunk,
+///     EntryTypes::BlobManifest,
+/// )?;
+/// ```
+///
+/// `chunk_size` defaults to [`BLOB_CHUNK_SIZE_DEFAULT`].
+///
+/// The whole blob is held in memory for the zome call. For large blobs a
+/// client can instead send one chunk per call to [`create_blob_chunk`] and
+/// finish with [`create_blob_manifest`].
+pub fn create_blob<I, E, E2>(
+    data: &[u8],
+    chunk_size: Option<u32>,
+    content_type: Option<String>,
+    chunk: impl Fn(BlobChunk) -> I,
+    manifest: impl Fn(BlobManifest) -> I,
+) -> ExternResult<BlobCreated>
+where
+    ScopedEntryDefIndex: for<'a> TryFrom<&'a I, Error = E2>,
+    EntryVisibility: for<'a> From<&'a I>,
+    Entry: TryFrom<I, Error = E>,
+    WasmError: From<E>,
+    WasmError: From<E2>,
+{
+    let (blob_manifest, chunks) = chunk_blob(
+        data,
+        chunk_size.unwrap_or(BLOB_CHUNK_SIZE_DEFAULT),
+        content_type,
+    )?;
+    let mut created = HashSet::new();
+    for (chunk_hash, blob_chunk) in blob_manifest.chunks.iter().zip(chunks) {
+        if created.insert(chunk_hash.clone()) {
+            create_entry(chunk(blob_chunk))?;
+        }
+    }
+    create_blob_manifest(blob_manifest, manifest)
+}
+
+/// Commit a single chunk of a blob and return its entry hash.
+///
+/// Every chunk but the last must be `chunk_size` bytes long. The returned
+/// hashes, in order, make up the [`BlobManifest::chunks`] that are then
+/// committed with [`create_blob_manifest`].
+pub fn create_blob_chunk<I, E, E2>(
+    data: Vec<u8>,
+    chunk: impl Fn(BlobChunk) -> I,
+) -> ExternResult<EntryHash>
+where
+    ScopedEntryDefIndex: for<'a> TryFrom<&'a I, Error = E2>,
+    EntryVisibility: for<'a> From<&'a I>,
+    Entry: TryFrom<I, Error = E>,
+    WasmError: From<E>,
+    WasmError: From<E2>,
+{
+    let blob_chunk = BlobChunk(data);
+    if let ValidateCallbackResult::Invalid(reason) = validate_blob_chunk(&blob_chunk) {
+        return Err(wasm_error!(WasmErrorInner::Guest(reason)));
+    }
+    let chunk_hash = hash_chunk(&blob_chunk)?;
+    create_entry(chunk(blob_chunk))?;
+    Ok(chunk_hash)
+}
+
+/// Commit the manifest of a blob whose chunks have already been committed.
+///
+/// The manifest is checked with [`validate_blob_manifest`] first so that an
+/// inconsistent manifest is an error here rather than at validation.
+pub fn create_blob_manifest<I, E, E2>(
+    blob_manifest: BlobManifest,
+    manifest: impl Fn(BlobManifest) -> I,
+) -> ExternResult<BlobCreated>
+where
+    ScopedEntryDefIndex: for<'a> TryFrom<&'a I, Error = E2>,
+    EntryVisibility: for<'a> From<&'a I>,
+    Entry: TryFrom<I, Error = E>,
+    WasmError: From<E>,
+    WasmError: From<E2>,
+{
+    if let ValidateCallbackResult::Invalid(reason) = validate_blob_manifest(&blob_manifest) {
+        return Err(wasm_error!(WasmErrorInner::Guest(reason)));
+    }
+    let manifest_hash = hash_manifest(&blob_manifest)?;
+    let manifest_action_hash = create_entry(manifest(blob_manifest))?;
+    Ok(BlobCreated {
+        manifest_action_hash,
+        manifest_hash,
+    })
+}
+
+// Outside the create functions so their entry bounds don't capture the conversion.
+fn hash_manifest(blob_manifest: &BlobManifest) -> ExternResult<EntryHash> {
+    hash_entry(blob_manifest)
+}
+
+fn hash_chunk(blob_chunk: &BlobChunk) -> ExternResult<EntryHash> {
+    hash_entry(blob_chunk)
+}
+
+/// Get the [`BlobManifest`] for a blob.
+pub fn get_blob_manifest(
+    manifest_hash: EntryHash,
+    options: GetOptions,
+) -> ExternResult<Option<BlobManifest>> {
+    get(manifest_hash, options)?
+        .map(BlobManifest::try_from)
+        .transpose()
+}
+
+/// Get a whole blob into memory.
+///
+/// Returns [`None`] if the manifest can't be found and an error if any chunk
+/// can't be. Large blobs are better read chunk by chunk with a [`BlobReader`].
+pub fn get_blob(manifest_hash: EntryHash, options: GetOptions) -> ExternResult<Option<Vec<u8>>> {
+    let blob_manifest = match get_blob_manifest(manifest_hash, options.clone())? {
+        Some(blob_manifest) => blob_manifest,
+        None => return Ok(None),
+    };
+    let mut data = Vec::with_capacity(blob_manifest.size as usize);
+    for chunk in BlobReader::new(blob_manifest, options) {
+        data.extend(chunk?);
+    }
+    Ok(Some(data))
+}
+
+/// Reads a blob one chunk at a time.
+///
+/// Chunks are fetched a window at a time with [`get_many`], which groups the
+/// requests by authority and sends them in parallel, so only one window of
+/// chunks is held in memory at once. Each item is the bytes of the next chunk.
+/// A missing chunk or one with the wrong length is an error.
+///
+/// To page a blob out to a client over several zome calls, start each call's
+/// reader where the last one stopped with [`BlobReader::starting_at`] and
+/// [`Iterator::take`] as many chunks as fit in a response.
+///
+/// ```ignore
+/// let manifest = get_blob_manifest(manifest_hash, GetOptions::network())?.unwrap();
+/// for chunk in BlobReader::new(manifest, GetOptions::network()) {
+///     process(&chunk?);
+/// }
+/// ```
+pub struct BlobReader {
+    manifest: BlobManifest,
+    options: GetOptions,
+    window: usize,
+    next_index: usize,
+    fetched: VecDeque<ExternResult<Vec<u8>>>,
+}
+
+impl BlobReader {
+    /// Read the blob described by `manifest`.
+    pub fn new(manifest: BlobManifest, options: GetOptions) -> Self {
+        Self {
+            manifest,
+            options,
+            window: BLOB_READ_WINDOW_DEFAULT,
+            next_index: 0,
+            fetched: VecDeque::new(),
+        }
+    }
+
+    /// Fetch this many chunks per request instead of [`BLOB_READ_WINDOW_DEFAULT`].
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = std::cmp::max(window, 1);
+        self
+    }
+
+    /// Start reading at the chunk at `index` instead of the first one.
+    pub fn starting_at(mut self, index: usize) -> Self {
+        self.next_index = std::cmp::min(index, self.manifest.chunks.len());
+        self
+    }
+
+    /// The manifest being read.
+    pub fn manifest(&self) -> &BlobManifest {
+        &self.manifest
+    }
+
+    fn fetch_window(&mut self) -> ExternResult<()> {
+        let start = self.next_index;
+        let end = std::cmp::min(start + self.window, self.manifest.chunks.len());
+        let hashes = self.manifest.chunks[start..end].to_vec();
+        let results = get_many(hashes, self.options.clone())?;
+        for (offset, result) in results.into_iter().enumerate() {
+            let index = start + offset;
+            self.fetched.push_back(result.and_then(|maybe_record| {
+                let record = maybe_record.ok_or_else(|| {
+                    wasm_error!(WasmErrorInner::Guest(format!(
+                        "Blob chunk {} at {} could not be found",
+                        index, self.manifest.chunks[index]
+                    )))
+                })?;
+                let chunk = BlobChunk::try_from(record)?;
+                if chunk.0.len() as u64 != self.manifest.chunk_len(index) {
+                    return Err(wasm_error!(WasmErrorInner::Guest(format!(
+                        "Blob chunk {} has {} bytes but the manifest expects {}",
+                        index,
+                        chunk.0.len(),
+                        self.manifest.chunk_len(index)
+                    ))));
+                }
+                Ok(chunk.0)
+            }));
+        }
+        self.next_index = end;
+        Ok(())
+    }
+}
+
+impl Iterator for BlobReader {
+    type Item = ExternResult<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.fetched.is_empty() && self.next_index < self.manifest.chunks.len() {
+            if let Err(e) = self.fetch_window() {
+                // Don't retry a window that failed as a whole.
+                self.next_index = self.manifest.chunks.len();
+                return Some(Err(e));
+            }
+        }
+        self.fetched.pop_front()
+    }
+}
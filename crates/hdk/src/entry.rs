@@ -229,6 +229,75 @@ where
         .unwrap())
 }
 
+/// Get many records at once, for any mix of entry and action hashes.
+///
+/// This behaves like calling [`get`] for every hash, but hashes that have to be
+/// fetched from the network are grouped by authority so that each authority
+/// is sent a single request rather than one request per hash.
+///
+/// The results are in the same order as the hashes passed in. A failure to get
+/// one hash, e.g. because its authorities could not be reached, is returned as
+/// an error for that item only.
+///
+/// ```ignore
+/// let records = get_many(hashes, GetOptions::network())?
+///     .into_iter()
+///     .filter_map(|result| result.ok().flatten())
+///     .collect::<Vec<Record>>();
+/// ```
+pub fn get_many<H>(
+    hashes: Vec<H>,
+    options: GetOptions,
+) -> ExternResult<Vec<ExternResult<Option<Record>>>>
+where
+    AnyDhtHash: From<H>,
+{
+    Ok(HDK
+        .with(|h| {
+            h.borrow().get_many(GetManyInput::new(
+                hashes.into_iter().map(AnyDhtHash::from).collect(),
+                options,
+            ))
+        })?
+        .into_iter()
+        .map(|result| result.map_err(|e| wasm_error!(WasmErrorInner::Host(e))))
+        .collect())
+}
+
+/// Get the latest version of a record by following its updates.
+///
+/// Starting from `action_hash`, this follows the chain of updates to the
+/// action that has not been updated any further. When an action has several
+/// concurrent updates, `tie_break` decides which one to follow. Every
+/// [ `UpdateTieBreak` ] falls back to comparing action hashes, so all agents
+/// that see the same updates resolve the same latest version. Rejected updates
+/// are never followed.
+///
+/// Each update lives with its own authorities, so the walk usually takes one
+/// network request per update in the chain.
+///
+/// If the chosen update can't be fetched the walk stops there rather than
+/// following another update, and [ `LatestRecord::continue_from` ] holds its
+/// hash so the walk can be carried on later.
+///
+/// Returns [`None`] if the starting action can't be found. Deletes don't stop
+/// the walk, use [`get_details`] on the result to check whether the latest
+/// version has been deleted.
+///
+/// ```ignore
+/// let latest = get_latest(post_hash, UpdateTieBreak::Author, GetOptions::network())?;
+/// ```
+pub fn get_latest(
+    action_hash: ActionHash,
+    tie_break: UpdateTieBreak,
+    options: GetOptions,
+) -> ExternResult<Option<LatestRecord>> {
+    HDK.with(|h| {
+        h.borrow()
+            .get_latest(GetLatestInput::new(action_hash, tie_break, options))
+    })
+}
+
 /// Get a record and its details for the entry or action hash passed in.
 /// Returns [`None`] if the entry/action does not exist.
 /// The details returned are a contextual mix of records and action hashes.
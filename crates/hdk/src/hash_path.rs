@@ -40,3 +40,10 @@ pub mod anchor;
 ///
 /// If an application knows `[ A B ]` then a link to `C` will be discoverable.
 pub mod path;
+
+/// A time-bucketed index built on [Path](hdi::prelude::Path).
+///
+/// Hashes are linked from a path per year, month, day and hour below the
+/// index path, so recent items can be paged through without reading
+/// everything ever indexed.
+pub mod time_index;
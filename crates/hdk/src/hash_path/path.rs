@@ -9,22 +9,76 @@ pub trait HdkPathExt {
     fn exists(&self) -> ExternResult<bool>;
 }
 
+/// List the links from `path` to the paths below it without touching anything.
+/// Only one link per tag is returned.
+pub(crate) fn child_links(path: &TypedPath) -> ExternResult<Vec<Link>> {
+    let mut unwrapped = get_links(
+        GetLinksInputBuilder::try_new(
+            path.path_entry_hash()?,
+            LinkTypeFilter::single_type(path.link_type.zome_index, path.link_type.zome_type),
+        )?
+        .build(),
+    )?;
+    // Only need one of each hash to build the tree.
+    unwrapped.sort_unstable_by(|a, b| a.tag.cmp(&b.tag));
+    unwrapped.dedup_by(|a, b| a.tag.eq(&b.tag));
+    Ok(unwrapped)
+}
+
+/// Build the child paths of `path` from the component encoded in each link tag.
+pub(crate) fn child_paths(path: &TypedPath, links: Vec<Link>) -> ExternResult<Vec<TypedPath>> {
+    let components: ExternResult<Vec<Option<Component>>> = links
+        .into_iter()
+        .map(|link| {
+            let component_bytes = &link.tag.0[..];
+            if component_bytes.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(
+                    SerializedBytes::from(UnsafeBytes::from(component_bytes.to_vec()))
+                        .try_into()
+                        .map_err(|e: SerializedBytesError| wasm_error!(e))?,
+                ))
+            }
+        })
+        .collect();
+    Ok(components?
+        .into_iter()
+        .map(|maybe_component| {
+            let mut new_path = path.path.clone();
+            if let Some(component) = maybe_component {
+                new_path.append_component(component);
+            }
+            new_path.into_typed(path.link_type)
+        })
+        .collect())
+}
+
+/// List every path `depth` levels below `path` without touching anything.
+///
+/// Each level is read with one `get_links` per path, so this is how to
+/// enumerate the leaves of a sharded tree. For example all the paths like
+/// `Path::from("foo.1:2#bar")` are found with `path_leaves(&foo, 3)` on the
+/// `foo` path: one level for each shard and one for the full components
+/// themselves. Branches that end before `depth` are not included.
+pub fn path_leaves(path: &TypedPath, depth: usize) -> ExternResult<Vec<TypedPath>> {
+    let mut level = vec![path.clone()];
+    for _ in 0..depth {
+        let mut next = Vec::new();
+        for path in level {
+            next.extend(child_paths(&path, child_links(&path)?)?);
+        }
+        level = next;
+    }
+    Ok(level)
+}
+
 impl HdkPathExt for TypedPath {
     /// Touch and list all the links from this path to paths below it.
     /// Only returns links between paths, not to other entries that might have their own links.
     fn children(&self) -> ExternResult<Vec<holochain_zome_types::link::Link>> {
         Self::ensure(self)?;
-        let mut unwrapped = get_links(
-            GetLinksInputBuilder::try_new(
-                self.path_entry_hash()?,
-                LinkTypeFilter::single_type(self.link_type.zome_index, self.link_type.zome_type),
-            )?
-            .build(),
-        )?;
-        // Only need one of each hash to build the tree.
-        unwrapped.sort_unstable_by(|a, b| a.tag.cmp(&b.tag));
-        unwrapped.dedup_by(|a, b| a.tag.eq(&b.tag));
-        Ok(unwrapped)
+        child_links(self)
     }
 
     /// Touch and list all the links from this path to paths below it.
@@ -35,32 +89,7 @@ impl HdkPathExt for TypedPath {
     /// the parent path + child link must be combined, which this function does
     /// to produce each child, by using `&self` as that parent.
     fn children_paths(&self) -> ExternResult<Vec<TypedPath>> {
-        let children = self.children()?;
-        let components: ExternResult<Vec<Option<Component>>> = children
-            .into_iter()
-            .map(|link| {
-                let component_bytes = &link.tag.0[..];
-                if component_bytes.is_empty() {
-                    Ok(None)
-                } else {
-                    Ok(Some(
-                        SerializedBytes::from(UnsafeBytes::from(component_bytes.to_vec()))
-                            .try_into()
-                            .map_err(|e: SerializedBytesError| wasm_error!(e))?,
-                    ))
-                }
-            })
-            .collect();
-        Ok(components?
-            .into_iter()
-            .map(|maybe_component| {
-                let mut new_path = self.path.clone();
-                if let Some(component) = maybe_component {
-                    new_path.append_component(component);
-                }
-                new_path.into_typed(self.link_type)
-            })
-            .collect())
+        child_paths(self, self.children()?)
     }
 
     fn children_details(&self) -> ExternResult<holochain_zome_types::link::LinkDetails> {
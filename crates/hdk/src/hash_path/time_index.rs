@@ -0,0 +1,361 @@
+use crate::hash_path::path::child_links;
+use crate::hash_path::path::child_paths;
+use crate::prelude::*;
+use hdi::hash_path::path::Component;
+
+const MICROS_PER_HOUR: i64 = 60 * 60 * 1_000_000;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+
+/// The bytes of the timestamp at the start of every index link tag.
+const TIMESTAMP_TAG_LEN: usize = std::mem::size_of::<i64>();
+
+/// How finely a [`TimeIndex`] buckets its links.
+///
+/// Each level adds one component under the index path, so an hourly index
+/// links an entry from `<index>.2024.03.07.15`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TimeIndexGranularity {
+    /// One bucket per year.
+    Year,
+    /// One bucket per month.
+    Month,
+    /// One bucket per day.
+    Day,
+    /// One bucket per hour.
+    #[default]
+    Hour,
+}
+
+impl TimeIndexGranularity {
+    fn depth(&self) -> usize {
+        match self {
+            Self::Year => 1,
+            Self::Month => 2,
+            Self::Day => 3,
+            Self::Hour => 4,
+        }
+    }
+}
+
+/// Something linked from a [`TimeIndex`].
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TimeIndexItem {
+    /// The time it was indexed at.
+    pub timestamp: Timestamp,
+    /// The indexed hash.
+    pub target: AnyLinkableHash,
+    /// The tag passed to [`TimeIndex::index`].
+    pub tag: LinkTag,
+    /// The agent that indexed it.
+    pub author: AgentPubKey,
+    /// The hash of the link from the bucket, which is needed to delete it.
+    pub create_link_hash: ActionHash,
+}
+
+/// An index of hashes by time, built on [`Path`].
+///
+/// Hashes are linked from a bucket path below the index path, one component
+/// per year, month, day and hour down to the [`TimeIndexGranularity`] of the
+/// index. Only the buckets that have something in them exist, so a range is
+/// read by walking the buckets that overlap it instead of every possible one.
+///
+/// ```ignore
+/// let index = TimeIndex::new(Path::from("posts").typed(LinkTypes::Posts)?);
+/// index.index(post_hash, sys_time()?, ())?;
+/// let newest = index.get_range(Timestamp::from_micros(0), sys_time()?, 20)?;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeIndex {
+    path: TypedPath,
+    granularity: TimeIndexGranularity,
+}
+
+impl TimeIndex {
+    /// An hourly index under `path`.
+    /// Every link is created with the link type of `path`.
+    pub fn new(path: TypedPath) -> Self {
+        Self {
+            path,
+            granularity: TimeIndexGranularity::default(),
+        }
+    }
+
+    /// Use buckets of this size instead of hours.
+    ///
+    /// The granularity is part of every bucket path, so it can't be changed
+    /// once anything has been indexed.
+    pub fn with_granularity(mut self, granularity: TimeIndexGranularity) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    /// The path under which the index buckets are.
+    pub fn path(&self) -> &TypedPath {
+        &self.path
+    }
+
+    /// The bucket path that `timestamp` is indexed under.
+    pub fn bucket(&self, timestamp: Timestamp) -> TypedPath {
+        let mut path = self.path.path.clone();
+        for component in bucket_components(timestamp)
+            .into_iter()
+            .take(self.granularity.depth())
+        {
+            path.append_component(Component::from(component));
+        }
+        path.into_typed(self.path.link_type)
+    }
+
+    /// Link `target` from the bucket for `timestamp`, creating the bucket path
+    /// if it doesn't exist yet.
+    ///
+    /// The timestamp is stored at the start of the link tag, so the time the
+    /// target is indexed at doesn't have to be the time of the link.
+    pub fn index(
+        &self,
+        target: impl Into<AnyLinkableHash>,
+        timestamp: Timestamp,
+        tag: impl Into<LinkTag>,
+    ) -> ExternResult<ActionHash> {
+        let bucket = self.bucket(timestamp);
+        bucket.ensure()?;
+        let mut tag_bytes = timestamp.as_micros().to_be_bytes().to_vec();
+        tag_bytes.extend(tag.into().into_inner());
+        create_link(
+            bucket.path_entry_hash()?,
+            target,
+            self.path.link_type,
+            LinkTag::new(tag_bytes),
+        )
+    }
+
+    /// Get up to `limit` items indexed in `[from, until)`, newest first.
+    ///
+    /// Items indexed at the same time are never split across pages, so a page
+    /// can be longer than `limit`. The next page is therefore the range up to
+    /// the timestamp of the last item.
+    pub fn get_range(
+        &self,
+        from: Timestamp,
+        until: Timestamp,
+        limit: usize,
+    ) -> ExternResult<Vec<TimeIndexItem>> {
+        let mut items = Vec::new();
+        if from < until && limit > 0 {
+            self.walk(
+                &self.path,
+                &mut Vec::new(),
+                from.as_micros(),
+                until.as_micros(),
+                limit,
+                &mut items,
+            )?;
+        }
+        if items.len() > limit {
+            let last = items[limit - 1].timestamp;
+            items.retain(|item| item.timestamp >= last);
+        }
+        Ok(items)
+    }
+
+    /// Collect the items in the buckets below `path` that overlap the range,
+    /// newest bucket first, until there are at least `limit` of them.
+    fn walk(
+        &self,
+        path: &TypedPath,
+        bucket: &mut Vec<i64>,
+        from: i64,
+        until: i64,
+        limit: usize,
+        items: &mut Vec<TimeIndexItem>,
+    ) -> ExternResult<()> {
+        if bucket.len() == self.granularity.depth() {
+            let mut found = self.bucket_items(path, from, until)?;
+            found.sort_by(|a, b| {
+                b.timestamp
+                    .cmp(&a.timestamp)
+                    .then_with(|| a.create_link_hash.cmp(&b.create_link_hash))
+            });
+            items.extend(found);
+            return Ok(());
+        }
+        let mut children = Vec::new();
+        for child in child_paths(path, child_links(path)?)? {
+            // Anything that isn't a number isn't a bucket.
+            let value = match child
+                .leaf()
+                .and_then(|component| String::try_from(component).ok())
+                .and_then(|s| s.parse::<i64>().ok())
+            {
+                Some(value) => value,
+                None => continue,
+            };
+            bucket.push(value);
+            let (start, end) = bucket_span(bucket);
+            bucket.pop();
+            if start < until && from < end {
+                children.push((value, child));
+            }
+        }
+        children.sort_by(|a, b| b.0.cmp(&a.0));
+        for (value, child) in children {
+            if items.len() >= limit {
+                break;
+            }
+            bucket.push(value);
+            let result = self.walk(&child, bucket, from, until, limit, items);
+            bucket.pop();
+            result?;
+        }
+        Ok(())
+    }
+
+    fn bucket_items(
+        &self,
+        bucket: &TypedPath,
+        from: i64,
+        until: i64,
+    ) -> ExternResult<Vec<TimeIndexItem>> {
+        let links = get_links(
+            GetLinksInputBuilder::try_new(
+                bucket.path_entry_hash()?,
+                LinkTypeFilter::single_type(
+                    self.path.link_type.zome_index,
+                    self.path.link_type.zome_type,
+                ),
+            )?
+            .build(),
+        )?;
+        Ok(links
+            .into_iter()
+            .filter_map(|link| {
+                let tag = link.tag.into_inner();
+                if tag.len() < TIMESTAMP_TAG_LEN {
+                    return None;
+                }
+                let (micros, tag) = tag.split_at(TIMESTAMP_TAG_LEN);
+                let micros = i64::from_be_bytes(micros.try_into().ok()?);
+                (from..until).contains(&micros).then(|| TimeIndexItem {
+                    timestamp: Timestamp::from_micros(micros),
+                    target: link.target,
+                    tag: LinkTag::new(tag),
+                    author: link.author,
+                    create_link_hash: link.create_link_hash,
+                })
+            })
+            .collect())
+    }
+}
+
+/// The year, month, day and hour of a timestamp in UTC, as path components.
+fn bucket_components(timestamp: Timestamp) -> [String; 4] {
+    let micros = timestamp.as_micros();
+    let (year, month, day) = civil_from_days(micros.div_euclid(MICROS_PER_DAY));
+    let hour = micros.rem_euclid(MICROS_PER_DAY) / MICROS_PER_HOUR;
+    [
+        format!("{:04}", year),
+        format!("{:02}", month),
+        format!("{:02}", day),
+        format!("{:02}", hour),
+    ]
+}
+
+/// The `[start, end)` micros of the bucket with these year, month, day and
+/// hour values, as many of them as the bucket has.
+fn bucket_span(bucket: &[i64]) -> (i64, i64) {
+    let start_of = |year: i64, month: i64, day: i64| {
+        days_from_civil(year, month, day).saturating_mul(MICROS_PER_DAY)
+    };
+    match *bucket {
+        [year] => (start_of(year, 1, 1), start_of(year + 1, 1, 1)),
+        [year, month] => {
+            let (next_year, next_month) = if month >= 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+            (start_of(year, month, 1), start_of(next_year, next_month, 1))
+        }
+        [year, month, day] => {
+            let start = start_of(year, month, day);
+            (start, start.saturating_add(MICROS_PER_DAY))
+        }
+        [year, month, day, hour, ..] => {
+            let start =
+                start_of(year, month, day).saturating_add(hour.saturating_mul(MICROS_PER_HOUR));
+            (start, start.saturating_add(MICROS_PER_HOUR))
+        }
+        [] => (i64::MIN, i64::MAX),
+    }
+}
+
+/// The year, month and day of a count of days since 1970-01-01.
+///
+/// Howard Hinnant's `civil_from_days`, which is exact for the proleptic
+/// Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// The count of days since 1970-01-01 of a year, month and day.
+///
+/// Howard Hinnant's `days_from_civil`, the inverse of [`civil_from_days`].
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_days_round_trip() {
+        assert_eq!((1970, 1, 1), civil_from_days(0));
+        assert_eq!((1969, 12, 31), civil_from_days(-1));
+        assert_eq!((2000, 3, 1), civil_from_days(11_017));
+        assert_eq!(19_782, days_from_civil(2024, 2, 29));
+        for days in -800_000..800_000 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days, days_from_civil(year, month, day));
+        }
+    }
+
+    #[test]
+    fn bucket_components_are_utc_calendar_fields() {
+        // 2024-03-07T15:04:05Z
+        let timestamp = Timestamp::from_micros(1_709_823_845_000_000);
+        assert_eq!(["2024", "03", "07", "15"], bucket_components(timestamp),);
+        let micros = timestamp.as_micros();
+        for depth in 1..=4 {
+            let values: Vec<i64> = bucket_components(timestamp)
+                .iter()
+                .take(depth)
+                .map(|s| s.parse().unwrap())
+                .collect();
+            let (start, end) = bucket_span(&values);
+            assert!(start <= micros && micros < end);
+        }
+        assert_eq!(
+            (
+                days_from_civil(2024, 12, 1) * MICROS_PER_DAY,
+                days_from_civil(2025, 1, 1) * MICROS_PER_DAY
+            ),
+            bucket_span(&[2024, 12]),
+        );
+    }
+}
@@ -36,6 +36,8 @@ pub trait HdkT: HdiT {
     fn delete(&self, delete_input: DeleteInput) -> ExternResult<ActionHash>;
     fn get(&self, get_input: Vec<GetInput>) -> ExternResult<Vec<Option<Record>>>;
     fn get_details(&self, get_input: Vec<GetInput>) -> ExternResult<Vec<Option<Details>>>;
+    fn get_many(&self, get_many_input: GetManyInput) -> ExternResult<Vec<GetManyResult>>;
+    fn get_latest(&self, get_latest_input: GetLatestInput) -> ExternResult<Option<LatestRecord>>;
     // CounterSigning
     #[cfg(feature = "unstable-countersigning")]
     fn accept_countersigning_preflight_request(
@@ -63,14 +65,28 @@ pub trait HdkT: HdiT {
     #[cfg(feature = "unstable-functions")]
     fn unblock_agent(&self, unblock_agent_input: BlockAgentInput) -> ExternResult<()>;
     fn call(&self, call: Vec<Call>) -> ExternResult<Vec<ZomeCallResponse>>;
+    fn call_remote_many(
+        &self,
+        call_remote_many_input: CallRemoteManyInput,
+    ) -> ExternResult<CallRemoteManyOutput>;
     fn emit_signal(&self, app_signal: AppSignal) -> ExternResult<()>;
     fn send_remote_signal(&self, remote_signal: RemoteSignal) -> ExternResult<()>;
+    fn send_remote_signal_with_ack(
+        &self,
+        remote_signal: RemoteSignalWithAck,
+    ) -> ExternResult<RemoteSignalDelivery>;
     // Random
     fn random_bytes(&self, number_of_bytes: u32) -> ExternResult<Bytes>;
     // Time
     fn sys_time(&self, sys_time_input: ()) -> ExternResult<Timestamp>;
     #[cfg(feature = "unstable-functions")]
     fn schedule(&self, scheduled_fn: String) -> ExternResult<()>;
+    #[cfg(feature = "unstable-functions")]
+    fn schedule_with_payload(&self, input: ScheduleWithPayloadInput) -> ExternResult<ScheduleId>;
+    #[cfg(feature = "unstable-functions")]
+    fn cancel_schedule(&self, schedule_id: ScheduleId) -> ExternResult<()>;
+    #[cfg(feature = "unstable-functions")]
+    fn list_schedules(&self, list_schedules_input: ()) -> ExternResult<Vec<PendingSchedule>>;
     // XSalsa20Poly1305
     fn x_salsa20_poly1305_shared_secret_create_random(
         &self,
@@ -132,6 +148,8 @@ mockall::mock! {
         fn delete(&self, delete_input: DeleteInput) -> ExternResult<ActionHash>;
         fn get(&self, get_input: Vec<GetInput>) -> ExternResult<Vec<Option<Record>>>;
         fn get_details(&self, get_input: Vec<GetInput>) -> ExternResult<Vec<Option<Details>>>;
+        fn get_many(&self, get_many_input: GetManyInput) -> ExternResult<Vec<GetManyResult>>;
+        fn get_latest(&self, get_latest_input: GetLatestInput) -> ExternResult<Option<LatestRecord>>;
         // CounterSigning
         fn accept_countersigning_preflight_request(
             &self,
@@ -153,13 +171,27 @@ mockall::mock! {
         fn block_agent(&self, block_agent_input: BlockAgentInput) -> ExternResult<()>;
         fn unblock_agent(&self, unblock_agent_input: BlockAgentInput) -> ExternResult<()>;
         fn call(&self, call: Vec<Call>) -> ExternResult<Vec<ZomeCallResponse>>;
+        fn call_remote_many(
+            &self,
+            call_remote_many_input: CallRemoteManyInput,
+        ) -> ExternResult<CallRemoteManyOutput>;
         fn emit_signal(&self, app_signal: AppSignal) -> ExternResult<()>;
         fn send_remote_signal(&self, remote_signal: RemoteSignal) -> ExternResult<()>;
+        fn send_remote_signal_with_ack(
+            &self,
+            remote_signal: RemoteSignalWithAck,
+        ) -> ExternResult<RemoteSignalDelivery>;
         // Random
         fn random_bytes(&self, number_of_bytes: u32) -> ExternResult<Bytes>;
         // Time
         fn sys_time(&self, sys_time_input: ()) -> ExternResult<Timestamp>;
         fn schedule(&self, scheduled_fn: String) -> ExternResult<()>;
+        fn schedule_with_payload(
+            &self,
+            input: ScheduleWithPayloadInput,
+        ) -> ExternResult<ScheduleId>;
+        fn cancel_schedule(&self, schedule_id: ScheduleId) -> ExternResult<()>;
+        fn list_schedules(&self, list_schedules_input: ()) -> ExternResult<Vec<PendingSchedule>>;
         // XSalsa20Poly1305
         fn x_salsa20_poly1305_shared_secret_create_random(
             &self,
@@ -204,6 +236,10 @@ mockall::mock! {
             &self,
             must_get_action_input: MustGetActionInput,
         ) -> ExternResult<SignedActionHashed>;
+        fn must_get_actions(
+            &self,
+            must_get_actions_input: MustGetActionsInput,
+        ) -> ExternResult<Vec<SignedActionHashed>>;
         fn must_get_valid_record(
             &self,
             must_get_valid_record_input: MustGetValidRecordInput,
@@ -353,6 +389,12 @@ impl HdkT for ErrHdk {
     fn get_details(&self, _: Vec<GetInput>) -> ExternResult<Vec<Option<Details>>> {
         Self::err()
     }
+    fn get_many(&self, _: GetManyInput) -> ExternResult<Vec<GetManyResult>> {
+        Self::err()
+    }
+    fn get_latest(&self, _: GetLatestInput) -> ExternResult<Option<LatestRecord>> {
+        Self::err()
+    }
     // CounterSigning
     #[cfg(feature = "unstable-countersigning")]
     fn accept_countersigning_preflight_request(
@@ -400,12 +442,21 @@ impl HdkT for ErrHdk {
     fn call(&self, _: Vec<Call>) -> ExternResult<Vec<ZomeCallResponse>> {
         Self::err()
     }
+    fn call_remote_many(&self, _: CallRemoteManyInput) -> ExternResult<CallRemoteManyOutput> {
+        Self::err()
+    }
     fn emit_signal(&self, _: AppSignal) -> ExternResult<()> {
         Self::err()
     }
     fn send_remote_signal(&self, _: RemoteSignal) -> ExternResult<()> {
         Self::err()
     }
+    fn send_remote_signal_with_ack(
+        &self,
+        _: RemoteSignalWithAck,
+    ) -> ExternResult<RemoteSignalDelivery> {
+        Self::err()
+    }
     // Random
     fn random_bytes(&self, _: u32) -> ExternResult<Bytes> {
         Self::err()
@@ -418,6 +469,18 @@ impl HdkT for ErrHdk {
     fn schedule(&self, _: String) -> ExternResult<()> {
         Self::err()
     }
+    #[cfg(feature = "unstable-functions")]
+    fn schedule_with_payload(&self, _: ScheduleWithPayloadInput) -> ExternResult<ScheduleId> {
+        Self::err()
+    }
+    #[cfg(feature = "unstable-functions")]
+    fn cancel_schedule(&self, _: ScheduleId) -> ExternResult<()> {
+        Self::err()
+    }
+    #[cfg(feature = "unstable-functions")]
+    fn list_schedules(&self, _: ()) -> ExternResult<Vec<PendingSchedule>> {
+        Self::err()
+    }
 
     // XSalsa20Poly1305
     fn x_salsa20_poly1305_shared_secret_create_random(
@@ -527,6 +590,12 @@ impl HdiT for HostHdk {
     ) -> ExternResult<SignedActionHashed> {
         HostHdi::new().must_get_action(must_get_action_input)
     }
+    fn must_get_actions(
+        &self,
+        must_get_actions_input: MustGetActionsInput,
+    ) -> ExternResult<Vec<SignedActionHashed>> {
+        HostHdi::new().must_get_actions(must_get_actions_input)
+    }
     fn must_get_valid_record(
         &self,
         must_get_valid_record_input: MustGetValidRecordInput,
@@ -611,6 +680,12 @@ impl HdkT for HostHdk {
     fn get_details(&self, get_inputs: Vec<GetInput>) -> ExternResult<Vec<Option<Details>>> {
         host_call::<Vec<GetInput>, Vec<Option<Details>>>(__hc__get_details_1, get_inputs)
     }
+    fn get_many(&self, get_many_input: GetManyInput) -> ExternResult<Vec<GetManyResult>> {
+        host_call::<GetManyInput, Vec<GetManyResult>>(__hc__get_many_1, get_many_input)
+    }
+    fn get_latest(&self, get_latest_input: GetLatestInput) -> ExternResult<Option<LatestRecord>> {
+        host_call::<GetLatestInput, Option<LatestRecord>>(__hc__get_latest_1, get_latest_input)
+    }
     #[cfg(feature = "unstable-countersigning")]
     // CounterSigning
     fn accept_countersigning_preflight_request(
@@ -662,12 +737,30 @@ impl HdkT for HostHdk {
     fn call(&self, call_input: Vec<Call>) -> ExternResult<Vec<ZomeCallResponse>> {
         host_call::<Vec<Call>, Vec<ZomeCallResponse>>(__hc__call_1, call_input)
     }
+    fn call_remote_many(
+        &self,
+        call_remote_many_input: CallRemoteManyInput,
+    ) -> ExternResult<CallRemoteManyOutput> {
+        host_call::<CallRemoteManyInput, CallRemoteManyOutput>(
+            __hc__call_remote_many_1,
+            call_remote_many_input,
+        )
+    }
     fn emit_signal(&self, app_signal: AppSignal) -> ExternResult<()> {
         host_call::<AppSignal, ()>(__hc__emit_signal_1, app_signal)
     }
     fn send_remote_signal(&self, remote_signal: RemoteSignal) -> ExternResult<()> {
         host_call::<RemoteSignal, ()>(__hc__send_remote_signal_1, remote_signal)
     }
+    fn send_remote_signal_with_ack(
+        &self,
+        remote_signal: RemoteSignalWithAck,
+    ) -> ExternResult<RemoteSignalDelivery> {
+        host_call::<RemoteSignalWithAck, RemoteSignalDelivery>(
+            __hc__send_remote_signal_with_ack_1,
+            remote_signal,
+        )
+    }
     fn random_bytes(&self, number_of_bytes: u32) -> ExternResult<Bytes> {
         host_call::<u32, Bytes>(__hc__random_bytes_1, number_of_bytes)
     }
@@ -678,6 +771,18 @@ impl HdkT for HostHdk {
     fn schedule(&self, scheduled_fn: String) -> ExternResult<()> {
         host_call::<String, ()>(__hc__schedule_1, scheduled_fn)
     }
+    #[cfg(feature = "unstable-functions")]
+    fn schedule_with_payload(&self, input: ScheduleWithPayloadInput) -> ExternResult<ScheduleId> {
+        host_call::<ScheduleWithPayloadInput, ScheduleId>(__hc__schedule_with_payload_1, input)
+    }
+    #[cfg(feature = "unstable-functions")]
+    fn cancel_schedule(&self, schedule_id: ScheduleId) -> ExternResult<()> {
+        host_call::<ScheduleId, ()>(__hc__cancel_schedule_1, schedule_id)
+    }
+    #[cfg(feature = "unstable-functions")]
+    fn list_schedules(&self, _: ()) -> ExternResult<Vec<PendingSchedule>> {
+        host_call::<(), Vec<PendingSchedule>>(__hc__list_schedules_1, ())
+    }
 
     fn x_salsa20_poly1305_shared_secret_create_random(
         &self,
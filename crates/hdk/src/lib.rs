@@ -402,6 +402,14 @@ pub mod entry;
 pub use hdi;
 pub use hdi::entry_types;
 
+/// Storing and reading blobs that are too large for a single entry.
+///
+/// Blobs are split into content-addressed chunk entries and a manifest entry, see
+/// [`hdi::blob`] for the entry types and validation helpers. This module adds
+/// [`blob::create_blob`] to commit a blob and [`blob::BlobReader`] to stream it back,
+/// fetching chunks from many authorities in parallel.
+pub mod blob;
+
 pub mod hash;
 
 pub mod hash_path;
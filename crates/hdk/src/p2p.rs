@@ -95,6 +95,67 @@ where
         .unwrap())
 }
 
+/// Call the same remote function on several agents concurrently.
+///
+/// This is [ `call_remote` ] fanned out to every agent in `agents` at once,
+/// which is much faster than calling each agent in turn when polling peers for
+/// presence, votes and the like.
+///
+/// - agents: The agents to call. Duplicates are only called once. At most
+///   [ `CALL_REMOTE_MANY_MAX_AGENTS` ] agents can be called at once.
+/// - zome: The zome to call the remote function in.
+/// - fn_name: The name of the function in the zome to call.
+/// - cap_secret: Optional cap claim secret, the same secret is sent to every agent.
+/// - payload: The payload to send to the remote function.
+/// - timeout: How long to wait for responses in total, from when every call
+///   has been signed.
+/// - min_responses: Return as soon as this many agents have responded with
+///   [ `ZomeCallResponse::Ok` ]. `None` waits for every agent or the timeout.
+///
+/// The result has an entry for every agent. Agents that answered, or that the
+/// network failed to reach, have a [ `RemoteCallResult::Response` ]. Agents
+/// still outstanding when the call returns are [ `RemoteCallResult::TimedOut` ]
+/// if the quorum was not met, or [ `RemoteCallResult::NotAwaited` ] if it was.
+///
+/// ```ignore
+/// ...
+/// let results = call_remote_many(
+///     peers,
+///     zome_info()?.name,
+///     "vote".into(),
+///     None,
+///     &proposal,
+///     std::time::Duration::from_secs(5),
+///     Some(quorum),
+/// )?;
+/// ...
+/// ```
+pub fn call_remote_many<I, Z>(
+    agents: Vec<AgentPubKey>,
+    zome: Z,
+    fn_name: FunctionName,
+    cap_secret: Option<CapSecret>,
+    payload: I,
+    timeout: std::time::Duration,
+    min_responses: Option<usize>,
+) -> ExternResult<CallRemoteManyOutput>
+where
+    I: serde::Serialize + std::fmt::Debug,
+    Z: Into<ZomeName>,
+{
+    HDK.with(|h| {
+        h.borrow().call_remote_many(CallRemoteManyInput::new(
+            agents,
+            zome.into(),
+            fn_name,
+            cap_secret,
+            ExternIO::encode(payload).map_err(|e| wasm_error!(e))?,
+            timeout,
+            min_responses,
+        ))
+    })
+}
+
 /// Emit an app-defined Signal.
 ///
 /// Only clients who have subscribed to signals from this Cell with the proper
@@ -156,3 +217,38 @@ where
         })
     })
 }
+
+/// ## Remote Signal With Acknowledgement
+/// Send a signal to a list of other agents and wait for their conductors to
+/// acknowledge it.
+///
+/// Unlike [ `send_remote_signal` ] this blocks until every agent has
+/// acknowledged the signal or `retry_for` has elapsed. Agents that can't be
+/// reached, e.g. because their connection dropped, are retried with a backoff
+/// until then. The host caps `retry_for` at 30 seconds.
+///
+/// Every attempt carries the same signal id and each recipient's conductor
+/// only delivers a given id once, so `recv_remote_signal` is not called twice
+/// when an acknowledgement is lost and the signal is retried.
+///
+/// The returned [ `RemoteSignalDelivery` ] lists which agents acknowledged the
+/// signal and which did not.
+///
+/// The recipient side needs the same `recv_remote_signal` function and cap
+/// grant as [ `send_remote_signal` ].
+pub fn send_remote_signal_with_ack<I>(
+    input: I,
+    agents: Vec<AgentPubKey>,
+    retry_for: std::time::Duration,
+) -> ExternResult<RemoteSignalDelivery>
+where
+    I: serde::Serialize + std::fmt::Debug,
+{
+    HDK.with(|h| {
+        h.borrow().send_remote_signal_with_ack(RemoteSignalWithAck {
+            signal: ExternIO::encode(input).map_err(|e| wasm_error!(e))?,
+            agents,
+            retry_for,
+        })
+    })
+}
@@ -1,3 +1,9 @@
+pub use crate::blob::create_blob;
+pub use crate::blob::create_blob_chunk;
+pub use crate::blob::create_blob_manifest;
+pub use crate::blob::get_blob;
+pub use crate::blob::get_blob_manifest;
+pub use crate::blob::BlobReader;
 pub use crate::capability::create_cap_claim;
 pub use crate::capability::create_cap_grant;
 pub use crate::capability::delete_cap_grant;
@@ -21,7 +27,10 @@ pub use crate::entry::delete;
 pub use crate::entry::delete_entry;
 pub use crate::entry::get;
 pub use crate::entry::get_details;
+pub use crate::entry::get_latest;
+pub use crate::entry::get_many;
 pub use crate::entry::must_get_action;
+pub use crate::entry::must_get_actions;
 pub use crate::entry::must_get_entry;
 pub use crate::entry::must_get_valid_record;
 pub use crate::entry::update;
@@ -34,7 +43,11 @@ pub use crate::hash_path::anchor::list_anchor_addresses;
 pub use crate::hash_path::anchor::list_anchor_tags;
 pub use crate::hash_path::anchor::list_anchor_type_addresses;
 pub use crate::hash_path::anchor::TryFromPath;
+pub use crate::hash_path::path::path_leaves;
 pub use crate::hash_path::path::HdkPathExt;
+pub use crate::hash_path::time_index::TimeIndex;
+pub use crate::hash_path::time_index::TimeIndexGranularity;
+pub use crate::hash_path::time_index::TimeIndexItem;
 pub use crate::hdk::*;
 pub use crate::info::agent_info;
 pub use crate::info::call_info;
@@ -52,8 +65,10 @@ pub use crate::map_extern::ExternResult;
 pub use crate::migrate::*;
 pub use crate::p2p::call;
 pub use crate::p2p::call_remote;
+pub use crate::p2p::call_remote_many;
 pub use crate::p2p::emit_signal;
 pub use crate::p2p::send_remote_signal;
+pub use crate::p2p::send_remote_signal_with_ack;
 pub use crate::random::*;
 pub use crate::time::sys_time;
 pub use crate::validation_receipt::get_validation_receipts;
@@ -72,6 +87,8 @@ pub use hdi::map_extern_infallible;
 pub use hdi::op::OpHelper;
 pub use hdi::prelude::app_entry;
 pub use hdi::prelude::Anchor;
+pub use hdi::prelude::BlobChunk;
+pub use hdi::prelude::BlobManifest;
 pub use hdi::prelude::Component;
 pub use hdi::prelude::Path;
 pub use hdi::prelude::ShardStrategy;
@@ -111,8 +128,14 @@ pub use tracing::{debug, error, info, instrument, trace, warn};
 #[cfg(feature = "unstable-countersigning")]
 pub use crate::countersigning::accept_countersigning_preflight_request;
 
+#[cfg(feature = "unstable-functions")]
+pub use crate::time::cancel_schedule;
+#[cfg(feature = "unstable-functions")]
+pub use crate::time::list_schedules;
 #[cfg(feature = "unstable-functions")]
 pub use crate::time::schedule;
+#[cfg(feature = "unstable-functions")]
+pub use crate::time::schedule_with_payload;
 
 #[cfg(feature = "mock")]
 pub use mockall;
@@ -142,6 +165,8 @@ macro_rules! holochain_externs {
             capability_info:1,
             get:1,
             get_details:1,
+            get_many:1,
+            get_latest:1,
             get_links:1,
             get_link_details:1,
             count_links:1,
@@ -149,12 +174,15 @@ macro_rules! holochain_externs {
             must_get_entry:1,
             must_get_valid_record:1,
             must_get_action:1,
+            must_get_actions:1,
             query:1,
             call_remote:1,
+            call_remote_many:1,
             call:1,
             create:1,
             emit_signal:1,
             send_remote_signal:1,
+            send_remote_signal_with_ack:1,
             create_link:1,
             delete_link:1,
             update:1,
@@ -188,7 +216,10 @@ macro_rules! holochain_externs {
             get_agent_key_lineage:1,
             block_agent:1,
             unblock_agent:1,
-            schedule:1
+            schedule:1,
+            schedule_with_payload:1,
+            cancel_schedule:1,
+            list_schedules:1
         );
     };
 }
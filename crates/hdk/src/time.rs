@@ -135,3 +135,49 @@ pub fn sys_time() -> ExternResult<Timestamp> {
 pub fn schedule(scheduled_fn: &str) -> ExternResult<()> {
     HDK.with(|h| h.borrow().schedule(String::from(scheduled_fn)))
 }
+
+/// Adds a function from the current zome to the scheduler along with a payload.
+///
+/// Unlike [`schedule`] this is not idempotent. Every call creates a new
+/// schedule, so the same function can be scheduled many times with different
+/// payloads. The returned [`ScheduleId`] can be passed to [`cancel_schedule`].
+///
+/// The scheduled function receives a [`ScheduledFnInput`] instead of an
+/// `Option<Schedule>`, and returns the next schedule exactly as for [`schedule`].
+///
+/// ```ignore
+/// #[hdk_extern(infallible)]
+/// fn remind(input: ScheduledFnInput) -> Option<Schedule> {
+///   let message: String = input.payload.decode().ok()?;
+///   ...
+///   None
+/// }
+/// ```
+#[cfg(feature = "unstable-functions")]
+pub fn schedule_with_payload<I>(scheduled_fn: &str, payload: I) -> ExternResult<ScheduleId>
+where
+    I: serde::Serialize + std::fmt::Debug,
+{
+    HDK.with(|h| {
+        h.borrow().schedule_with_payload(ScheduleWithPayloadInput {
+            fn_name: scheduled_fn.into(),
+            payload: ExternIO::encode(payload).map_err(|e| wasm_error!(e))?,
+        })
+    })
+}
+
+/// Removes a schedule from the scheduler.
+///
+/// Like scheduling, the cancellation only takes effect once the current zome
+/// call commits. Cancelling a schedule that does not exist is a noop.
+#[cfg(feature = "unstable-functions")]
+pub fn cancel_schedule(schedule_id: ScheduleId) -> ExternResult<()> {
+    HDK.with(|h| h.borrow().cancel_schedule(schedule_id))
+}
+
+/// Lists the schedules of the current zome that have not yet completed or
+/// been cancelled.
+#[cfg(feature = "unstable-functions")]
+pub fn list_schedules() -> ExternResult<Vec<PendingSchedule>> {
+    HDK.with(|h| h.borrow().list_schedules(()))
+}
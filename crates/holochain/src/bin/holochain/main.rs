@@ -59,6 +59,12 @@ struct Opt {
     /// directly to manipulate holochain databases.
     #[structopt(long)]
     pub danger_print_db_secrets: bool,
+
+    /// Export spans to the OpenTelemetry collector at this url,
+    /// e.g. `http://localhost:4317`, over OTLP.
+    #[cfg(feature = "opentelemetry-on")]
+    #[structopt(long)]
+    pub otlp_endpoint: Option<String>,
 }
 
 fn main() {
@@ -87,14 +93,24 @@ async fn async_main() {
         std::env::set_var("CUSTOM_FILTER", t);
     }
 
+    #[cfg(feature = "opentelemetry-on")]
+    if opt.otlp_endpoint.is_some() {
+        holochain_trace::init_open_telemetry(holochain_trace::Config {
+            collector_endpoint: opt.otlp_endpoint.clone(),
+            ..Default::default()
+        })
+        .expect("Failed to start OpenTelemetry span export");
+    } else {
+        holochain_trace::init_fmt(opt.structured.clone())
+            .expect("Failed to start contextual logging");
+    }
+    #[cfg(not(feature = "opentelemetry-on"))]
     holochain_trace::init_fmt(opt.structured.clone()).expect("Failed to start contextual logging");
     debug!("holochain_trace initialized");
 
     let data_root_path: DataRootPath = config.data_root_path_or_die();
 
-    holochain_metrics::HolochainMetricsConfig::new(data_root_path.as_ref())
-        .init()
-        .await;
+    metrics_config(&config, &data_root_path).init().await;
 
     kitsune_p2p_types::metrics::init_sys_info_poll();
 
@@ -122,9 +138,38 @@ async fn async_main() {
         .unwrap_or_else(|e| tracing::error!("Could not handle termination signal: {:?}", e));
     tracing::info!("Gracefully shutting down conductor...");
     let shutdown_result = conductor.shutdown().await;
+    #[cfg(feature = "opentelemetry-on")]
+    holochain_trace::shutdown_open_telemetry();
     handle_shutdown(shutdown_result);
 }
 
+/// Prometheus metrics configured in the conductor config take precedence
+/// over metrics configured by environment variables.
+fn metrics_config(
+    config: &ConductorConfig,
+    data_root_path: &DataRootPath,
+) -> holochain_metrics::HolochainMetricsConfig {
+    #[cfg(feature = "metrics_prometheus")]
+    if let Some(prometheus) = &config.prometheus_metrics {
+        use holochain_conductor_api::conductor::PrometheusMetricLabels;
+        use holochain_metrics::PrometheusLabels;
+        return holochain_metrics::HolochainMetricsConfig::Prometheus {
+            bind_address: prometheus.bind_address,
+            labels: match prometheus.labels {
+                PrometheusMetricLabels::Aggregate => PrometheusLabels::Aggregate,
+                PrometheusMetricLabels::Dna => PrometheusLabels::Dna,
+                PrometheusMetricLabels::Cell => PrometheusLabels::Cell,
+            },
+        };
+    }
+    #[cfg(not(feature = "metrics_prometheus"))]
+    if config.prometheus_metrics.is_some() {
+        warn!("prometheus_metrics is set, but this conductor was built without the metrics_prometheus feature");
+    }
+
+    holochain_metrics::HolochainMetricsConfig::new(data_root_path.as_ref())
+}
+
 async fn conductor_handle_from_config(opt: &Opt, config: ConductorConfig) -> ConductorHandle {
     // read the passphrase to prepare for usage
     let passphrase = match &config.keystore {
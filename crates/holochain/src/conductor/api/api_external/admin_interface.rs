@@ -87,6 +87,9 @@ impl AdminInterfaceApi {
                                     .to_string(),
                             ));
                         }
+                        // The conductor doesn't keep the signatures of registered DNAs.
+                        self.conductor_handle
+                            .check_unbundled_dna(format!("derived from {hash}"))?;
                         self.conductor_handle
                             .get_dna_file(hash)
                             .ok_or_else(|| {
@@ -98,12 +101,15 @@ impl AdminInterfaceApi {
                             .update_modifiers(modifiers)
                     }
                     DnaSource::Path(ref path) => {
-                        let bundle = Bundle::read_from_file(path).await?;
+                        let bundle: Bundle<ValidatedDnaManifest> =
+                            Bundle::read_from_file(path).await?;
+                        self.conductor_handle.check_bundle_publishers(&bundle)?;
                         let bundle: DnaBundle = bundle.into();
                         let (dna_file, _original_hash) = bundle.into_dna_file(modifiers).await?;
                         dna_file
                     }
                     DnaSource::Bundle(bundle) => {
+                        self.conductor_handle.check_bundle_publishers(&*bundle)?;
                         let (dna_file, _original_hash) = bundle.into_dna_file(modifiers).await?;
                         dna_file
                     }
@@ -127,11 +133,16 @@ impl AdminInterfaceApi {
                 let UpdateCoordinatorsPayload { dna_hash, source } = *payload;
                 let (coordinator_zomes, wasms) = match source {
                     CoordinatorSource::Path(ref path) => {
-                        let bundle = Bundle::read_from_file(path).await?;
+                        let bundle: Bundle<CoordinatorManifest> =
+                            Bundle::read_from_file(path).await?;
+                        self.conductor_handle.check_bundle_publishers(&bundle)?;
                         let bundle: CoordinatorBundle = bundle.into();
                         bundle.into_zomes().await?
                     }
-                    CoordinatorSource::Bundle(bundle) => bundle.into_zomes().await?,
+                    CoordinatorSource::Bundle(bundle) => {
+                        self.conductor_handle.check_bundle_publishers(&*bundle)?;
+                        bundle.into_zomes().await?
+                    }
                 };
 
                 self.conductor_handle
@@ -341,6 +352,22 @@ impl AdminInterfaceApi {
                     .cells_by_dna_lineage(&dna_hash)
                     .await?,
             )),
+            ListScheduledFunctions { cell_id } => Ok(AdminResponse::ScheduledFunctionsListed(
+                self.conductor_handle.list_scheduled_fns(&cell_id).await?,
+            )),
+            CancelScheduledFunction {
+                cell_id,
+                schedule_id,
+            } => Ok(AdminResponse::ScheduledFunctionCancelled(
+                self.conductor_handle
+                    .cancel_scheduled_fn(&cell_id, schedule_id)
+                    .await?,
+            )),
+            ListZomeCallUsage { installed_app_id } => Ok(AdminResponse::ZomeCallUsageListed(
+                self.conductor_handle
+                    .list_zome_call_usage(installed_app_id.as_ref())
+                    .await?,
+            )),
         }
     }
 }
@@ -350,6 +377,8 @@ mod test {
     use super::*;
     use crate::conductor::Conductor;
     use anyhow::Result;
+    use holochain_conductor_api::conductor::BundleSigningConfig;
+    use holochain_conductor_api::conductor::ConductorConfig;
     use holochain_state::prelude::*;
     use holochain_trace;
     use holochain_types::test_utils::fake_dna_zomes;
@@ -487,6 +516,79 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn register_dna_bundle_requires_trusted_publisher() -> Result<()> {
+        holochain_trace::test_run();
+        let env_dir = test_db_dir();
+        let publisher = mr_bundle::SigningKey::from_bytes(&[1; 32]);
+        let stranger = mr_bundle::SigningKey::from_bytes(&[2; 32]);
+        let publisher_key = AgentPubKey::from_raw_32(publisher.verifying_key().to_bytes().to_vec());
+        let config = ConductorConfig {
+            bundle_signing: BundleSigningConfig {
+                trusted_publishers: Some([publisher_key.into()].into()),
+            },
+            ..Default::default()
+        };
+        let handle = Conductor::builder()
+            .config(config)
+            .with_data_root_path(env_dir.path().to_path_buf().into())
+            .test(&[])
+            .await?;
+        let admin_api = AdminInterfaceApi::new(handle.clone());
+
+        let dna = fake_dna_zomes(
+            &Uuid::new_v4().to_string(),
+            vec![(TestWasm::Foo.into(), TestWasm::Foo.into())],
+        );
+        let dna_hash = dna.dna_hash().clone();
+        let bundle = (*DnaBundle::from_dna_file(dna)?).clone();
+        let register = |bundle: Bundle<ValidatedDnaManifest>| {
+            admin_api.handle_admin_request(AdminRequest::RegisterDna(Box::new(
+                RegisterDnaPayload {
+                    modifiers: DnaModifiersOpt::none(),
+                    source: DnaSource::Bundle(bundle.into()),
+                },
+            )))
+        };
+
+        assert_matches!(
+            register(bundle.clone()).await,
+            AdminResponse::Error(ExternalApiWireError::InternalError(_))
+        );
+        assert_matches!(
+            register(bundle.clone().sign(&stranger)?).await,
+            AdminResponse::Error(ExternalApiWireError::InternalError(_))
+        );
+        assert_matches!(
+            admin_api.handle_admin_request(AdminRequest::ListDnas).await,
+            AdminResponse::DnasListed(dnas) if dnas.is_empty()
+        );
+        assert_matches!(
+            register(bundle.sign(&publisher)?).await,
+            AdminResponse::DnaRegistered(h) if h == dna_hash
+        );
+
+        // DNAs derived from registered ones have no signatures to check.
+        assert_matches!(
+            admin_api
+                .handle_admin_request(AdminRequest::RegisterDna(Box::new(RegisterDnaPayload {
+                    modifiers: DnaModifiersOpt::none().with_network_seed("derived".into()),
+                    source: DnaSource::Hash(dna_hash.clone()),
+                })))
+                .await,
+            AdminResponse::Error(ExternalApiWireError::InternalError(_))
+        );
+        assert_matches!(
+            admin_api.handle_admin_request(AdminRequest::ListDnas).await,
+            AdminResponse::DnasListed(dnas) if dnas == vec![dna_hash.clone()]
+        );
+
+        tokio::time::timeout(std::time::Duration::from_secs(1), handle.shutdown())
+            .await
+            .ok();
+        Ok(())
+    }
+
     // @todo fix test by using new InstallApp call
     // #[tokio::test(flavor = "multi_thread")]
     // async fn install_list_dna_app() {
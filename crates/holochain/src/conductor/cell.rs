@@ -32,6 +32,9 @@ use crate::conductor::cell::error::CellResult;
 use crate::core::queue_consumer::spawn_queue_consumer_tasks;
 use crate::core::queue_consumer::InitialQueueTriggers;
 use crate::core::queue_consumer::QueueTriggers;
+use crate::core::ribosome::call_limits::ZomeCallLimits;
+use crate::core::ribosome::call_usage::CallUsage;
+use crate::core::ribosome::error::RibosomeError;
 use crate::core::ribosome::guest_callback::init::InitResult;
 use crate::core::ribosome::real_ribosome::RealRibosome;
 use crate::core::ribosome::ZomeCallInvocation;
@@ -278,10 +281,20 @@ impl Cell {
             }
             Ok(live_fns) => {
                 let mut tasks = vec![];
-                for (scheduled_fn, schedule) in &live_fns {
+                for (schedule_id, scheduled_fn, schedule) in &live_fns {
+                    // Functions scheduled with a payload receive it alongside
+                    // their schedule, otherwise only the schedule is passed.
                     // Failing to encode a schedule should never happen.
                     // If it does log the error and bail.
-                    let payload = match ExternIO::encode(schedule) {
+                    let payload = match scheduled_fn.payload() {
+                        Some(payload) => ExternIO::encode(ScheduledFnInput {
+                            schedule_id: schedule_id.clone(),
+                            schedule: schedule.clone(),
+                            payload: payload.clone(),
+                        }),
+                        None => ExternIO::encode(schedule),
+                    };
+                    let payload = match payload {
                         Ok(payload) => payload,
                         Err(e) => {
                             error!(
@@ -322,7 +335,9 @@ impl Cell {
                 // We don't do anything with errors in here.
                 let _ = authored_db
                     .write_async(move |txn| {
-                        for ((scheduled_fn, _), result) in live_fns.iter().zip(results.iter()) {
+                        for ((schedule_id, scheduled_fn, _), result) in
+                            live_fns.iter().zip(results.iter())
+                        {
                             match result {
                                 Ok(Ok(ZomeCallResponse::Ok(extern_io))) => {
                                     let next_schedule: Schedule = match extern_io.decode() {
@@ -341,6 +356,7 @@ impl Cell {
                                     if let Err(e) = schedule_fn(
                                         txn,
                                         &author,
+                                        schedule_id.clone(),
                                         scheduled_fn.clone(),
                                         Some(next_schedule),
                                         now,
@@ -420,6 +436,25 @@ impl Cell {
                 .await;
             }
 
+            GetLatest {
+                span_context: _,
+                respond,
+                action_hash,
+                tie_break,
+                options,
+                ..
+            } => {
+                async {
+                    let res = self
+                        .handle_get_latest(action_hash, tie_break, options)
+                        .await
+                        .map_err(holochain_p2p::HolochainP2pError::other);
+                    respond.respond(Ok(async move { res }.boxed().into()));
+                }
+                .instrument(debug_span!("cell_handle_get_latest"))
+                .await;
+            }
+
             GetMeta {
                 span_context: _,
                 respond,
@@ -655,6 +690,20 @@ impl Cell {
             .map_err(Into::into)
     }
 
+    #[cfg_attr(feature = "instrument", tracing::instrument(skip(self, options)))]
+    /// a remote node is asking us to follow the updates of an action
+    async fn handle_get_latest(
+        &self,
+        hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: holochain_p2p::event::GetOptions,
+    ) -> CellResult<Vec<WireRecordOps>> {
+        let db = self.space.dht_db.clone();
+        authority::handle_get_latest(db.into(), hash, tie_break, options)
+            .await
+            .map_err(Into::into)
+    }
+
     #[cfg_attr(
         feature = "instrument",
         tracing::instrument(skip(self, _dht_hash, _options))
@@ -888,6 +937,23 @@ impl Cell {
         params: ZomeCallParams,
         workspace_lock: Option<SourceChainWorkspace>,
     ) -> CellResult<ZomeCallResult> {
+        let limits = self
+            .conductor_handle
+            .wasm_limits_for_cell(&self.id)
+            .await
+            .map_err(Box::new)?;
+        // Calls made from one zome function to another don't take a permit,
+        // since they would otherwise wait on the call which made them.
+        // The permit is released when the ribosome drops the call's limits,
+        // which is only once the wasm has returned, even if the call timed out.
+        let concurrency_permit = match (&limits.concurrent_calls, workspace_lock.is_none()) {
+            (Some((max_calls, permits)), true) => match permits.clone().try_acquire_owned() {
+                Ok(permit) => Some(Arc::new(permit)),
+                Err(_) => return Ok(Err(RibosomeError::TooManyConcurrentCalls(*max_calls))),
+            },
+            _ => None,
+        };
+
         // Only check if init has run if this call is not coming from
         // an already running init call.
         if workspace_lock
@@ -923,15 +989,29 @@ impl Cell {
                 .await?
             }
         };
+        let span = info_span!(
+            "call_zome_workflow",
+            zome = %invocation.zome.zome_name(),
+            fn_name = %invocation.fn_name,
+        );
+        let zome_name = invocation.zome.zome_name().clone();
+        let fn_name = invocation.fn_name.clone();
+        let usage = Arc::new(CallUsage::default());
         let args = CallZomeWorkflowArgs {
             cell_id: self.id.clone(),
             ribosome,
             invocation,
             signal_tx: self.signal_tx.clone(),
-            conductor_handle,
+            conductor_handle: conductor_handle.clone(),
             is_root_zome_call,
+            usage: usage.clone(),
+            limits: ZomeCallLimits {
+                concurrency_permit,
+                ..limits.call.clone()
+            },
         };
-        Ok(call_zome_workflow(
+        let started = std::time::Instant::now();
+        let result = call_zome_workflow(
             workspace_lock,
             self.holochain_p2p_cell.clone(),
             keystore,
@@ -940,8 +1020,15 @@ impl Cell {
             self.queue_triggers.integrate_dht_ops.clone(),
             self.queue_triggers.countersigning.clone(),
         )
-        .await
-        .map_err(Box::new)?)
+        .instrument(span)
+        .await;
+        conductor_handle.record_zome_call_usage(
+            &self.id,
+            zome_name,
+            fn_name,
+            usage.to_resource_usage(started.elapsed()),
+        );
+        Ok(result.map_err(Box::new)?)
     }
 
     /// Check if each Zome's init callback has been run, and if not, run it.
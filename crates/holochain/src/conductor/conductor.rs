@@ -137,6 +137,10 @@ mod graft_records_onto_source_chain;
 
 mod app_auth_token_store;
 
+mod wasm_limits;
+
+mod zome_call_usage;
+
 /// Verify signature of a signed zome call.
 ///
 /// [Signature verification](holochain_conductor_api::AppRequest::CallZome)
@@ -273,6 +277,12 @@ pub struct Conductor {
 
     /// Container to connect app signals to app interfaces, by installed app id.
     app_broadcast: AppBroadcast,
+
+    /// The resources used by zome calls since the conductor started.
+    zome_call_usage: zome_call_usage::ZomeCallUsageTracker,
+
+    /// The limits on the zome calls of each cell which has been called.
+    wasm_limits: wasm_limits::WasmLimitsCache,
 }
 
 impl Conductor {
@@ -334,6 +344,8 @@ mod startup_shutdown_impls {
                 ))),
                 app_auth_token_store: RwShare::default(),
                 app_broadcast: AppBroadcast::default(),
+                zome_call_usage: Default::default(),
+                wasm_limits: Default::default(),
             }
         }
 
@@ -843,7 +855,7 @@ mod network_impls {
     use rusqlite::params;
 
     use holochain_conductor_api::{
-        CellInfo, DnaStorageInfo, NetworkInfo, StorageBlob, StorageInfo,
+        CellInfo, DnaStorageInfo, NetworkInfo, ResourceUsage, StorageBlob, StorageInfo, ZomeFnUsage,
     };
     use holochain_p2p::HolochainP2pSender;
     use holochain_sqlite::stats::{get_size_on_disk, get_used_size};
@@ -977,6 +989,10 @@ mod network_impls {
                 ));
             }
 
+            // these are per conductor, every dna gets the same list
+            let preflight_rejections = self.holochain_p2p.get_preflight_rejections().await?;
+            let preflight_rejections = &preflight_rejections;
+
             futures::future::join_all(dnas.iter().map(|dna| async move {
                 let diagnostics = self.holochain_p2p.get_diagnostics(dna.clone()).await?;
                 let fetch_pool_info = diagnostics
@@ -1085,6 +1101,7 @@ mod network_impls {
                     total_network_peers,
                     bytes_since_last_time_queried,
                     completed_rounds_since_last_time_queried,
+                    preflight_rejections: preflight_rejections.clone(),
                 })
             }))
             .await
@@ -1123,6 +1140,58 @@ mod network_impls {
             })
         }
 
+        /// List the resources used by zome calls to the cells of installed apps,
+        /// or only to the cells of one app.
+        pub(crate) async fn list_zome_call_usage(
+            &self,
+            installed_app_id: Option<&InstalledAppId>,
+        ) -> ConductorResult<Vec<ZomeFnUsage>> {
+            let state = self.get_state().await?;
+            let cell_apps: HashMap<CellId, InstalledAppId> = state
+                .installed_apps_and_services()
+                .iter()
+                .filter(|(id, _)| installed_app_id.map_or(true, |wanted| wanted == *id))
+                .flat_map(|(id, app)| app.all_cells().map(move |cell_id| (cell_id, id.clone())))
+                .collect();
+            Ok(self.zome_call_usage.list(&cell_apps))
+        }
+
+        /// Add the usage of a zome call to the totals of the function it called.
+        pub(crate) fn record_zome_call_usage(
+            &self,
+            cell_id: &CellId,
+            zome_name: ZomeName,
+            fn_name: FunctionName,
+            usage: ResourceUsage,
+        ) {
+            self.zome_call_usage
+                .record(cell_id, zome_name, fn_name, usage);
+        }
+
+        /// The limits on the zome calls of a cell, from the conductor config
+        /// and the limits requested when its app was installed.
+        pub(crate) async fn wasm_limits_for_cell(
+            &self,
+            cell_id: &CellId,
+        ) -> ConductorResult<Arc<wasm_limits::CellWasmLimits>> {
+            if let Some(limits) = self.wasm_limits.get(cell_id) {
+                return Ok(limits);
+            }
+            let config = &self.config.wasm_limits;
+            let state = self.get_state().await?;
+            let app_role = state.find_app_containing_cell(cell_id).and_then(|app| {
+                let role_name = app.role_name_of_cell(cell_id)?;
+                Some((app, role_name))
+            });
+            Ok(match app_role {
+                Some((app, role_name)) => {
+                    self.wasm_limits
+                        .resolve(config, app, role_name, cell_id.clone())
+                }
+                None => self.wasm_limits.resolve_default(config, cell_id.clone()),
+            })
+        }
+
         async fn storage_info_for_dna(
             &self,
             dna_hash: &DnaHash,
@@ -1294,6 +1363,7 @@ mod network_impls {
                 HolochainP2pEvent::CallRemote { .. }
                 | CountersigningSessionNegotiation { .. }
                 | Get { .. }
+                | GetLatest { .. }
                 | GetMeta { .. }
                 | GetLinks { .. }
                 | CountLinks { .. }
@@ -1466,6 +1536,8 @@ pub struct InstallAppCommonFlags {
     pub ignore_genesis_failure: bool,
     /// From [`InstallAppPayload::allow_throwaway_random_agent_key`]
     pub allow_throwaway_random_agent_key: bool,
+    /// From [`InstallAppPayload::wasm_limits`]
+    pub wasm_limits: AppWasmLimits,
 }
 
 /// Methods related to app installation and management
@@ -1480,6 +1552,35 @@ mod app_impls {
     use super::*;
 
     impl Conductor {
+        /// Check the publisher signatures on a bundle, and that one of its
+        /// publishers is trusted if the conductor only trusts some.
+        pub(crate) fn check_bundle_publishers<M: mr_bundle::Manifest>(
+            &self,
+            bundle: &mr_bundle::Bundle<M>,
+        ) -> ConductorResult<()> {
+            let publishers: Vec<AgentPubKey> = bundle
+                .verify_signatures()?
+                .into_iter()
+                .map(|publisher| AgentPubKey::from_raw_32(publisher.to_vec()))
+                .collect();
+            if !self.config.bundle_signing.is_trusted(&publishers) {
+                return Err(ConductorError::UntrustedBundle(publishers));
+            }
+            Ok(())
+        }
+
+        /// Refuse a DNA which is not in a bundle, and so has no publisher
+        /// signatures to check, if the conductor only trusts some publishers.
+        pub(crate) fn check_unbundled_dna(
+            &self,
+            dna: impl std::fmt::Display,
+        ) -> ConductorResult<()> {
+            if self.config.bundle_signing.trusted_publishers.is_some() {
+                return Err(ConductorError::UnbundledDna(dna.to_string()));
+            }
+            Ok(())
+        }
+
         /// Install an app from minimal elements, without needing construct a whole AppBundle.
         /// (This function constructs a bundle under the hood.)
         /// This is just a convenience for testing.
@@ -1521,6 +1622,7 @@ mod app_impls {
                         defer_memproofs: false,
                         ignore_genesis_failure: false,
                         allow_throwaway_random_agent_key: true,
+                        wasm_limits: Default::default(),
                     },
                 )
                 .await?;
@@ -1612,13 +1714,14 @@ mod app_impls {
 
             let app_result = if flags.defer_memproofs {
                 let roles = ops.role_assignments;
-                let app = InstalledAppCommon::new(
+                let mut app = InstalledAppCommon::new(
                     installed_app_id.clone(),
                     agent_key.clone(),
                     roles,
                     manifest,
                     Timestamp::now(),
                 )?;
+                app.wasm_limits = flags.wasm_limits;
 
                 let (_, app) = self
                     .update_state_prime(move |mut state| {
@@ -1633,13 +1736,14 @@ mod app_impls {
 
                 if genesis_result.is_ok() || flags.ignore_genesis_failure {
                     let roles = ops.role_assignments;
-                    let app = InstalledAppCommon::new(
+                    let mut app = InstalledAppCommon::new(
                         installed_app_id.clone(),
                         agent_key.clone(),
                         roles,
                         manifest,
                         Timestamp::now(),
                     )?;
+                    app.wasm_limits = flags.wasm_limits;
 
                     // Update the db
                     let stopped_app = self.add_disabled_app_to_db(app).await?;
@@ -1711,6 +1815,7 @@ mod app_impls {
                 roles_settings,
                 ignore_genesis_failure,
                 allow_throwaway_random_agent_key,
+                wasm_limits,
             } = payload;
 
             let modifiers = get_modifiers_map_from_role_settings(&roles_settings);
@@ -1719,6 +1824,16 @@ mod app_impls {
 
             let bundle = {
                 let original_bundle = source.resolve().await?;
+                // Check the signatures before the manifest changes invalidate them.
+                self.check_bundle_publishers(&original_bundle)?;
+                // The signatures only cover the DNAs in the bundle.
+                for role in original_bundle.manifest().app_roles() {
+                    if let Some(location @ (DnaLocation::Path(_) | DnaLocation::Url(_))) =
+                        &role.dna.location
+                    {
+                        self.check_unbundled_dna(format!("role {} at {location:?}", role.name))?;
+                    }
+                }
                 let mut manifest = original_bundle.manifest().to_owned();
                 if let Some(network_seed) = network_seed {
                     manifest.set_network_seed(network_seed);
@@ -1740,6 +1855,7 @@ mod app_impls {
                 defer_memproofs,
                 ignore_genesis_failure,
                 allow_throwaway_random_agent_key,
+                wasm_limits: wasm_limits.unwrap_or_default(),
             };
 
             let installed_app_id =
@@ -1792,6 +1908,7 @@ mod app_impls {
                 let self_clone = self.clone();
                 let app = self.remove_app_from_db(installed_app_id).await?;
                 tracing::debug!(msg = "Removed app from db.", app = ?app);
+                self.wasm_limits.forget_app(installed_app_id);
 
                 // Remove cells which may now be dangling due to the removed app
                 self_clone
@@ -2890,6 +3007,36 @@ mod scheduler_impls {
                 .map(|cell_arc| cell_arc.dispatch_scheduled_fns(now));
             futures::future::join_all(tasks).await;
         }
+
+        /// List the pending scheduled functions of a cell.
+        pub async fn list_scheduled_fns(
+            &self,
+            cell_id: &CellId,
+        ) -> ConductorApiResult<Vec<PendingSchedule>> {
+            let cell = self.cell_by_id(cell_id).await?;
+            let authored_db = cell.get_or_create_authored_db()?;
+            let author = cell_id.agent_pubkey().clone();
+            Ok(authored_db
+                .read_async(move |txn| {
+                    holochain_state::schedule::pending_schedules(txn, &author, None)
+                })
+                .await?)
+        }
+
+        /// Cancel a scheduled function of a cell.
+        /// Returns whether there was a schedule to cancel.
+        pub async fn cancel_scheduled_fn(
+            &self,
+            cell_id: &CellId,
+            schedule_id: ScheduleId,
+        ) -> ConductorApiResult<bool> {
+            let cell = self.cell_by_id(cell_id).await?;
+            let authored_db = cell.get_or_create_authored_db()?;
+            let author = cell_id.agent_pubkey().clone();
+            Ok(authored_db
+                .write_async(move |txn| cancel_schedule(txn, &author, &schedule_id))
+                .await?)
+        }
     }
 }
 
@@ -3499,6 +3646,9 @@ impl Conductor {
 
         let all_dnas: HashSet<_> = all_cells.iter().map(|cell_id| cell_id.dna_hash()).collect();
 
+        // Zome call usage is only listed for installed cells, so forget the rest.
+        self.zome_call_usage.retain_cells(&all_cells);
+
         // Clean up all cells that will be dropped (leave network, etc.)
         let cells_to_cleanup: Vec<_> = self.running_cells.share_mut(|cells| {
             let to_remove: Vec<_> = cells
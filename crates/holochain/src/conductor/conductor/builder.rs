@@ -260,7 +260,11 @@ impl ConductorBuilder {
         let dpki_uuid = dpki_dna_to_install
             .as_ref()
             .map(|dna| dna.dna_hash().get_raw_32().try_into().expect("32 bytes"));
-        let network_compat = NetworkCompatParams { dpki_uuid };
+        let network_compat = NetworkCompatParams {
+            dpki_uuid,
+            network_id: config.network_compat.network_id.clone(),
+            compat_tags: config.network_compat.tags.clone(),
+        };
 
         let (holochain_p2p, p2p_evt) = match holochain_p2p::spawn_holochain_p2p(
             network_config,
@@ -536,7 +540,11 @@ impl ConductorBuilder {
             ),
         };
 
-        let network_compat = NetworkCompatParams { dpki_uuid };
+        let network_compat = NetworkCompatParams {
+            dpki_uuid,
+            network_id: config.network_compat.network_id.clone(),
+            compat_tags: config.network_compat.tags.clone(),
+        };
 
         let (holochain_p2p, p2p_evt) =
                 holochain_p2p::spawn_holochain_p2p(network_config, holochain_p2p::kitsune_p2p::dependencies::kitsune_p2p_types::tls::TlsConfig::new_ephemeral().await.unwrap(), host, network_compat)
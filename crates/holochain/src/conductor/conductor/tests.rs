@@ -1221,6 +1221,7 @@ async fn test_deferred_memproof_provisioning() {
             network_seed: None,
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         })
         .await
         .unwrap();
@@ -1349,6 +1350,7 @@ async fn test_deferred_memproof_provisioning_uninstall() {
             network_seed: None,
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         })
         .await
         .unwrap();
@@ -1459,3 +1461,346 @@ async fn test_app_info_cells_sorted_consistently() {
         ]
     );
 }
+
+/// Check that a conductor with trusted publishers only installs app bundles
+/// signed by one of them.
+#[tokio::test(flavor = "multi_thread")]
+async fn install_app_bundle_requires_trusted_publisher() {
+    holochain_trace::test_run();
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Foo]).await;
+    let publisher = mr_bundle::SigningKey::from_bytes(&[1; 32]);
+    let stranger = mr_bundle::SigningKey::from_bytes(&[2; 32]);
+    let publisher_key = AgentPubKey::from_raw_32(publisher.verifying_key().to_bytes().to_vec());
+    let mut config = SweetConductorConfig::standard();
+    config.bundle_signing.trusted_publishers = Some([publisher_key.clone().into()].into());
+    let conductor = SweetConductor::from_config(config).await;
+
+    let install = |app_id: &str, signer: Option<&mr_bundle::SigningKey>| {
+        let conductor = conductor.clone();
+        let dna = dna.clone();
+        let app_id = app_id.to_string();
+        let signer = signer.cloned();
+        async move {
+            let mut bundle = app_bundle_from_dnas(&[("role".to_string(), dna)], false, None)
+                .await
+                .into_inner();
+            if let Some(signer) = signer {
+                bundle = bundle.sign(&signer).unwrap();
+            }
+            conductor
+                .install_app_bundle(InstallAppPayload {
+                    source: AppBundleSource::Bundle(bundle.into()),
+                    agent_key: None,
+                    installed_app_id: Some(app_id),
+                    roles_settings: Default::default(),
+                    network_seed: None,
+                    ignore_genesis_failure: false,
+                    allow_throwaway_random_agent_key: true,
+                    wasm_limits: None,
+                })
+                .await
+        }
+    };
+
+    //- Unsigned bundles are refused
+    assert_matches!(
+        install("unsigned", None).await,
+        Err(ConductorError::UntrustedBundle(publishers)) if publishers.is_empty()
+    );
+
+    //- Bundles signed by anyone else are refused
+    assert_matches!(
+        install("stranger", Some(&stranger)).await,
+        Err(ConductorError::UntrustedBundle(publishers)) if publishers.len() == 1
+    );
+
+    //- Bundles signed by a trusted publisher are installed
+    install("trusted", Some(&publisher)).await.unwrap();
+    for (app_id, installed) in [("unsigned", false), ("stranger", false), ("trusted", true)] {
+        assert_eq!(
+            installed,
+            conductor
+                .get_app_info(&app_id.to_string())
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn signed_app_bundle_cannot_use_unbundled_dnas_when_publishers_are_trusted() {
+    holochain_trace::test_run();
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Foo]).await;
+    let publisher = mr_bundle::SigningKey::from_bytes(&[1; 32]);
+    let publisher_key = AgentPubKey::from_raw_32(publisher.verifying_key().to_bytes().to_vec());
+    let mut config = SweetConductorConfig::standard();
+    config.bundle_signing.trusted_publishers = Some([publisher_key.into()].into());
+    let conductor = SweetConductor::from_config(config).await;
+
+    //- An unsigned DNA bundle outside of the app bundle
+    let dir = tempfile::tempdir().unwrap();
+    let dna_path = dir.path().join("unsigned.dna");
+    DnaBundle::from_dna_file(dna)
+        .unwrap()
+        .write_to_file(&dna_path)
+        .await
+        .unwrap();
+
+    let manifest = AppManifestCurrentBuilder::default()
+        .name("app".into())
+        .description(None)
+        .roles(vec![AppRoleManifest {
+            name: "role".into(),
+            dna: AppRoleDnaManifest {
+                location: Some(DnaLocation::Path(dna_path)),
+                modifiers: DnaModifiersOpt::none(),
+                installed_hash: None,
+                clone_limit: 0,
+            },
+            provisioning: Some(CellProvisioning::Create { deferred: false }),
+        }])
+        .build()
+        .unwrap()
+        .into();
+    let bundle = AppBundle::new(
+        manifest,
+        Vec::<(PathBuf, DnaBundle)>::new(),
+        dir.path().to_path_buf(),
+    )
+    .await
+    .unwrap()
+    .into_inner()
+    .sign(&publisher)
+    .unwrap();
+
+    //- The app bundle is trusted, but the DNA it points to is refused
+    assert_matches!(
+        conductor
+            .install_app_bundle(InstallAppPayload {
+                source: AppBundleSource::Bundle(bundle.into()),
+                agent_key: None,
+                installed_app_id: Some("app".into()),
+                roles_settings: Default::default(),
+                network_seed: None,
+                ignore_genesis_failure: false,
+                allow_throwaway_random_agent_key: true,
+                wasm_limits: None,
+            })
+            .await,
+        Err(ConductorError::UnbundledDna(_))
+    );
+    assert!(conductor.list_dnas().is_empty());
+    assert!(conductor
+        .get_app_info(&"app".to_string())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn zome_call_usage_is_accounted_per_function() {
+    holochain_trace::test_run();
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Create]).await;
+    let mut conductor = SweetConductor::from_standard_config().await;
+    let app = conductor.setup_app("app", [&dna]).await.unwrap();
+    let (cell,) = app.into_tuple();
+    let zome = cell.zome(TestWasm::Create);
+
+    let _: ActionHash = conductor.call(&zome, "create_entry", ()).await;
+    let _: ActionHash = conductor.call(&zome, "create_entry", ()).await;
+    let _: Option<Record> = conductor.call(&zome, "get_entry", ()).await;
+
+    let usage = conductor
+        .list_zome_call_usage(Some(&"app".to_string()))
+        .await
+        .unwrap();
+    let fn_usage = |fn_name: &str| {
+        usage
+            .iter()
+            .find(|u| u.cell_id == *cell.cell_id() && u.fn_name.0 == fn_name)
+            .unwrap()
+            .usage
+            .clone()
+    };
+
+    let create = fn_usage("create_entry");
+    assert_eq!(2, create.call_count);
+    assert!(create.metering_points > 0);
+    assert!(create.guest_payload_bytes_written > 0);
+    assert_eq!(0, create.guest_payload_bytes_read);
+    assert_eq!(0, create.network_requests);
+
+    let get = fn_usage("get_entry");
+    assert_eq!(1, get.call_count);
+    assert!(get.guest_payload_bytes_read > 0);
+    assert_eq!(0, get.guest_payload_bytes_written);
+    //- `get_entry` only gets from the local databases,
+    //  so it makes no network request
+    assert_eq!(0, get.network_requests);
+
+    //- Other apps' usage is not listed
+    assert!(conductor
+        .list_zome_call_usage(Some(&"other".to_string()))
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn zome_calls_are_stopped_at_wasm_limits() {
+    holochain_trace::test_run();
+    let (halt_dna, _, _) =
+        SweetDnaFile::unique_from_test_wasms(vec![TestWasm::TheIncredibleHalt]).await;
+    let (create_dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Create]).await;
+    let mut config = SweetConductorConfig::standard();
+    config.wasm_limits.apps = [
+        (
+            "halting".to_string(),
+            AppWasmLimits {
+                app: WasmLimits {
+                    max_metering_points: Some(10_000_000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ),
+        (
+            "busy".to_string(),
+            AppWasmLimits {
+                app: WasmLimits {
+                    max_concurrent_calls: Some(0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ),
+    ]
+    .into();
+    let mut conductor = SweetConductor::from_config(config).await;
+    let (halting,) = conductor
+        .setup_app("halting", [&halt_dna])
+        .await
+        .unwrap()
+        .into_tuple();
+    let (busy,) = conductor
+        .setup_app("busy", [&create_dna])
+        .await
+        .unwrap()
+        .into_tuple();
+
+    //- A call that never ends is stopped when it runs out of metering points
+    let err = conductor
+        .call_fallible::<_, ()>(&halting.zome(TestWasm::TheIncredibleHalt), "smash", ())
+        .await
+        .unwrap_err();
+    assert_matches!(err, ConductorApiError::Other(e) if e.to_string().contains("ran out of wasm metering points"));
+
+    //- Calls beyond the concurrency limit are rejected
+    let err = conductor
+        .call_fallible::<_, ActionHash>(&busy.zome(TestWasm::Create), "create_entry", ())
+        .await
+        .unwrap_err();
+    assert_matches!(err, ConductorApiError::Other(e) if e.to_string().contains("already 0 zome calls running"));
+}
+
+/// Wasm only stops early when it runs out of metering points, which the
+/// wasmer_wamr engine doesn't support.
+#[cfg(feature = "wasmer_sys")]
+#[tokio::test(flavor = "multi_thread")]
+async fn timed_out_zome_calls_hold_their_permit_until_the_wasm_returns() {
+    holochain_trace::test_run();
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::TheIncredibleHalt]).await;
+    let mut config = SweetConductorConfig::standard();
+    config.wasm_limits.apps = [(
+        "halting".to_string(),
+        AppWasmLimits {
+            app: WasmLimits {
+                max_metering_points: Some(1_000_000_000),
+                max_call_duration: Some(std::time::Duration::from_millis(200)),
+                max_concurrent_calls: Some(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )]
+    .into();
+    let mut conductor = SweetConductor::from_config(config).await;
+    let (halting,) = conductor
+        .setup_app("halting", [&dna])
+        .await
+        .unwrap()
+        .into_tuple();
+    let zome = halting.zome(TestWasm::TheIncredibleHalt);
+
+    //- The call fails once it has run for too long
+    let err = conductor
+        .call_fallible::<_, ()>(&zome, "smash", ())
+        .await
+        .unwrap_err();
+    assert_matches!(err, ConductorApiError::Other(e) if e.to_string().contains("did not finish within"));
+
+    //- The wasm is still running, so it still holds the only permit
+    let err = conductor
+        .call_fallible::<_, ActionHash>(&zome, "create_a_thing", ())
+        .await
+        .unwrap_err();
+    assert_matches!(err, ConductorApiError::Other(e) if e.to_string().contains("already 1 zome calls running"));
+
+    //- The permit is released once the wasm runs out of metering points
+    tokio::time::timeout(std::time::Duration::from_secs(60), async {
+        loop {
+            match conductor
+                .call_fallible::<_, ActionHash>(&zome, "create_a_thing", ())
+                .await
+            {
+                Ok(_) => break,
+                Err(ConductorApiError::Other(e))
+                    if e.to_string().contains("already 1 zome calls running") =>
+                {
+                    tokio::time::sleep(std::time::Duration::from_millis(100)).await
+                }
+                Err(e) => panic!("unexpected error: {e:?}"),
+            }
+        }
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn zome_calls_cannot_grow_memory_beyond_the_limit() {
+    holochain_trace::test_run();
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::RandomBytes]).await;
+    let mut config = SweetConductorConfig::standard();
+    config.wasm_limits.apps = [(
+        "bounded".to_string(),
+        AppWasmLimits {
+            app: WasmLimits {
+                // 4 MiB
+                max_memory_pages: Some(64),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )]
+    .into();
+    let mut conductor = SweetConductor::from_config(config).await;
+    let (bounded,) = conductor
+        .setup_app("bounded", [&dna])
+        .await
+        .unwrap()
+        .into_tuple();
+    let zome = bounded.zome(TestWasm::RandomBytes);
+
+    //- A call that fits within the limit succeeds
+    let bytes: Bytes = conductor.call(&zome, "random_bytes", 1024_u32).await;
+    assert_eq!(1024, bytes.len());
+
+    //- A call that needs more memory fails
+    let err = conductor
+        .call_fallible::<_, Bytes>(&zome, "random_bytes", 8 * 1024 * 1024_u32)
+        .await
+        .unwrap_err();
+    assert_matches!(err, ConductorApiError::Other(e) if e.to_string().contains("pages of wasm memory"));
+}
@@ -0,0 +1,165 @@
+//! The limits on the resources used by each cell's zome calls, resolved from
+//! the conductor config and the limits requested when each app was installed.
+
+use crate::core::ribosome::call_limits::ZomeCallLimits;
+use holochain_conductor_api::conductor::WasmLimitsConfig;
+use holochain_types::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// The limits on the zome calls of one cell.
+#[derive(Debug, Default)]
+pub(crate) struct CellWasmLimits {
+    /// Limits on the wasm of each call.
+    pub call: ZomeCallLimits,
+    /// The number of calls that may run at once, and the permits for them,
+    /// which are shared by all cells of the same role.
+    pub concurrent_calls: Option<(usize, Arc<Semaphore>)>,
+}
+
+impl CellWasmLimits {
+    fn new(limits: &WasmLimits, concurrent_calls: Option<(usize, Arc<Semaphore>)>) -> Self {
+        Self {
+            call: ZomeCallLimits {
+                max_metering_points: limits.max_metering_points,
+                max_memory_pages: limits.max_memory_pages,
+                max_call_duration: limits.max_call_duration,
+                concurrency_permit: None,
+            },
+            concurrent_calls,
+        }
+    }
+}
+
+/// Resolves the limits of each cell when it's first called,
+/// so that zome calls don't have to read the conductor state.
+#[derive(Default)]
+pub(crate) struct WasmLimitsCache {
+    cells: parking_lot::RwLock<HashMap<CellId, Arc<CellWasmLimits>>>,
+    roles: parking_lot::Mutex<HashMap<(InstalledAppId, RoleName), (usize, Arc<Semaphore>)>>,
+}
+
+impl WasmLimitsCache {
+    /// The limits of a cell, if they have been resolved.
+    pub fn get(&self, cell_id: &CellId) -> Option<Arc<CellWasmLimits>> {
+        self.cells.read().get(cell_id).cloned()
+    }
+
+    /// Resolve and remember the limits of a cell of an app's role.
+    pub fn resolve(
+        &self,
+        config: &WasmLimitsConfig,
+        app: &InstalledAppCommon,
+        role_name: &RoleName,
+        cell_id: CellId,
+    ) -> Arc<CellWasmLimits> {
+        let limits = resolve_wasm_limits(config, app.id(), &app.wasm_limits, role_name);
+        let concurrent_calls = limits.max_concurrent_calls.map(|max| {
+            self.roles
+                .lock()
+                .entry((app.id().clone(), role_name.clone()))
+                .and_modify(|entry| {
+                    if entry.0 != max {
+                        *entry = (max, Arc::new(Semaphore::new(max)));
+                    }
+                })
+                .or_insert_with(|| (max, Arc::new(Semaphore::new(max))))
+                .clone()
+        });
+        let cell_limits = Arc::new(CellWasmLimits::new(&limits, concurrent_calls));
+        self.cells.write().insert(cell_id, cell_limits.clone());
+        cell_limits
+    }
+
+    /// Resolve and remember the limits of a cell which doesn't belong to
+    /// a role of any app, which only has the config default limits.
+    pub fn resolve_default(
+        &self,
+        config: &WasmLimitsConfig,
+        cell_id: CellId,
+    ) -> Arc<CellWasmLimits> {
+        let cell_limits = Arc::new(CellWasmLimits::new(&config.default, None));
+        self.cells.write().insert(cell_id, cell_limits.clone());
+        cell_limits
+    }
+
+    /// Forget the limits resolved for every cell, along with the permits of
+    /// an app's roles, so that an app installed again with the same id
+    /// gets the limits it asks for.
+    pub fn forget_app(&self, installed_app_id: &InstalledAppId) {
+        self.cells.write().clear();
+        self.roles
+            .lock()
+            .retain(|(app_id, _), _| app_id != installed_app_id);
+    }
+}
+
+/// Combine the limits set for a role of an app, in order of precedence:
+/// the conductor config for the app, then the limits requested at installation,
+/// then the conductor config default.
+fn resolve_wasm_limits(
+    config: &WasmLimitsConfig,
+    installed_app_id: &InstalledAppId,
+    installed: &AppWasmLimits,
+    role_name: &RoleName,
+) -> WasmLimits {
+    let configured = config
+        .apps
+        .get(installed_app_id)
+        .map(|app_limits| app_limits.for_role(role_name))
+        .unwrap_or_default();
+    configured
+        .or(&installed.for_role(role_name))
+        .or(&config.default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn config_limits_take_precedence_over_installed_limits() {
+        let installed = AppWasmLimits {
+            app: WasmLimits {
+                max_metering_points: Some(1),
+                max_memory_pages: Some(1),
+                ..Default::default()
+            },
+            roles: [(
+                "role".to_string(),
+                WasmLimits {
+                    max_call_duration: Some(Duration::from_secs(1)),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+        };
+        let config = WasmLimitsConfig {
+            default: WasmLimits {
+                max_concurrent_calls: Some(3),
+                max_call_duration: Some(Duration::from_secs(3)),
+                ..Default::default()
+            },
+            apps: [(
+                "app".to_string(),
+                AppWasmLimits {
+                    app: WasmLimits {
+                        max_metering_points: Some(2),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )]
+            .into(),
+        };
+
+        let limits =
+            resolve_wasm_limits(&config, &"app".to_string(), &installed, &"role".to_string());
+        assert_eq!(Some(2), limits.max_metering_points);
+        assert_eq!(Some(1), limits.max_memory_pages);
+        assert_eq!(Some(Duration::from_secs(1)), limits.max_call_duration);
+        assert_eq!(Some(3), limits.max_concurrent_calls);
+    }
+}
@@ -0,0 +1,119 @@
+//! Totals of the resources used by zome calls, per zome function of each cell.
+
+use crate::conductor::metrics::{create_zome_call_metrics, ZomeCallMetrics};
+use holochain_conductor_api::{ResourceUsage, ZomeFnUsage};
+use holochain_types::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+type ZomeFnKey = (CellId, ZomeName, FunctionName);
+
+/// Adds up the usage of every zome call made since the conductor started,
+/// and publishes each call's usage as metrics.
+///
+/// The totals are only kept in memory, until the cell is uninstalled.
+pub(crate) struct ZomeCallUsageTracker {
+    totals: parking_lot::Mutex<HashMap<ZomeFnKey, ResourceUsage>>,
+    metrics: ZomeCallMetrics,
+}
+
+impl Default for ZomeCallUsageTracker {
+    fn default() -> Self {
+        Self {
+            totals: Default::default(),
+            metrics: create_zome_call_metrics(),
+        }
+    }
+}
+
+impl ZomeCallUsageTracker {
+    /// Add the usage of a zome call to the totals of the function it called.
+    pub fn record(
+        &self,
+        cell_id: &CellId,
+        zome_name: ZomeName,
+        fn_name: FunctionName,
+        usage: ResourceUsage,
+    ) {
+        // Attributes match those of `hc.ribosome.wasm.usage`.
+        let attributes = [
+            opentelemetry_api::KeyValue::new("dna", cell_id.dna_hash().to_string()),
+            opentelemetry_api::KeyValue::new("agent", cell_id.agent_pubkey().to_string()),
+            opentelemetry_api::KeyValue::new("zome", zome_name.to_string()),
+            opentelemetry_api::KeyValue::new("fn", fn_name.to_string()),
+        ];
+        self.metrics
+            .duration
+            .record(usage.wall_time_us as f64 / 1_000_000.0, &attributes);
+        self.metrics
+            .guest_payload_bytes_read
+            .add(usage.guest_payload_bytes_read, &attributes);
+        self.metrics
+            .guest_payload_bytes_written
+            .add(usage.guest_payload_bytes_written, &attributes);
+        self.metrics
+            .network_requests
+            .add(usage.network_requests, &attributes);
+
+        self.totals
+            .lock()
+            .entry((cell_id.clone(), zome_name, fn_name))
+            .or_default()
+            .add(&usage);
+    }
+
+    /// Drop the totals of cells which are no longer installed.
+    pub fn retain_cells(&self, installed: &HashSet<CellId>) {
+        self.totals
+            .lock()
+            .retain(|(cell_id, _, _), _| installed.contains(cell_id));
+    }
+
+    /// The totals for the cells in `cell_apps`, which maps each cell to
+    /// the app it belongs to.
+    pub fn list(&self, cell_apps: &HashMap<CellId, InstalledAppId>) -> Vec<ZomeFnUsage> {
+        self.totals
+            .lock()
+            .iter()
+            .filter_map(|((cell_id, zome_name, fn_name), usage)| {
+                Some(ZomeFnUsage {
+                    installed_app_id: cell_apps.get(cell_id)?.clone(),
+                    cell_id: cell_id.clone(),
+                    zome_name: zome_name.clone(),
+                    fn_name: fn_name.clone(),
+                    usage: usage.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::fixt::prelude::*;
+    use hdk::prelude::CellIdFixturator;
+
+    #[test]
+    fn uninstalled_cells_are_forgotten() {
+        let tracker = ZomeCallUsageTracker::default();
+        let kept = fixt!(CellId);
+        let dropped = fixt!(CellId);
+        for cell_id in [&kept, &dropped] {
+            tracker.record(
+                cell_id,
+                "zome".into(),
+                "fn".into(),
+                ResourceUsage {
+                    call_count: 1,
+                    ..Default::default()
+                },
+            );
+        }
+
+        tracker.retain_cells(&[kept.clone()].into_iter().collect());
+
+        let totals = tracker.totals.lock();
+        assert_eq!(1, totals.len());
+        assert!(totals.keys().all(|(cell_id, _, _)| *cell_id == kept));
+    }
+}
@@ -29,6 +29,12 @@ pub enum ConductorError {
     #[error(transparent)]
     AppManifestError(#[from] AppManifestError),
 
+    #[error("The bundle is not signed by a trusted publisher. Publishers: {0:?}")]
+    UntrustedBundle(Vec<AgentPubKey>),
+
+    #[error("Only DNAs in a signed bundle can be used when publishers are trusted, but this DNA is not: {0}")]
+    UnbundledDna(String),
+
     #[error(transparent)]
     DatabaseError(#[from] DatabaseError),
 
@@ -458,7 +458,11 @@ async fn handle_incoming_app_message(
         }
         ReceiveMessage::Request(data, respond) => {
             use holochain_serialized_bytes::SerializedBytesError;
-            let result: AppResponse = api.handle_request(installed_app_id, Ok(data)).await?;
+            let span = info_span!("app_request", app_id = %installed_app_id);
+            let result: AppResponse = api
+                .handle_request(installed_app_id, Ok(data))
+                .instrument(span)
+                .await?;
             // Have to jump through some hoops, because our response type
             // only implements try_into, but the responder needs try_from.
             let result = result.try_into();
@@ -580,6 +584,7 @@ mod test {
             network_seed: None,
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         }));
         let response: AdminResponse = admin_tx.request(request).await.unwrap();
         let app_info = match response {
@@ -861,6 +866,7 @@ mod test {
                         total_network_peers: 1,
                         bytes_since_last_time_queried: 1838,
                         completed_rounds_since_last_time_queried: 0,
+                        preflight_rejections: vec![],
                     }]
                 )
             }
@@ -28,3 +28,41 @@ pub fn create_post_commit_duration_metric() -> P2pEventDurationMetric {
     .with_description("The time spent executing a post commit")
     .init()
 }
+
+/// Metrics for the resources used by zome calls.
+pub struct ZomeCallMetrics {
+    pub duration: Histogram<f64>,
+    pub guest_payload_bytes_read: Counter<u64>,
+    pub guest_payload_bytes_written: Counter<u64>,
+    pub network_requests: Counter<u64>,
+}
+
+pub fn create_zome_call_metrics() -> ZomeCallMetrics {
+    let meter = meter_with_version(
+        "hc.conductor",
+        None::<&'static str>,
+        None::<&'static str>,
+        Some(vec![]),
+    );
+    ZomeCallMetrics {
+        duration: meter
+            .f64_histogram("hc.conductor.zome_call.duration")
+            .with_unit(Unit::new("s"))
+            .with_description("The time spent running a zome call")
+            .init(),
+        guest_payload_bytes_read: meter
+            .u64_counter("hc.conductor.zome_call.guest_payload_read.byte.count")
+            .with_unit(Unit::new("By"))
+            .with_description("The bytes of data passed into zome calls by reading host functions")
+            .init(),
+        guest_payload_bytes_written: meter
+            .u64_counter("hc.conductor.zome_call.guest_payload_write.byte.count")
+            .with_unit(Unit::new("By"))
+            .with_description("The bytes of data passed by zome calls to writing host functions")
+            .init(),
+        network_requests: meter
+            .u64_counter("hc.conductor.zome_call.network_request.count")
+            .with_description("The requests made to other agents by zome calls")
+            .init(),
+    }
+}
@@ -329,6 +329,7 @@ impl ConductorState {
                 tracing::warn!("Using default NetworkCompatParams");
                 None
             },
+            ..Default::default()
         }
     }
 
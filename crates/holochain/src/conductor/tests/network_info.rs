@@ -74,3 +74,47 @@ async fn network_info() {
     let network_info = conductors[1].network_info(&app_id, &payload).await.unwrap();
     assert!(network_info[0].bytes_since_last_time_queried > 0);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn network_info_reports_network_id_mismatch() {
+    holochain_trace::test_run();
+
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Create]).await;
+    let configs = ["staging", "production"].map(|network_id| {
+        let mut config = SweetConductorConfig::standard().no_dpki();
+        config.network_compat.network_id = Some(network_id.to_string());
+        config
+    });
+    let mut conductors = SweetConductorBatch::from_configs(configs).await;
+    let app_id: InstalledAppId = "app".into();
+    let apps = conductors
+        .setup_app(&app_id, &[dna.clone()])
+        .await
+        .unwrap()
+        .into_inner();
+
+    // the conductors know about each other, but must refuse to connect
+    conductors.exchange_peer_info().await;
+
+    let payload = NetworkInfoRequestPayload {
+        agent_pub_key: apps[0].agent().clone(),
+        dnas: vec![dna.dna_hash().clone()],
+        last_time_queried: None,
+    };
+
+    tokio::time::timeout(std::time::Duration::from_secs(30), async {
+        loop {
+            let network_info = conductors[0].network_info(&app_id, &payload).await.unwrap();
+            if let Some(rejection) = network_info[0].preflight_rejections.first() {
+                assert!(
+                    rejection.reason.contains("network_id mismatch"),
+                    "{rejection:?}"
+                );
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        }
+    })
+    .await
+    .unwrap();
+}
@@ -68,6 +68,7 @@ async fn network_seed_regression() {
             roles_settings: Default::default(),
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         })
         .await
         .unwrap();
@@ -82,6 +83,7 @@ async fn network_seed_regression() {
             roles_settings: Default::default(),
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         })
         .await
         .unwrap();
@@ -316,6 +318,7 @@ impl TestCase {
                 roles_settings: Default::default(),
                 ignore_genesis_failure: false,
                 allow_throwaway_random_agent_key: true,
+                wasm_limits: None,
             })
             .await
             .unwrap();
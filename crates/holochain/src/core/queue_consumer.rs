@@ -145,6 +145,10 @@ pub async fn spawn_queue_consumer_tasks(
                 cache.clone(),
                 keystore.clone(),
                 Arc::new(dna_def),
+                conductor
+                    .get_config()
+                    .conductor_tuning_params()
+                    .app_validation_concurrency(),
             ),
             conductor.clone(),
             tx_integration.clone(),
@@ -676,7 +680,7 @@ impl BackOff {
         // Sleep this task for the chosen duration.
         // This future may be cancelled during this await,
         // and any code following will not be executed.
-        tokio::time::sleep(dur).await;
+        sleep(dur).await;
         // If the sleep completes then we bump the start of the range
         // or take the end if we have reached the end.
         self.range.start = std::cmp::min(self.range.start * 2, self.range.end);
@@ -691,6 +695,15 @@ impl BackOff {
     }
 }
 
+/// Sleep until the simulated clock has advanced by `dur` when this thread
+/// follows one, or for `dur` of real time otherwise.
+async fn sleep(dur: Duration) {
+    match holochain_zome_types::timestamp::sim_clock::sleep(dur) {
+        Some(sim_sleep) => sim_sleep.await,
+        None => tokio::time::sleep(dur).await,
+    }
+}
+
 /// Declares whether a workflow has exhausted the queue or not
 #[derive(Clone, Debug, PartialEq)]
 pub enum WorkComplete {
@@ -738,7 +751,7 @@ async fn queue_consumer_main_task_impl<
                             dly.as_millis(),
                             name
                         );
-                        tokio::time::sleep(dly).await;
+                        sleep(dly).await;
                     }
                     tx.trigger(&"retrigger")
                 }
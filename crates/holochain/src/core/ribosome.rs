@@ -8,6 +8,8 @@
 // This allow is here because #[automock] automaticaly creates a struct without
 // documentation, and there seems to be no way to add docs to it after the fact
 #[allow(missing_docs)]
+pub mod call_limits;
+pub mod call_usage;
 pub mod error;
 
 /// How to version guest callbacks.
@@ -186,6 +188,24 @@ impl HostContext {
         }
     }
 
+    /// Get the resource counters of a zome call.
+    /// Callbacks aren't accounted to any zome call, so they have none.
+    pub fn maybe_call_usage(&self) -> Option<Arc<call_usage::CallUsage>> {
+        match self {
+            Self::ZomeCall(ZomeCallHostAccess { usage, .. }) => Some(usage.clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the limits on the wasm of a zome call.
+    /// Callbacks have no limits of their own, so the conductor's built-in limits apply.
+    pub fn call_limits(&self) -> call_limits::ZomeCallLimits {
+        match self {
+            Self::ZomeCall(ZomeCallHostAccess { limits, .. }) => limits.clone(),
+            _ => Default::default(),
+        }
+    }
+
     /// Get the DPKI service if installed.
     pub fn maybe_dpki(&self) -> DpkiApi {
         match self.clone() {
@@ -224,8 +244,10 @@ impl HostContext {
     /// Get the network, panics if none was provided
     pub fn network(&self) -> Arc<dyn HolochainP2pDnaT> {
         match self {
-            Self::ZomeCall(ZomeCallHostAccess { network, .. })
-            | Self::Init(InitHostAccess { network, .. })
+            Self::ZomeCall(ZomeCallHostAccess { network, usage, .. }) => Arc::new(
+                call_usage::CallUsageNetwork::new(Arc::new(network.clone()), usage.clone()),
+            ),
+            Self::Init(InitHostAccess { network, .. })
             | Self::PostCommit(PostCommitHostAccess { network, .. }) => Arc::new(network.clone()),
             Self::Validate(ValidateHostAccess { network, .. }) => network.clone(),
             _ => panic!(
@@ -579,6 +601,10 @@ pub struct ZomeCallHostAccess {
     pub network: HolochainP2pDna,
     pub signal_tx: broadcast::Sender<Signal>,
     pub call_zome_handle: CellConductorReadHandle,
+    /// The resources used by this zome call so far.
+    pub usage: Arc<call_usage::CallUsage>,
+    /// The limits on the wasm of this zome call.
+    pub limits: call_limits::ZomeCallLimits,
 }
 
 impl std::fmt::Debug for ZomeCallHostAccess {
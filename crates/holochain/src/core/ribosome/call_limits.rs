@@ -0,0 +1,23 @@
+//! Limits on the resources that a single zome call may use while it runs.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::OwnedSemaphorePermit;
+
+/// Limits that the ribosome enforces on the wasm of a zome call.
+///
+/// The number of calls that may run at once is enforced by the cell,
+/// but the permit it takes is held here, so that it's only released
+/// once the wasm has returned.
+#[derive(Clone, Debug, Default)]
+pub struct ZomeCallLimits {
+    /// The metering points the call may use.
+    /// If not set, the conductor's built-in limit applies.
+    pub max_metering_points: Option<u64>,
+    /// The pages of memory the wasm instance may grow to.
+    pub max_memory_pages: Option<u32>,
+    /// The time the wasm may run before the call fails.
+    pub max_call_duration: Option<Duration>,
+    /// The permit this call holds against its role's concurrent calls.
+    pub concurrency_permit: Option<Arc<OwnedSemaphorePermit>>,
+}
@@ -0,0 +1,347 @@
+//! Counting the resources that a single zome call uses while it runs.
+
+use holochain_chc::ChcImpl;
+use holochain_conductor_api::ResourceUsage;
+use holochain_p2p::actor::HolochainP2pResult;
+use holochain_p2p::dht::Arq;
+use holochain_p2p::kitsune_p2p::agent_store::AgentInfoSigned;
+use holochain_p2p::kitsune_p2p::dependencies::kitsune_p2p_fetch::OpHashSized;
+use holochain_p2p::{actor, event, HolochainP2pDnaT};
+use holochain_types::prelude::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Resources used by one zome call, counted as the call runs.
+///
+/// Host functions are counted by name as they return. The guest payload read
+/// is the size of the serialized output that a reading host function passes
+/// back into the wasm, and the guest payload written is the size of the
+/// serialized input that the wasm passes to a writing host function. Database
+/// I/O, including that of the cascade, validation and integration, is not
+/// measured.
+/// Network requests are counted by [`CallUsageNetwork`] as they are made.
+#[derive(Debug, Default)]
+pub struct CallUsage {
+    metering_points: AtomicU64,
+    guest_payload_bytes_read: AtomicU64,
+    guest_payload_bytes_written: AtomicU64,
+    network_requests: AtomicU64,
+}
+
+impl CallUsage {
+    /// Count a call to the host function with the given name, which was
+    /// passed `input_len` bytes by the wasm and passed back `output_len`.
+    pub(crate) fn record_host_fn(&self, host_fn_name: &str, input_len: u64, output_len: u64) {
+        match host_fn_name {
+            "__hc__create_1"
+            | "__hc__update_1"
+            | "__hc__delete_1"
+            | "__hc__create_link_1"
+            | "__hc__delete_link_1" => {
+                self.guest_payload_bytes_written
+                    .fetch_add(input_len, Ordering::Relaxed);
+            }
+            "__hc__query_1"
+            | "__hc__get_1"
+            | "__hc__get_details_1"
+            | "__hc__get_many_1"
+            | "__hc__get_latest_1"
+            | "__hc__get_links_1"
+            | "__hc__get_link_details_1"
+            | "__hc__count_links_1"
+            | "__hc__get_agent_activity_1"
+            | "__hc__must_get_entry_1"
+            | "__hc__must_get_action_1"
+            | "__hc__must_get_actions_1"
+            | "__hc__must_get_valid_record_1"
+            | "__hc__must_get_agent_activity_1" => {
+                self.guest_payload_bytes_read
+                    .fetch_add(output_len, Ordering::Relaxed);
+            }
+            _ => (),
+        }
+    }
+
+    /// Count the wasm metering points used by the call.
+    pub(crate) fn add_metering_points(&self, points: u64) {
+        self.metering_points.fetch_add(points, Ordering::Relaxed);
+    }
+
+    fn add_network_requests(&self, count: usize) {
+        self.network_requests
+            .fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    /// The usage of this call, which took `wall_time` to run.
+    pub fn to_resource_usage(&self, wall_time: std::time::Duration) -> ResourceUsage {
+        ResourceUsage {
+            call_count: 1,
+            wall_time_us: wall_time.as_micros() as u64,
+            metering_points: self.metering_points.load(Ordering::Relaxed),
+            guest_payload_bytes_read: self.guest_payload_bytes_read.load(Ordering::Relaxed),
+            guest_payload_bytes_written: self.guest_payload_bytes_written.load(Ordering::Relaxed),
+            network_requests: self.network_requests.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// The network of a zome call, which counts the requests the call makes to
+/// other agents. Gets that the cascade answers locally, because this agent
+/// is an authority or has the data cached, never reach it, so they aren't
+/// counted.
+pub struct CallUsageNetwork {
+    network: Arc<dyn HolochainP2pDnaT>,
+    usage: Arc<CallUsage>,
+}
+
+impl CallUsageNetwork {
+    /// Count the requests made through `network` in `usage`.
+    pub fn new(network: Arc<dyn HolochainP2pDnaT>, usage: Arc<CallUsage>) -> Self {
+        Self { network, usage }
+    }
+}
+
+#[async_trait::async_trait]
+impl HolochainP2pDnaT for CallUsageNetwork {
+    fn dna_hash(&self) -> DnaHash {
+        self.network.dna_hash()
+    }
+
+    async fn join(
+        &self,
+        agent: AgentPubKey,
+        maybe_agent_info: Option<AgentInfoSigned>,
+        initial_arq: Option<Arq>,
+    ) -> HolochainP2pResult<()> {
+        self.network
+            .join(agent, maybe_agent_info, initial_arq)
+            .await
+    }
+
+    async fn leave(&self, agent: AgentPubKey) -> HolochainP2pResult<()> {
+        self.network.leave(agent).await
+    }
+
+    async fn call_remote(
+        &self,
+        to_agent: AgentPubKey,
+        zome_call_params_serialized: ExternIO,
+        signature: Signature,
+    ) -> HolochainP2pResult<SerializedBytes> {
+        self.usage.add_network_requests(1);
+        self.network
+            .call_remote(to_agent, zome_call_params_serialized, signature)
+            .await
+    }
+
+    async fn send_remote_signal(
+        &self,
+        to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
+    ) -> HolochainP2pResult<()> {
+        self.usage.add_network_requests(to_agent_list.len());
+        self.network.send_remote_signal(to_agent_list).await
+    }
+
+    async fn send_remote_signal_with_ack(
+        &self,
+        signal_id: RemoteSignalId,
+        to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
+        retry_for: std::time::Duration,
+    ) -> HolochainP2pResult<Vec<AgentPubKey>> {
+        self.usage.add_network_requests(to_agent_list.len());
+        self.network
+            .send_remote_signal_with_ack(signal_id, to_agent_list, retry_for)
+            .await
+    }
+
+    async fn publish(
+        &self,
+        request_validation_receipt: bool,
+        countersigning_session: bool,
+        basis_hash: OpBasis,
+        source: AgentPubKey,
+        op_hash_list: Vec<OpHashSized>,
+        timeout_ms: Option<u64>,
+        reflect_ops: Option<Vec<DhtOp>>,
+    ) -> HolochainP2pResult<()> {
+        self.network
+            .publish(
+                request_validation_receipt,
+                countersigning_session,
+                basis_hash,
+                source,
+                op_hash_list,
+                timeout_ms,
+                reflect_ops,
+            )
+            .await
+    }
+
+    async fn publish_countersign(
+        &self,
+        flag: bool,
+        basis_hash: OpBasis,
+        op: DhtOp,
+    ) -> HolochainP2pResult<()> {
+        self.network.publish_countersign(flag, basis_hash, op).await
+    }
+
+    async fn get(
+        &self,
+        dht_hash: AnyDhtHash,
+        options: actor::GetOptions,
+    ) -> HolochainP2pResult<Vec<WireOps>> {
+        self.usage.add_network_requests(1);
+        self.network.get(dht_hash, options).await
+    }
+
+    async fn get_many(
+        &self,
+        dht_hashes: Vec<AnyDhtHash>,
+        options: actor::GetOptions,
+    ) -> HolochainP2pResult<Vec<HolochainP2pResult<Vec<WireOps>>>> {
+        self.usage.add_network_requests(1);
+        self.network.get_many(dht_hashes, options).await
+    }
+
+    async fn get_latest(
+        &self,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: actor::GetOptions,
+    ) -> HolochainP2pResult<Vec<WireRecordOps>> {
+        self.usage.add_network_requests(1);
+        self.network
+            .get_latest(action_hash, tie_break, options)
+            .await
+    }
+
+    async fn get_meta(
+        &self,
+        dht_hash: AnyDhtHash,
+        options: actor::GetMetaOptions,
+    ) -> HolochainP2pResult<Vec<MetadataSet>> {
+        self.usage.add_network_requests(1);
+        self.network.get_meta(dht_hash, options).await
+    }
+
+    async fn get_links(
+        &self,
+        link_key: WireLinkKey,
+        options: actor::GetLinksOptions,
+    ) -> HolochainP2pResult<Vec<WireLinkOps>> {
+        self.usage.add_network_requests(1);
+        self.network.get_links(link_key, options).await
+    }
+
+    async fn count_links(&self, query: WireLinkQuery) -> HolochainP2pResult<CountLinksResponse> {
+        self.usage.add_network_requests(1);
+        self.network.count_links(query).await
+    }
+
+    async fn get_agent_activity(
+        &self,
+        agent: AgentPubKey,
+        query: ChainQueryFilter,
+        options: actor::GetActivityOptions,
+    ) -> HolochainP2pResult<Vec<AgentActivityResponse>> {
+        self.usage.add_network_requests(1);
+        self.network.get_agent_activity(agent, query, options).await
+    }
+
+    async fn must_get_agent_activity(
+        &self,
+        author: AgentPubKey,
+        filter: ChainFilter,
+    ) -> HolochainP2pResult<Vec<MustGetAgentActivityResponse>> {
+        self.usage.add_network_requests(1);
+        self.network.must_get_agent_activity(author, filter).await
+    }
+
+    async fn send_validation_receipts(
+        &self,
+        to_agent: AgentPubKey,
+        receipts: ValidationReceiptBundle,
+    ) -> HolochainP2pResult<()> {
+        self.network
+            .send_validation_receipts(to_agent, receipts)
+            .await
+    }
+
+    async fn authority_for_hash(&self, basis: OpBasis) -> HolochainP2pResult<bool> {
+        self.network.authority_for_hash(basis).await
+    }
+
+    async fn countersigning_session_negotiation(
+        &self,
+        agents: Vec<AgentPubKey>,
+        message: event::CountersigningSessionNegotiationMessage,
+    ) -> HolochainP2pResult<()> {
+        self.usage.add_network_requests(agents.len());
+        self.network
+            .countersigning_session_negotiation(agents, message)
+            .await
+    }
+
+    async fn new_integrated_data(&self) -> HolochainP2pResult<()> {
+        self.network.new_integrated_data().await
+    }
+
+    fn chc(&self) -> Option<ChcImpl> {
+        self.network.chc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use holochain_p2p::MockHolochainP2pDnaT;
+
+    #[test]
+    fn host_fns_are_counted_by_kind() {
+        let usage = CallUsage::default();
+        usage.record_host_fn("__hc__create_1", 100, 10);
+        usage.record_host_fn("__hc__get_1", 10, 50);
+        usage.record_host_fn("__hc__query_1", 10, 50);
+        usage.record_host_fn("__hc__sys_time_1", 10, 20);
+        usage.add_metering_points(7);
+
+        let usage = usage.to_resource_usage(std::time::Duration::from_millis(2));
+        assert_eq!(1, usage.call_count);
+        assert_eq!(2_000, usage.wall_time_us);
+        assert_eq!(7, usage.metering_points);
+        assert_eq!(100, usage.guest_payload_bytes_written);
+        assert_eq!(100, usage.guest_payload_bytes_read);
+        assert_eq!(0, usage.network_requests);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn network_requests_are_counted_as_they_are_made() {
+        let mut mock = MockHolochainP2pDnaT::new();
+        mock.expect_get().returning(|_, _| Ok(vec![]));
+        mock.expect_authority_for_hash().returning(|_| Ok(true));
+        mock.expect_send_remote_signal().returning(|_| Ok(()));
+
+        let usage = Arc::new(CallUsage::default());
+        let network = CallUsageNetwork::new(Arc::new(mock), usage.clone());
+        let hash: AnyDhtHash = ActionHash::from_raw_36(vec![0; 36]).into();
+        network.get(hash.clone(), Default::default()).await.unwrap();
+        network.get(hash.clone(), Default::default()).await.unwrap();
+        network
+            .authority_for_hash(hash.clone().into())
+            .await
+            .unwrap();
+        let signal = (
+            AgentPubKey::from_raw_36(vec![0; 36]),
+            ExternIO::encode(()).unwrap(),
+            Signature([0; 64]),
+        );
+        network
+            .send_remote_signal(vec![signal.clone(), signal])
+            .await
+            .unwrap();
+
+        let usage = usage.to_resource_usage(std::time::Duration::ZERO);
+        assert_eq!(4, usage.network_requests);
+    }
+}
@@ -112,6 +112,22 @@ pub enum RibosomeError {
 
     #[error("The callback has invalid parameters: {0}")]
     CallbackInvalidParameters(String),
+
+    /// The zome call used up all the metering points it was allowed.
+    #[error("The zome call ran out of wasm metering points")]
+    MeteringLimitExceeded,
+
+    /// The wasm of the zome call ran for longer than it was allowed.
+    #[error("The zome call did not finish within {0:?}")]
+    ZomeCallTimeout(std::time::Duration),
+
+    /// The zome call needed more pages of wasm memory than it was allowed.
+    #[error("The zome call needed more than the {0} pages of wasm memory allowed")]
+    MemoryLimitExceeded(u32),
+
+    /// The role already has as many zome calls running as it is allowed.
+    #[error("There are already {0} zome calls running for this role")]
+    TooManyConcurrentCalls(usize),
 }
 
 /// Type alias
@@ -85,6 +85,9 @@ host_fn_api_impls! {
 
     fn call (Vec<zt::call::Call>) -> Vec<zt::prelude::ZomeCallResponse>;
 
+    // Call the same function on many agents concurrently.
+    fn call_remote_many (zt::call::CallRemoteManyInput) -> zt::call::CallRemoteManyOutput;
+
     // @todo List all the local capability claims.
     fn capability_claims (()) -> ();
 
@@ -154,6 +157,12 @@ host_fn_api_impls! {
     // Attempt to get a live entry from the cascade.
     fn get (Vec<zt::entry::GetInput>) -> Vec<Option<zt::record::Record>>;
 
+    // Get many live entries from the cascade with one request per authority.
+    fn get_many (zt::entry::GetManyInput) -> Vec<zt::entry::GetManyResult>;
+
+    // Follow the updates of an action to the latest version.
+    fn get_latest (zt::entry::GetLatestInput) -> Option<zt::entry::LatestRecord>;
+
     fn get_agent_activity (zt::agent_activity::GetAgentActivityInput) -> zt::query::AgentActivity;
 
     // Query agent key lineage for the provided key.
@@ -186,6 +195,9 @@ host_fn_api_impls! {
     // Retrieve an action from the DHT or short circuit.
     fn must_get_action (zt::entry::MustGetActionInput) -> SignedActionHashed;
 
+    // Retrieve many actions from the DHT or short circuit.
+    fn must_get_actions (zt::entry::MustGetActionsInput) -> Vec<SignedActionHashed>;
+
     fn must_get_agent_activity (zt::chain::MustGetAgentActivityInput) -> Vec<zt::op::RegisterAgentActivity>;
 
     // Query the source chain for data.
@@ -197,10 +209,26 @@ host_fn_api_impls! {
     // Remotely signal many agents without waiting for responses
     fn send_remote_signal (zt::signal::RemoteSignal) -> ();
 
+    // Send a remote signal and wait for the recipients to acknowledge it.
+    fn send_remote_signal_with_ack (zt::signal::RemoteSignalWithAck) -> zt::signal::RemoteSignalDelivery;
+
     // @todo
     #[cfg(feature = "unstable-functions")]
     fn schedule (String) -> ();
 
+    // Schedule a function to be called with a payload, returning the id of
+    // the new schedule.
+    #[cfg(feature = "unstable-functions")]
+    fn schedule_with_payload (zt::schedule::ScheduleWithPayloadInput) -> zt::schedule::ScheduleId;
+
+    // Cancel a schedule by its id.
+    #[cfg(feature = "unstable-functions")]
+    fn cancel_schedule (zt::schedule::ScheduleId) -> ();
+
+    // List the pending schedules of the calling zome.
+    #[cfg(feature = "unstable-functions")]
+    fn list_schedules (()) -> Vec<zt::schedule::PendingSchedule>;
+
     // TODO deprecated, remove me
     #[cfg(feature = "unstable-functions")]
     fn sleep (core::time::Duration) -> ();
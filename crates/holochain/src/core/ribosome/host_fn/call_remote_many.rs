@@ -0,0 +1,279 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use crate::core::ribosome::ZomeCallParamsSigned;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use holochain_nonce::fresh_nonce;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+/// Call the same zome function on many agents concurrently.
+///
+/// Every call is signed up front and then sent at once, so the timeout only
+/// starts once signing is done. Results are collected until either
+/// `min_responses` agents have answered with [`ZomeCallResponse::Ok`], every
+/// agent has answered, or the timeout elapses. Calls still in flight at that
+/// point are dropped and reported as [`RemoteCallResult::NotAwaited`] or
+/// [`RemoteCallResult::TimedOut`].
+///
+/// At most [`CALL_REMOTE_MANY_MAX_AGENTS`] agents can be called at once.
+#[cfg_attr(feature = "instrument", tracing::instrument(skip(ribosome, call_context), fields(?call_context.zome, function = ?call_context.function_name)))]
+pub fn call_remote_many(
+    ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: CallRemoteManyInput,
+) -> Result<CallRemoteManyOutput, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            write_network: Permission::Allow,
+            agent_info: Permission::Allow,
+            ..
+        } => {
+            let CallRemoteManyInput {
+                agents,
+                zome_name,
+                fn_name,
+                cap_secret,
+                payload,
+                timeout,
+                min_responses,
+            } = input;
+            let agents: BTreeSet<AgentPubKey> = agents.into_iter().collect();
+            if agents.len() > CALL_REMOTE_MANY_MAX_AGENTS {
+                return Err(wasm_error!(WasmErrorInner::Host(format!(
+                    "call_remote_many can call at most {} agents, but {} were given",
+                    CALL_REMOTE_MANY_MAX_AGENTS,
+                    agents.len()
+                )))
+                .into());
+            }
+            let min_responses = min_responses.unwrap_or(agents.len());
+            let provenance = call_context
+                .host_context
+                .workspace()
+                .source_chain()
+                .as_ref()
+                .expect("Must have source chain to know provenance")
+                .agent_pubkey()
+                .clone();
+            let dna_hash = ribosome.dna_def().as_hash().clone();
+            let network = call_context.host_context().network().clone();
+            let keystore = call_context.host_context.keystore().clone();
+
+            tokio_helper::block_forever_on(async move {
+                let signed_calls =
+                    futures::future::try_join_all(agents.iter().cloned().map(|agent| {
+                        let zome_call_params =
+                            fresh_nonce(Timestamp::now()).map(|(nonce, expires_at)| {
+                                ZomeCallParams {
+                                    provenance: provenance.clone(),
+                                    cell_id: CellId::new(dna_hash.clone(), agent.clone()),
+                                    zome_name: zome_name.clone(),
+                                    fn_name: fn_name.clone(),
+                                    cap_secret,
+                                    payload: payload.clone(),
+                                    nonce,
+                                    expires_at,
+                                }
+                            });
+                        let keystore = keystore.clone();
+                        async move {
+                            let zome_call_params =
+                                zome_call_params.map_err(|e| -> RuntimeError {
+                                    wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                                })?;
+                            let signed =
+                                ZomeCallParamsSigned::try_from_params(&keystore, zome_call_params)
+                                    .await
+                                    .map_err(|e| -> RuntimeError {
+                                        wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                                    })?;
+                            Result::<_, RuntimeError>::Ok((agent, signed))
+                        }
+                    }))
+                    .await?;
+
+                let deadline = tokio::time::Instant::now() + timeout;
+                let mut calls = FuturesUnordered::new();
+                for (agent, signed) in signed_calls {
+                    let network = network.clone();
+                    calls.push(async move {
+                        let response = match network
+                            .call_remote(agent.clone(), signed.bytes, signed.signature)
+                            .await
+                        {
+                            Ok(serialized_bytes) => ZomeCallResponse::try_from(serialized_bytes)
+                                .unwrap_or_else(|e| ZomeCallResponse::NetworkError(e.to_string())),
+                            Err(e) => ZomeCallResponse::NetworkError(e.to_string()),
+                        };
+                        (agent, response)
+                    });
+                }
+
+                let mut results = CallRemoteManyOutput::new();
+                let mut ok_responses = 0;
+                while ok_responses < min_responses {
+                    match tokio::time::timeout_at(deadline, calls.next()).await {
+                        Ok(Some((agent, response))) => {
+                            if matches!(response, ZomeCallResponse::Ok(_)) {
+                                ok_responses += 1;
+                            }
+                            results.insert(agent, RemoteCallResult::Response(response));
+                        }
+                        Ok(None) | Err(_) => break,
+                    }
+                }
+
+                let unanswered = if ok_responses >= min_responses {
+                    RemoteCallResult::NotAwaited
+                } else {
+                    RemoteCallResult::TimedOut
+                };
+                for agent in agents {
+                    results.entry(agent).or_insert_with(|| unanswered.clone());
+                }
+                Ok(results)
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "call_remote_many".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+pub mod wasm_test {
+    use crate::core::ribosome::wasm_test::RibosomeTestFixture;
+    use ::fixt::prelude::*;
+    use hdk::prelude::*;
+    use holochain_wasm_test_utils::TestWasm;
+
+    #[tokio::test(flavor = "multi_thread")]
+    /// every agent is called and answers for itself
+    async fn call_remote_many_test() {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            conductor,
+            alice,
+            bob,
+            alice_pubkey,
+            bob_pubkey,
+            ..
+        } = RibosomeTestFixture::new(TestWasm::WhoAmI).await;
+
+        let _: () = conductor.call(&bob, "set_access", ()).await;
+        let results: CallRemoteManyOutput = conductor
+            .call(
+                &alice,
+                "whoarethey_many",
+                (
+                    vec![bob_pubkey.clone(), alice_pubkey.clone(), bob_pubkey.clone()],
+                    None::<usize>,
+                ),
+            )
+            .await;
+
+        assert_eq!(2, results.len());
+        for agent in [alice_pubkey, bob_pubkey] {
+            match &results[&agent] {
+                RemoteCallResult::Response(ZomeCallResponse::Ok(io)) => {
+                    let agent_info: AgentInfo = io.decode().unwrap();
+                    assert_eq!(agent, agent_info.agent_latest_pubkey);
+                }
+                r => panic!("unexpected result for {agent}: {r:?}"),
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    /// once the quorum is met the remaining agents are not waited for
+    async fn call_remote_many_quorum() {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            conductor,
+            alice,
+            bob,
+            alice_pubkey,
+            bob_pubkey,
+            ..
+        } = RibosomeTestFixture::new(TestWasm::WhoAmI).await;
+
+        let _: () = conductor.call(&bob, "set_access", ()).await;
+        let results: CallRemoteManyOutput = conductor
+            .call(
+                &alice,
+                "whoarethey_many",
+                (vec![bob_pubkey, alice_pubkey], Some(1_usize)),
+            )
+            .await;
+
+        assert_eq!(2, results.len());
+        assert!(results
+            .values()
+            .any(|r| matches!(r, RemoteCallResult::Response(ZomeCallResponse::Ok(_)))));
+        assert!(results
+            .values()
+            .all(|r| !matches!(r, RemoteCallResult::TimedOut)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    /// agents that haven't answered by the timeout are reported as timed out
+    async fn call_remote_many_timed_out() {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            conductor,
+            alice,
+            bob,
+            alice_pubkey,
+            bob_pubkey,
+            ..
+        } = RibosomeTestFixture::new(TestWasm::WhoAmI).await;
+
+        let _: () = conductor.call(&bob, "set_access", ()).await;
+        let results: CallRemoteManyOutput = conductor
+            .call(
+                &alice,
+                "whoarethey_many_within",
+                (vec![bob_pubkey, alice_pubkey], std::time::Duration::ZERO),
+            )
+            .await;
+
+        assert_eq!(2, results.len());
+        assert!(results
+            .values()
+            .all(|r| matches!(r, RemoteCallResult::TimedOut)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    /// the number of agents called at once is capped
+    async fn call_remote_many_too_many_agents() {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            conductor, alice, ..
+        } = RibosomeTestFixture::new(TestWasm::WhoAmI).await;
+
+        let agents: Vec<AgentPubKey> = (0..=CALL_REMOTE_MANY_MAX_AGENTS)
+            .map(|_| fixt!(AgentPubKey))
+            .collect();
+        let result: Result<CallRemoteManyOutput, _> = conductor
+            .call_fallible(
+                &alice,
+                "whoarethey_many_within",
+                (agents, std::time::Duration::from_secs(1)),
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+}
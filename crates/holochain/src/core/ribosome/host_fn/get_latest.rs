@@ -0,0 +1,82 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_cascade::CascadeImpl;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+#[cfg_attr(feature = "instrument", tracing::instrument(skip(_ribosome, call_context), fields(?call_context.zome, function = ?call_context.function_name)))]
+pub fn get_latest(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: GetLatestInput,
+) -> Result<Option<LatestRecord>, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            read_workspace: Permission::Allow,
+            ..
+        } => {
+            let GetLatestInput {
+                action_hash,
+                tie_break,
+                get_options,
+            } = input;
+            tokio_helper::block_forever_on(async move {
+                CascadeImpl::from_workspace_and_network(
+                    &call_context.host_context.workspace(),
+                    call_context.host_context.network().clone(),
+                )
+                .get_latest(action_hash, tie_break, get_options)
+                .await
+            })
+            .map_err(|cascade_error| -> RuntimeError {
+                wasm_error!(WasmErrorInner::Host(cascade_error.to_string())).into()
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "get_latest".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+pub mod slow_tests {
+    use crate::core::ribosome::wasm_test::RibosomeTestFixture;
+    use hdk::prelude::*;
+    use holochain_wasm_test_utils::TestWasm;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_latest_follows_update_chain() {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            conductor, alice, ..
+        } = RibosomeTestFixture::new(TestWasm::Update).await;
+
+        let (original, latest): (ActionHash, ActionHash) =
+            conductor.call(&alice, "create_msg_chain", ()).await;
+
+        let latest_record: Option<LatestRecord> =
+            conductor.call(&alice, "get_latest_msg", original).await;
+        let latest_record = latest_record.unwrap();
+        assert_eq!(&latest, latest_record.record.action_address());
+        assert_eq!(None, latest_record.continue_from);
+
+        let latest_record: Option<LatestRecord> = conductor
+            .call(&alice, "get_latest_msg", latest.clone())
+            .await;
+        assert_eq!(
+            Some(&latest),
+            latest_record.as_ref().map(|l| l.record.action_address())
+        );
+    }
+}
@@ -149,6 +149,109 @@ pub mod slow_tests {
         assert_eq!(links[1].target, foo_baz);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn hash_path_leaves_walks_shards() {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            conductor, alice, ..
+        } = RibosomeTestFixture::new(TestWasm::HashPath).await;
+
+        let sharded = ["foo.1:2#barbaz", "foo.1:2#bazqux", "foo.1:2#quux"];
+        for path in sharded {
+            let _: () = conductor.call(&alice, "ensure", path.to_string()).await;
+        }
+
+        // One level for each shard and one for the full components.
+        let leaves: Vec<Path> = conductor
+            .call(&alice, "leaves", ("foo".to_string(), 3_usize))
+            .await;
+        assert_eq!(sharded.len(), leaves.len());
+        for path in sharded {
+            assert!(leaves.contains(&Path::from(path)));
+        }
+
+        // Only the shards are at depth 2.
+        let shards: Vec<Path> = conductor
+            .call(&alice, "leaves", ("foo".to_string(), 2_usize))
+            .await;
+        assert_eq!(2, shards.len());
+
+        // Nothing is below the leaves.
+        let below: Vec<Path> = conductor
+            .call(&alice, "leaves", ("foo".to_string(), 4_usize))
+            .await;
+        assert!(below.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn hash_path_time_index_pages_newest_first() {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            conductor, alice, ..
+        } = RibosomeTestFixture::new(TestWasm::HashPath).await;
+
+        const HOUR: i64 = 60 * 60 * 1_000_000;
+        // 2024-03-07T15:00:00Z
+        let t0 = 1_709_823_600_000_000;
+        for (micros, tag) in [
+            (t0, "a"),
+            (t0 + 1, "b"),
+            (t0 + HOUR, "c"),
+            (t0 + 24 * HOUR, "d"),
+            (t0 + 24 * HOUR, "e"),
+            (t0 - 365 * 24 * HOUR, "f"),
+        ] {
+            let _: ActionHash = conductor
+                .call(
+                    &alice,
+                    "time_index_at",
+                    (
+                        "posts".to_string(),
+                        Timestamp::from_micros(micros),
+                        tag.to_string(),
+                    ),
+                )
+                .await;
+        }
+
+        let get_range = |from: i64, until: i64, limit: usize| {
+            conductor.call::<_, Vec<TimeIndexItem>>(
+                &alice,
+                "time_index_range",
+                (
+                    "posts".to_string(),
+                    Timestamp::from_micros(from),
+                    Timestamp::from_micros(until),
+                    limit,
+                ),
+            )
+        };
+        let tags = |items: &[TimeIndexItem]| -> Vec<String> {
+            items
+                .iter()
+                .map(|item| String::from_utf8(item.tag.0.clone()).unwrap())
+                .collect()
+        };
+
+        // - Items at the same time are never split across pages.
+        let page = get_range(0, i64::MAX, 1).await;
+        let mut newest = tags(&page);
+        newest.sort();
+        assert_eq!(vec!["d", "e"], newest);
+
+        // - Each page carries on from the time of the last item.
+        let page = get_range(0, page[1].timestamp.as_micros(), 2).await;
+        assert_eq!(vec!["c", "b"], tags(&page));
+        let page = get_range(0, page[1].timestamp.as_micros(), 2).await;
+        assert_eq!(vec!["a", "f"], tags(&page));
+        let page = get_range(0, page[1].timestamp.as_micros(), 2).await;
+        assert!(page.is_empty());
+
+        // - Only the buckets that overlap the range are read.
+        let page = get_range(t0, t0 + HOUR, 10).await;
+        assert_eq!(vec!["b", "a"], tags(&page));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn hash_path_anchor_list_anchors() {
         holochain_trace::test_run();
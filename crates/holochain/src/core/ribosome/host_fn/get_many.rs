@@ -0,0 +1,111 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use futures::StreamExt;
+use holochain_cascade::error::CascadeResult;
+use holochain_cascade::CascadeImpl;
+use holochain_cascade::DHT_GET_CHUNK_LEN_DEFAULT;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+#[cfg_attr(feature = "instrument", tracing::instrument(skip(_ribosome, call_context), fields(?call_context.zome, function = ?call_context.function_name)))]
+pub fn get_many(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: GetManyInput,
+) -> Result<Vec<GetManyResult>, RuntimeError> {
+    let num_requests = input.any_dht_hashes.len();
+    tracing::debug!("Starting with {} requests.", num_requests);
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            read_workspace: Permission::Allow,
+            ..
+        } => {
+            let GetManyInput {
+                any_dht_hashes,
+                get_options,
+            } = input;
+            // Large requests, such as blob chunks, go out a chunk at a time
+            // rather than in a single round of requests to every authority.
+            let results = tokio_helper::block_forever_on(async move {
+                let cascade = CascadeImpl::from_workspace_and_network(
+                    &call_context.host_context.workspace(),
+                    call_context.host_context.network().clone(),
+                );
+                let chunked =
+                    cascade.dht_get_chunked(any_dht_hashes, DHT_GET_CHUNK_LEN_DEFAULT, get_options);
+                futures::pin_mut!(chunked);
+                let mut results = Vec::with_capacity(num_requests);
+                while let Some(chunk) = chunked.next().await {
+                    results.extend(chunk?);
+                }
+                CascadeResult::Ok(results)
+            })
+            .map_err(|cascade_error| -> RuntimeError {
+                wasm_error!(WasmErrorInner::Host(cascade_error.to_string())).into()
+            })?;
+            let results: Vec<GetManyResult> = results
+                .into_iter()
+                .map(|result| result.map_err(|cascade_error| cascade_error.to_string()))
+                .collect();
+            tracing::debug!(
+                "Ending with {} out of {} results.",
+                results.iter().filter(|r| matches!(r, Ok(Some(_)))).count(),
+                num_requests,
+            );
+            Ok(results)
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "get_many".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+pub mod slow_tests {
+    use crate::core::ribosome::wasm_test::RibosomeTestFixture;
+    use ::fixt::prelude::*;
+    use hdk::prelude::*;
+    use holochain_wasm_test_utils::TestWasm;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_many_returns_results_in_order() {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            conductor, alice, ..
+        } = RibosomeTestFixture::new(TestWasm::Create).await;
+
+        let first: ActionHash = conductor.call(&alice, "create_entry", ()).await;
+        let second: ActionHash = conductor.call(&alice, "create_msg", ()).await;
+        let missing = fixt!(ActionHash);
+
+        let records: Vec<Option<Record>> = conductor
+            .call(
+                &alice,
+                "get_many_posts",
+                vec![second.clone(), missing, first.clone()],
+            )
+            .await;
+
+        assert_eq!(3, records.len());
+        assert_eq!(
+            Some(&second),
+            records[0].as_ref().map(|r| r.action_address())
+        );
+        assert!(records[1].is_none());
+        assert_eq!(
+            Some(&first),
+            records[2].as_ref().map(|r| r.action_address())
+        );
+    }
+}
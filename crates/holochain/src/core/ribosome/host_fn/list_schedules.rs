@@ -0,0 +1,45 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_state::schedule::pending_schedules;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+pub fn list_schedules(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    _input: (),
+) -> Result<Vec<PendingSchedule>, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            read_workspace: Permission::Allow,
+            ..
+        } => tokio_helper::block_forever_on(async move {
+            let workspace = call_context.host_context.workspace();
+            let source_chain = workspace
+                .source_chain()
+                .as_ref()
+                .expect("Must have source chain if read_workspace access is given");
+            let author = source_chain.agent_pubkey().clone();
+            let zome_name = call_context.zome.zome_name().clone();
+            source_chain
+                .author_db()
+                .read_async(move |txn| pending_schedules(txn, &author, Some(&zome_name)))
+                .await
+                .map_err(|e| -> RuntimeError {
+                    wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                })
+        }),
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "list_schedules".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
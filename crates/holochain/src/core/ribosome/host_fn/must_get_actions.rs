@@ -0,0 +1,109 @@
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostContext;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_cascade::CascadeImpl;
+use holochain_p2p::actor::GetOptions as NetworkGetOptions;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use wasmer::RuntimeError;
+
+#[cfg_attr(
+    feature = "instrument",
+    tracing::instrument(skip(_ribosome, call_context))
+)]
+pub fn must_get_actions(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: MustGetActionsInput,
+) -> Result<Vec<SignedActionHashed>, RuntimeError> {
+    tracing::debug!("begin must_get_actions");
+    let ret = match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            read_workspace_deterministic: Permission::Allow,
+            ..
+        } => {
+            let action_hashes = input.into_inner();
+
+            // timeouts must be handled by the network
+            tokio_helper::block_forever_on(async move {
+                let workspace = call_context.host_context.workspace();
+                use crate::core::ribosome::ValidateHostAccess;
+                let cascade = match call_context.host_context {
+                    HostContext::Validate(ValidateHostAccess { is_inline, .. }) => {
+                        if is_inline {
+                            CascadeImpl::from_workspace_and_network(
+                                &workspace,
+                                call_context.host_context.network().clone(),
+                            )
+                        } else {
+                            CascadeImpl::from_workspace_stores(workspace.stores(), None)
+                        }
+                    }
+                    _ => CascadeImpl::from_workspace_and_network(
+                        &workspace,
+                        call_context.host_context.network().clone(),
+                    ),
+                };
+                let actions = cascade
+                    .retrieve_actions(action_hashes.clone(), NetworkGetOptions::must_get_options())
+                    .await
+                    .map_err(|cascade_error| -> RuntimeError {
+                        wasm_error!(WasmErrorInner::Host(cascade_error.to_string())).into()
+                    })?;
+                let missing: Vec<AnyDhtHash> = action_hashes
+                    .into_iter()
+                    .zip(actions.iter())
+                    .filter(|(_, action)| action.is_none())
+                    .map(|(action_hash, _)| action_hash.into())
+                    .collect();
+                if missing.is_empty() {
+                    return Ok(actions.into_iter().flatten().collect());
+                }
+                match call_context.host_context {
+                    HostContext::EntryDefs(_)
+                    | HostContext::GenesisSelfCheckV1(_)
+                    | HostContext::GenesisSelfCheckV2(_)
+                    | HostContext::PostCommit(_)
+                    | HostContext::ZomeCall(_) => Err(wasm_error!(WasmErrorInner::Host(format!(
+                        "Failed to get SignedActionHashed {:?}",
+                        missing
+                    )))
+                    .into()),
+                    HostContext::Init(_) => Err(wasm_error!(WasmErrorInner::HostShortCircuit(
+                        holochain_serialized_bytes::encode(
+                            &ExternIO::encode(InitCallbackResult::UnresolvedDependencies(
+                                UnresolvedDependencies::Hashes(missing),
+                            ))
+                            .map_err(|e| -> RuntimeError { wasm_error!(e).into() })?,
+                        )
+                        .map_err(|e| -> RuntimeError { wasm_error!(e).into() })?
+                    ))
+                    .into()),
+                    HostContext::Validate(_) => Err(wasm_error!(WasmErrorInner::HostShortCircuit(
+                        holochain_serialized_bytes::encode(
+                            &ExternIO::encode(ValidateCallbackResult::UnresolvedDependencies(
+                                UnresolvedDependencies::Hashes(missing),
+                            ))
+                            .map_err(|e| -> RuntimeError { wasm_error!(e).into() })?,
+                        )
+                        .map_err(|e| -> RuntimeError { wasm_error!(e).into() })?
+                    ))
+                    .into()),
+                }
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "must_get_actions".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    };
+    tracing::debug!(?ret);
+    ret
+}
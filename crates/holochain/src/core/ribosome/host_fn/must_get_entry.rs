@@ -188,10 +188,14 @@ pub mod test {
             .call(&bob, "must_get_action", action_hash.clone())
             .await;
         assert_eq!(must_get_action.action(), &action,);
+        let must_get_actions: Vec<SignedActionHashed> = conductor
+            .call(&bob, "must_get_actions", vec![action_hash.clone()])
+            .await;
+        assert_eq!(vec![must_get_action], must_get_actions);
 
         // Must get VALID record ONLY returns the record if it is valid.
         let must_get_valid_record: Result<Record, _> = conductor
-            .call_fallible(&bob, "must_get_valid_record", action_hash)
+            .call_fallible(&bob, "must_get_valid_record", action_hash.clone())
             .await;
         assert!(must_get_valid_record.is_err());
 
@@ -203,8 +207,14 @@ pub mod test {
 
         let bad_action_hash = ActionHash::from_raw_32(vec![2; 32]);
         let bad_must_get_action: Result<SignedActionHashed, _> = conductor
-            .call_fallible(&bob, "must_get_action", bad_action_hash)
+            .call_fallible(&bob, "must_get_action", bad_action_hash.clone())
             .await;
         assert!(bad_must_get_action.is_err());
+
+        // Must get actions fails if any of the actions is missing.
+        let bad_must_get_actions: Result<Vec<SignedActionHashed>, _> = conductor
+            .call_fallible(&bob, "must_get_actions", vec![action_hash, bad_action_hash])
+            .await;
+        assert!(bad_must_get_actions.is_err());
     }
 }
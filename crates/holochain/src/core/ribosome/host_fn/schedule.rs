@@ -24,10 +24,10 @@ pub fn schedule(
                 .expect("Must have source chain if write_workspace access is given")
                 .scratch()
                 .apply(|scratch| {
-                    scratch.add_scheduled_fn(ScheduledFn::new(
-                        call_context.zome.zome_name().clone(),
-                        input.into(),
-                    ));
+                    scratch.add_scheduled_fn(
+                        ScheduleId(nanoid::nanoid!()),
+                        ScheduledFn::new(call_context.zome.zome_name().clone(), input.into()),
+                    );
                 })
                 .map_err(|e| wasm_error!(WasmErrorInner::Host(e.to_string())))?;
             Ok(())
@@ -48,10 +48,12 @@ pub fn schedule(
 mod tests {
     use crate::core::ribosome::wasm_test::RibosomeTestFixture;
     use hdk::prelude::*;
+    use holochain_state::prelude::cancel_schedule;
     use holochain_state::prelude::schedule_fn;
     use holochain_state::prelude::*;
     use holochain_state::schedule::fn_is_scheduled;
     use holochain_state::schedule::live_scheduled_fns;
+    use holochain_state::schedule::pending_schedules;
     use holochain_wasm_test_utils::TestWasm;
 
     #[tokio::test(flavor = "multi_thread")]
@@ -75,10 +77,13 @@ mod tests {
                 let ephemeral_scheduled_fn = ScheduledFn::new("foo".into(), "bar".into());
                 let persisted_scheduled_fn = ScheduledFn::new("1".into(), "2".into());
                 let persisted_schedule = Schedule::Persisted("* * * * * * *".into());
+                let ephemeral_id = ScheduleId::from("ephemeral".to_string());
+                let persisted_id = ScheduleId::from("persisted".to_string());
 
                 schedule_fn(
                     txn,
                     &alice_pubkey,
+                    persisted_id.clone(),
                     persisted_scheduled_fn.clone(),
                     Some(persisted_schedule.clone()),
                     now,
@@ -87,6 +92,7 @@ mod tests {
                 schedule_fn(
                     txn,
                     &alice_pubkey,
+                    ephemeral_id.clone(),
                     ephemeral_scheduled_fn.clone(),
                     None,
                     now,
@@ -112,6 +118,7 @@ mod tests {
                 schedule_fn(
                     txn,
                     &alice_pubkey,
+                    ephemeral_id.clone(),
                     ephemeral_scheduled_fn.clone(),
                     None,
                     now,
@@ -146,6 +153,7 @@ mod tests {
                 schedule_fn(
                     txn,
                     &alice_pubkey,
+                    ephemeral_id.clone(),
                     ephemeral_scheduled_fn.clone(),
                     None,
                     now,
@@ -167,6 +175,7 @@ mod tests {
                 schedule_fn(
                     txn,
                     &alice_pubkey,
+                    ephemeral_id.clone(),
                     ephemeral_scheduled_fn.clone(),
                     Some(ephemeral_future_schedule.clone()),
                     now,
@@ -174,6 +183,7 @@ mod tests {
                 .unwrap();
                 assert_eq!(
                     vec![(
+                        persisted_id.clone(),
                         persisted_scheduled_fn.clone(),
                         Some(persisted_schedule.clone())
                     )],
@@ -181,8 +191,16 @@ mod tests {
                 );
                 assert_eq!(
                     vec![
-                        (persisted_scheduled_fn, Some(persisted_schedule)),
-                        (ephemeral_scheduled_fn, Some(ephemeral_future_schedule)),
+                        (
+                            persisted_id,
+                            persisted_scheduled_fn,
+                            Some(persisted_schedule)
+                        ),
+                        (
+                            ephemeral_id,
+                            ephemeral_scheduled_fn,
+                            Some(ephemeral_future_schedule)
+                        ),
                     ],
                     live_scheduled_fns(txn, the_distant_future, &alice_pubkey,).unwrap(),
                 );
@@ -194,6 +212,153 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "test_utils")]
+    async fn schedule_with_payload_low_level() -> anyhow::Result<()> {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            alice_pubkey,
+            alice_host_fn_caller,
+            ..
+        } = RibosomeTestFixture::new(TestWasm::Schedule).await;
+
+        alice_host_fn_caller
+            .authored_db
+            .write_async(move |txn| {
+                let now = Timestamp::now();
+                let payload_a = ExternIO::encode("a").unwrap();
+                let payload_b = ExternIO::encode("b").unwrap();
+                let id_a = ScheduleId::from("a".to_string());
+                let id_b = ScheduleId::from("b".to_string());
+                let scheduled_fn_a =
+                    ScheduledFn::with_payload("foo".into(), "bar".into(), payload_a.clone());
+                let scheduled_fn_b =
+                    ScheduledFn::with_payload("foo".into(), "bar".into(), payload_b.clone());
+
+                // The same function can be scheduled many times with
+                // different payloads.
+                schedule_fn(
+                    txn,
+                    &alice_pubkey,
+                    id_a.clone(),
+                    scheduled_fn_a.clone(),
+                    None,
+                    now,
+                )
+                .unwrap();
+                schedule_fn(
+                    txn,
+                    &alice_pubkey,
+                    id_b.clone(),
+                    scheduled_fn_b.clone(),
+                    None,
+                    now,
+                )
+                .unwrap();
+                assert_eq!(
+                    vec![
+                        (id_a.clone(), scheduled_fn_a, None),
+                        (id_b.clone(), scheduled_fn_b, None),
+                    ],
+                    live_scheduled_fns(txn, now, &alice_pubkey).unwrap(),
+                );
+
+                let pending = pending_schedules(txn, &alice_pubkey, Some(&"foo".into())).unwrap();
+                assert_eq!(2, pending.len());
+                assert_eq!(Some(&payload_a), pending[0].payload.as_ref());
+                assert!(pending_schedules(txn, &alice_pubkey, Some(&"baz".into()))
+                    .unwrap()
+                    .is_empty());
+
+                // Cancelling removes only the one schedule.
+                assert!(cancel_schedule(txn, &alice_pubkey, &id_a).unwrap());
+                assert!(!cancel_schedule(txn, &alice_pubkey, &id_a).unwrap());
+                let pending = pending_schedules(txn, &alice_pubkey, None).unwrap();
+                assert_eq!(1, pending.len());
+                assert_eq!(id_b, pending[0].schedule_id);
+                assert_eq!(Some(&payload_b), pending[0].payload.as_ref());
+
+                Result::<(), DatabaseError>::Ok(())
+            })
+            .await
+            .unwrap();
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "test_utils")]
+    async fn schedule_with_payload_test_wasm() -> anyhow::Result<()> {
+        holochain_trace::test_run();
+        let RibosomeTestFixture {
+            conductor, alice, ..
+        } = RibosomeTestFixture::new(TestWasm::Schedule).await;
+
+        // Reminders are only run when dispatched below.
+        conductor
+            .raw_handle()
+            .start_scheduler(std::time::Duration::from_millis(1_000_000_000))
+            .await?;
+
+        let id_a: ScheduleId = conductor
+            .call(&alice, "schedule_reminder", "a".to_string())
+            .await;
+        let id_b: ScheduleId = conductor
+            .call(&alice, "schedule_reminder", "b".to_string())
+            .await;
+        assert_ne!(id_a, id_b);
+
+        let mut pending: Vec<PendingSchedule> = conductor.call(&alice, "list_reminders", ()).await;
+        pending.sort_by_key(|p| p.payload.as_ref().map(|p| p.0.clone()));
+        assert_eq!(
+            vec![
+                (id_a.clone(), Some(ExternIO::encode("a").unwrap())),
+                (id_b.clone(), Some(ExternIO::encode("b").unwrap())),
+            ],
+            pending
+                .into_iter()
+                .map(|p| (p.schedule_id, p.payload))
+                .collect::<Vec<_>>(),
+        );
+
+        let _: () = conductor.call(&alice, "cancel_reminder", id_a).await;
+        let pending: Vec<PendingSchedule> = conductor.call(&alice, "list_reminders", ()).await;
+        assert_eq!(
+            vec![id_b],
+            pending
+                .into_iter()
+                .map(|p| p.schedule_id)
+                .collect::<Vec<_>>(),
+        );
+
+        // Only the reminder that wasn't cancelled runs, with its payload.
+        conductor
+            .raw_handle()
+            .dispatch_scheduled_fns(Timestamp::now())
+            .await;
+        let reminders = loop {
+            let reminders: Vec<Record> = conductor.call(&alice, "query_reminders", ()).await;
+            if !reminders.is_empty() {
+                break reminders;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        };
+        let messages: Vec<String> = reminders
+            .iter()
+            .map(|record| match record.entry().as_option() {
+                Some(Entry::App(bytes)) => {
+                    holochain_serialized_bytes::decode(bytes.bytes()).unwrap()
+                }
+                entry => panic!("unexpected entry {:?}", entry),
+            })
+            .collect();
+        assert_eq!(vec!["b".to_string()], messages);
+
+        let pending: Vec<PendingSchedule> = conductor.call(&alice, "list_reminders", ()).await;
+        assert!(pending.is_empty());
+
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     #[cfg(feature = "test_utils")]
     async fn schedule_test_wasm() -> anyhow::Result<()> {
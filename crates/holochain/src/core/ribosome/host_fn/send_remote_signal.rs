@@ -5,8 +5,11 @@ use crate::core::ribosome::RibosomeT;
 use holochain_keystore::AgentPubKeyExt;
 use holochain_nonce::fresh_nonce;
 use holochain_types::access::Permission;
+use holochain_types::prelude::AgentPubKey;
 use holochain_types::prelude::CellId;
+use holochain_types::prelude::DnaHash;
 use holochain_types::prelude::ExternIO;
+use holochain_types::prelude::Signature;
 use holochain_wasmer_host::prelude::*;
 use holochain_zome_types::prelude::Timestamp;
 use holochain_zome_types::signal::RemoteSignal;
@@ -31,67 +34,31 @@ pub fn send_remote_signal(
             agent_info: Permission::Allow,
             ..
         } => {
-            const FN_NAME: &str = "recv_remote_signal";
             let from_agent = super::agent_info::agent_info(_ribosome, call_context.clone(), ())?
                 .agent_latest_pubkey;
             // Timeouts and errors are ignored,
             // this is a send and forget operation.
             let network = call_context.host_context().network().clone();
             let RemoteSignal { agents, signal } = input;
-            let zome_name = call_context.zome().zome_name().clone();
-            let fn_name: FunctionName = FN_NAME.into();
 
             tokio::task::spawn(
                 async move {
-                    let mut to_agent_list = Vec::new();
-
-                    let (nonce, expires_at) = match fresh_nonce(Timestamp::now()) {
-                        Ok(nonce) => nonce,
+                    let to_agent_list = match sign_remote_signals(
+                        &call_context,
+                        &from_agent,
+                        network.dna_hash(),
+                        agents,
+                        signal,
+                    )
+                    .await
+                    {
+                        Ok(to_agent_list) => to_agent_list,
                         Err(e) => {
-                            tracing::info!("Failed to get a fresh nonce because of {:?}", e);
+                            tracing::info!("{}", e);
                             return;
                         }
                     };
 
-                    for agent in agents {
-                        let zome_call_params = ZomeCallParams {
-                            provenance: from_agent.clone(),
-                            cell_id: CellId::new(network.dna_hash(), agent.clone()),
-                            zome_name: zome_name.clone(),
-                            fn_name: fn_name.clone(),
-                            cap_secret: None,
-                            payload: signal.clone(),
-                            nonce,
-                            expires_at,
-                        };
-                        let (bytes, bytes_hash) = match zome_call_params.serialize_and_hash() {
-                            Ok(bytes_and_hash) => bytes_and_hash,
-                            Err(e) => {
-                                tracing::info!(
-                                    "Failed to serialize zome call for signal because of {:?}",
-                                    e
-                                );
-                                return;
-                            }
-                        };
-
-                        match from_agent
-                            .sign_raw(call_context.host_context.keystore(), bytes_hash.into())
-                            .await
-                        {
-                            Ok(signature) => {
-                                to_agent_list.push((agent, ExternIO(bytes), signature))
-                            }
-                            Err(e) => {
-                                tracing::info!(
-                                    "Failed to sign and send remote signals because of {:?}",
-                                    e
-                                );
-                                return;
-                            }
-                        };
-                    }
-
                     if let Err(e) = network.send_remote_signal(to_agent_list).await {
                         tracing::info!("Failed to send remote signals because of {:?}", e);
                     }
@@ -112,6 +79,50 @@ pub fn send_remote_signal(
     }
 }
 
+/// Sign a `recv_remote_signal` zome call carrying the signal for each agent,
+/// ready to be sent over the network.
+pub(crate) async fn sign_remote_signals(
+    call_context: &CallContext,
+    from_agent: &AgentPubKey,
+    dna_hash: DnaHash,
+    agents: Vec<AgentPubKey>,
+    signal: ExternIO,
+) -> Result<Vec<(AgentPubKey, ExternIO, Signature)>, String> {
+    const FN_NAME: &str = "recv_remote_signal";
+    let zome_name = call_context.zome().zome_name().clone();
+    let fn_name: FunctionName = FN_NAME.into();
+
+    let (nonce, expires_at) = fresh_nonce(Timestamp::now())
+        .map_err(|e| format!("Failed to get a fresh nonce because of {:?}", e))?;
+
+    let mut to_agent_list = Vec::with_capacity(agents.len());
+    for agent in agents {
+        let zome_call_params = ZomeCallParams {
+            provenance: from_agent.clone(),
+            cell_id: CellId::new(dna_hash.clone(), agent.clone()),
+            zome_name: zome_name.clone(),
+            fn_name: fn_name.clone(),
+            cap_secret: None,
+            payload: signal.clone(),
+            nonce,
+            expires_at,
+        };
+        let (bytes, bytes_hash) = zome_call_params.serialize_and_hash().map_err(|e| {
+            format!(
+                "Failed to serialize zome call for signal because of {:?}",
+                e
+            )
+        })?;
+
+        let signature = from_agent
+            .sign_raw(call_context.host_context.keystore(), bytes_hash.into())
+            .await
+            .map_err(|e| format!("Failed to sign and send remote signals because of {:?}", e))?;
+        to_agent_list.push((agent, ExternIO(bytes), signature));
+    }
+    Ok(to_agent_list)
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::atomic::AtomicUsize;
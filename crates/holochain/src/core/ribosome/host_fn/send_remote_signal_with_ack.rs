@@ -0,0 +1,216 @@
+use super::send_remote_signal::sign_remote_signals;
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::HostFnAccess;
+use crate::core::ribosome::RibosomeError;
+use crate::core::ribosome::RibosomeT;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::*;
+use std::sync::Arc;
+use std::time::Duration;
+use wasmer::RuntimeError;
+
+/// Upper bound on how long unacknowledged recipients are retried, which is
+/// how long the calling zome call can be held open.
+pub const MAX_REMOTE_SIGNAL_RETRY_PERIOD: Duration = Duration::from_secs(30);
+
+#[cfg_attr(
+    feature = "instrument",
+    tracing::instrument(skip(_ribosome, call_context, input))
+)]
+pub fn send_remote_signal_with_ack(
+    _ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: RemoteSignalWithAck,
+) -> Result<RemoteSignalDelivery, RuntimeError> {
+    match HostFnAccess::from(&call_context.host_context()) {
+        HostFnAccess {
+            write_network: Permission::Allow,
+            agent_info: Permission::Allow,
+            ..
+        } => {
+            let from_agent = super::agent_info::agent_info(_ribosome, call_context.clone(), ())?
+                .agent_latest_pubkey;
+            let network = call_context.host_context().network().clone();
+            let RemoteSignalWithAck {
+                mut agents,
+                signal,
+                retry_for,
+            } = input;
+            agents.sort();
+            agents.dedup();
+            let retry_for = std::cmp::min(retry_for, MAX_REMOTE_SIGNAL_RETRY_PERIOD);
+            let signal_id = RemoteSignalId(nanoid::nanoid!());
+
+            tokio_helper::block_forever_on(async move {
+                let to_agent_list = sign_remote_signals(
+                    &call_context,
+                    &from_agent,
+                    network.dna_hash(),
+                    agents.clone(),
+                    signal,
+                )
+                .await
+                .map_err(|e| -> RuntimeError { wasm_error!(WasmErrorInner::Host(e)).into() })?;
+
+                let delivered = network
+                    .send_remote_signal_with_ack(signal_id.clone(), to_agent_list, retry_for)
+                    .await
+                    .map_err(|e| -> RuntimeError {
+                        wasm_error!(WasmErrorInner::Host(e.to_string())).into()
+                    })?;
+                let undelivered = agents
+                    .into_iter()
+                    .filter(|agent| !delivered.contains(agent))
+                    .collect();
+
+                Ok(RemoteSignalDelivery {
+                    signal_id,
+                    delivered,
+                    undelivered,
+                })
+            })
+        }
+        _ => Err(wasm_error!(WasmErrorInner::Host(
+            RibosomeError::HostFnPermissions(
+                call_context.zome.zome_name().clone(),
+                call_context.function_name().clone(),
+                "send_remote_signal_with_ack".into(),
+            )
+            .to_string(),
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "test_utils")]
+mod tests {
+    use crate::sweettest::*;
+    use ::fixt::prelude::*;
+    use hdk::prelude::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    fn test_zome(num_signals: Arc<AtomicUsize>) -> InlineIntegrityZome {
+        let entry_def = EntryDef::default_from_id("entrydef");
+
+        InlineIntegrityZome::new_unique(vec![entry_def.clone()], 0)
+            .function("signal_others", move |api, agents: Vec<AgentPubKey>| {
+                let delivery = api.send_remote_signal_with_ack(RemoteSignalWithAck {
+                    agents,
+                    signal: ExternIO::encode("Hey").unwrap(),
+                    retry_for: std::time::Duration::from_secs(2),
+                })?;
+                Ok(delivery)
+            })
+            .function("revoke_signals", move |api, ()| {
+                let grants = api.query(
+                    ChainQueryFilter::new()
+                        .entry_type(EntryType::CapGrant)
+                        .include_entries(true),
+                )?;
+                for grant in grants {
+                    api.delete(DeleteInput::new(
+                        grant.action_address().clone(),
+                        ChainTopOrdering::default(),
+                    ))?;
+                }
+                Ok(())
+            })
+            .function("recv_remote_signal", move |_api, _signal: ExternIO| {
+                num_signals.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .function("init", move |api, ()| {
+                let mut fns = BTreeSet::new();
+                fns.insert((api.zome_info(()).unwrap().name, "recv_remote_signal".into()));
+                let functions = GrantedFunctions::Listed(fns);
+                let cap_grant_entry = CapGrantEntry {
+                    tag: "".into(),
+                    // empty access converts to unrestricted
+                    access: ().into(),
+                    functions,
+                };
+                api.create(CreateInput::new(
+                    EntryDefLocation::CapGrant,
+                    EntryVisibility::Private,
+                    Entry::CapGrant(cap_grant_entry),
+                    ChainTopOrdering::default(),
+                ))
+                .unwrap();
+
+                Ok(InitCallbackResult::Pass)
+            })
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn remote_signal_with_ack_reports_delivery() {
+        holochain_trace::test_run();
+
+        let num_signals = Arc::new(AtomicUsize::new(0));
+        let config = SweetConductorConfig::standard().no_dpki();
+        let mut conductors = SweetConductorBatch::from_config(3, config).await;
+
+        let (dna_file, _, _) =
+            SweetDnaFile::unique_from_inline_zomes(("zome", test_zome(num_signals.clone()))).await;
+        let apps = conductors.setup_app("app", &[dna_file]).await.unwrap();
+        conductors.exchange_peer_info().await;
+
+        let ((alice,), (bob,), (carol,)) = apps.into_tuples();
+        let offline = fixt!(AgentPubKey);
+
+        let delivery: RemoteSignalDelivery = conductors[0]
+            .call(
+                &alice.zome("zome"),
+                "signal_others",
+                vec![
+                    bob.agent_pubkey().clone(),
+                    carol.agent_pubkey().clone(),
+                    offline.clone(),
+                ],
+            )
+            .await;
+
+        let mut expected = vec![bob.agent_pubkey().clone(), carol.agent_pubkey().clone()];
+        expected.sort();
+        let mut delivered = delivery.delivered;
+        delivered.sort();
+        assert_eq!(expected, delivered);
+        assert_eq!(vec![offline], delivery.undelivered);
+        assert_eq!(2, num_signals.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn remote_signal_refused_by_recipient_is_undelivered() {
+        holochain_trace::test_run();
+
+        let num_signals = Arc::new(AtomicUsize::new(0));
+        let config = SweetConductorConfig::standard().no_dpki();
+        let mut conductors = SweetConductorBatch::from_config(2, config).await;
+
+        let (dna_file, _, _) =
+            SweetDnaFile::unique_from_inline_zomes(("zome", test_zome(num_signals.clone()))).await;
+        let apps = conductors.setup_app("app", &[dna_file]).await.unwrap();
+        conductors.exchange_peer_info().await;
+
+        let ((alice,), (bob,)) = apps.into_tuples();
+
+        // Without the grant bob's conductor refuses the signal.
+        let _: () = conductors[1]
+            .call(&bob.zome("zome"), "revoke_signals", ())
+            .await;
+
+        let delivery: RemoteSignalDelivery = conductors[0]
+            .call(
+                &alice.zome("zome"),
+                "signal_others",
+                vec![bob.agent_pubkey().clone()],
+            )
+            .await;
+
+        assert!(delivery.delivered.is_empty());
+        assert_eq!(vec![bob.agent_pubkey().clone()], delivery.undelivered);
+        assert_eq!(0, num_signals.load(Ordering::SeqCst));
+    }
+}
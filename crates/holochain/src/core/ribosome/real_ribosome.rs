@@ -29,6 +29,7 @@ use crate::core::ribosome::guest_callback::CallStream;
 use crate::core::ribosome::host_fn::agent_info::agent_info;
 use crate::core::ribosome::host_fn::call::call;
 use crate::core::ribosome::host_fn::call_info::call_info;
+use crate::core::ribosome::host_fn::call_remote_many::call_remote_many;
 use crate::core::ribosome::host_fn::capability_claims::capability_claims;
 use crate::core::ribosome::host_fn::capability_grants::capability_grants;
 use crate::core::ribosome::host_fn::capability_info::capability_info;
@@ -45,16 +46,20 @@ use crate::core::ribosome::host_fn::ed_25519_x_salsa20_poly1305_encrypt::ed_2551
 use crate::core::ribosome::host_fn::emit_signal::emit_signal;
 use crate::core::ribosome::host_fn::get::get;
 use crate::core::ribosome::host_fn::get_details::get_details;
+use crate::core::ribosome::host_fn::get_latest::get_latest;
 use crate::core::ribosome::host_fn::get_link_details::get_link_details;
 use crate::core::ribosome::host_fn::get_links::get_links;
+use crate::core::ribosome::host_fn::get_many::get_many;
 use crate::core::ribosome::host_fn::hash::hash;
 use crate::core::ribosome::host_fn::must_get_action::must_get_action;
+use crate::core::ribosome::host_fn::must_get_actions::must_get_actions;
 use crate::core::ribosome::host_fn::must_get_agent_activity::must_get_agent_activity;
 use crate::core::ribosome::host_fn::must_get_entry::must_get_entry;
 use crate::core::ribosome::host_fn::must_get_valid_record::must_get_valid_record;
 use crate::core::ribosome::host_fn::query::query;
 use crate::core::ribosome::host_fn::random_bytes::random_bytes;
 use crate::core::ribosome::host_fn::send_remote_signal::send_remote_signal;
+use crate::core::ribosome::host_fn::send_remote_signal_with_ack::send_remote_signal_with_ack;
 use crate::core::ribosome::host_fn::sign::sign;
 use crate::core::ribosome::host_fn::sign_ephemeral::sign_ephemeral;
 use crate::core::ribosome::host_fn::sys_time::sys_time;
@@ -104,10 +109,16 @@ use crate::core::ribosome::host_fn::accept_countersigning_preflight_request::acc
 #[cfg(feature = "unstable-functions")]
 use crate::core::ribosome::host_fn::block_agent::block_agent;
 #[cfg(feature = "unstable-functions")]
+use crate::core::ribosome::host_fn::cancel_schedule::cancel_schedule;
+#[cfg(feature = "unstable-functions")]
 use crate::core::ribosome::host_fn::is_same_agent::is_same_agent;
 #[cfg(feature = "unstable-functions")]
+use crate::core::ribosome::host_fn::list_schedules::list_schedules;
+#[cfg(feature = "unstable-functions")]
 use crate::core::ribosome::host_fn::schedule::schedule;
 #[cfg(feature = "unstable-functions")]
+use crate::core::ribosome::host_fn::schedule_with_payload::schedule_with_payload;
+#[cfg(feature = "unstable-functions")]
 use crate::core::ribosome::host_fn::sleep::sleep;
 #[cfg(feature = "unstable-functions")]
 use crate::core::ribosome::host_fn::unblock_agent::unblock_agent;
@@ -137,7 +148,8 @@ mod wasmer_wamr;
 #[cfg(feature = "wasmer_wamr")]
 use wasmer_wamr::*;
 
-pub(crate) type ModuleCacheLock = parking_lot::RwLock<ModuleCache>;
+/// The lock around the cache of compiled wasm modules.
+pub type ModuleCacheLock = parking_lot::RwLock<ModuleCache>;
 
 /// The only RealRibosome is a Wasm ribosome.
 /// note that this is cloned on every invocation so keep clones cheap!
@@ -194,6 +206,7 @@ impl HostFnBuilder {
     {
         let ribosome_arc = Arc::clone(&self.ribosome_arc);
         let context_key = self.context_key;
+        let span_name = host_function_name.to_string();
         {
             let mut store_lock = self.store.lock();
             let mut store_mut = store_lock.as_store_mut();
@@ -215,27 +228,33 @@ impl HostFnBuilder {
                                 })
                                 .clone()
                         };
+                        let call_usage = context_arc.host_context.maybe_call_usage();
                         let (env, mut store_mut) = function_env_mut.data_and_store_mut();
                         let result = match env.consume_bytes_from_guest(&mut store_mut, guest_ptr, len) {
-                            Ok(input) => host_function(Arc::clone(&ribosome_arc), context_arc, input),
+                            Ok(input) => tracing::info_span!("host_fn", name = %span_name)
+                                .in_scope(|| host_function(Arc::clone(&ribosome_arc), context_arc, input)),
                             Err(runtime_error) => Result::<_, RuntimeError>::Err(runtime_error),
                         };
-                        Ok(u64::from_le_bytes(
-                            env.move_data_to_guest(&mut store_mut, match result {
-                                Err(runtime_error) => match runtime_error.downcast::<WasmError>() {
-                                    Ok(wasm_error) => match wasm_error {
-                                        WasmError {
-                                            error: WasmErrorInner::HostShortCircuit(_),
-                                            ..
-                                        } => return Err(WasmHostError(wasm_error).into()),
-                                        _ => Err(WasmHostError(wasm_error)),
-                                    },
-                                    Err(runtime_error) => return Err(runtime_error),
+                        let succeeded = result.is_ok();
+                        let guest_ptr_len = env.move_data_to_guest(&mut store_mut, match result {
+                            Err(runtime_error) => match runtime_error.downcast::<WasmError>() {
+                                Ok(wasm_error) => match wasm_error {
+                                    WasmError {
+                                        error: WasmErrorInner::HostShortCircuit(_),
+                                        ..
+                                    } => return Err(WasmHostError(wasm_error).into()),
+                                    _ => Err(WasmHostError(wasm_error)),
                                 },
-                                Ok(o) => Result::<_, WasmHostError>::Ok(o),
-                            })?
-                            .to_le_bytes(),
-                        ))
+                                Err(runtime_error) => return Err(runtime_error),
+                            },
+                            Ok(o) => Result::<_, WasmHostError>::Ok(o),
+                        })?;
+                        if succeeded {
+                            if let (Some(call_usage), Ok((_, output_len))) = (&call_usage, split_u64(guest_ptr_len)) {
+                                call_usage.record_host_fn(&span_name, len as u64, output_len as u64);
+                            }
+                        }
+                        Ok(guest_ptr_len)
                     },
                 ),
             );
@@ -434,8 +453,9 @@ impl RealRibosome {
         module: Arc<Module>,
         context_key: u64,
         name: &str,
+        max_memory_pages: Option<u32>,
     ) -> RibosomeResult<Arc<InstanceWithStore>> {
-        let store = Arc::new(Mutex::new(Store::default()));
+        let store = Arc::new(Mutex::new(new_store(max_memory_pages)));
         let function_env = FunctionEnv::new(&mut store.lock().as_store_mut(), Env::default());
         let (function_env, imports) = Self::imports(self, context_key, store.clone(), function_env);
         let instance;
@@ -611,12 +631,15 @@ impl RealRibosome {
             .with_host_function(&mut ns, "__hc__capability_info_1", capability_info)
             .with_host_function(&mut ns, "__hc__get_1", get)
             .with_host_function(&mut ns, "__hc__get_details_1", get_details)
+            .with_host_function(&mut ns, "__hc__get_many_1", get_many)
+            .with_host_function(&mut ns, "__hc__get_latest_1", get_latest)
             .with_host_function(&mut ns, "__hc__get_links_1", get_links)
             .with_host_function(&mut ns, "__hc__get_link_details_1", get_link_details)
             .with_host_function(&mut ns, "__hc__count_links_1", count_links)
             .with_host_function(&mut ns, "__hc__get_agent_activity_1", get_agent_activity)
             .with_host_function(&mut ns, "__hc__must_get_entry_1", must_get_entry)
             .with_host_function(&mut ns, "__hc__must_get_action_1", must_get_action)
+            .with_host_function(&mut ns, "__hc__must_get_actions_1", must_get_actions)
             .with_host_function(
                 &mut ns,
                 "__hc__must_get_valid_record_1",
@@ -629,7 +652,13 @@ impl RealRibosome {
             )
             .with_host_function(&mut ns, "__hc__query_1", query)
             .with_host_function(&mut ns, "__hc__send_remote_signal_1", send_remote_signal)
+            .with_host_function(
+                &mut ns,
+                "__hc__send_remote_signal_with_ack_1",
+                send_remote_signal_with_ack,
+            )
             .with_host_function(&mut ns, "__hc__call_1", call)
+            .with_host_function(&mut ns, "__hc__call_remote_many_1", call_remote_many)
             .with_host_function(&mut ns, "__hc__create_1", create)
             .with_host_function(&mut ns, "__hc__emit_signal_1", emit_signal)
             .with_host_function(&mut ns, "__hc__create_link_1", create_link)
@@ -664,6 +693,13 @@ impl RealRibosome {
             .with_host_function(&mut ns, "__hc__is_same_agent_1", is_same_agent)
             .with_host_function(&mut ns, "__hc__block_agent_1", block_agent)
             .with_host_function(&mut ns, "__hc__schedule_1", schedule)
+            .with_host_function(
+                &mut ns,
+                "__hc__schedule_with_payload_1",
+                schedule_with_payload,
+            )
+            .with_host_function(&mut ns, "__hc__cancel_schedule_1", cancel_schedule)
+            .with_host_function(&mut ns, "__hc__list_schedules_1", list_schedules)
             .with_host_function(&mut ns, "__hc__unblock_agent_1", unblock_agent)
             // TODO deprecated, remove me
             .with_host_function(&mut ns, "__hc__sleep_1", sleep);
@@ -815,6 +851,17 @@ impl RealRibosome {
         do_callback!(self, host_access, invocation, ValidateCallbackResult)
     }
 
+    /// The pages of memory that a wasm instance has grown to.
+    fn memory_pages(instance_with_store: &InstanceWithStore) -> u32 {
+        let mut store_lock = instance_with_store.store.lock();
+        let store_mut = store_lock.as_store_mut();
+        instance_with_store
+            .instance
+            .exports
+            .get_memory("memory")
+            .map_or(0, |memory| memory.view(&store_mut).size().0)
+    }
+
     /// call a function in a zome for an invocation if it exists
     /// if it does not exist, then return Ok(None)
     pub async fn maybe_call<I: Invocation>(
@@ -839,6 +886,9 @@ impl RealRibosome {
             otel_info.push(opentelemetry_api::KeyValue::new("agent", agent_pubkey));
         }
 
+        let call_usage = host_context.maybe_call_usage();
+        let limits = host_context.call_limits();
+
         let call_context = CallContext {
             zome: zome.clone(),
             function_name: fn_name.clone(),
@@ -852,8 +902,12 @@ impl RealRibosome {
                 if module.info().exports.contains_key(fn_name.as_ref()) {
                     // there is a corresponding zome fn
                     let context_key = Self::next_context_key();
-                    let instance_with_store =
-                        self.build_instance_with_store(module, context_key, &zome.name.0)?;
+                    let instance_with_store = self.build_instance_with_store(
+                        module,
+                        context_key,
+                        &zome.name.0,
+                        limits.max_memory_pages,
+                    )?;
                     // add call context to map for the following call
                     {
                         CONTEXT_MAP
@@ -862,27 +916,72 @@ impl RealRibosome {
                     }
 
                     // Reset available metering points to the maximum allowed per zome call
-                    reset_metering_points(instance_with_store.clone());
+                    reset_metering_points(instance_with_store.clone(), limits.max_metering_points);
 
                     // be aware of this clone!
                     // the whole invocation is cloned!
                     // @todo - is this a problem for large payloads like entries?
                     let input = invocation.clone().host_input()?;
                     let instance_with_store_clone = instance_with_store.clone();
-                    let result = tokio::task::spawn_blocking(move || {
-                        Self::call_zome_fn(input, zome, fn_name, instance_with_store_clone)
-                            .map(Some)
-                    })
-                    .await?;
+                    // Host functions are called from the blocking thread, so the
+                    // span of the call has to be carried over for their spans
+                    // to belong to it.
+                    let span = tracing::Span::current();
+                    let mut call = tokio::task::spawn_blocking(move || {
+                        span.in_scope(|| {
+                            Self::call_zome_fn(input, zome, fn_name, instance_with_store_clone)
+                                .map(Some)
+                        })
+                    });
+                    let result = match limits.max_call_duration {
+                        None => call.await?,
+                        Some(max_duration) => {
+                            match tokio::time::timeout(max_duration, &mut call).await {
+                                Ok(result) => result?,
+                                Err(_) => {
+                                    // Running wasm can't be interrupted, so it runs on until
+                                    // it returns or runs out of metering points. Its context,
+                                    // along with the call's concurrency permit, is only
+                                    // dropped once it has.
+                                    tokio::task::spawn(async move {
+                                        let _ = call.await;
+                                        CONTEXT_MAP.lock().remove(&context_key);
+                                    });
+                                    return Err(RibosomeError::ZomeCallTimeout(max_duration));
+                                }
+                            }
+                        }
+                    };
 
                     // Get metering points consumed in zome call and save to usage_meter
-                    let points_used = get_used_metering_points(instance_with_store.clone());
+                    let points_used = get_used_metering_points(
+                        instance_with_store.clone(),
+                        limits.max_metering_points,
+                    );
                     self.usage_meter.add(points_used, &otel_info);
+                    if let Some(call_usage) = call_usage {
+                        call_usage.add_metering_points(points_used);
+                    }
 
                     // remove context from map after call
                     {
                         CONTEXT_MAP.lock().remove(&context_key);
                     }
+
+                    // The trap raised when metering points run out is reported
+                    // as the limit that was hit rather than as a runtime error.
+                    if result.is_err() && metering_points_exhausted(instance_with_store.clone()) {
+                        return Err(RibosomeError::MeteringLimitExceeded);
+                    }
+                    // Memory can't grow beyond the limit, so a call which failed with
+                    // its memory at the limit is reported as having hit it.
+                    // Engines which can't cap memory are only checked after the call.
+                    if let Some(max_pages) = limits.max_memory_pages {
+                        let pages = Self::memory_pages(&instance_with_store);
+                        if pages > max_pages || (result.is_err() && pages == max_pages) {
+                            return Err(RibosomeError::MemoryLimitExceeded(max_pages));
+                        }
+                    }
                     result
                 } else {
                     // the callback fn does not exist
@@ -1011,7 +1110,7 @@ impl RibosomeT for RealRibosome {
                     // create a new key for the context map.
                     let context_key = Self::next_context_key();
                     let instance_with_store =
-                        self.build_instance_with_store(module, context_key, &zome.name.0)?;
+                        self.build_instance_with_store(module, context_key, &zome.name.0, None)?;
 
                     // add call context to map for following call
                     {
@@ -1345,6 +1444,9 @@ pub mod wasm_test {
                 "__hc__block_agent_1",
                 "__hc__call_1",
                 "__hc__call_info_1",
+                "__hc__call_remote_many_1",
+                #[cfg(feature = "unstable-functions")]
+                "__hc__cancel_schedule_1",
                 "__hc__capability_claims_1",
                 "__hc__capability_grants_1",
                 "__hc__capability_info_1",
@@ -1369,13 +1471,18 @@ pub mod wasm_test {
                 #[cfg(feature = "unstable-functions")]
                 "__hc__get_agent_key_lineage_1",
                 "__hc__get_details_1",
+                "__hc__get_latest_1",
                 "__hc__get_link_details_1",
                 "__hc__get_links_1",
+                "__hc__get_many_1",
                 "__hc__get_validation_receipts_1",
                 "__hc__hash_1",
                 #[cfg(feature = "unstable-functions")]
                 "__hc__is_same_agent_1",
+                #[cfg(feature = "unstable-functions")]
+                "__hc__list_schedules_1",
                 "__hc__must_get_action_1",
+                "__hc__must_get_actions_1",
                 "__hc__must_get_agent_activity_1",
                 "__hc__must_get_entry_1",
                 "__hc__must_get_valid_record_1",
@@ -1384,7 +1491,10 @@ pub mod wasm_test {
                 "__hc__random_bytes_1",
                 #[cfg(feature = "unstable-functions")]
                 "__hc__schedule_1",
+                #[cfg(feature = "unstable-functions")]
+                "__hc__schedule_with_payload_1",
                 "__hc__send_remote_signal_1",
+                "__hc__send_remote_signal_with_ack_1",
                 "__hc__sign_1",
                 "__hc__sign_ephemeral_1",
                 #[cfg(feature = "unstable-functions")]
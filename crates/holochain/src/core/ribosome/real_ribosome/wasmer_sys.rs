@@ -3,27 +3,121 @@ use crate::{
 };
 use holochain_wasmer_host::module::InstanceWithStore;
 use holochain_zome_types::prelude::WasmZome;
+use std::ptr::NonNull;
 use std::sync::Arc;
-use wasmer::{AsStoreMut, Module};
+use wasmer::sys::{BaseTunables, NativeEngineExt};
+use wasmer::vm::{
+    MemoryError, MemoryStyle, TableStyle, VMMemory, VMMemoryDefinition, VMTable, VMTableDefinition,
+};
+use wasmer::{AsStoreMut, Engine, MemoryType, Module, Pages, Store, TableType, Tunables};
 use wasmer_middlewares::metering::{get_remaining_points, set_remaining_points, MeteringPoints};
 
-pub fn reset_metering_points(instance_with_store: Arc<InstanceWithStore>) {
+pub fn reset_metering_points(instance_with_store: Arc<InstanceWithStore>, limit: Option<u64>) {
     let mut store_lock = instance_with_store.store.lock();
     let mut store_mut = store_lock.as_store_mut();
     set_remaining_points(
         &mut store_mut,
         instance_with_store.instance.as_ref(),
-        WASM_METERING_LIMIT,
+        limit.unwrap_or(WASM_METERING_LIMIT),
     );
 }
 
-pub fn get_used_metering_points(instance_with_store: Arc<InstanceWithStore>) -> u64 {
+pub fn get_used_metering_points(
+    instance_with_store: Arc<InstanceWithStore>,
+    limit: Option<u64>,
+) -> u64 {
+    let limit = limit.unwrap_or(WASM_METERING_LIMIT);
     let mut store_lock = instance_with_store.store.lock();
     let mut store_mut = store_lock.as_store_mut();
 
     match get_remaining_points(&mut store_mut, instance_with_store.instance.as_ref()) {
-        MeteringPoints::Remaining(points) => WASM_METERING_LIMIT - points,
-        MeteringPoints::Exhausted => WASM_METERING_LIMIT,
+        MeteringPoints::Remaining(points) => limit.saturating_sub(points),
+        MeteringPoints::Exhausted => limit,
+    }
+}
+
+pub fn metering_points_exhausted(instance_with_store: Arc<InstanceWithStore>) -> bool {
+    let mut store_lock = instance_with_store.store.lock();
+    let mut store_mut = store_lock.as_store_mut();
+
+    matches!(
+        get_remaining_points(&mut store_mut, instance_with_store.instance.as_ref()),
+        MeteringPoints::Exhausted
+    )
+}
+
+/// A store for a wasm instance whose memory can't grow beyond
+/// `max_memory_pages`. Growing it further fails inside the wasm.
+pub fn new_store(max_memory_pages: Option<u32>) -> Store {
+    match max_memory_pages {
+        Some(max_pages) => {
+            let mut engine = Engine::default();
+            let base = BaseTunables::for_target(engine.target());
+            engine.set_tunables(MemoryLimitTunables {
+                base,
+                max_pages: Pages(max_pages),
+            });
+            Store::new(engine)
+        }
+        None => Store::default(),
+    }
+}
+
+/// Tunables which cap the maximum size of every memory an instance creates.
+struct MemoryLimitTunables {
+    base: BaseTunables,
+    max_pages: Pages,
+}
+
+impl MemoryLimitTunables {
+    fn limit(&self, ty: &MemoryType) -> MemoryType {
+        let mut ty = *ty;
+        ty.maximum = Some(
+            ty.maximum
+                .map_or(self.max_pages, |max| max.min(self.max_pages)),
+        );
+        ty
+    }
+}
+
+impl Tunables for MemoryLimitTunables {
+    fn memory_style(&self, memory: &MemoryType) -> MemoryStyle {
+        self.base.memory_style(&self.limit(memory))
+    }
+
+    fn table_style(&self, table: &TableType) -> TableStyle {
+        self.base.table_style(table)
+    }
+
+    fn create_host_memory(
+        &self,
+        ty: &MemoryType,
+        style: &MemoryStyle,
+    ) -> Result<VMMemory, MemoryError> {
+        self.base.create_host_memory(&self.limit(ty), style)
+    }
+
+    unsafe fn create_vm_memory(
+        &self,
+        ty: &MemoryType,
+        style: &MemoryStyle,
+        vm_definition_location: NonNull<VMMemoryDefinition>,
+    ) -> Result<VMMemory, MemoryError> {
+        self.base
+            .create_vm_memory(&self.limit(ty), style, vm_definition_location)
+    }
+
+    fn create_host_table(&self, ty: &TableType, style: &TableStyle) -> Result<VMTable, String> {
+        self.base.create_host_table(ty, style)
+    }
+
+    unsafe fn create_vm_table(
+        &self,
+        ty: &TableType,
+        style: &TableStyle,
+        vm_definition_location: NonNull<VMTableDefinition>,
+    ) -> Result<VMTable, String> {
+        self.base.create_vm_table(ty, style, vm_definition_location)
     }
 }
 
@@ -3,16 +3,30 @@ use holochain_wasmer_host::module::InstanceWithStore;
 use holochain_zome_types::prelude::WasmZome;
 use std::sync::Arc;
 use tracing::warn;
-use wasmer::Module;
+use wasmer::{Module, Store};
 
 // Metering is not supported in wasmer_wamr feature. This is a no-op.
-pub fn reset_metering_points(_instance_with_store: Arc<InstanceWithStore>) {}
+pub fn reset_metering_points(_instance_with_store: Arc<InstanceWithStore>, _limit: Option<u64>) {}
 
 // Metering is not supported in wasmer_wamr feature. This is a no-op.
-pub fn get_used_metering_points(_instance_with_store: Arc<InstanceWithStore>) -> u64 {
+pub fn get_used_metering_points(
+    _instance_with_store: Arc<InstanceWithStore>,
+    _limit: Option<u64>,
+) -> u64 {
     0
 }
 
+// Metering is not supported in wasmer_wamr feature, so points are never exhausted.
+pub fn metering_points_exhausted(_instance_with_store: Arc<InstanceWithStore>) -> bool {
+    false
+}
+
+// Memory tunables are not supported in wasmer_wamr feature, so memory is
+// only checked against the limit after each call.
+pub fn new_store(_max_memory_pages: Option<u32>) -> Store {
+    Store::default()
+}
+
 // Use of precompiled and serialized modules is not supported in wasmer_wamr feature.
 // If a preserialized_path is specified for the zome, it is ignored.
 pub fn get_prebuilt_module(wasm_zome: &WasmZome) -> RibosomeResult<Option<Arc<Module>>> {
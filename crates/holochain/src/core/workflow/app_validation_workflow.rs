@@ -5,24 +5,26 @@
 //! [`DhtOp`]s that have passed system validation, validates each op, updates its validation status
 //! in the database accordingly, and triggers op integration if necessary.
 //!
-//! ### Sequential validation
+//! ### Parallel validation
 //!
+//! Ops are sorted by their op type and the timestamp they were authored
+//! (see [`OpOrder`] and [`OpNumericalOrder`]), so that ops that depend on
+//! earlier ops have a higher chance of being validated after them. An example
+//! is an incoming delete op that depends on a create op. Validated in order of
+//! their authoring, the create op is validated first, followed at some stage
+//! by the delete op. If the validation function references the original action
+//! when validating delete ops, the create op will have been validated and is
+//! available in the database. Otherwise the delete op could not be validated
+//! and its dependency, the create op, would be awaited first.
+//!
+//! The ops of each author are validated one after the other in the sorted
+//! order, since they are the ones most likely to depend on each other, like the create and delete above or the
+//! [`ChainOp::RegisterAgentActivity`] ops of the author's chain. The ops of
+//! different authors are validated in parallel, up to the
+//! `app_validation_concurrency` of the conductor's tuning params.
 
-// Even though ops are validated in sequence, they could all be validated in parallel too with the same result.
 // All actions are written to the database straight away in the incoming dht ops workflow and do not require validation to be available for validating other ops. See https://github.com/holochain/holochain/issues/3724
 
-//! Ops are validated in sequence based on their op type and the timestamp they
-//! were authored (see [`OpOrder`] and [`OpNumericalOrder`]). Validating one op
-//! after the other with this ordering was chosen so that ops that depend on earlier
-//! ops will be validated after the earlier ops, and therefore have a higher chance
-//! of being validated successfully. An example is an incoming delete
-//! op that depends on a create op. Validated in order of their authoring, the
-//! create op is validated first, followed at some stage by the delete op. If
-//! the validation function references the original action when validating
-//! delete ops, the create op will have been validated and is available in the
-//! database. Otherwise the delete op could not be validated and its dependency,
-//! the create op, would be awaited first.
-//!
 //! ### Op validation
 //!
 //! For each op the [corresponding app validation function](https://docs.rs/hdi/latest/hdi/#data-validation)
@@ -68,11 +70,14 @@
 //! the validation function may require actions, entries or agent activity
 //! (segments of an agent's source chain) that currently are not stored in the
 //! local databases. These are dependencies of the op. If they are missing
-//! locally, a network get request will be sent in the background. The op
-//! validation outcome will be [`Outcome::AwaitingDeps`]. Validation of
-//! remaining ops will carry on, as the network request's response is not
-//! awaited within the op validation loop. Instead the whole workflow triggers
-//! itself again after a delay.
+//! locally, the op validation outcome will be [`Outcome::AwaitingDeps`].
+//! Validation of remaining ops will carry on. Once all ops have been
+//! validated, the dependencies that any of them were missing are fetched
+//! from the network in a single background task, each one only once.
+//! Missing records are fetched together, with one request to each authority
+//! that holds several of them, while agent activity is fetched per author.
+//! Instead of awaiting them, the whole workflow triggers itself again after
+//! a delay.
 //!
 //! ### Workflow re-triggering
 //!
@@ -125,7 +130,11 @@ use holochain_state::host_fn_workspace::HostFnWorkspace;
 use holochain_state::host_fn_workspace::HostFnWorkspaceRead;
 use holochain_state::prelude::*;
 
+use futures::future::FutureExt;
+use futures::stream::StreamExt;
+use futures::stream::TryStreamExt;
 use parking_lot::Mutex;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
@@ -218,143 +227,189 @@ async fn app_validation_workflow_inner(
     let rejected_ops = Arc::new(AtomicUsize::new(0));
     let warranted_ops = Arc::new(AtomicUsize::new(0));
     let failed_ops = Arc::new(Mutex::new(HashSet::new()));
-    let mut agent_activity = vec![];
+    let agent_activity = Mutex::new(vec![]);
     #[cfg(feature = "unstable-warrants")]
-    let mut warrant_op_hashes = vec![];
+    let warrant_op_hashes = Mutex::new(vec![]);
+    let missing_deps = MissingDeps::default();
+
+    // Validate one op and record its outcome.
+    let validate = |sorted_dht_op: DhtOpHashed| {
+        let dna_hash = dna_hash.clone();
+        let workspace = &workspace;
+        let conductor = &conductor;
+        let cascade = &cascade;
+        let accepted_ops = &accepted_ops;
+        let awaiting_ops = &awaiting_ops;
+        let rejected_ops = &rejected_ops;
+        #[cfg(feature = "unstable-warrants")]
+        let warranted_ops = &warranted_ops;
+        let failed_ops = &failed_ops;
+        let agent_activity = &agent_activity;
+        #[cfg(feature = "unstable-warrants")]
+        let warrant_op_hashes = &warrant_op_hashes;
+        let missing_deps = &missing_deps;
+        async move {
+            let (dht_op, dht_op_hash) = sorted_dht_op.into_inner();
+            let deps = dht_op.sys_validation_dependencies();
+
+            let chain_op = match dht_op {
+                DhtOp::ChainOp(chain_op) => chain_op,
+                _ => unreachable!("warrant ops are never sent to app validation"),
+            };
 
-    // Validate ops sequentially
-    for sorted_dht_op in sorted_dht_ops.into_iter() {
-        let (dht_op, dht_op_hash) = sorted_dht_op.into_inner();
-        let deps = dht_op.sys_validation_dependencies();
+            let op_type = chain_op.get_type();
+            let action = chain_op.action();
+            let dht_op_lite = chain_op.to_lite();
+
+            // If this is agent activity, track it for the cache.
+            let activity = matches!(op_type, ChainOpType::RegisterAgentActivity).then(|| {
+                (
+                    action.author().clone(),
+                    action.action_seq(),
+                    deps.is_empty(),
+                )
+            });
 
-        let chain_op = match dht_op {
-            DhtOp::ChainOp(chain_op) => chain_op,
-            _ => unreachable!("warrant ops are never sent to app validation"),
-        };
+            // Validate this op
+            let validation_outcome = match chain_op_to_op(*chain_op.clone(), cascade.clone()).await
+            {
+                Ok(op) => {
+                    validate_op_outer(
+                        dna_hash.clone(),
+                        &op,
+                        conductor,
+                        workspace,
+                        network,
+                        missing_deps,
+                    )
+                    .await
+                }
+                Err(e) => Err(e),
+            };
+            // Flatten nested app validation outcome to either ok or error
+            let validation_outcome = match validation_outcome {
+                Ok(outcome) => AppValidationResult::Ok(outcome),
+                Err(OutcomeOrError::Outcome(outcome)) => AppValidationResult::Ok(outcome),
+                Err(OutcomeOrError::Err(err)) => AppValidationResult::Err(err),
+            };
 
-        let op_type = chain_op.get_type();
-        let action = chain_op.action();
-        let dht_op_lite = chain_op.to_lite();
-
-        // If this is agent activity, track it for the cache.
-        let activity = matches!(op_type, ChainOpType::RegisterAgentActivity).then(|| {
-            (
-                action.author().clone(),
-                action.action_seq(),
-                deps.is_empty(),
-            )
-        });
+            match validation_outcome {
+                Ok(outcome) => {
+                    // Collect all agent activity.
+                    if let Some(activity) = activity {
+                        // If the activity is accepted or rejected then it's ready to integrate.
+                        if matches!(&outcome, Outcome::Accepted | Outcome::Rejected(_)) {
+                            agent_activity.lock().push(activity);
+                        }
+                    }
+                    if let Outcome::AwaitingDeps(_) | Outcome::Rejected(_) = &outcome {
+                        warn!(?outcome, ?dht_op_lite, "DhtOp has failed app validation");
+                    }
 
-        // Validate this op
-        let validation_outcome = match chain_op_to_op(*chain_op.clone(), cascade.clone()).await {
-            Ok(op) => {
-                validate_op_outer(dna_hash.clone(), &op, &conductor, &workspace, network).await
-            }
-            Err(e) => Err(e),
-        };
-        // Flatten nested app validation outcome to either ok or error
-        let validation_outcome = match validation_outcome {
-            Ok(outcome) => AppValidationResult::Ok(outcome),
-            Err(OutcomeOrError::Outcome(outcome)) => AppValidationResult::Ok(outcome),
-            Err(OutcomeOrError::Err(err)) => AppValidationResult::Err(err),
-        };
+                    let accepted_ops = accepted_ops.clone();
+                    let awaiting_ops = awaiting_ops.clone();
+                    let rejected_ops = rejected_ops.clone();
+
+                    #[cfg(feature = "unstable-warrants")]
+                    if let Outcome::Rejected(_) = &outcome {
+                        let warrant_op =
+                            crate::core::workflow::sys_validation_workflow::make_warrant_op(
+                                conductor,
+                                &dna_hash,
+                                &chain_op,
+                                ValidationType::App,
+                            )
+                            .await?;
 
-        match validation_outcome {
-            Ok(outcome) => {
-                // Collect all agent activity.
-                if let Some(activity) = activity {
-                    // If the activity is accepted or rejected then it's ready to integrate.
-                    if matches!(&outcome, Outcome::Accepted | Outcome::Rejected(_)) {
-                        agent_activity.push(activity);
-                    }
-                }
-                if let Outcome::AwaitingDeps(_) | Outcome::Rejected(_) = &outcome {
-                    warn!(?outcome, ?dht_op_lite, "DhtOp has failed app validation");
-                }
+                        warrant_op_hashes
+                            .lock()
+                            .push((warrant_op.to_hash(), warrant_op.dht_basis().clone()));
 
-                let accepted_ops = accepted_ops.clone();
-                let awaiting_ops = awaiting_ops.clone();
-                let rejected_ops = rejected_ops.clone();
-
-                #[cfg(feature = "unstable-warrants")]
-                if let Outcome::Rejected(_) = &outcome {
-                    let warrant_op =
-                        crate::core::workflow::sys_validation_workflow::make_warrant_op(
-                            &conductor,
-                            &dna_hash,
-                            &chain_op,
-                            ValidationType::App,
-                        )
-                        .await?;
-
-                    warrant_op_hashes.push((warrant_op.to_hash(), warrant_op.dht_basis().clone()));
-
-                    workspace
-                        .authored_db
-                        .write_async(move |txn| {
-                            warn!("Inserting warrant op");
-                            insert_op_authored(txn, &warrant_op)
-                        })
-                        .await?;
+                        workspace
+                            .authored_db
+                            .write_async(move |txn| {
+                                warn!("Inserting warrant op");
+                                insert_op_authored(txn, &warrant_op)
+                            })
+                            .await?;
 
-                    warranted_ops.fetch_add(1, Ordering::SeqCst);
-                }
+                        warranted_ops.fetch_add(1, Ordering::SeqCst);
+                    }
 
-                let write_result = workspace
-                    .dht_db
-                    .write_async(move|txn| match outcome {
-                        Outcome::Accepted => {
-                            accepted_ops.fetch_add(1, Ordering::SeqCst);
+                    let write_result = workspace
+                        .dht_db
+                        .write_async(move|txn| match outcome {
+                            Outcome::Accepted => {
+                                accepted_ops.fetch_add(1, Ordering::SeqCst);
 
 
-                            if deps.is_empty() {
+                                if deps.is_empty() {
 
-                                put_integrated(txn, &dht_op_hash, ValidationStatus::Valid)
-                            } else {
-                                put_integration_limbo(txn, &dht_op_hash, ValidationStatus::Valid)
+                                    put_integrated(txn, &dht_op_hash, ValidationStatus::Valid)
+                                } else {
+                                    put_integration_limbo(txn, &dht_op_hash, ValidationStatus::Valid)
+                                }
                             }
-                        }
-                        Outcome::AwaitingDeps(_) => {
-                            awaiting_ops.fetch_add(1, Ordering::SeqCst);
-                            put_validation_limbo(
-                                txn,
-                                &dht_op_hash,
-                                ValidationStage::AwaitingAppDeps,
-                            )
-                        }
-                        Outcome::Rejected(_) => {
-                            rejected_ops.fetch_add(1, Ordering::SeqCst);
+                            Outcome::AwaitingDeps(_) => {
+                                awaiting_ops.fetch_add(1, Ordering::SeqCst);
+                                put_validation_limbo(
+                                    txn,
+                                    &dht_op_hash,
+                                    ValidationStage::AwaitingAppDeps,
+                                )
+                            }
+                            Outcome::Rejected(_) => {
+                                rejected_ops.fetch_add(1, Ordering::SeqCst);
 
-                            tracing::info!("Received invalid op. The op author will be blocked. Op: {dht_op_lite:?}");
+                                tracing::info!("Received invalid op. The op author will be blocked. Op: {dht_op_lite:?}");
 
-                            if deps.is_empty() {
-                                put_integrated(txn, &dht_op_hash, ValidationStatus::Rejected)
-                            } else {
-                                put_integration_limbo(txn, &dht_op_hash, ValidationStatus::Rejected)
+                                if deps.is_empty() {
+                                    put_integrated(txn, &dht_op_hash, ValidationStatus::Rejected)
+                                } else {
+                                    put_integration_limbo(txn, &dht_op_hash, ValidationStatus::Rejected)
+                                }
                             }
-                        }
-                    })
-                    .await;
-                if let Err(err) = write_result {
-                    tracing::error!(?chain_op, ?err, "Error updating dht op in database.");
+                        })
+                        .await;
+                    if let Err(err) = write_result {
+                        tracing::error!(?chain_op, ?err, "Error updating dht op in database.");
+                    }
+                }
+                Err(err) => {
+                    tracing::error!(
+                        ?chain_op,
+                        ?err,
+                        "App validation error when validating dht op."
+                    );
+                    failed_ops.lock().insert(dht_op_hash);
                 }
             }
-            Err(err) => {
-                tracing::error!(
-                    ?chain_op,
-                    ?err,
-                    "App validation error when validating dht op."
-                );
-                failed_ops.lock().insert(dht_op_hash);
-            }
+            WorkflowResult::Ok(())
         }
-    }
+    };
+
+    // Validate the ops of each author in sequence,
+    // and the ops of different authors in parallel.
+    let validate = &validate;
+    futures::stream::iter(author_chains(sorted_dht_ops))
+        .map(|chain| async move {
+            for sorted_dht_op in chain {
+                validate(sorted_dht_op).await?;
+            }
+            WorkflowResult::Ok(())
+        })
+        .buffer_unordered(workspace.concurrency.max(1))
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // Fetch the dependencies that any op was missing.
+    missing_deps.fetch_in_background((*cascade).clone(), workspace.concurrency.max(1));
 
     // "self-publish" warrants, i.e. insert them into the DHT db as if they were published to us by another node
     #[cfg(feature = "unstable-warrants")]
     holochain_state::integrate::authored_ops_to_dht_db(
         network,
-        warrant_op_hashes,
+        warrant_op_hashes.into_inner(),
         workspace.authored_db.clone().into(),
         workspace.dht_db.clone(),
         &workspace.dht_db_cache,
@@ -363,7 +418,7 @@ async fn app_validation_workflow_inner(
 
     // Once the database transaction is committed, add agent activity to the cache
     // that is ready for integration.
-    for (author, seq, has_no_dependency) in agent_activity {
+    for (author, seq, has_no_dependency) in agent_activity.into_inner() {
         // Any activity with no dependency is integrated in this workflow.
         // TODO: This will no longer be true when [#1212](https://github.com/holochain/holochain/pull/1212) lands.
         if has_no_dependency {
@@ -399,6 +454,107 @@ async fn app_validation_workflow_inner(
     Ok(outcome_summary)
 }
 
+/// Split sorted ops into the chains that must be validated in sequence,
+/// keeping their order. The ops of each author form one chain.
+fn author_chains(sorted_dht_ops: Vec<DhtOpHashed>) -> Vec<Vec<DhtOpHashed>> {
+    let mut chains: Vec<Vec<DhtOpHashed>> = Vec::new();
+    let mut author_chains: HashMap<AgentPubKey, usize> = HashMap::new();
+    for sorted_dht_op in sorted_dht_ops {
+        let author = match sorted_dht_op.as_content() {
+            DhtOp::ChainOp(chain_op) => Some(chain_op.action().author().clone()),
+            _ => None,
+        };
+        match author {
+            Some(author) => match author_chains.get(&author) {
+                Some(index) => chains[*index].push(sorted_dht_op),
+                None => {
+                    author_chains.insert(author, chains.len());
+                    chains.push(vec![sorted_dht_op]);
+                }
+            },
+            None => chains.push(vec![sorted_dht_op]),
+        }
+    }
+    chains
+}
+
+/// The dependencies that ops were missing while being validated,
+/// so that each one is only fetched once.
+#[derive(Default)]
+pub struct MissingDeps {
+    hashes: Mutex<HashSet<AnyDhtHash>>,
+    agent_activity: Mutex<Vec<(AgentPubKey, ChainFilter)>>,
+}
+
+impl MissingDeps {
+    fn add(&self, deps: UnresolvedDependencies) {
+        match deps {
+            UnresolvedDependencies::Hashes(hashes) => self.hashes.lock().extend(hashes),
+            UnresolvedDependencies::AgentActivity(author, filter) => {
+                let mut agent_activity = self.agent_activity.lock();
+                if !agent_activity.contains(&(author.clone(), filter.clone())) {
+                    agent_activity.push((author, filter));
+                }
+            }
+        }
+    }
+
+    /// Fetch all missing dependencies from the network in a background task,
+    /// at most `concurrency` at a time.
+    fn fetch_in_background(self, cascade: CascadeImpl, concurrency: usize) {
+        let hashes = self.hashes.into_inner();
+        let agent_activity = self.agent_activity.into_inner();
+        if hashes.is_empty() && agent_activity.is_empty() {
+            return;
+        }
+        tracing::debug!(
+            num_hashes = hashes.len(),
+            num_agent_activity = agent_activity.len(),
+            "Fetching missing app validation dependencies"
+        );
+
+        let record_fetches = (!hashes.is_empty()).then(|| {
+            let cascade = cascade.clone();
+            let hashes: Vec<AnyDhtHash> = hashes.into_iter().collect();
+            async move {
+                let results = cascade
+                    .fetch_records(hashes.clone(), NetworkGetOptions::must_get_options())
+                    .await;
+                match results {
+                    Ok(results) => {
+                        for (hash, result) in hashes.iter().zip(results) {
+                            if let Err(err) = result {
+                                tracing::warn!("error fetching dependent hash {hash:?}: {err}");
+                            }
+                        }
+                    }
+                    Err(err) => tracing::warn!("error fetching dependent hashes: {err}"),
+                }
+            }
+            .boxed()
+        });
+        let activity_fetches = agent_activity.into_iter().map(move |(author, filter)| {
+            let cascade = cascade.clone();
+            async move {
+                let result = cascade
+                    .must_get_agent_activity(author.clone(), filter)
+                    .await;
+                if let Err(err) = result {
+                    tracing::warn!("error fetching dependent chain of agent {author:?}: {err}");
+                }
+            }
+            .boxed()
+        });
+
+        // await all fetches in a separate task in the background
+        tokio::spawn(
+            futures::stream::iter(record_fetches.into_iter().chain(activity_fetches))
+                .buffer_unordered(concurrency)
+                .collect::<Vec<()>>(),
+        );
+    }
+}
+
 // This fn is only used in the zome call workflow's inline validation.
 pub async fn record_to_op(
     record: Record,
@@ -511,6 +667,7 @@ async fn validate_op_outer(
     conductor_handle: &ConductorHandle,
     workspace: &AppValidationWorkspace,
     network: &HolochainP2pDna,
+    missing_deps: &MissingDeps,
 ) -> AppValidationOutcome<Outcome> {
     // Get the workspace for the validation calls
     let host_fn_workspace = workspace.validation_workspace().await?;
@@ -530,6 +687,7 @@ async fn validate_op_outer(
         conductor_handle,
         dpki,
         false, // is_inline
+        Some(missing_deps),
     )
     .await
 }
@@ -543,6 +701,7 @@ pub async fn validate_op(
     conductor_handle: &ConductorHandle,
     dpki: DpkiApi,
     is_inline: bool,
+    missing_deps: Option<&MissingDeps>,
 ) -> AppValidationOutcome<Outcome> {
     check_entry_def(op, &network.dna_hash(), conductor_handle)
         .await
@@ -558,8 +717,16 @@ pub async fn validate_op(
     let invocation = ValidateInvocation::new(zomes_to_invoke, op)
         .map_err(|e| AppValidationError::RibosomeError(e.into()))?;
 
-    let outcome =
-        run_validation_callback(invocation, ribosome, workspace, network, dpki, is_inline).await?;
+    let outcome = run_validation_callback(
+        invocation,
+        ribosome,
+        workspace,
+        network,
+        dpki,
+        is_inline,
+        missing_deps,
+    )
+    .await?;
 
     Ok(outcome)
 }
@@ -740,6 +907,10 @@ fn get_integrity_zome_from_ribosome(
     Ok(ZomesToInvoke::OneIntegrity(zome))
 }
 
+/// Run the validation callback of the zomes to invoke.
+///
+/// The dependencies which the op is missing are added to `missing_deps`,
+/// or if that is not given, fetched in the background straight away.
 #[allow(clippy::too_many_arguments)]
 async fn run_validation_callback(
     invocation: ValidateInvocation,
@@ -748,6 +919,7 @@ async fn run_validation_callback(
     network: GenericNetwork,
     dpki: DpkiApi,
     is_inline: bool,
+    missing_deps: Option<&MissingDeps>,
 ) -> AppValidationResult<Outcome> {
     let validate_result = ribosome
         .run_validate(
@@ -758,60 +930,34 @@ async fn run_validation_callback(
     match validate_result {
         ValidateResult::Valid => Ok(Outcome::Accepted),
         ValidateResult::Invalid(reason) => Ok(Outcome::Rejected(reason)),
-        ValidateResult::UnresolvedDependencies(UnresolvedDependencies::Hashes(hashes)) => {
-            tracing::debug!(
-                ?hashes,
-                "Op validation returned unresolved dependencies -  Hashes"
-            );
-            // fetch all missing hashes in the background without awaiting them
-            let cascade_workspace = workspace.clone();
-            let cascade =
-                CascadeImpl::from_workspace_and_network(&cascade_workspace, network.clone());
-
-            // build a collection of futures to fetch the missing hashes
-            let fetches = hashes.clone().into_iter().map(move |hash| {
-                let cascade = cascade.clone();
-                async move {
-                    let result = cascade
-                        .fetch_record(hash.clone(), NetworkGetOptions::must_get_options())
-                        .await;
-                    if let Err(err) = result {
-                        tracing::warn!("error fetching dependent hash {hash:?}: {err}");
-                    }
+        ValidateResult::UnresolvedDependencies(deps) => {
+            let awaiting = match &deps {
+                UnresolvedDependencies::Hashes(hashes) => {
+                    tracing::debug!(
+                        ?hashes,
+                        "Op validation returned unresolved dependencies -  Hashes"
+                    );
+                    hashes.clone()
                 }
-            });
-            // await all fetches in a separate task in the background
-            tokio::spawn(async { futures::future::join_all(fetches).await });
-            Ok(Outcome::AwaitingDeps(hashes))
-        }
-        ValidateResult::UnresolvedDependencies(UnresolvedDependencies::AgentActivity(
-            author,
-            filter,
-        )) => {
-            tracing::debug!(
-                ?author,
-                ?filter,
-                "Op validation returned unresolved dependencies -  AgentActivity"
-            );
-            // fetch missing agent activities in the background without awaiting them
-            let cascade_workspace = workspace.clone();
-            let author = author.clone();
-            let cascade =
-                CascadeImpl::from_workspace_and_network(&cascade_workspace, network.clone());
-
-            // fetch dependency
-            tokio::spawn({
-                let author = author.clone();
-                async move {
-                    let result = cascade
-                        .must_get_agent_activity(author.clone(), filter)
-                        .await;
-                    if let Err(err) = result {
-                        tracing::warn!("error fetching dependent chain of agent {author:?}: {err}");
-                    }
+                UnresolvedDependencies::AgentActivity(author, filter) => {
+                    tracing::debug!(
+                        ?author,
+                        ?filter,
+                        "Op validation returned unresolved dependencies -  AgentActivity"
+                    );
+                    vec![author.clone().into()]
                 }
-            });
-            Ok(Outcome::AwaitingDeps(vec![author.into()]))
+            };
+            match missing_deps {
+                Some(missing_deps) => missing_deps.add(deps),
+                None => {
+                    let missing_deps = MissingDeps::default();
+                    missing_deps.add(deps);
+                    let cascade = CascadeImpl::from_workspace_and_network(&workspace, network);
+                    missing_deps.fetch_in_background(cascade, usize::MAX);
+                }
+            }
+            Ok(Outcome::AwaitingDeps(awaiting))
         }
     }
 }
@@ -857,6 +1003,8 @@ pub struct AppValidationWorkspace {
     cache: DbWrite<DbKindCache>,
     keystore: MetaLairClient,
     dna_def: Arc<DnaDef>,
+    /// The number of ops to validate at the same time.
+    concurrency: usize,
 }
 
 impl AppValidationWorkspace {
@@ -868,6 +1016,7 @@ impl AppValidationWorkspace {
         cache: DbWrite<DbKindCache>,
         keystore: MetaLairClient,
         dna_def: Arc<DnaDef>,
+        concurrency: usize,
     ) -> Self {
         Self {
             authored_db,
@@ -876,6 +1025,7 @@ impl AppValidationWorkspace {
             cache,
             keystore,
             dna_def,
+            concurrency,
         }
     }
 
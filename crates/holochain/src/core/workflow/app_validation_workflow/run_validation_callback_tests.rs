@@ -99,6 +99,7 @@ async fn validation_callback_must_get_action() {
         network.clone(),
         dpki.clone(),
         false,
+        None,
     )
     .await
     .unwrap();
@@ -112,9 +113,10 @@ async fn validation_callback_must_get_action() {
     });
 
     // the same validation should now successfully validate the op
-    let outcome = run_validation_callback(invocation, &ribosome, workspace, network, dpki, false)
-        .await
-        .unwrap();
+    let outcome =
+        run_validation_callback(invocation, &ribosome, workspace, network, dpki, false, None)
+            .await
+            .unwrap();
     assert_matches!(outcome, Outcome::Accepted);
 }
 
@@ -199,6 +201,7 @@ async fn validation_callback_awaiting_deps_hashes() {
         network.clone(),
         dpki.clone(),
         false,
+        None,
     )
     .await
     .unwrap();
@@ -213,9 +216,10 @@ async fn validation_callback_awaiting_deps_hashes() {
 
     // app validation outcome should be accepted, now that the missing record
     // has been fetched
-    let outcome = run_validation_callback(invocation, &ribosome, workspace, network, dpki, false)
-        .await
-        .unwrap();
+    let outcome =
+        run_validation_callback(invocation, &ribosome, workspace, network, dpki, false, None)
+            .await
+            .unwrap();
     assert_matches!(outcome, Outcome::Accepted)
 }
 
@@ -325,6 +329,7 @@ async fn validation_callback_awaiting_deps_agent_activity() {
         network.clone(),
         dpki.clone(),
         false,
+        None,
     )
     .await
     .unwrap();
@@ -342,9 +347,10 @@ async fn validation_callback_awaiting_deps_agent_activity() {
 
     // app validation outcome should be accepted, now that bob's missing agent
     // activity is available in alice's cache
-    let outcome = run_validation_callback(invocation, &ribosome, workspace, network, dpki, false)
-        .await
-        .unwrap();
+    let outcome =
+        run_validation_callback(invocation, &ribosome, workspace, network, dpki, false, None)
+            .await
+            .unwrap();
     assert_matches!(outcome, Outcome::Accepted);
 }
 
@@ -2,7 +2,7 @@ use crate::conductor::{Conductor, ConductorHandle};
 use crate::core::ribosome::guest_callback::validate::ValidateResult;
 use crate::core::ribosome::ZomeCallInvocation;
 use crate::core::workflow::app_validation_workflow::{
-    app_validation_workflow_inner, check_app_entry_def, put_validation_limbo,
+    app_validation_workflow_inner, author_chains, check_app_entry_def, put_validation_limbo,
     AppValidationWorkspace, OutcomeSummary,
 };
 use crate::core::workflow::sys_validation_workflow::validation_query;
@@ -17,6 +17,7 @@ use hdk::hdi::test_utils::set_zome_types;
 use hdk::prelude::*;
 use holo_hash::{fixt::AgentPubKeyFixturator, ActionHash, AnyDhtHash, DhtOpHash, EntryHash};
 use holochain_conductor_api::conductor::paths::DataRootPath;
+use holochain_conductor_api::conductor::ConductorTuningParams;
 use holochain_p2p::actor::HolochainP2pRefToDna;
 use holochain_sqlite::error::DatabaseResult;
 use holochain_state::mutations::insert_op_dht;
@@ -27,11 +28,14 @@ use holochain_types::dht_op::DhtOpHashed;
 use holochain_types::inline_zome::InlineZomeSet;
 use holochain_types::prelude::*;
 use holochain_wasm_test_utils::{TestWasm, TestWasmPair, TestZomes};
-use holochain_zome_types::fixt::{CreateFixturator, DeleteFixturator, SignatureFixturator};
+use holochain_zome_types::fixt::{
+    CreateFixturator, CreateLinkFixturator, DeleteFixturator, SignatureFixturator,
+};
 use holochain_zome_types::timestamp::Timestamp;
 use holochain_zome_types::Action;
 use matches::assert_matches;
 use rusqlite::{named_params, Transaction};
+use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::hash::Hash;
 use std::sync::Arc;
@@ -84,6 +88,7 @@ async fn main_workflow() {
         conductor.get_cache_db(&cell_id).await.unwrap(),
         conductor.keystore(),
         Arc::new(dna_file.dna_def().clone()),
+        ConductorTuningParams::default().app_validation_concurrency(),
     ));
 
     // check there are no ops to app validate
@@ -288,6 +293,7 @@ async fn validate_ops_in_sequence_must_get_agent_activity() {
         conductor.get_cache_db(&cell_id).await.unwrap(),
         conductor.keystore(),
         Arc::new(dna_file.dna_def().clone()),
+        ConductorTuningParams::default().app_validation_concurrency(),
     ));
 
     // check there are no ops to app validate
@@ -407,6 +413,7 @@ async fn validate_ops_in_sequence_must_get_action() {
         conductor.get_cache_db(&cell_id).await.unwrap(),
         conductor.keystore(),
         Arc::new(dna_file.dna_def().clone()),
+        ConductorTuningParams::default().app_validation_concurrency(),
     ));
 
     // check there are no ops to app validate
@@ -495,6 +502,138 @@ async fn validate_ops_in_sequence_must_get_action() {
     assert_eq!(ops_to_validate, 0);
 }
 
+// test that the ops of different authors are validated in parallel, while
+// the ops of each author are validated one after the other in sorted order
+#[tokio::test(flavor = "multi_thread")]
+async fn ops_of_different_authors_are_validated_in_parallel() {
+    holochain_trace::test_run();
+
+    let validated = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let in_flight = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let max_in_flight = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let zomes = SweetInlineZomes::new(vec![], 0).integrity_function("validate", {
+        let validated = validated.clone();
+        let in_flight = in_flight.clone();
+        let max_in_flight = max_in_flight.clone();
+        move |_api, op: Op| {
+            let running = in_flight.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(running, std::sync::atomic::Ordering::SeqCst);
+            validated.lock().unwrap().push((
+                op.author().clone(),
+                op.action_seq(),
+                matches!(op, Op::RegisterAgentActivity(_)),
+            ));
+            std::thread::sleep(Duration::from_millis(20));
+            in_flight.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(ValidateCallbackResult::Valid)
+        }
+    });
+
+    let (dna_file, _, _) = SweetDnaFile::unique_from_inline_zomes(zomes).await;
+    let dna_hash = dna_file.dna_hash().clone();
+
+    let mut conductor = SweetConductor::from_standard_config().await;
+    let app = conductor.setup_app("", &[dna_file.clone()]).await.unwrap();
+    let cell_id = app.cells()[0].cell_id().clone();
+
+    let app_validation_workspace = Arc::new(AppValidationWorkspace::new(
+        conductor
+            .get_or_create_authored_db(&dna_hash, cell_id.agent_pubkey().clone())
+            .unwrap(),
+        conductor.get_dht_db(&dna_hash).unwrap(),
+        conductor.get_dht_db_cache(&dna_hash).unwrap(),
+        conductor.get_cache_db(&cell_id).await.unwrap(),
+        conductor.keystore(),
+        Arc::new(dna_file.dna_def().clone()),
+        4,
+    ));
+
+    // each of three authors has a chain of links, each of which is
+    // registered as agent activity and at the link base
+    let mut ops = Vec::new();
+    for _ in 0..3 {
+        let author = fixt!(AgentPubKey);
+        for seq in 1..=3 {
+            let mut create_link = fixt!(CreateLink);
+            create_link.author = author.clone();
+            create_link.action_seq = seq;
+            create_link.timestamp = Timestamp::from_micros(seq as i64);
+            create_link.zome_index = 0.into();
+            let action = Action::CreateLink(create_link.clone());
+            ops.push(DhtOpHashed::from_content_sync(
+                ChainOp::RegisterAgentActivity(fixt!(Signature), action),
+            ));
+            ops.push(DhtOpHashed::from_content_sync(ChainOp::RegisterAddLink(
+                fixt!(Signature),
+                create_link,
+            )));
+        }
+    }
+    app_validation_workspace.dht_db.test_write(move |txn| {
+        for op in ops {
+            insert_op_dht(txn, &op, None).unwrap();
+            put_validation_limbo(txn, &op.hash, ValidationStage::SysValidated).unwrap();
+        }
+    });
+
+    let sorted_ops = validation_query::get_ops_to_app_validate(&app_validation_workspace.dht_db)
+        .await
+        .unwrap();
+    assert_eq!(sorted_ops.len(), 18);
+
+    let outcome_summary = app_validation_workflow_inner(
+        Arc::new(dna_hash.clone()),
+        app_validation_workspace.clone(),
+        conductor.raw_handle(),
+        &conductor.holochain_p2p().to_dna(dna_hash.clone(), None),
+        conductor
+            .get_or_create_space(&dna_hash)
+            .unwrap()
+            .dht_query_cache,
+    )
+    .await
+    .unwrap();
+    assert_matches!(
+        outcome_summary,
+        OutcomeSummary {
+            ops_to_validate: 18,
+            validated: 18,
+            accepted: 18,
+            rejected: 0,
+            warranted: 0,
+            missing: 0,
+            failed: empty_set,
+        } if empty_set == HashSet::<DhtOpHash>::new()
+    );
+
+    // the ops of different authors overlapped
+    assert!(max_in_flight.load(std::sync::atomic::Ordering::SeqCst) > 1);
+
+    // the ops of each author were validated in the sorted order
+    let by_author = |ops: &[(AgentPubKey, u32, bool)]| {
+        let mut by_author: HashMap<AgentPubKey, Vec<(u32, bool)>> = HashMap::new();
+        for (author, seq, activity) in ops {
+            by_author
+                .entry(author.clone())
+                .or_default()
+                .push((*seq, *activity));
+        }
+        by_author
+    };
+    let expected = sorted_ops
+        .iter()
+        .map(|op| match op.as_content() {
+            DhtOp::ChainOp(op) => (
+                op.action().author().clone(),
+                op.action().action_seq(),
+                op.get_type() == ChainOpType::RegisterAgentActivity,
+            ),
+            DhtOp::WarrantOp(_) => unreachable!("no warrants were inserted"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(by_author(&expected), by_author(&validated.lock().unwrap()));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn multi_create_link_validation() {
     holochain_trace::test_run();
@@ -575,6 +714,7 @@ async fn handle_error_in_op_validation() {
         conductor.get_cache_db(&cell_id).await.unwrap(),
         conductor.keystore(),
         Arc::new(dna_file.dna_def().clone()),
+        ConductorTuningParams::default().app_validation_concurrency(),
     ));
 
     // create register agent activity op that will return an error during validation
@@ -930,6 +1070,53 @@ async fn check_app_entry_def_test() {
     );
 }
 
+#[test]
+fn ops_of_each_author_are_validated_in_sequence() {
+    let alice = fixt!(AgentPubKey);
+    let bob = fixt!(AgentPubKey);
+    let op = |author: &AgentPubKey, seq: u32, activity: bool| {
+        let mut create = fixt!(Create);
+        create.author = author.clone();
+        create.action_seq = seq;
+        let action = Action::Create(create);
+        let op = if activity {
+            ChainOp::RegisterAgentActivity(fixt!(Signature), action)
+        } else {
+            ChainOp::StoreRecord(fixt!(Signature), action, RecordEntry::NA)
+        };
+        DhtOpHashed::from_content_sync(DhtOp::from(op))
+    };
+    let alice_1 = op(&alice, 1, true);
+    let bob_1 = op(&bob, 1, true);
+    let alice_record = op(&alice, 2, false);
+    let alice_2 = op(&alice, 2, true);
+
+    let chains = author_chains(vec![
+        alice_1.clone(),
+        bob_1.clone(),
+        alice_record.clone(),
+        alice_2.clone(),
+    ]);
+
+    let hashes = |chain: &Vec<DhtOpHashed>| {
+        chain
+            .iter()
+            .map(|op| op.as_hash().clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        vec![
+            vec![
+                alice_1.as_hash().clone(),
+                alice_record.as_hash().clone(),
+                alice_2.as_hash().clone()
+            ],
+            vec![bob_1.as_hash().clone()],
+        ],
+        chains.iter().map(hashes).collect::<Vec<_>>()
+    );
+}
+
 /// Three agent test.
 /// Alice is bypassing validation.
 /// Bob and Carol are running a DNA with validation that will reject any new action authored.
@@ -9,6 +9,9 @@ use crate::conductor::api::DpkiApi;
 use crate::conductor::ConductorHandle;
 use crate::core::check_dpki_agent_validity_for_record;
 use crate::core::queue_consumer::TriggerSender;
+use crate::core::ribosome::call_limits::ZomeCallLimits;
+use crate::core::ribosome::call_usage::CallUsage;
+use crate::core::ribosome::error::RibosomeError;
 use crate::core::ribosome::error::RibosomeResult;
 use crate::core::ribosome::guest_callback::post_commit::send_post_commit;
 use crate::core::ribosome::RibosomeT;
@@ -38,6 +41,10 @@ pub struct CallZomeWorkflowArgs<RibosomeT> {
     pub conductor_handle: ConductorHandle,
     pub is_root_zome_call: bool,
     pub cell_id: CellId,
+    /// Counts the resources used by the call.
+    pub usage: Arc<CallUsage>,
+    /// Limits on the wasm of the call.
+    pub limits: ZomeCallLimits,
 }
 
 #[cfg_attr(feature = "instrument", tracing::instrument(skip_all))]
@@ -79,8 +86,12 @@ where
     .await?;
     // --- END OF WORKFLOW, BEGIN FINISHER BOILERPLATE ---
 
+    // The wasm of a call that timed out may still be writing to the scratch,
+    // so nothing it has written is committed.
+    let timed_out = matches!(result, Err(RibosomeError::ZomeCallTimeout(_)));
+
     // commit the workspace
-    if should_write {
+    if should_write && !timed_out {
         let countersigning_op = workspace.source_chain().countersigning_op()?;
         match workspace.source_chain().flush(&network).await {
             Ok(flushed_actions) => {
@@ -148,6 +159,8 @@ where
         signal_tx,
         conductor_handle,
         cell_id,
+        usage,
+        limits,
         ..
     } = args;
 
@@ -162,11 +175,17 @@ where
         network.clone(),
         signal_tx,
         call_zome_handle,
+        usage,
+        limits,
     );
     let (ribosome, result) =
         call_zome_function_authorized(ribosome, host_access, invocation).await?;
     tracing::trace!("After zome call");
 
+    if let Err(RibosomeError::ZomeCallTimeout(_)) = result {
+        return Ok(result);
+    }
+
     let validation_result =
         inline_validation(workspace.clone(), network, conductor_handle, ribosome).await;
 
@@ -316,6 +335,7 @@ where
                 &conductor_handle,
                 dpki.clone(),
                 true, // is_inline
+                None,
             )
             .await;
             let outcome = outcome.or_else(Outcome::try_from);
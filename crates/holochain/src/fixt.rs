@@ -353,6 +353,8 @@ fixturator!(
         network: HolochainP2pDnaFixturator::new(Empty).next().unwrap(),
         signal_tx: broadcast::channel(50).0,
         call_zome_handle: CellConductorReadHandleFixturator::new(Empty).next().unwrap(),
+        usage: Default::default(),
+        limits: Default::default(),
     };
     curve Unpredictable ZomeCallHostAccess {
         workspace: HostFnWorkspaceFixturator::new(Unpredictable).next().unwrap(),
@@ -362,6 +364,8 @@ fixturator!(
         network: HolochainP2pDnaFixturator::new(Unpredictable).next().unwrap(),
         signal_tx: broadcast::channel(50).0,
         call_zome_handle: CellConductorReadHandleFixturator::new(Unpredictable).next().unwrap(),
+        usage: Default::default(),
+        limits: Default::default(),
     };
     curve Predictable ZomeCallHostAccess {
         workspace: HostFnWorkspaceFixturator::new_indexed(Predictable, get_fixt_index!())
@@ -379,6 +383,8 @@ fixturator!(
         call_zome_handle: CellConductorReadHandleFixturator::new_indexed(Predictable, get_fixt_index!())
             .next()
             .unwrap(),
+        usage: Default::default(),
+        limits: Default::default(),
     };
 );
 
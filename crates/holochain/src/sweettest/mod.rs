@@ -16,6 +16,7 @@ mod sweet_agents;
 mod sweet_app;
 mod sweet_app_installation;
 mod sweet_cell;
+mod sweet_clock;
 mod sweet_conductor;
 mod sweet_conductor_batch;
 mod sweet_conductor_config;
@@ -31,6 +32,7 @@ pub use sweet_agents::*;
 pub use sweet_app::*;
 pub use sweet_app_installation::*;
 pub use sweet_cell::*;
+pub use sweet_clock::*;
 pub use sweet_conductor::*;
 pub use sweet_conductor_batch::*;
 pub use sweet_conductor_config::*;
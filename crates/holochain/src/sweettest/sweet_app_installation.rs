@@ -89,5 +89,6 @@ pub async fn get_install_app_payload_from_dnas(
         roles_settings,
         ignore_genesis_failure: false,
         allow_throwaway_random_agent_key: false,
+        wasm_limits: None,
     }
 }
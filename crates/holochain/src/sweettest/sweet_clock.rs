@@ -0,0 +1,68 @@
+//! A simulated clock for conductors under test, which only moves when the
+//! test advances it.
+
+use holochain_types::prelude::Timestamp;
+use holochain_zome_types::timestamp::sim_clock::{self, SimClock};
+use std::future::Future;
+use std::time::Duration;
+
+/// A simulated clock, followed by the conductors of a test which runs on it
+/// with [`SweetClock::block_on`].
+///
+/// On the threads of that test, [`Timestamp::now`], the `sys_time` host
+/// function and the back off of the workflow queues all follow the simulated
+/// clock, so a test can skip over delays by advancing it instead of waiting
+/// them out. Other tests in the same process keep the system time.
+///
+/// Everything else that waits on tokio time still waits in real time,
+/// including gossip rounds, network timeouts and `await_consistency`.
+#[derive(Clone, Debug)]
+pub struct SweetClock(SimClock);
+
+impl SweetClock {
+    /// A simulated clock which starts at the current system time.
+    pub fn new() -> Self {
+        Self::new_at(Timestamp::now())
+    }
+
+    /// A simulated clock which starts at the given time.
+    pub fn new_at(at: Timestamp) -> Self {
+        Self(SimClock::new(at))
+    }
+
+    /// The current simulated time.
+    pub fn now(&self) -> Timestamp {
+        self.0.now()
+    }
+
+    /// Run a test on a new multi-threaded runtime, every thread of which
+    /// follows this clock.
+    pub fn block_on<F: Future>(&self, test: F) -> F::Output {
+        let clock = self.0.clone();
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .on_thread_start(move || {
+                sim_clock::set_current(Some(clock.clone()));
+            })
+            .build()
+            .expect("failed to build the simulated clock runtime");
+        let _guard = self.0.enter();
+        runtime.block_on(test)
+    }
+
+    /// Advance the clock, then wait for every task whose sleep on the clock
+    /// came due to resume.
+    pub async fn advance(&self, by: Duration) -> Timestamp {
+        let now = self.0.advance(by);
+        while self.0.due_sleeps() > 0 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        now
+    }
+}
+
+impl Default for SweetClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
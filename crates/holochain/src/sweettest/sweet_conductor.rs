@@ -750,6 +750,23 @@ impl SweetConductor {
         crate::conductor::p2p_agent_store::forget_peer_info(all, agents_to_forget).await;
     }
 
+    /// The urls that the running cells of this conductor advertise to their
+    /// peers, which identify this conductor to the network.
+    pub async fn local_peer_urls(&self) -> Vec<String> {
+        let mut urls = Vec::new();
+        for cell_id in self.running_cell_ids() {
+            for info in self.get_agent_infos(Some(cell_id)).await.unwrap() {
+                for url in info.url_list.iter() {
+                    let url = url.to_string();
+                    if !urls.contains(&url) {
+                        urls.push(url);
+                    }
+                }
+            }
+        }
+        urls
+    }
+
     /// Let each conductor know about each others' agents so they can do networking
     pub async fn exchange_peer_info_sampled(
         conductors: impl IntoIterator<Item = &Self>,
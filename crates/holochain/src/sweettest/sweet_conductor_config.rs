@@ -47,6 +47,7 @@ impl From<KitsuneP2pConfig> for SweetConductorConfig {
                 countersigning_resolution_retry_delay: Some(std::time::Duration::from_secs(3)),
                 countersigning_resolution_retry_limit: None,
                 min_publish_interval: None,
+                app_validation_concurrency: None,
             }),
             ..Default::default()
         }
@@ -3,18 +3,22 @@ use super::node::NetworkTopologyNode;
 use crate::sweettest::SweetConductor;
 use arbitrary::Arbitrary;
 use holochain_zome_types::prelude::CellId;
+use kitsune_p2p::test_util::mem_faults::MemFault;
 use rand::Rng;
 use std::hash::{Hash, Hasher};
 
 /// A network edge in a network topology. Represents a network connection.
 /// Edges are directed, so if you want a bidirectional connection you need two
 /// edges.
-#[derive(Arbitrary, Clone, Debug, Default, Eq)]
+#[derive(Arbitrary, Clone, Debug, Default)]
 pub struct NetworkTopologyEdge {
     id: [u8; 32],
     source_conductor: NetworkTopologyConductor,
     target_conductor: NetworkTopologyConductor,
     cells: Vec<CellId>,
+    /// Faults in the messages sent from the source to the target conductor.
+    #[arbitrary(default)]
+    fault: MemFault,
 }
 
 /// ID based hashing means we can use edges as keys in a hashmap and they'll
@@ -33,6 +37,8 @@ impl PartialEq for NetworkTopologyEdge {
     }
 }
 
+impl Eq for NetworkTopologyEdge {}
+
 impl NetworkTopologyEdge {
     /// Get the cells in this edge.
     /// MAY disagree with the cells in the conductor.
@@ -52,6 +58,19 @@ impl NetworkTopologyEdge {
         &self.target_conductor
     }
 
+    /// Get the faults in the messages sent from the source to the target
+    /// conductor of this edge.
+    pub fn fault(&self) -> &MemFault {
+        &self.fault
+    }
+
+    /// Set the faults in the messages sent from the source to the target
+    /// conductor of this edge. Only takes effect once the network topology
+    /// applies its faults.
+    pub fn set_fault(&mut self, fault: MemFault) {
+        self.fault = fault;
+    }
+
     /// Apply the edge state to its associated conductor.
     pub async fn apply(&mut self) -> anyhow::Result<()> {
         async fn envs_from_conductor(
@@ -92,6 +111,7 @@ impl NetworkTopologyEdge {
             source_conductor: source.conductor().clone(),
             target_conductor: target.conductor().clone(),
             cells: target.cells(),
+            fault: MemFault::default(),
         }
     }
 }
@@ -9,6 +9,7 @@ use contrafact::MutationError;
 use holochain_conductor_api::config::conductor::KeystoreConfig;
 use holochain_types::prelude::DnaFile;
 use holochain_util::tokio_helper;
+use kitsune_p2p::test_util::mem_faults::{MemFault, MemFaults};
 use petgraph::algo::connected_components;
 use petgraph::dot::{Config, Dot};
 use petgraph::prelude::*;
@@ -19,6 +20,7 @@ use shrinkwraprs::Shrinkwrap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 
 /// Some orphan rule hoop jumping.
@@ -85,6 +87,9 @@ pub struct NetworkTopology {
     /// referencing a different conductor.
     #[shrinkwrap(main_field)]
     graph: Graph<NetworkTopologyNode, NetworkTopologyEdge, Directed, usize>,
+    /// The faults applied to the messages between the nodes, which stop
+    /// applying once this network and all its clones are dropped.
+    faults: MemFaults,
 }
 
 /// Errors that can occur when manipulating a `NetworkTopology`.
@@ -209,9 +214,62 @@ impl NetworkTopology {
             edge.apply().await?;
         }
 
+        self.apply_faults().await
+    }
+
+    /// Apply the faults of the network to the messages sent between its nodes,
+    /// which only affects nodes using the mem transport. Messages between nodes
+    /// in different strict partitions are all dropped, messages along an edge
+    /// get the faults of that edge, and all other messages are left untouched.
+    ///
+    /// This can be called again at any time to change the faults while the
+    /// nodes are running.
+    pub async fn apply_faults(&self) -> anyhow::Result<()> {
+        let mut node_urls = Vec::with_capacity(self.node_count());
+        for node in self.node_weights() {
+            node_urls.push(node_peer_urls(node).await?);
+        }
+
+        let vertex_sets = self.vertex_sets();
+        for source in self.node_indices() {
+            for target in self.node_indices() {
+                if source == target {
+                    continue;
+                }
+                let fault = if !vertex_sets.equiv(source.index(), target.index()) {
+                    MemFault::partition()
+                } else if let Some(edge) = self.find_edge(source, target) {
+                    self.graph[edge].fault().clone()
+                } else {
+                    MemFault::default()
+                };
+                for from in &node_urls[source.index()] {
+                    for to in &node_urls[target.index()] {
+                        // Urls of other transports can't carry faults.
+                        if let Err(err) = self.faults.set(from, to, fault.clone()) {
+                            tracing::debug!(?err, %from, %to, "not applying network fault");
+                        }
+                    }
+                }
+            }
+        }
+
         Ok(())
     }
 
+    /// Set the faults in the messages sent along the edge from the origin to
+    /// the target node. Returns true if there is such an edge.
+    /// Only takes effect once the faults are applied.
+    pub fn set_edge_fault(&mut self, origin: usize, target: usize, fault: MemFault) -> bool {
+        match self.graph.find_edge(origin.into(), target.into()) {
+            Some(edge) => {
+                self.graph[edge].set_fault(fault);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Get the DnaFiles that are in this graph.
     pub fn dnas(&self) -> &[DnaFile] {
         &self.dnas
@@ -460,6 +518,26 @@ impl NetworkTopology {
     }
 }
 
+/// The peer urls of a node's conductor, waiting for its cells to have
+/// joined the network.
+async fn node_peer_urls(node: &NetworkTopologyNode) -> anyhow::Result<Vec<String>> {
+    if node.cells().is_empty() {
+        return Ok(Vec::new());
+    }
+    let conductor = node.conductor().lock().await.read().await;
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let urls = conductor.local_peer_urls().await;
+            if !urls.is_empty() {
+                return urls;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .map_err(|_| anyhow::anyhow!("node has not joined the network"))
+}
+
 /// Implement Arbitrary for a Network Topology as an empty network. Use facts
 /// or similar to mutate the network into a more interesting state.
 /// The network will have some DNA files in it, but no nodes or edges.
@@ -468,7 +546,11 @@ impl<'a> Arbitrary<'a> for NetworkTopology {
         let graph = Graph::<_, _, _, _>::default();
         let dnas: Result<Vec<DnaFile>, _> = u.arbitrary_iter::<DnaFile>()?.collect();
 
-        Ok(Self { dnas: dnas?, graph })
+        Ok(Self {
+            dnas: dnas?,
+            graph,
+            faults: MemFaults::new(),
+        })
     }
 }
 
@@ -681,4 +763,98 @@ mod test {
             );
         });
     }
+
+    #[test]
+    fn test_network_topology_faults_partition_nodes() {
+        crate::big_stack_test!(async move {
+            use crate::sweettest::{await_consistency, SweetDnaFile};
+            use holo_hash::ActionHash;
+            use holochain_types::prelude::{DhtOp, Record};
+            use holochain_wasm_test_utils::TestWasm;
+
+            let (dna_file, _, _) =
+                SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Create]).await;
+
+            let mut topology = NetworkTopology::default();
+            topology.add_dnas(vec![dna_file.clone()]);
+            let mut alice_node = NetworkTopologyNode::new();
+            let mut bob_node = NetworkTopologyNode::new();
+            for node in [&mut alice_node, &mut bob_node] {
+                node.ensure_dnas(vec![dna_file.clone()]);
+                node.generate_cells(1).await;
+                assert!(topology.add_node(node.clone()));
+            }
+            let alice_to_bob = NetworkTopologyEdge::new_full_view_on_node(&alice_node, &bob_node);
+            let bob_to_alice = NetworkTopologyEdge::new_full_view_on_node(&bob_node, &alice_node);
+            assert!(topology.add_simple_edge(0, 1, alice_to_bob));
+            assert!(topology.add_simple_edge(1, 0, bob_to_alice));
+            topology.apply().await.unwrap();
+
+            assert!(topology.set_edge_fault(0, 1, MemFault::partition()));
+            assert!(topology.set_edge_fault(1, 0, MemFault::partition()));
+            topology.apply_faults().await.unwrap();
+
+            let alice_conductor = alice_node.conductor().lock().await;
+            let bob_conductor = bob_node.conductor().lock().await;
+            let alice = alice_conductor
+                .read()
+                .await
+                .get_sweet_cell(alice_node.cells()[0].clone())
+                .unwrap();
+            let bob = bob_conductor
+                .read()
+                .await
+                .get_sweet_cell(bob_node.cells()[0].clone())
+                .unwrap();
+
+            let action_hash: ActionHash = alice_conductor
+                .read()
+                .await
+                .call(&alice.zome(TestWasm::Create), "create_entry", ())
+                .await;
+
+            // Wait for alice to publish the entry, which bob doesn't receive
+            // while they are partitioned.
+            crate::wait_for_10s!(
+                alice
+                    .authored_db()
+                    .read_async({
+                        let action_hash = action_hash.clone();
+                        move |txn| -> holochain_sqlite::error::DatabaseResult<bool> {
+                            Ok(txn.query_row(
+                                "SELECT NOT EXISTS(SELECT 1 FROM DhtOp
+                                WHERE action_hash = ? AND last_publish_time IS NULL)",
+                                [action_hash],
+                                |row| row.get(0),
+                            )?)
+                        }
+                    })
+                    .await
+                    .unwrap(),
+                |published: &bool| *published,
+                |published: bool| assert!(published)
+            );
+            let held = crate::test_utils::get_integrated_ops(bob.dht_db())
+                .await
+                .into_iter()
+                .any(|op| match op {
+                    DhtOp::ChainOp(op) => ActionHash::with_data_sync(&op.action()) == action_hash,
+                    DhtOp::WarrantOp(_) => false,
+                });
+            assert!(!held);
+
+            assert!(topology.set_edge_fault(0, 1, MemFault::default()));
+            assert!(topology.set_edge_fault(1, 0, MemFault::default()));
+            topology.apply_faults().await.unwrap();
+
+            // Once the partition is healed, gossip brings bob up to date.
+            await_consistency(60, [&alice, &bob]).await.unwrap();
+            let record: Option<Record> = bob_conductor
+                .read()
+                .await
+                .call(&bob.zome(TestWasm::Create), "get_entry", ())
+                .await;
+            assert_eq!(record.unwrap().action_address(), &action_hash);
+        });
+    }
 }
@@ -202,6 +202,8 @@ impl HostFnCaller {
             network,
             signal_tx,
             call_zome_handle,
+            Default::default(),
+            Default::default(),
         );
         let ribosome = Arc::new(ribosome);
         let zome = ribosome.dna_def().get_zome(&zome_name).unwrap();
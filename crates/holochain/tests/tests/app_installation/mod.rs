@@ -103,6 +103,7 @@ async fn can_install_app_with_custom_modifiers_overridden_correctly() {
             roles_settings: None,
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         })
         .await
         .unwrap();
@@ -117,6 +118,7 @@ async fn can_install_app_with_custom_modifiers_overridden_correctly() {
             roles_settings: Some(HashMap::from([role_settings])),
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         })
         .await
         .unwrap();
@@ -281,6 +283,7 @@ async fn install_app_with_custom_modifier_fields_none_does_not_override_existing
             roles_settings: Some(HashMap::from([role_settings])),
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         })
         .await
         .unwrap();
@@ -342,6 +345,7 @@ async fn installing_with_modifiers_for_non_existing_role_fails() {
             roles_settings: Some(HashMap::from([role_settings])),
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         })
         .await;
 
@@ -378,6 +382,7 @@ async fn providing_membrane_proof_overrides_deferred_provisioning() {
             network_seed: None,
             ignore_genesis_failure: false,
             allow_throwaway_random_agent_key: true,
+            wasm_limits: None,
         })
         .await
         .unwrap();
@@ -0,0 +1,120 @@
+use hdk::blob::BlobCreated;
+use hdk::prelude::{BlobManifest, EntryHash};
+use holochain::sweettest::{await_consistency, SweetConductorBatch, SweetDnaFile};
+use holochain_wasm_test_utils::TestWasm;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blob_round_trip_between_agents() {
+    holochain_trace::test_run();
+
+    let mut conductors = SweetConductorBatch::from_standard_config_rendezvous(2).await;
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Blob]).await;
+    let cells = conductors
+        .setup_app("app", [&dna])
+        .await
+        .unwrap()
+        .cells_flattened();
+    let alice = cells.first().unwrap();
+    let bob = cells.last().unwrap();
+
+    // 4 distinct chunks of 1000 bytes, the last 2 repeated, and a short tail.
+    let mut data: Vec<u8> = (0..4000).map(|i| (i / 1000) as u8).collect();
+    data.extend_from_slice(&data.clone()[2000..4000]);
+    data.extend_from_slice(&[9; 500]);
+
+    let created: BlobCreated = conductors[0]
+        .call(
+            &alice.zome(TestWasm::Blob.coordinator_zome_name()),
+            "create_test_blob",
+            (data.clone(), 1000_u32),
+        )
+        .await;
+
+    await_consistency(std::time::Duration::from_secs(60), [alice, bob])
+        .await
+        .unwrap();
+
+    let read: Option<Vec<u8>> = conductors[1]
+        .call(
+            &bob.zome(TestWasm::Blob.coordinator_zome_name()),
+            "read_test_blob",
+            created.manifest_hash.clone(),
+        )
+        .await;
+    assert_eq!(Some(data), read);
+
+    let chunk_lengths: Vec<usize> = conductors[1]
+        .call(
+            &bob.zome(TestWasm::Blob.coordinator_zome_name()),
+            "read_test_blob_chunks",
+            created.manifest_hash,
+        )
+        .await;
+    assert_eq!(vec![1000, 1000, 1000, 1000, 1000, 1000, 500], chunk_lengths);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blob_uploaded_and_read_a_chunk_per_call() {
+    holochain_trace::test_run();
+
+    let mut conductors = SweetConductorBatch::from_standard_config_rendezvous(2).await;
+    let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Blob]).await;
+    let cells = conductors
+        .setup_app("app", [&dna])
+        .await
+        .unwrap()
+        .cells_flattened();
+    let alice = cells.first().unwrap();
+    let bob = cells.last().unwrap();
+    let alice_zome = alice.zome(TestWasm::Blob.coordinator_zome_name());
+    let bob_zome = bob.zome(TestWasm::Blob.coordinator_zome_name());
+
+    let data: Vec<u8> = (0..2500).map(|i| (i % 251) as u8).collect();
+
+    let mut chunks = Vec::new();
+    for chunk in data.chunks(1000) {
+        let chunk_hash: EntryHash = conductors[0]
+            .call(&alice_zome, "create_test_blob_chunk", chunk.to_vec())
+            .await;
+        chunks.push(chunk_hash);
+    }
+
+    // A manifest that doesn't match its chunks is refused.
+    let inconsistent = BlobManifest {
+        size: data.len() as u64,
+        chunk_size: 1000,
+        chunks: chunks[..2].to_vec(),
+        content_type: None,
+    };
+    assert!(conductors[0]
+        .call_fallible::<_, BlobCreated>(&alice_zome, "create_test_blob_manifest", inconsistent)
+        .await
+        .is_err());
+
+    let manifest = BlobManifest {
+        size: data.len() as u64,
+        chunk_size: 1000,
+        chunks,
+        content_type: None,
+    };
+    let created: BlobCreated = conductors[0]
+        .call(&alice_zome, "create_test_blob_manifest", manifest)
+        .await;
+
+    await_consistency(std::time::Duration::from_secs(60), [alice, bob])
+        .await
+        .unwrap();
+
+    let mut read = Vec::new();
+    for start in [0, 2] {
+        let page: Vec<u8> = conductors[1]
+            .call(
+                &bob_zome,
+                "read_test_blob_page",
+                (created.manifest_hash.clone(), start, 2_usize),
+            )
+            .await;
+        read.extend(page);
+    }
+    assert_eq!(data, read);
+}
@@ -3,6 +3,7 @@ mod agent_scaling;
 mod app_installation;
 mod app_interface_security;
 mod authored_test;
+mod blob;
 mod clone_cell;
 #[cfg(feature = "unstable-dpki")]
 mod conductor_services;
@@ -25,6 +26,7 @@ mod ser_regression;
 #[cfg(not(target_os = "macos"))]
 mod sharded_gossip;
 mod signals;
+mod sim_clock;
 mod test_cli;
 mod test_utils;
 mod validate;
@@ -0,0 +1,65 @@
+use holochain::core::queue_consumer::TriggerSender;
+use holochain::prelude::*;
+use holochain::sweettest::*;
+use holochain_wasm_test_utils::TestWasm;
+use std::time::Duration;
+
+#[test]
+fn sys_time_follows_the_simulated_clock() {
+    let clock = SweetClock::new_at(Timestamp::HOLOCHAIN_EPOCH);
+    clock.block_on(async {
+        holochain_trace::test_run();
+
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let (dna, _, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::SysTime]).await;
+        let app = conductor.setup_app("app", [&dna]).await.unwrap();
+        let zome = app.cells()[0].zome(TestWasm::SysTime);
+
+        let time: Timestamp = conductor.call(&zome, "sys_time", ()).await;
+        assert_eq!(Timestamp::HOLOCHAIN_EPOCH, time);
+
+        let later = clock.advance(Duration::from_secs(60 * 60)).await;
+        let time: Timestamp = conductor.call(&zome, "sys_time", ()).await;
+        assert_eq!(later, time);
+    });
+}
+
+#[test]
+fn workflow_back_off_follows_the_simulated_clock() {
+    let clock = SweetClock::new();
+    clock.block_on(async {
+        let (_tx, mut rx) =
+            TriggerSender::new_with_loop(Duration::from_secs(60)..Duration::from_secs(60), false);
+        let mut listen = tokio::spawn(async move { rx.listen().await.unwrap() });
+
+        // The back off doesn't pass in real time.
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), &mut listen)
+                .await
+                .is_err()
+        );
+
+        clock.advance(Duration::from_secs(30)).await;
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), &mut listen)
+                .await
+                .is_err()
+        );
+
+        // Once the back off has passed, the listener has resumed by the
+        // time the clock has been advanced.
+        clock.advance(Duration::from_secs(30)).await;
+        tokio::time::timeout(Duration::from_secs(1), listen)
+            .await
+            .unwrap()
+            .unwrap();
+    });
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn other_tests_keep_the_system_time() {
+    let clock = SweetClock::new_at(Timestamp::HOLOCHAIN_EPOCH);
+    clock.advance(Duration::from_secs(1)).await;
+    assert_ne!(clock.now(), Timestamp::now());
+    assert!(Timestamp::now() > Timestamp::HOLOCHAIN_EPOCH);
+}
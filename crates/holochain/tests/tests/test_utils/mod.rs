@@ -368,6 +368,7 @@ pub async fn register_and_install_dna_named(
         roles_settings: Default::default(),
         ignore_genesis_failure: false,
         allow_throwaway_random_agent_key: true,
+        wasm_limits: None,
     };
     let request = AdminRequest::InstallApp(Box::new(payload));
     let response = client.request(request);
@@ -54,6 +54,66 @@ pub async fn handle_get_record(
     Ok(results)
 }
 
+/// The most steps of an update chain an authority follows for one
+/// get_latest request.
+pub const GET_LATEST_MAX_STEPS: usize = 100;
+
+/// Handler for get_latest query to a Record authority.
+///
+/// Follows the valid updates of the action, choosing between concurrent
+/// updates with the tie-break policy, for as long as this authority holds
+/// the next action. Each update has its own authorities, so this is often
+/// only the first step. Returns the ops for each step in order, so the last
+/// step is where the requester carries on from.
+#[cfg_attr(feature = "instrument", tracing::instrument(skip(env)))]
+pub async fn handle_get_latest(
+    env: DbRead<DbKindDht>,
+    hash: ActionHash,
+    tie_break: UpdateTieBreak,
+    options: holochain_p2p::event::GetOptions,
+) -> CascadeResult<Vec<WireRecordOps>> {
+    let results = env
+        .read_async(move |txn| -> CascadeResult<Vec<WireRecordOps>> {
+            let mut steps = Vec::new();
+            let mut next = Some(hash);
+            while let Some(hash) = next.take() {
+                if steps.len() >= GET_LATEST_MAX_STEPS {
+                    break;
+                }
+                let ops = GetRecordOpsQuery::new(hash, options.clone())
+                    .run(CascadeTxnWrapper::from(txn))?;
+                let action = match &ops.action {
+                    Some(action) => action,
+                    // We don't hold this step, the requester carries on
+                    // from the previous one.
+                    None => break,
+                };
+                if action.validation_status() == Some(ValidationStatus::Valid) {
+                    if let Some(entry_hash) = action.data.action().entry_hash() {
+                        let mut updates: Vec<SignedActionHashed> = ops
+                            .updates
+                            .iter()
+                            .filter(|update| {
+                                update.validation_status() == Some(ValidationStatus::Valid)
+                            })
+                            .map(|update| {
+                                SignedActionHashed::from_content_sync(
+                                    update.data.clone().into_signed_action(entry_hash.clone()),
+                                )
+                            })
+                            .collect();
+                        tie_break.sort_updates(action.data.action().author(), &mut updates);
+                        next = updates.first().map(|update| update.as_hash().clone());
+                    }
+                }
+                steps.push(ops);
+            }
+            Ok(steps)
+        })
+        .await?;
+    Ok(results)
+}
+
 /// Handler for get_agent_activity query to an Activity authority.
 #[cfg_attr(feature = "instrument", tracing::instrument(skip(env)))]
 pub async fn handle_get_agent_activity(
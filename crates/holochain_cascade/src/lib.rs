@@ -85,6 +85,17 @@ macro_rules! some_or_return {
     };
 }
 
+/// The number of hashes [`CascadeImpl::dht_get_chunked`] fetches at a time
+/// when no other length is given.
+pub const DHT_GET_CHUNK_LEN_DEFAULT: usize = 16;
+
+/// What one step of a [`CascadeImpl::get_latest`] walk found.
+enum LatestStep {
+    Valid(RecordDetails),
+    Rejected,
+    Missing,
+}
+
 /// Marks whether data came from a local store or another node on the network
 #[derive(Debug, Clone)]
 pub enum CascadeSource {
@@ -550,6 +561,62 @@ impl CascadeImpl {
         Ok(())
     }
 
+    /// Like [`fetch_record`](Self::fetch_record) for many hashes at once.
+    ///
+    /// The records are fetched together, with a single request to each
+    /// authority that holds several of them. The results are in the same
+    /// order as the requested hashes, and a failure to fetch one hash does
+    /// not fail the others.
+    #[cfg_attr(feature = "instrument", tracing::instrument(skip(self, options)))]
+    pub async fn fetch_records(
+        &self,
+        hashes: Vec<AnyDhtHash>,
+        options: NetworkGetOptions,
+    ) -> CascadeResult<Vec<CascadeResult<()>>> {
+        let network = some_or_return!(
+            self.network.as_ref(),
+            hashes.iter().map(|_| Ok(())).collect()
+        );
+        let results = network
+            .get_many(hashes, options)
+            .instrument(debug_span!("fetch_records::network_get_many"))
+            .await?;
+
+        let mut out = Vec::with_capacity(results.len());
+        for result in results {
+            match result {
+                Ok(ops) => out.push(self.merge_ops_into_cache(ops).await),
+                Err(e) => out.push(Err(e.into())),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Fetch the update chain of an action from the network, caching the
+    /// results and returning the actions the authorities walked through.
+    #[cfg_attr(feature = "instrument", tracing::instrument(skip(self, options)))]
+    async fn fetch_latest(
+        &self,
+        hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: NetworkGetOptions,
+    ) -> CascadeResult<Vec<ActionHash>> {
+        let network = some_or_return!(self.network.as_ref(), Vec::with_capacity(0));
+        let steps = network
+            .get_latest(hash, tie_break, options)
+            .instrument(debug_span!("fetch_latest::network_get_latest"))
+            .await?;
+        let walked = steps
+            .iter()
+            .filter_map(|step| step.action.as_ref())
+            .map(|action| ActionHash::with_data_sync(action.data.action()))
+            .collect();
+
+        self.merge_ops_into_cache(steps.into_iter().map(WireOps::Record).collect())
+            .await?;
+        Ok(walked)
+    }
+
     #[cfg_attr(feature = "instrument", tracing::instrument(skip(self, options)))]
     async fn fetch_links(
         &self,
@@ -758,6 +825,98 @@ impl CascadeImpl {
         self.cascading(query).await
     }
 
+    /// Follow the updates of an action to its latest version.
+    ///
+    /// Concurrent updates of the same action are chosen between with the
+    /// `tie_break` policy and rejected updates are skipped. The authorities
+    /// for an action follow its updates for as long as they hold them, which
+    /// is usually one step, so the network is asked again for each update
+    /// after that.
+    ///
+    /// If the chosen update can't be fetched the walk stops and returns its
+    /// hash as [`LatestRecord::continue_from`]. Following the next-best update
+    /// instead would make the result depend on which authorities are reachable.
+    ///
+    /// Returns `None` if the starting action can't be found.
+    /// Deletes don't stop the walk, so the latest version may be deleted.
+    #[cfg_attr(feature = "instrument", tracing::instrument(skip(self, options)))]
+    pub async fn get_latest(
+        &self,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: GetOptions,
+    ) -> CascadeResult<Option<LatestRecord>> {
+        let mut fetched = HashSet::new();
+        let mut head = match self
+            .get_latest_step(action_hash, tie_break, &options, &mut fetched)
+            .await?
+        {
+            LatestStep::Valid(details) => details,
+            LatestStep::Rejected | LatestStep::Missing => return Ok(None),
+        };
+        'walk: loop {
+            let mut updates = head.updates;
+            tie_break.sort_updates(head.record.action().author(), &mut updates);
+            for update in updates {
+                match self
+                    .get_latest_step(update.as_hash().clone(), tie_break, &options, &mut fetched)
+                    .await?
+                {
+                    LatestStep::Valid(details) => {
+                        head = details;
+                        continue 'walk;
+                    }
+                    LatestStep::Rejected => continue,
+                    LatestStep::Missing => {
+                        return Ok(Some(LatestRecord {
+                            record: head.record,
+                            continue_from: Some(update.into_hash()),
+                        }))
+                    }
+                }
+            }
+            return Ok(Some(LatestRecord {
+                record: head.record,
+                continue_from: None,
+            }));
+        }
+    }
+
+    /// Get the details of one step of a [`get_latest`](Self::get_latest) walk,
+    /// asking the network to walk on from it unless a previous request
+    /// already covered it.
+    async fn get_latest_step(
+        &self,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: &GetOptions,
+        fetched: &mut HashSet<ActionHash>,
+    ) -> CascadeResult<LatestStep> {
+        if let GetStrategy::Network = options.strategy {
+            if fetched.insert(action_hash.clone()) {
+                // If we are not in the process of authoring this hash or its
+                // authority we need a network call.
+                let authoring = self.am_i_authoring(&action_hash.clone().into())?;
+                let authority = self.am_i_an_authority(action_hash.clone().into()).await?;
+                if !(authoring || authority) {
+                    fetched.extend(
+                        self.fetch_latest(action_hash.clone(), tie_break, options.clone().into())
+                            .await?,
+                    );
+                }
+            }
+        }
+
+        let query: GetRecordDetailsQuery = self.construct_query_with_data_access(action_hash);
+        Ok(match self.cascading(query).await? {
+            Some(details) if details.validation_status == ValidationStatus::Valid => {
+                LatestStep::Valid(details)
+            }
+            Some(_) => LatestStep::Rejected,
+            None => LatestStep::Missing,
+        })
+    }
+
     #[cfg_attr(feature = "instrument", tracing::instrument(skip(self, options)))]
     /// Returns the [Record] for this [ActionHash] if it is live
     /// by getting the latest available metadata from authorities
@@ -858,6 +1017,140 @@ impl CascadeImpl {
         }
     }
 
+    #[cfg_attr(feature = "instrument", tracing::instrument(skip(self)))]
+    /// Like [`dht_get`](Self::dht_get) for many hashes at once.
+    ///
+    /// Hashes that need to come from the network are fetched together, with a
+    /// single request to each authority that holds several of them.
+    /// The results are in the same order as the requested hashes, and a
+    /// failure to get one hash does not fail the others.
+    pub async fn dht_get_many(
+        &self,
+        hashes: Vec<AnyDhtHash>,
+        options: GetOptions,
+    ) -> CascadeResult<Vec<CascadeResult<Option<Record>>>> {
+        let mut fetch_errors = HashMap::new();
+        if let (GetStrategy::Network, Some(network)) = (&options.strategy, self.network.as_ref()) {
+            // If we are not in the process of authoring a hash or its
+            // authority we need a network call.
+            let mut to_fetch = Vec::new();
+            for (index, hash) in hashes.iter().enumerate() {
+                let authoring = self.am_i_authoring(hash)?;
+                let authority = self.am_i_an_authority(hash.clone().into()).await?;
+                if !(authoring || authority) {
+                    to_fetch.push(index);
+                }
+            }
+
+            if !to_fetch.is_empty() {
+                let results = network
+                    .get_many(
+                        to_fetch.iter().map(|i| hashes[*i].clone()).collect(),
+                        options.clone().into(),
+                    )
+                    .instrument(debug_span!("dht_get_many::network_get_many"))
+                    .await?;
+                for (index, result) in to_fetch.into_iter().zip(results) {
+                    match result {
+                        Ok(ops) => self.merge_ops_into_cache(ops).await?,
+                        Err(e) => {
+                            fetch_errors.insert(index, e);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Check what we have now after the network calls.
+        let mut out = Vec::with_capacity(hashes.len());
+        for (index, hash) in hashes.into_iter().enumerate() {
+            match fetch_errors.remove(&index) {
+                Some(e) => out.push(Err(e.into())),
+                None => out.push(self.dht_get(hash, GetOptions::local()).await),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Like [`dht_get_many`](Self::dht_get_many), but fetches `chunk_len` hashes
+    /// at a time as the stream is polled.
+    ///
+    /// Each item holds the results for the next chunk of hashes in the order
+    /// they were requested, so only one chunk of records is held in memory at
+    /// once. Nothing is fetched after a chunk fails as a whole.
+    pub fn dht_get_chunked(
+        &self,
+        hashes: Vec<AnyDhtHash>,
+        chunk_len: usize,
+        options: GetOptions,
+    ) -> impl futures::stream::Stream<Item = CascadeResult<Vec<CascadeResult<Option<Record>>>>> + '_
+    {
+        let chunks: Vec<Vec<AnyDhtHash>> = hashes
+            .chunks(std::cmp::max(chunk_len, 1))
+            .map(<[AnyDhtHash]>::to_vec)
+            .collect();
+        futures::stream::unfold(chunks.into_iter(), move |mut chunks| {
+            let options = options.clone();
+            async move {
+                let results = self.dht_get_many(chunks.next()?, options).await;
+                if results.is_err() {
+                    chunks = Vec::new().into_iter();
+                }
+                Some((results, chunks))
+            }
+        })
+    }
+
+    /// Like [`retrieve_action`](Cascade::retrieve_action) for many hashes at once.
+    ///
+    /// The actions we don't have are fetched together, with a single request to
+    /// each authority that holds several of them. The results are in the same
+    /// order as the requested hashes.
+    #[cfg_attr(feature = "instrument", tracing::instrument(skip(self, options)))]
+    pub async fn retrieve_actions(
+        &self,
+        hashes: Vec<ActionHash>,
+        mut options: NetworkGetOptions,
+    ) -> CascadeResult<Vec<Option<SignedActionHashed>>> {
+        let mut out = Vec::with_capacity(hashes.len());
+        for hash in &hashes {
+            let hash = hash.clone();
+            out.push(
+                self.find_map(move |store| Ok(store.get_action(&hash)?))
+                    .await?,
+            );
+        }
+
+        let missing: Vec<usize> = (0..hashes.len()).filter(|i| out[*i].is_none()).collect();
+        let network = match self.network.as_ref() {
+            Some(network) if !missing.is_empty() => network,
+            _ => return Ok(out),
+        };
+        options.request_type = holochain_p2p::event::GetRequest::Pending;
+        let results = network
+            .get_many(
+                missing.iter().map(|i| hashes[*i].clone().into()).collect(),
+                options,
+            )
+            .instrument(debug_span!("retrieve_actions::network_get_many"))
+            .await?;
+        for result in results {
+            // An action that couldn't be fetched is reported as missing.
+            if let Ok(ops) = result {
+                self.merge_ops_into_cache(ops).await?;
+            }
+        }
+
+        // Check what we have now after the network call.
+        for index in missing {
+            let hash = hashes[index].clone();
+            out[index] = self
+                .find_map(move |store| Ok(store.get_action(&hash)?))
+                .await?;
+        }
+        Ok(out)
+    }
+
     /// Get either [`EntryDetails`] or [`RecordDetails`], depending on the hash provided
     #[cfg_attr(feature = "instrument", tracing::instrument(skip(self)))]
     pub async fn get_details(
@@ -93,6 +93,39 @@ impl HolochainP2pDnaT for PassThroughNetwork {
         Ok(out)
     }
 
+    async fn get_many(
+        &self,
+        dht_hashes: Vec<holo_hash::AnyDhtHash>,
+        options: actor::GetOptions,
+    ) -> actor::HolochainP2pResult<Vec<actor::HolochainP2pResult<Vec<WireOps>>>> {
+        let mut out = Vec::new();
+        for dht_hash in dht_hashes {
+            out.push(self.get(dht_hash, options.clone()).await);
+        }
+        Ok(out)
+    }
+
+    async fn get_latest(
+        &self,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: actor::GetOptions,
+    ) -> actor::HolochainP2pResult<Vec<WireRecordOps>> {
+        let mut out = Vec::new();
+        for db in &self.envs {
+            let r = authority::handle_get_latest(
+                db.clone(),
+                action_hash.clone(),
+                tie_break,
+                (&options).into(),
+            )
+            .await
+            .map_err(|e| HolochainP2pError::Other(e.into()))?;
+            out.extend(r);
+        }
+        Ok(out)
+    }
+
     async fn get_meta(
         &self,
         _dht_hash: holo_hash::AnyDhtHash,
@@ -194,6 +227,15 @@ impl HolochainP2pDnaT for PassThroughNetwork {
         todo!()
     }
 
+    async fn send_remote_signal_with_ack(
+        &self,
+        _signal_id: RemoteSignalId,
+        _to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
+        _retry_for: std::time::Duration,
+    ) -> actor::HolochainP2pResult<Vec<AgentPubKey>> {
+        todo!()
+    }
+
     async fn publish(
         &self,
         _request_validation_receipt: bool,
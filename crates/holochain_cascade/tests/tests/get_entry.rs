@@ -494,3 +494,244 @@ async fn test_pending_data_isnt_returned() {
 
     assert_can_retrieve(&td_entry, &cascade, GetOptions::network()).await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_many_not_authority_or_authoring() {
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+    let authority = test_dht_db();
+
+    // Data
+    let td_entry = EntryTestData::create();
+    let td_record = RecordTestData::create();
+    fill_db(&authority.to_db(), td_entry.store_entry_op.clone()).await;
+    fill_db(&authority.to_db(), td_record.any_store_record_op.clone()).await;
+    let missing_hash = AnyDhtHash::from(::fixt::fixt!(ActionHash));
+
+    // Network
+    let network = PassThroughNetwork::authority_for_nothing(vec![authority.to_db().clone().into()]);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(network, cache.to_db());
+
+    let results = cascade
+        .dht_get_many(
+            vec![
+                td_record.any_action_hash.clone().into(),
+                missing_hash,
+                td_entry.hash.clone().into(),
+            ],
+            GetOptions::network(),
+        )
+        .await
+        .unwrap();
+
+    // Results are in the order the hashes were requested.
+    assert_eq!(3, results.len());
+    let mut results = results.into_iter().map(Result::unwrap);
+    assert_eq!(
+        *results.next().unwrap().unwrap().action_address(),
+        td_record.any_action_hash
+    );
+    assert!(results.next().unwrap().is_none());
+    assert_eq!(
+        *results.next().unwrap().unwrap().action_address(),
+        td_entry.create_hash
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_many_makes_one_network_call_with_per_item_errors() {
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+
+    // Data
+    let td_entry = EntryTestData::create();
+    let td_record = RecordTestData::create();
+
+    // Network
+    // - All hashes go out in a single call and the second one fails.
+    let mut mock = MockHolochainP2pDnaT::new();
+    mock.expect_authority_for_hash().returning(|_| Ok(false));
+    mock.expect_get_many().times(1).returning(|hashes, _| {
+        assert_eq!(2, hashes.len());
+        Ok(vec![
+            Ok(vec![]),
+            Err(holochain_p2p::HolochainP2pError::other("unreachable")),
+        ])
+    });
+    let mock = Arc::new(mock);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(mock, cache.to_db());
+
+    let results = cascade
+        .dht_get_many(
+            vec![
+                td_entry.hash.clone().into(),
+                td_record.any_action_hash.clone().into(),
+            ],
+            GetOptions::network(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(2, results.len());
+    assert!(results[0].as_ref().unwrap().is_none());
+    assert!(results[1].is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_chunked_makes_one_network_call_per_chunk() {
+    use futures::StreamExt;
+
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+
+    // Data
+    let hashes: Vec<AnyDhtHash> = std::iter::repeat_with(|| ::fixt::fixt!(ActionHash).into())
+        .take(5)
+        .collect();
+
+    // Network
+    // - 5 hashes in chunks of 2 go out as calls for 2, 2 and 1 hashes.
+    let requested = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut mock = MockHolochainP2pDnaT::new();
+    mock.expect_authority_for_hash().returning(|_| Ok(false));
+    mock.expect_get_many().times(3).returning({
+        let requested = requested.clone();
+        move |hashes, _| {
+            requested.lock().unwrap().push(hashes.len());
+            Ok(hashes.iter().map(|_| Ok(vec![])).collect())
+        }
+    });
+    let mock = Arc::new(mock);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(mock, cache.to_db());
+
+    let chunk_lens: Vec<usize> = cascade
+        .dht_get_chunked(hashes, 2, GetOptions::network())
+        .map(|chunk| chunk.unwrap().len())
+        .collect()
+        .await;
+    assert_eq!(vec![2, 2, 1], chunk_lens);
+    assert_eq!(vec![2, 2, 1], *requested.lock().unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_chunked_stops_after_a_failed_chunk() {
+    use futures::StreamExt;
+
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+
+    // Data
+    let hashes: Vec<AnyDhtHash> = std::iter::repeat_with(|| ::fixt::fixt!(ActionHash).into())
+        .take(4)
+        .collect();
+
+    // Network
+    // - The first chunk fails as a whole, so the second is never requested.
+    let mut mock = MockHolochainP2pDnaT::new();
+    mock.expect_authority_for_hash().returning(|_| Ok(false));
+    mock.expect_get_many()
+        .times(1)
+        .returning(|_, _| Err(holochain_p2p::HolochainP2pError::other("unreachable")));
+    let mock = Arc::new(mock);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(mock, cache.to_db());
+
+    let chunks: Vec<_> = cascade
+        .dht_get_chunked(hashes, 2, GetOptions::network())
+        .collect()
+        .await;
+    assert_eq!(1, chunks.len());
+    assert!(chunks[0].is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn retrieve_actions_not_authority_or_authoring() {
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+    let authority = test_dht_db();
+
+    // Data
+    let td_record = RecordTestData::create();
+    fill_db(&authority.to_db(), td_record.any_store_record_op.clone()).await;
+    let missing_hash = ::fixt::fixt!(ActionHash);
+
+    // Network
+    let network = PassThroughNetwork::authority_for_nothing(vec![authority.to_db().clone().into()]);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(network, cache.to_db());
+
+    let results = cascade
+        .retrieve_actions(
+            vec![missing_hash, td_record.any_action_hash.clone()],
+            holochain_p2p::actor::GetOptions::must_get_options(),
+        )
+        .await
+        .unwrap();
+
+    // Results are in the order the hashes were requested.
+    assert_eq!(2, results.len());
+    assert!(results[0].is_none());
+    assert_eq!(
+        Some(&td_record.any_action_hash),
+        results[1].as_ref().map(|action| action.as_hash())
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn retrieve_actions_makes_one_network_call() {
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+
+    // Data
+    let td_record = RecordTestData::create();
+
+    // Network
+    // - All missing actions go out in a single call, and failing to get
+    //   one of them reports it as missing.
+    let mut mock = MockHolochainP2pDnaT::new();
+    mock.expect_get_many().times(1).returning(|hashes, _| {
+        assert_eq!(2, hashes.len());
+        Ok(vec![
+            Ok(vec![]),
+            Err(holochain_p2p::HolochainP2pError::other("unreachable")),
+        ])
+    });
+    let mock = Arc::new(mock);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(mock, cache.to_db());
+
+    let results = cascade
+        .retrieve_actions(
+            vec![
+                td_record.create_hash.clone(),
+                td_record.any_action_hash.clone(),
+            ],
+            holochain_p2p::actor::GetOptions::must_get_options(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(2, results.len());
+    assert!(results.iter().all(Option::is_none));
+}
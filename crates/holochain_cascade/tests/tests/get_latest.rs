@@ -0,0 +1,331 @@
+use std::sync::Arc;
+
+use ::fixt::prelude::*;
+use holochain_cascade::authority;
+use holochain_cascade::test_utils::*;
+use holochain_cascade::CascadeImpl;
+use holochain_p2p::MockHolochainP2pDnaT;
+use holochain_state::prelude::*;
+
+/// An action with the ops its authority holds.
+struct TestAction {
+    hash: ActionHash,
+    action: Action,
+    entry: Entry,
+    store_record_op: ChainOpHashed,
+    register_update_op: Option<ChainOpHashed>,
+}
+
+fn entry(byte: u8) -> Entry {
+    Entry::App(
+        SerializedBytes::from(UnsafeBytes::from(vec![byte]))
+            .try_into()
+            .unwrap(),
+    )
+}
+
+fn create(author: AgentPubKey) -> TestAction {
+    let entry = entry(0);
+    let mut create = fixt!(Create);
+    create.author = author;
+    create.entry_hash = EntryHash::with_data_sync(&entry);
+    create.entry_type = EntryType::App(AppEntryDef::new(
+        0.into(),
+        0.into(),
+        EntryVisibility::Public,
+    ));
+    let action = Action::Create(create);
+    TestAction {
+        hash: ActionHash::with_data_sync(&action),
+        store_record_op: ChainOpHashed::from_content_sync(ChainOp::StoreRecord(
+            fixt!(Signature),
+            action.clone(),
+            entry.clone().into(),
+        )),
+        action,
+        entry,
+        register_update_op: None,
+    }
+}
+
+fn update(original: &TestAction, author: AgentPubKey, micros: i64, byte: u8) -> TestAction {
+    let entry = entry(byte);
+    let mut update = fixt!(Update);
+    update.author = author;
+    update.timestamp = Timestamp::from_micros(micros);
+    update.original_action_address = original.hash.clone();
+    update.original_entry_address = original.action.entry_hash().unwrap().clone();
+    update.entry_hash = EntryHash::with_data_sync(&entry);
+    update.entry_type = original.action.entry_type().unwrap().clone();
+    let action = Action::Update(update.clone());
+    TestAction {
+        hash: ActionHash::with_data_sync(&action),
+        store_record_op: ChainOpHashed::from_content_sync(ChainOp::StoreRecord(
+            fixt!(Signature),
+            action.clone(),
+            entry.clone().into(),
+        )),
+        register_update_op: Some(ChainOpHashed::from_content_sync(
+            ChainOp::RegisterUpdatedRecord(fixt!(Signature), update, entry.clone().into()),
+        )),
+        action,
+        entry,
+    }
+}
+
+async fn hold(db: &DbWrite<DbKindDht>, action: &TestAction) {
+    fill_db(db, action.store_record_op.clone()).await;
+    if let Some(op) = &action.register_update_op {
+        fill_db(db, op.clone()).await;
+    }
+}
+
+/// Alice creates an entry, which Bob and then Alice update concurrently.
+/// Carol then updates Bob's update.
+struct ConcurrentUpdates {
+    create: TestAction,
+    bob_update: TestAction,
+    alice_update: TestAction,
+    carol_update: TestAction,
+}
+
+impl ConcurrentUpdates {
+    fn new() -> Self {
+        let alice = fixt!(AgentPubKey, Predictable, 0);
+        let bob = fixt!(AgentPubKey, Predictable, 1);
+        let carol = fixt!(AgentPubKey, Predictable, 2);
+        let create = create(alice.clone());
+        let bob_update = update(&create, bob, 2, 1);
+        let alice_update = update(&create, alice, 1, 2);
+        let carol_update = update(&bob_update, carol, 3, 3);
+        Self {
+            create,
+            bob_update,
+            alice_update,
+            carol_update,
+        }
+    }
+
+    async fn hold_all(&self, db: &DbWrite<DbKindDht>) {
+        for action in [
+            &self.create,
+            &self.bob_update,
+            &self.alice_update,
+            &self.carol_update,
+        ] {
+            hold(db, action).await;
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_latest_follows_updates_by_tie_break() {
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+    let authority = test_dht_db();
+
+    // Data
+    let td = ConcurrentUpdates::new();
+    td.hold_all(&authority.to_db()).await;
+
+    // Network
+    let network = PassThroughNetwork::authority_for_nothing(vec![authority.to_db().clone().into()]);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(network, cache.to_db());
+
+    let latest = |tie_break| {
+        let cascade = cascade.clone();
+        let hash = td.create.hash.clone();
+        async move {
+            cascade
+                .get_latest(hash, tie_break, GetOptions::network())
+                .await
+                .unwrap()
+                .unwrap()
+                .record
+        }
+    };
+
+    // - Bob's update is newer, so the walk carries on to Carol's.
+    let record = latest(UpdateTieBreak::Timestamp).await;
+    assert_eq!(*record.action_address(), td.carol_update.hash);
+    assert_eq!(record.entry().as_option(), Some(&td.carol_update.entry));
+
+    // - Alice authored the entry, so her update wins.
+    let record = latest(UpdateTieBreak::Author).await;
+    assert_eq!(*record.action_address(), td.alice_update.hash);
+
+    // - The highest hash wins.
+    let expected = if td.bob_update.hash > td.alice_update.hash {
+        &td.carol_update.hash
+    } else {
+        &td.alice_update.hash
+    };
+    let record = latest(UpdateTieBreak::Hash).await;
+    assert_eq!(record.action_address(), expected);
+
+    // - An action without updates is its own latest version.
+    let record = cascade
+        .get_latest(
+            td.carol_update.hash.clone(),
+            UpdateTieBreak::Timestamp,
+            GetOptions::network(),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .record;
+    assert_eq!(*record.action_address(), td.carol_update.hash);
+
+    // - A missing action has no latest version.
+    assert!(cascade
+        .get_latest(
+            fixt!(ActionHash),
+            UpdateTieBreak::Timestamp,
+            GetOptions::network()
+        )
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_latest_makes_one_network_call_when_the_authority_holds_the_chain() {
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+    let authority = test_dht_db();
+
+    // Data
+    let td = ConcurrentUpdates::new();
+    td.hold_all(&authority.to_db()).await;
+    let steps = authority::handle_get_latest(
+        authority.to_db().into(),
+        td.create.hash.clone(),
+        UpdateTieBreak::Timestamp,
+        (&holochain_p2p::actor::GetOptions::from(GetOptions::network())).into(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(3, steps.len());
+
+    // Network
+    // - The authority walks the whole chain in the first call.
+    let mut mock = MockHolochainP2pDnaT::new();
+    mock.expect_authority_for_hash().returning(|_| Ok(false));
+    mock.expect_get_latest()
+        .times(1)
+        .returning(move |_, _, _| Ok(steps.clone()));
+    let mock = Arc::new(mock);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(mock, cache.to_db());
+
+    let record = cascade
+        .get_latest(
+            td.create.hash.clone(),
+            UpdateTieBreak::Timestamp,
+            GetOptions::network(),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .record;
+    assert_eq!(*record.action_address(), td.carol_update.hash);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_latest_skips_rejected_updates() {
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+    let authority = test_dht_db();
+
+    // Data
+    // - Bob's update is the newest but his record was rejected.
+    let td = ConcurrentUpdates::new();
+    hold(&authority.to_db(), &td.create).await;
+    hold(&authority.to_db(), &td.alice_update).await;
+    fill_db(
+        &authority.to_db(),
+        td.bob_update.register_update_op.clone().unwrap(),
+    )
+    .await;
+    fill_db_rejected(&authority.to_db(), td.bob_update.store_record_op.clone()).await;
+
+    // Network
+    let network = PassThroughNetwork::authority_for_nothing(vec![authority.to_db().clone().into()]);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(network, cache.to_db());
+
+    let record = cascade
+        .get_latest(
+            td.create.hash.clone(),
+            UpdateTieBreak::Timestamp,
+            GetOptions::network(),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .record;
+    assert_eq!(*record.action_address(), td.alice_update.hash);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_latest_stops_at_an_update_it_cannot_fetch() {
+    holochain_trace::test_run();
+
+    // Environments
+    let cache = test_cache_db();
+    let authority = test_dht_db();
+
+    // Data
+    // - Bob's update is the newest but nobody holds his record.
+    let td = ConcurrentUpdates::new();
+    hold(&authority.to_db(), &td.create).await;
+    hold(&authority.to_db(), &td.alice_update).await;
+    fill_db(
+        &authority.to_db(),
+        td.bob_update.register_update_op.clone().unwrap(),
+    )
+    .await;
+
+    // Network
+    let network = PassThroughNetwork::authority_for_nothing(vec![authority.to_db().clone().into()]);
+
+    // Cascade
+    let cascade = CascadeImpl::empty().with_network(network, cache.to_db());
+
+    // - Alice's update is not followed in place of Bob's.
+    let latest = cascade
+        .get_latest(
+            td.create.hash.clone(),
+            UpdateTieBreak::Timestamp,
+            GetOptions::network(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(*latest.record.action_address(), td.create.hash);
+    assert_eq!(latest.continue_from, Some(td.bob_update.hash.clone()));
+
+    // - Once Bob's record can be fetched the walk carries on from there.
+    fill_db(&authority.to_db(), td.bob_update.store_record_op.clone()).await;
+    let latest = cascade
+        .get_latest(
+            latest.continue_from.unwrap(),
+            UpdateTieBreak::Timestamp,
+            GetOptions::network(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(*latest.record.action_address(), td.bob_update.hash);
+    assert_eq!(latest.continue_from, None);
+}
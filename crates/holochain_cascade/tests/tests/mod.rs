@@ -1,4 +1,5 @@
 mod count_links;
 mod get_activity;
 mod get_entry;
+mod get_latest;
 mod get_links;
@@ -6,7 +6,7 @@ use holochain_types::websocket::AllowedOrigins;
 use holochain_zome_types::cell::CellId;
 use kitsune_p2p_types::agent_info::AgentInfoSigned;
 
-use crate::{AppInfo, FullStateDump, RevokeAgentKeyPayload, StorageInfo};
+use crate::{AppInfo, FullStateDump, RevokeAgentKeyPayload, StorageInfo, ZomeFnUsage};
 
 /// Represents the available conductor functions to call over an admin interface.
 ///
@@ -402,6 +402,45 @@ pub enum AdminRequest {
     /// Find installed cells which use a DNA that's forward-compatible with the given DNA hash.
     /// Namely, this finds cells with DNAs whose manifest lists the given DNA hash in its `lineage` field.
     GetCompatibleCells(DnaHash),
+
+    /// List the scheduled functions of the cell specified by argument `cell_id`
+    /// which have not yet completed or been cancelled.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::ScheduledFunctionsListed`]
+    ListScheduledFunctions {
+        /// The cell ID for which to list scheduled functions
+        cell_id: Box<CellId>,
+    },
+
+    /// Cancel a scheduled function of the cell specified by argument `cell_id`.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::ScheduledFunctionCancelled`]
+    CancelScheduledFunction {
+        /// The cell ID that owns the schedule
+        cell_id: Box<CellId>,
+        /// The ID of the schedule to cancel
+        schedule_id: ScheduleId,
+    },
+
+    /// List the resources used by zome calls since the conductor was started,
+    /// per zome function of each cell. Hosting providers can use this to bill
+    /// apps, or to find apps which use more than their share.
+    ///
+    /// Usage is only kept in memory, so it starts from zero when the conductor
+    /// restarts and is dropped when an app is uninstalled.
+    ///
+    /// # Returns
+    ///
+    /// [`AdminResponse::ZomeCallUsageListed`]
+    ListZomeCallUsage {
+        /// Only list the usage of this app's cells.
+        /// If not set, the usage of every installed app is listed.
+        installed_app_id: Option<InstalledAppId>,
+    },
 }
 
 /// Represents the possible responses to an [`AdminRequest`]
@@ -562,6 +601,17 @@ pub enum AdminResponse {
 
     /// The successful response to an [`AdminRequest::GetCompatibleCells`].
     CompatibleCells(CompatibleCells),
+
+    /// The successful response to an [`AdminRequest::ListScheduledFunctions`].
+    ScheduledFunctionsListed(Vec<PendingSchedule>),
+
+    /// The successful response to an [`AdminRequest::CancelScheduledFunction`].
+    ///
+    /// `true` if a schedule was cancelled, `false` if there was no such schedule.
+    ScheduledFunctionCancelled(bool),
+
+    /// The successful response to an [`AdminRequest::ListZomeCallUsage`].
+    ZomeCallUsageListed(Vec<ZomeFnUsage>),
 }
 
 pub type CompatibleCells = BTreeSet<(InstalledAppId, BTreeSet<CellId>)>;
@@ -541,6 +541,10 @@ pub struct NetworkInfo {
     pub total_network_peers: u32,
     pub bytes_since_last_time_queried: u64,
     pub completed_rounds_since_last_time_queried: u32,
+    /// Peers this conductor recently refused to connect to, because their
+    /// network compatibility settings (e.g. `network_id`) don't match ours.
+    #[serde(default)]
+    pub preflight_rejections: Vec<PreflightRejection>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, SerializedBytes)]
@@ -46,6 +46,38 @@
 //!           { "urls": ["stun:stun-1.main.infra.holo.host:443"] }
 //!         ]
 //!       }
+//!
+//! ## Only connect to conductors configured with the same network id.
+//! ## This is OPTIONAL. Useful when several networks share a signal server.
+//! network_compat:
+//!   network_id: production
+//!
+//! ## Only use bundles signed by one of these publishers.
+//! ## This is OPTIONAL. Publisher keys are printed by `hc app keygen`.
+//! bundle_signing:
+//!   trusted_publishers:
+//!     - uhCAkWCsAgoKkkfwyJAglj30xX_GLLV-3BXuFy436a2SqpcEwyBzm
+//!
+//! ## Serve metrics for Prometheus to scrape at http://127.0.0.1:9464/metrics.
+//! ## This is OPTIONAL.
+//! prometheus_metrics:
+//!   bind_address: 127.0.0.1:9464
+//!
+//! ## Limit the resources that zome calls may use.
+//! ## This is OPTIONAL. Limits which are not set here fall back to
+//! ## those requested when an app was installed.
+//! wasm_limits:
+//!   default:
+//!     max_call_duration:
+//!       secs: 60
+//!       nanos: 0
+//!   apps:
+//!     my_app:
+//!       app:
+//!         max_concurrent_calls: 8
+//!       roles:
+//!         my_role:
+//!           max_metering_points: 1000000000
 //! "#;
 //!
 //!use holochain_conductor_api::conductor::ConductorConfig;
@@ -61,21 +93,29 @@ use serde::Deserialize;
 use serde::Serialize;
 
 mod admin_interface_config;
+mod bundle_signing_config;
 mod dpki_config;
 #[allow(missing_docs)]
 mod error;
 mod keystore_config;
+mod network_compat_config;
 /// Defines subdirectories of the config directory.
 pub mod paths;
 pub mod process;
+mod prometheus_metrics_config;
+mod wasm_limits_config;
 //mod logger_config;
 //mod signal_config;
 
 pub use super::*;
+pub use bundle_signing_config::BundleSigningConfig;
 pub use dpki_config::DpkiConfig;
 //pub use logger_config::LoggerConfig;
 pub use error::*;
 pub use keystore_config::KeystoreConfig;
+pub use network_compat_config::NetworkCompatConfig;
+pub use prometheus_metrics_config::{PrometheusMetricLabels, PrometheusMetricsConfig};
+pub use wasm_limits_config::WasmLimitsConfig;
 //pub use signal_config::SignalConfig;
 use std::path::Path;
 
@@ -129,6 +169,25 @@ pub struct ConductorConfig {
     #[serde(default)]
     pub network: KitsuneP2pConfig,
 
+    /// Restrict which conductors this conductor will network with.
+    /// Conductors with mismatched settings reject each other during the
+    /// connection preflight.
+    #[serde(default)]
+    pub network_compat: NetworkCompatConfig,
+
+    /// Restrict the app, DNA and coordinator bundles this conductor will use
+    /// to those signed by trusted publishers.
+    #[serde(default)]
+    pub bundle_signing: BundleSigningConfig,
+
+    /// Serve the conductor's metrics for Prometheus to scrape.
+    #[serde(default)]
+    pub prometheus_metrics: Option<PrometheusMetricsConfig>,
+
+    /// Limit the resources that the zome calls of each app may use.
+    #[serde(default)]
+    pub wasm_limits: WasmLimitsConfig,
+
     /// Optional specification of Chain Head Coordination service URL.
     /// If set, each cell's commit workflow will include synchronizing with the specified CHC service.
     /// If you don't know what this means, leave this setting alone (as `None`)
@@ -243,6 +302,12 @@ pub struct ConductorTuningParams {
     ///
     /// Default: 5 minutes
     pub min_publish_interval: Option<std::time::Duration>,
+    /// The number of ops that app validation validates at the same time.
+    /// Ops which record activity on the same author's chain are always
+    /// validated one after the other, in the order they were authored.
+    ///
+    /// Default: 10
+    pub app_validation_concurrency: Option<usize>,
 }
 
 impl ConductorTuningParams {
@@ -253,6 +318,7 @@ impl ConductorTuningParams {
             countersigning_resolution_retry_delay: None,
             countersigning_resolution_retry_limit: None,
             min_publish_interval: None,
+            app_validation_concurrency: None,
         }
     }
 
@@ -273,6 +339,11 @@ impl ConductorTuningParams {
         self.min_publish_interval
             .unwrap_or_else(|| std::time::Duration::from_secs(60 * 5))
     }
+
+    /// Get the current value of `app_validation_concurrency` or its default value.
+    pub fn app_validation_concurrency(&self) -> usize {
+        self.app_validation_concurrency.unwrap_or(10)
+    }
 }
 
 impl Default for ConductorTuningParams {
@@ -285,6 +356,7 @@ impl Default for ConductorTuningParams {
             ),
             countersigning_resolution_retry_limit: None,
             min_publish_interval: None,
+            app_validation_concurrency: None,
         }
     }
 }
@@ -292,6 +364,8 @@ impl Default for ConductorTuningParams {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use holo_hash::AgentPubKeyB64;
+    use holochain_types::prelude::{AppWasmLimits, WasmLimits};
     use holochain_types::websocket::AllowedOrigins;
     use kitsune_p2p_types::config::TransportConfig;
     use matches::assert_matches;
@@ -335,6 +409,10 @@ mod tests {
                 device_seed_lair_tag: None,
                 danger_generate_throwaway_device_seed: false,
                 network: KitsuneP2pConfig::mem(),
+                network_compat: NetworkCompatConfig::default(),
+                bundle_signing: BundleSigningConfig::default(),
+                prometheus_metrics: None,
+                wasm_limits: Default::default(),
                 dpki: DpkiConfig::default(),
                 keystore: KeystoreConfig::DangerTestKeystore,
                 admin_interfaces: None,
@@ -396,6 +474,32 @@ mod tests {
         tx5_max_ephemeral_udp_port: 40255
       network_type: quic_bootstrap
 
+    network_compat:
+      network_id: staging
+      tags:
+        - v2
+
+    bundle_signing:
+      trusted_publishers:
+        - uhCAkWCsAgoKkkfwyJAglj30xX_GLLV-3BXuFy436a2SqpcEwyBzm
+
+    prometheus_metrics:
+      bind_address: 127.0.0.1:9464
+      labels: cell
+
+    wasm_limits:
+      default:
+        max_call_duration:
+          secs: 60
+          nanos: 0
+      apps:
+        my_app:
+          app:
+            max_concurrent_calls: 8
+          roles:
+            my_role:
+              max_metering_points: 1000000000
+
     db_sync_strategy: Fast
     "#;
         let result: ConductorConfigResult<ConductorConfig> = config_from_yaml(yaml);
@@ -439,6 +543,47 @@ mod tests {
                     }
                 }]),
                 network: network_config,
+                network_compat: NetworkCompatConfig {
+                    network_id: Some("staging".into()),
+                    tags: ["v2".to_string()].into(),
+                },
+                bundle_signing: BundleSigningConfig {
+                    trusted_publishers: Some(
+                        [AgentPubKeyB64::from_b64_str(
+                            "uhCAkWCsAgoKkkfwyJAglj30xX_GLLV-3BXuFy436a2SqpcEwyBzm"
+                        )
+                        .unwrap()]
+                        .into()
+                    ),
+                },
+                prometheus_metrics: Some(PrometheusMetricsConfig {
+                    bind_address: ([127, 0, 0, 1], 9464).into(),
+                    labels: PrometheusMetricLabels::Cell,
+                }),
+                wasm_limits: WasmLimitsConfig {
+                    default: WasmLimits {
+                        max_call_duration: Some(std::time::Duration::from_secs(60)),
+                        ..Default::default()
+                    },
+                    apps: [(
+                        "my_app".to_string(),
+                        AppWasmLimits {
+                            app: WasmLimits {
+                                max_concurrent_calls: Some(8),
+                                ..Default::default()
+                            },
+                            roles: [(
+                                "my_role".to_string(),
+                                WasmLimits {
+                                    max_metering_points: Some(1_000_000_000),
+                                    ..Default::default()
+                                },
+                            )]
+                            .into(),
+                        },
+                    )]
+                    .into(),
+                },
                 db_sync_strategy: DbSyncStrategy::Fast,
                 #[cfg(feature = "chc")]
                 chc_url: None,
@@ -496,6 +641,19 @@ mod tests {
         tx5_max_ephemeral_udp_port: 40255
       network_type: quic_bootstrap
 
+    network_compat:
+      network_id: staging
+      tags:
+        - v2
+
+    bundle_signing:
+      trusted_publishers:
+        - uhCAkWCsAgoKkkfwyJAglj30xX_GLLV-3BXuFy436a2SqpcEwyBzm
+
+    prometheus_metrics:
+      bind_address: 127.0.0.1:9464
+      labels: cell
+
     db_sync_strategy: Fast
     "#;
         let result: ConductorConfigResult<ConductorConfig> = config_from_yaml(yaml);
@@ -540,6 +698,24 @@ mod tests {
                     }
                 }]),
                 network: network_config,
+                network_compat: NetworkCompatConfig {
+                    network_id: Some("staging".into()),
+                    tags: ["v2".to_string()].into(),
+                },
+                bundle_signing: BundleSigningConfig {
+                    trusted_publishers: Some(
+                        [AgentPubKeyB64::from_b64_str(
+                            "uhCAkWCsAgoKkkfwyJAglj30xX_GLLV-3BXuFy436a2SqpcEwyBzm"
+                        )
+                        .unwrap()]
+                        .into()
+                    ),
+                },
+                prometheus_metrics: Some(PrometheusMetricsConfig {
+                    bind_address: ([127, 0, 0, 1], 9464).into(),
+                    labels: PrometheusMetricLabels::Cell,
+                }),
+                wasm_limits: Default::default(),
                 db_sync_strategy: DbSyncStrategy::Fast,
                 #[cfg(feature = "chc")]
                 chc_url: None,
@@ -569,6 +745,10 @@ mod tests {
                 device_seed_lair_tag: None,
                 danger_generate_throwaway_device_seed: false,
                 network: KitsuneP2pConfig::mem(),
+                network_compat: NetworkCompatConfig::default(),
+                bundle_signing: BundleSigningConfig::default(),
+                prometheus_metrics: None,
+                wasm_limits: Default::default(),
                 dpki: Default::default(),
                 keystore: KeystoreConfig::LairServer {
                     connection_url: url2::url2!("unix:///var/run/lair-keystore/socket?k=EcRDnP3xDIZ9Rk_1E-egPE0mGZi5CcszeRxVkb2QXXQ"),
@@ -0,0 +1,37 @@
+use std::collections::BTreeSet;
+
+use holo_hash::AgentPubKeyB64;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configure which publishers this conductor trusts to sign bundles.
+///
+/// The policy applies to app bundles when they are installed, and to DNA and
+/// coordinator bundles when they are registered or used to update
+/// coordinators. Bundles are signed with `hc app sign` or `hc dna sign`,
+/// which also print the key that identifies the publisher. Any signature on a
+/// bundle is always checked, and a bundle that was modified after it was
+/// signed is never used.
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq, Default)]
+pub struct BundleSigningConfig {
+    /// If set, only use bundles signed by at least one of these
+    /// publishers. An empty set refuses every bundle. DNAs which are not in
+    /// a bundle, such as those an app bundle points at by path or url, are
+    /// refused too.
+    ///
+    /// If not set, unsigned bundles and bundles signed by anyone are used.
+    #[serde(default)]
+    pub trusted_publishers: Option<BTreeSet<AgentPubKeyB64>>,
+}
+
+impl BundleSigningConfig {
+    /// Whether a bundle signed by these publishers may be used.
+    pub fn is_trusted(&self, publishers: &[holo_hash::AgentPubKey]) -> bool {
+        match &self.trusted_publishers {
+            Some(trusted) => publishers
+                .iter()
+                .any(|publisher| trusted.contains(&publisher.clone().into())),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::collections::BTreeSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configure which conductors this conductor is willing to network with.
+///
+/// These settings are sent to every peer during the connection preflight,
+/// and peers with different settings refuse to connect to each other.
+/// This keeps separate networks, such as staging and production,
+/// from cross-connecting when they share a signal server.
+///
+/// Also see `NetworkCompatParams` in the `holochain_p2p` crate.
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq, Default)]
+pub struct NetworkCompatConfig {
+    /// The id of the network this conductor belongs to.
+    /// Only conductors with the same `network_id`, or that both leave it unset,
+    /// can connect to each other.
+    #[serde(default)]
+    pub network_id: Option<String>,
+
+    /// Additional compatibility tags.
+    /// Only conductors with exactly the same set of tags can connect to each other.
+    #[serde(default)]
+    pub tags: BTreeSet<String>,
+}
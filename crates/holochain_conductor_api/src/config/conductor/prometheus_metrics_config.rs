@@ -0,0 +1,36 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Serve the conductor's metrics over HTTP for Prometheus to scrape.
+///
+/// Every metric is exported at `/metrics` in the Prometheus text format.
+/// This replaces the InfluxDB metrics configured by environment variables.
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq)]
+pub struct PrometheusMetricsConfig {
+    /// The address to serve `/metrics` on, e.g. `127.0.0.1:9464`.
+    pub bind_address: std::net::SocketAddr,
+
+    /// How finely metrics are broken down by label.
+    /// Defaults to [`PrometheusMetricLabels::Dna`].
+    #[serde(default)]
+    pub labels: PrometheusMetricLabels,
+}
+
+/// How finely metrics are broken down by label.
+///
+/// Each distinct set of labels is a separate time series, so a conductor
+/// running many cells can produce a great many series if every label is kept.
+/// Labels identifying remote peers or connections are never exported.
+#[derive(Clone, Copy, Deserialize, Serialize, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PrometheusMetricLabels {
+    /// Drop the labels identifying DNAs and cells,
+    /// so that each metric covers the whole conductor.
+    Aggregate,
+    /// Keep the labels identifying DNAs, but drop those identifying agents.
+    #[default]
+    Dna,
+    /// Keep the labels identifying DNAs and agents, so that metrics
+    /// can be broken down per cell.
+    Cell,
+}
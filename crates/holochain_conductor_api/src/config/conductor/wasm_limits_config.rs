@@ -0,0 +1,20 @@
+use holochain_types::prelude::{AppWasmLimits, InstalledAppId, WasmLimits};
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Limit the resources that zome calls may use, so that one app can't
+/// starve the others running on this conductor.
+///
+/// Limits set here take precedence over those requested when an app is installed.
+/// See [`WasmLimits`] for how limits set at different levels are combined.
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq, Default)]
+pub struct WasmLimitsConfig {
+    /// Limits for every app which doesn't set its own.
+    #[serde(default)]
+    pub default: WasmLimits,
+
+    /// Limits for particular apps and their roles.
+    #[serde(default)]
+    pub apps: HashMap<InstalledAppId, AppWasmLimits>,
+}
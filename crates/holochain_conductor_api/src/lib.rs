@@ -30,9 +30,11 @@ pub mod config;
 pub mod signal_subscription;
 pub mod state_dump;
 pub mod storage_info;
+pub mod zome_call_usage;
 
 pub use admin_interface::*;
 pub use app_interface::*;
 pub use config::*;
 pub use state_dump::*;
 pub use storage_info::*;
+pub use zome_call_usage::*;
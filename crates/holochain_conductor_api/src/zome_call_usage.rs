@@ -0,0 +1,64 @@
+use holochain_types::prelude::*;
+
+/// Resources used by the zome calls made to one function of one cell,
+/// since the conductor was started.
+///
+/// Usage is only kept in memory. It starts from zero when the conductor
+/// restarts, and is dropped when the cell's app is uninstalled, so anyone
+/// billing for it should list it periodically and store it themselves.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct ZomeFnUsage {
+    /// The app that the cell belongs to.
+    pub installed_app_id: InstalledAppId,
+    /// The cell that was called.
+    pub cell_id: CellId,
+    /// The zome that was called.
+    pub zome_name: ZomeName,
+    /// The function that was called.
+    pub fn_name: FunctionName,
+    /// The resources used by all calls to this function.
+    pub usage: ResourceUsage,
+}
+
+/// Resources used by one or more zome calls.
+///
+/// Calls that a zome function makes to other zome functions, including those
+/// of the same cell, are accounted to the functions they call.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, SerializedBytes,
+)]
+pub struct ResourceUsage {
+    /// The number of calls made.
+    pub call_count: u64,
+    /// The total wall-clock time the calls took, in microseconds.
+    pub wall_time_us: u64,
+    /// The wasm metering points the calls consumed.
+    pub metering_points: u64,
+    /// The bytes of serialized data that reading host functions, such as
+    /// `get` and `query`, passed back into the wasm. This is the size of
+    /// what the zome received, not of what was read from the databases.
+    pub guest_payload_bytes_read: u64,
+    /// The bytes of serialized data that the wasm passed to writing host
+    /// functions, such as `create` and `create_link`. This is the size of
+    /// what the zome asked to write, not of what was written to the databases.
+    pub guest_payload_bytes_written: u64,
+    /// The requests that the calls made to other agents over the network.
+    /// Gets answered locally, because this agent is an authority or has the
+    /// data cached, don't make a request. A request sent to several agents
+    /// at once, such as a remote signal, counts once per agent, but a
+    /// `get_many` counts once however many authorities it's split across.
+    pub network_requests: u64,
+}
+
+impl ResourceUsage {
+    /// Add the usage of other calls to this usage,
+    /// for example to total the usage of an app.
+    pub fn add(&mut self, other: &ResourceUsage) {
+        self.call_count += other.call_count;
+        self.wall_time_us += other.wall_time_us;
+        self.metering_points += other.metering_points;
+        self.guest_payload_bytes_read += other.guest_payload_bytes_read;
+        self.guest_payload_bytes_written += other.guest_payload_bytes_written;
+        self.network_requests += other.network_requests;
+    }
+}
@@ -231,3 +231,19 @@ impl MustGetActionInput {
         self.0
     }
 }
+
+/// Zome input for must_get_actions.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct MustGetActionsInput(pub Vec<ActionHash>);
+
+impl MustGetActionsInput {
+    /// Constructor.
+    pub fn new(action_hashes: Vec<ActionHash>) -> Self {
+        Self(action_hashes)
+    }
+
+    /// Consumes self for inner.
+    pub fn into_inner(self) -> Vec<ActionHash> {
+        self.0
+    }
+}
@@ -31,6 +31,10 @@
 //!   - The influxdb auth token must have permission to write to all buckets
 //!   - Metrics will be set up to report to this already running InfluxDB.
 //!
+//! Metrics can also be served for Prometheus to scrape, with the `prometheus`
+//! feature. This is configured in code rather than by environment variables,
+//! with [`HolochainMetricsConfig::Prometheus`].
+//!
 //! ## Metric Naming Conventions
 //!
 //! We will largely attempt to follow the guidelines for metric naming
@@ -98,6 +102,10 @@
 //! | `hc.conductor.p2p_event.duration`  | `f64_histogram` | `s` | The time spent processing a p2p event. |- `dna_hash`: The DNA hash that this event is being sent on behalf of. |
 //! | `hc.conductor.post_commit.duration` | `f64_histogram` | `s` | The time spent executing a post commit. |- `dna_hash`: The DNA hash that this post commit is running for.<br />- `agent`: The agent running the post commit. |
 //! | `hc.conductor.workflow.duration` | `f64_histogram` | `s` | The time spent running a workflow. |- `workflow`: The name of the workflow.<br />- `dna_hash`: The DNA hash that this workflow is running for.<br />- `agent`: (optional) The agent that this workflow is running for if the workflow is cell bound. |
+//! | `hc.conductor.zome_call.duration` | `f64_histogram` | `s` | The time spent running a zome call. |- `dna`: The DNA hash of the cell that was called.<br />- `agent`: The agent of the cell that was called.<br />- `zome`: The zome that was called.<br />- `fn`: The function that was called. |
+//! | `hc.conductor.zome_call.guest_payload_read.byte.count` | `u64_counter` | `By` | The bytes of data passed into zome calls by reading host functions. |- `dna`: The DNA hash of the cell that was called.<br />- `agent`: The agent of the cell that was called.<br />- `zome`: The zome that was called.<br />- `fn`: The function that was called. |
+//! | `hc.conductor.zome_call.guest_payload_write.byte.count` | `u64_counter` | `By` | The bytes of data passed by zome calls to writing host functions. |- `dna`: The DNA hash of the cell that was called.<br />- `agent`: The agent of the cell that was called.<br />- `zome`: The zome that was called.<br />- `fn`: The function that was called. |
+//! | `hc.conductor.zome_call.network_request.count` | `u64_counter` | | The requests made to other agents by zome calls. |- `dna`: The DNA hash of the cell that was called.<br />- `agent`: The agent of the cell that was called.<br />- `zome`: The zome that was called.<br />- `fn`: The function that was called. |
 //! | `hc.cascade.duration` | `f64_histogram` | `s` | The time taken to execute a cascade query. | |
 //! | `hc.db.pool.utilization` | `f64_gauge` | | The utilisation of connections in the pool. |- `kind`: The kind of database such as Conductor, Wasm or Dht etc.<br />- `id`: The unique identifier for this database if multiple instances can exist, such as a Dht database. |
 //! | `hc.db.connections.use_time` | `f64_histogram` | `s` | The time between borrowing a connection and returning it to the pool. |- `kind`: The kind of database such as Conductor, Wasm or Dht etc.<br />- `id`: The unique identifier for this database if multiple instances can exist, such as a Dht database. |
@@ -147,6 +155,72 @@ pub enum HolochainMetricsConfig {
         /// The meter provider config for setting up opentelemetry.
         otel_config: influxive::InfluxiveMeterProviderConfig,
     },
+
+    #[cfg(feature = "prometheus")]
+    /// Serve metrics over HTTP in the Prometheus text format.
+    Prometheus {
+        /// The address to serve `/metrics` on.
+        bind_address: std::net::SocketAddr,
+
+        /// Which metric attributes are kept as Prometheus labels.
+        labels: PrometheusLabels,
+    },
+}
+
+/// Which metric attributes are kept as Prometheus labels.
+///
+/// Attributes identifying remote peers and their connections are always
+/// dropped, since there is no bound on how many time series they produce.
+#[cfg(feature = "prometheus")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrometheusLabels {
+    /// Drop the attributes identifying DNAs and agents.
+    Aggregate,
+    /// Keep the attributes identifying DNAs, but drop those identifying agents.
+    #[default]
+    Dna,
+    /// Keep the attributes identifying DNAs and agents.
+    Cell,
+}
+
+#[cfg(feature = "prometheus")]
+impl PrometheusLabels {
+    /// The attribute keys of all the metrics documented in this crate.
+    /// Attributes with any other key are dropped.
+    pub const ATTRIBUTE_KEYS: &'static [&'static str] = &[
+        "agent",
+        "batch_size",
+        "conn_uniq",
+        "dna",
+        "dna_hash",
+        "fn",
+        "id",
+        "is_error",
+        "kind",
+        "remote_id",
+        "space",
+        "state_uniq",
+        "workflow",
+        "zome",
+    ];
+
+    /// Whether metric attributes with this key are kept.
+    pub fn keeps(&self, key: &str) -> bool {
+        match key {
+            "remote_id" | "state_uniq" | "conn_uniq" => false,
+            "agent" => *self == Self::Cell,
+            "dna_hash" | "dna" | "space" | "id" => *self != Self::Aggregate,
+            _ => Self::ATTRIBUTE_KEYS.contains(&key),
+        }
+    }
+
+    /// The attribute keys which are kept.
+    fn allowed_keys(&self) -> impl Iterator<Item = opentelemetry_api::Key> + '_ {
+        Self::ATTRIBUTE_KEYS
+            .iter()
+            .filter(|key| self.keeps(key))
+            .map(|key| opentelemetry_api::Key::from_static_str(key))
+    }
 }
 
 impl HolochainMetricsConfig {
@@ -239,9 +313,92 @@ impl HolochainMetricsConfig {
             } => {
                 Self::init_influxive_child_svc(*child_svc_config, otel_config).await;
             }
+            #[cfg(feature = "prometheus")]
+            Self::Prometheus {
+                bind_address,
+                labels,
+            } => {
+                Self::init_prometheus(bind_address, labels);
+            }
+        }
+    }
+
+    #[cfg(feature = "prometheus")]
+    fn init_prometheus(bind_address: std::net::SocketAddr, labels: PrometheusLabels) {
+        tracing::info!(%bind_address, ?labels, "initializing holochain_metrics");
+
+        let (meter_provider, registry) = match Self::prometheus_meter_provider(labels) {
+            Ok(r) => r,
+            Err(err) => {
+                tracing::warn!(?err, "unable to initialize prometheus metrics");
+                return;
+            }
+        };
+
+        match warp::serve(Self::prometheus_filter(registry)).try_bind_ephemeral(bind_address) {
+            Ok((addr, server)) => {
+                tokio::task::spawn(server);
+
+                // setup opentelemetry to use our metrics collector
+                opentelemetry_api::global::set_meter_provider(meter_provider);
+
+                tracing::info!(%addr, "prometheus metrics running");
+            }
+            Err(err) => {
+                tracing::warn!(?err, "unable to serve prometheus metrics");
+            }
         }
     }
 
+    /// A meter provider which collects into the returned registry,
+    /// keeping only the attributes allowed by `labels`.
+    #[cfg(feature = "prometheus")]
+    fn prometheus_meter_provider(
+        labels: PrometheusLabels,
+    ) -> opentelemetry_api::metrics::Result<(
+        opentelemetry_sdk::metrics::MeterProvider,
+        prometheus::Registry,
+    )> {
+        let registry = prometheus::Registry::new();
+        let exporter = opentelemetry_prometheus::exporter()
+            .with_registry(registry.clone())
+            .build()?;
+        let view = opentelemetry_sdk::metrics::new_view(
+            opentelemetry_sdk::metrics::Instrument::new().name("*"),
+            opentelemetry_sdk::metrics::Stream::new().allowed_attribute_keys(labels.allowed_keys()),
+        )?;
+        let meter_provider = opentelemetry_sdk::metrics::MeterProvider::builder()
+            .with_reader(exporter)
+            .with_view(view)
+            .build();
+        Ok((meter_provider, registry))
+    }
+
+    /// Serves the metrics in `registry` at `/metrics`.
+    #[cfg(feature = "prometheus")]
+    fn prometheus_filter(
+        registry: prometheus::Registry,
+    ) -> impl warp::Filter<Extract = (warp::reply::Response,), Error = warp::Rejection> + Clone
+    {
+        use warp::{Filter, Reply};
+
+        warp::path("metrics")
+            .and(warp::path::end())
+            .and(warp::get())
+            .map(move || {
+                match prometheus::TextEncoder::new().encode_to_string(&registry.gather()) {
+                    Ok(body) => {
+                        warp::reply::with_header(body, "content-type", prometheus::TEXT_FORMAT)
+                            .into_response()
+                    }
+                    Err(err) => {
+                        tracing::warn!(?err, "failed to encode prometheus metrics");
+                        warp::http::StatusCode::INTERNAL_SERVER_ERROR.into_response()
+                    }
+                }
+            })
+    }
+
     #[cfg(feature = "influxive")]
     fn init_influxive_external(
         writer_config: influxive::InfluxiveWriterConfig,
@@ -304,3 +461,59 @@ impl HolochainMetricsConfig {
         }
     }
 }
+
+#[cfg(all(test, feature = "prometheus"))]
+mod tests {
+    use super::*;
+    use opentelemetry_api::metrics::MeterProvider as _;
+    use opentelemetry_api::KeyValue;
+
+    #[test]
+    fn prometheus_labels_keeps() {
+        for (labels, agent, dna_hash) in [
+            (PrometheusLabels::Aggregate, false, false),
+            (PrometheusLabels::Dna, false, true),
+            (PrometheusLabels::Cell, true, true),
+        ] {
+            assert_eq!(agent, labels.keeps("agent"), "{labels:?}");
+            assert_eq!(dna_hash, labels.keeps("dna_hash"), "{labels:?}");
+            assert!(labels.keeps("zome"), "{labels:?}");
+            assert!(!labels.keeps("remote_id"), "{labels:?}");
+            assert!(!labels.keeps("conn_uniq"), "{labels:?}");
+            assert!(!labels.keeps("undocumented"), "{labels:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn prometheus_scrape() {
+        let (meter_provider, registry) =
+            HolochainMetricsConfig::prometheus_meter_provider(PrometheusLabels::Dna).unwrap();
+        meter_provider
+            .meter("test")
+            .u64_counter("hc.test.count")
+            .init()
+            .add(
+                1,
+                &[
+                    KeyValue::new("dna_hash", "dna-1"),
+                    KeyValue::new("agent", "agent-1"),
+                    KeyValue::new("remote_id", "peer-1"),
+                ],
+            );
+
+        let res = warp::test::request()
+            .path("/metrics")
+            .reply(&HolochainMetricsConfig::prometheus_filter(registry))
+            .await;
+        assert_eq!(200, res.status());
+        let body = String::from_utf8(res.body().to_vec()).unwrap();
+        let line = body
+            .lines()
+            .find(|l| l.starts_with("hc_test_count_total{"))
+            .unwrap_or_else(|| panic!("no counter in {body}"));
+        assert!(line.contains("dna_hash=\"dna-1\""), "{line}");
+        assert!(!line.contains("agent"), "{line}");
+        assert!(!line.contains("remote_id"), "{line}");
+        assert!(line.ends_with(" 1"), "{line}");
+    }
+}
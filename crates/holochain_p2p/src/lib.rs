@@ -66,6 +66,16 @@ pub trait HolochainP2pDnaT: Send + Sync + 'static {
         to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
     ) -> actor::HolochainP2pResult<()>;
 
+    /// Send a remote signal and wait for the recipients' conductors to
+    /// acknowledge it, retrying unreachable agents until `retry_for` has
+    /// elapsed. Returns the agents that acknowledged the signal.
+    async fn send_remote_signal_with_ack(
+        &self,
+        signal_id: RemoteSignalId,
+        to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
+        retry_for: std::time::Duration,
+    ) -> actor::HolochainP2pResult<Vec<AgentPubKey>>;
+
     /// Publish data to the correct neighborhood.
     #[allow(clippy::ptr_arg)]
     async fn publish(
@@ -94,6 +104,24 @@ pub trait HolochainP2pDnaT: Send + Sync + 'static {
         options: actor::GetOptions,
     ) -> actor::HolochainP2pResult<Vec<WireOps>>;
 
+    /// Get many entries from the DHT, sending a single request to each
+    /// authority that holds several of them.
+    /// The results are in the same order as the requested hashes.
+    async fn get_many(
+        &self,
+        dht_hashes: Vec<holo_hash::AnyDhtHash>,
+        options: actor::GetOptions,
+    ) -> actor::HolochainP2pResult<Vec<actor::HolochainP2pResult<Vec<WireOps>>>>;
+
+    /// Ask the authorities for an action to follow its updates as far as
+    /// they can, returning the ops for each step in order.
+    async fn get_latest(
+        &self,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: actor::GetOptions,
+    ) -> actor::HolochainP2pResult<Vec<WireRecordOps>>;
+
     /// Get metadata from the DHT.
     async fn get_meta(
         &self,
@@ -230,6 +258,22 @@ impl HolochainP2pDnaT for HolochainP2pDna {
             .await
     }
 
+    async fn send_remote_signal_with_ack(
+        &self,
+        signal_id: RemoteSignalId,
+        to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
+        retry_for: std::time::Duration,
+    ) -> actor::HolochainP2pResult<Vec<AgentPubKey>> {
+        self.sender
+            .send_remote_signal_with_ack(
+                (*self.dna_hash).clone(),
+                signal_id,
+                to_agent_list,
+                retry_for,
+            )
+            .await
+    }
+
     /// Publish data to the correct neighborhood.
     async fn publish(
         &self,
@@ -279,6 +323,29 @@ impl HolochainP2pDnaT for HolochainP2pDna {
             .await
     }
 
+    async fn get_many(
+        &self,
+        dht_hashes: Vec<holo_hash::AnyDhtHash>,
+        options: actor::GetOptions,
+    ) -> actor::HolochainP2pResult<Vec<actor::HolochainP2pResult<Vec<WireOps>>>> {
+        self.sender
+            .get_many((*self.dna_hash).clone(), dht_hashes, options)
+            .instrument(tracing::debug_span!("HolochainP2p::get_many"))
+            .await
+    }
+
+    async fn get_latest(
+        &self,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: actor::GetOptions,
+    ) -> actor::HolochainP2pResult<Vec<WireRecordOps>> {
+        self.sender
+            .get_latest((*self.dna_hash).clone(), action_hash, tie_break, options)
+            .instrument(tracing::debug_span!("HolochainP2p::get_latest"))
+            .await
+    }
+
     /// Get metadata from the DHT.
     async fn get_meta(
         &self,
@@ -1,5 +1,6 @@
 use crate::actor::*;
 use crate::event::*;
+use std::collections::BTreeSet;
 
 mod actor;
 use actor::*;
@@ -31,9 +32,93 @@ pub async fn spawn_holochain_p2p(
 }
 
 /// Some parameters used as part of a protocol compability check during tx5 preflight
-#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct NetworkCompatParams {
     /// The UUID of the installed DPKI service.
     /// If the service is backed by a Dna, this is the core 32 bytes of the DnaHash.
     pub dpki_uuid: Option<[u8; 32]>,
+
+    /// The id of the network this node belongs to, if configured.
+    // Left out when unset, so that nodes without a network id keep
+    // sending the same preflight bytes as before it existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+
+    /// Additional compatibility tags, which must match exactly.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub compat_tags: BTreeSet<String>,
+}
+
+impl NetworkCompatParams {
+    /// Check that a remote node's params are compatible with ours,
+    /// describing the first difference found if they are not.
+    pub fn check_compat(&self, theirs: &NetworkCompatParams) -> Result<(), String> {
+        if self.network_id != theirs.network_id {
+            return Err(format!(
+                "network_id mismatch: ours={:?}, theirs={:?}",
+                self.network_id, theirs.network_id
+            ));
+        }
+        if self.compat_tags != theirs.compat_tags {
+            return Err(format!(
+                "compat_tags mismatch: ours={:?}, theirs={:?}",
+                self.compat_tags, theirs.compat_tags
+            ));
+        }
+        if self.dpki_uuid != theirs.dpki_uuid {
+            return Err(format!(
+                "dpki_uuid mismatch: ours={:?}, theirs={:?}",
+                self.dpki_uuid, theirs.dpki_uuid
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_compat_params_unset_fields_are_not_encoded() {
+        let mut old = vec![];
+        kitsune_p2p_types::codec::rmp_encode(&mut old, LegacyParams { dpki_uuid: None }).unwrap();
+        let mut new = vec![];
+        kitsune_p2p_types::codec::rmp_encode(&mut new, NetworkCompatParams::default()).unwrap();
+
+        assert_eq!(old, new);
+
+        #[derive(serde::Serialize)]
+        struct LegacyParams {
+            dpki_uuid: Option<[u8; 32]>,
+        }
+    }
+
+    #[test]
+    fn network_compat_params_mismatch_reason() {
+        let staging = NetworkCompatParams {
+            network_id: Some("staging".into()),
+            ..Default::default()
+        };
+        let production = NetworkCompatParams {
+            network_id: Some("production".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(Ok(()), staging.check_compat(&staging.clone()));
+        assert_eq!(
+            Err(r#"network_id mismatch: ours=Some("staging"), theirs=Some("production")"#.into()),
+            staging.check_compat(&production)
+        );
+
+        let tagged = NetworkCompatParams {
+            network_id: Some("staging".into()),
+            compat_tags: ["v2".to_string()].into(),
+            ..Default::default()
+        };
+        assert!(staging
+            .check_compat(&tagged)
+            .unwrap_err()
+            .starts_with("compat_tags mismatch"));
+    }
 }
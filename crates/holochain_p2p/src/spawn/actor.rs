@@ -4,6 +4,7 @@ use crate::event::*;
 use crate::*;
 
 use futures::future::FutureExt;
+use futures::stream::StreamExt;
 use kitsune_p2p::actor::BroadcastData;
 use kitsune_p2p::dependencies::kitsune_p2p_fetch;
 use kitsune_p2p::dht::Arq;
@@ -191,6 +192,24 @@ impl WrapEvtSender {
         )
     }
 
+    fn get_latest(
+        &self,
+        dna_hash: DnaHash,
+        to_agent: AgentPubKey,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: event::GetOptions,
+    ) -> impl Future<Output = HolochainP2pResult<Vec<WireRecordOps>>> + 'static + Send {
+        timing_trace!(
+            true,
+            {
+                self.0
+                    .get_latest(dna_hash, to_agent, action_hash, tie_break, options)
+            },
+            a = "recv_get_latest",
+        )
+    }
+
     fn get_meta(
         &self,
         dna_hash: DnaHash,
@@ -354,11 +373,122 @@ impl WrapEvtSender {
     }
 }
 
+/// How many of the most recent preflight rejections to remember.
+const PREFLIGHT_REJECTIONS_MAX: usize = 32;
+
+/// Peers recently rejected during preflight, oldest first,
+/// with at most one entry per peer url.
+type PreflightRejections = Arc<std::sync::Mutex<std::collections::VecDeque<PreflightRejection>>>;
+
+fn record_preflight_rejection(rejections: &PreflightRejections, peer_url: &str, reason: &str) {
+    let mut rejections = rejections.lock().unwrap();
+    rejections.retain(|r| r.peer_url != peer_url);
+    if rejections.len() >= PREFLIGHT_REJECTIONS_MAX {
+        rejections.pop_front();
+    }
+    rejections.push_back(PreflightRejection {
+        peer_url: peer_url.to_string(),
+        reason: reason.to_string(),
+        timestamp: Timestamp::now(),
+    });
+}
+
+/// How long a recipient remembers the ids of acknowledged remote signals.
+/// A copy arriving any later carries a zome call nonce that has expired,
+/// so it would be rejected regardless.
+const RECEIVED_SIGNAL_RETENTION: std::time::Duration = holochain_nonce::FRESH_NONCE_EXPIRES_AFTER;
+
+/// The first pause before retrying agents that did not acknowledge a signal.
+const SIGNAL_RETRY_INITIAL_BACKOFF: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// The longest pause between retries of an unacknowledged signal.
+const SIGNAL_RETRY_MAX_BACKOFF: std::time::Duration = std::time::Duration::from_secs(8);
+
+/// The delivery of a remote signal to the recipient's zome, shared with the
+/// copies of the signal that arrive while it is running.
+type SignalDelivery =
+    futures::future::Shared<futures::future::BoxFuture<'static, Result<(), String>>>;
+
+enum ReceivedSignal {
+    /// Being delivered. Copies arriving meanwhile wait for the outcome.
+    Delivering(SignalDelivery),
+    /// Delivered. Copies are acknowledged straight away.
+    Delivered,
+}
+
+/// The remote signals received for each agent, so that the copies a sender
+/// retries with are only delivered once.
+#[derive(Clone, Default)]
+struct ReceivedSignals(
+    Arc<
+        std::sync::Mutex<
+            HashMap<(AgentPubKey, RemoteSignalId), (std::time::Instant, ReceivedSignal)>,
+        >,
+    >,
+);
+
+impl ReceivedSignals {
+    /// Deliver a signal with `deliver`, unless it has been delivered already
+    /// or is being delivered, in which case wait for that delivery instead.
+    /// Resolves once the signal has been delivered.
+    fn receive<F>(
+        &self,
+        key: (AgentPubKey, RemoteSignalId),
+        deliver: impl FnOnce() -> F,
+    ) -> futures::future::BoxFuture<'static, Result<(), String>>
+    where
+        F: Future<Output = HolochainP2pResult<()>> + Send + 'static,
+    {
+        let mut received = self.0.lock().unwrap();
+        let now = std::time::Instant::now();
+        received.retain(|_, (at, _)| now.duration_since(*at) < RECEIVED_SIGNAL_RETENTION);
+        match received.get(&key) {
+            Some((_, ReceivedSignal::Delivered)) => {
+                tracing::debug!(signal_id = ?key.1, "Acknowledging duplicate remote signal");
+                return async move { Ok(()) }.boxed();
+            }
+            Some((_, ReceivedSignal::Delivering(delivery))) => {
+                tracing::debug!(signal_id = ?key.1, "Awaiting the delivery of duplicate remote signal");
+                return delivery.clone().boxed();
+            }
+            None => (),
+        }
+
+        let delivery = deliver();
+        let received_signals = self.clone();
+        let delivery = {
+            let key = key.clone();
+            async move {
+                let delivered = delivery.await;
+                let mut received = received_signals.0.lock().unwrap();
+                match delivered {
+                    Ok(()) => {
+                        received
+                            .insert(key, (std::time::Instant::now(), ReceivedSignal::Delivered));
+                        Ok(())
+                    }
+                    Err(e) => {
+                        // Not delivered, so let a retry through.
+                        received.remove(&key);
+                        Err(e.to_string())
+                    }
+                }
+            }
+        }
+        .boxed()
+        .shared();
+        received.insert(key, (now, ReceivedSignal::Delivering(delivery.clone())));
+        delivery.boxed()
+    }
+}
+
 pub(crate) struct HolochainP2pActor {
     config: kitsune_p2p_types::config::KitsuneP2pConfig,
     evt_sender: WrapEvtSender,
     kitsune_p2p: ghost_actor::GhostSender<kitsune_p2p::actor::KitsuneP2p>,
     host: kitsune_p2p::HostApi,
+    preflight_rejections: PreflightRejections,
+    received_signals: ReceivedSignals,
 }
 
 impl ghost_actor::GhostControlHandler for HolochainP2pActor {
@@ -374,6 +504,15 @@ impl ghost_actor::GhostControlHandler for HolochainP2pActor {
     }
 }
 
+/// A span for handling a message from a remote conductor, which continues
+/// the trace the message was sent from, if any.
+fn remote_handler_span(kind: &'static str, trace_context: Vec<u8>) -> tracing::Span {
+    use holochain_trace::OpenSpanExt;
+    let span = tracing::info_span!("remote_handler", kind);
+    span.in_scope(|| holochain_trace::tracing::Span::set_current_bytes(trace_context));
+    span
+}
+
 impl HolochainP2pActor {
     /// constructor
     pub async fn new(
@@ -388,27 +527,29 @@ impl HolochainP2pActor {
         kitsune_p2p_types::codec::rmp_encode(&mut bytes, &compat)
             .map_err(HolochainP2pError::other)?;
 
+        let preflight_rejections = PreflightRejections::default();
+
         let preflight_user_data = PreflightUserData {
             bytes: bytes.clone(),
-            comparator: Box::new(move |url, mut recvd_bytes| {
-                if bytes.as_slice() != recvd_bytes {
+            comparator: Box::new({
+                let preflight_rejections = preflight_rejections.clone();
+                move |url, mut recvd_bytes| {
+                    if bytes.as_slice() == recvd_bytes {
+                        return Ok(());
+                    }
                     let common = "Cannot complete preflight handshake with peer because network compatibility params don't match";
-                    Err(
-                        match kitsune_p2p_types::codec::rmp_decode::<_, NetworkCompatParams>(
-                            &mut recvd_bytes,
-                        ) {
-                            Ok(theirs) => {
-                                format!("{common}. ours={compat:?}, theirs={theirs:?}, url={url}")
-                            }
-                            Err(err) => {
-                                format!(
-                                "{common}. (Can't decode peer's sent hash.) url={url}, err={err}"
-                            )
-                            }
+                    let reason = match kitsune_p2p_types::codec::rmp_decode::<_, NetworkCompatParams>(
+                        &mut recvd_bytes,
+                    ) {
+                        Ok(theirs) => match compat.check_compat(&theirs) {
+                            Err(reason) => reason,
+                            // equal once decoded, the remote just encodes differently
+                            Ok(()) => return Ok(()),
                         },
-                    )
-                } else {
-                    Ok(())
+                        Err(err) => format!("can't decode peer's compatibility params: {err}"),
+                    };
+                    record_preflight_rejection(&preflight_rejections, url, &reason);
+                    Err(format!("{common}. {reason}, url={url}"))
                 }
             }),
         };
@@ -428,6 +569,8 @@ impl HolochainP2pActor {
             evt_sender: WrapEvtSender(evt_sender),
             kitsune_p2p,
             host,
+            preflight_rejections,
+            received_signals: ReceivedSignals::default(),
         })
     }
 
@@ -452,6 +595,47 @@ impl HolochainP2pActor {
         .into())
     }
 
+    /// receiving a remote signal the sender wants acknowledged
+    ///
+    /// The signal is only acknowledged once the recipient's zome has received
+    /// it, and not when the call is refused, e.g. for lack of a capability.
+    /// A signal id already delivered to this agent is acknowledged again
+    /// without being delivered, so that retries from the sender are harmless.
+    /// A retry arriving while the signal is still being delivered gets the
+    /// outcome of that delivery.
+    fn handle_incoming_remote_signal_with_ack(
+        &mut self,
+        dna_hash: DnaHash,
+        signal_id: RemoteSignalId,
+        to_agent: AgentPubKey,
+        zome_call_params_serialized: ExternIO,
+        signature: Signature,
+    ) -> kitsune_p2p::actor::KitsuneP2pHandlerResult<Vec<u8>> {
+        let evt_sender = self.evt_sender.clone();
+        let delivery =
+            self.received_signals
+                .receive((to_agent.clone(), signal_id), move || async move {
+                    let response = evt_sender
+                        .call_remote(dna_hash, to_agent, zome_call_params_serialized, signature)
+                        .await?;
+                    match ZomeCallResponse::try_from(response)? {
+                        ZomeCallResponse::Ok(_) => Ok(()),
+                        response => Err(HolochainP2pError::other(format!(
+                            "remote signal not delivered: {:?}",
+                            response
+                        ))),
+                    }
+                });
+        Ok(async move {
+            delivery
+                .await
+                .map_err(kitsune_p2p::KitsuneP2pError::other)?;
+            Ok(Vec::new())
+        }
+        .boxed()
+        .into())
+    }
+
     /// receiving an incoming get request from a remote node
     #[cfg_attr(
         feature = "instrument",
@@ -476,6 +660,77 @@ impl HolochainP2pActor {
         .into())
     }
 
+    /// receiving an incoming get_many request from a remote node
+    fn handle_incoming_get_many(
+        &mut self,
+        dna_hash: DnaHash,
+        to_agent: AgentPubKey,
+        dht_hashes: Vec<holo_hash::AnyDhtHash>,
+        options: event::GetOptions,
+    ) -> kitsune_p2p::actor::KitsuneP2pHandlerResult<Vec<u8>> {
+        if dht_hashes.len() > crate::wire::MAX_GET_MANY_BATCH {
+            return Err(HolochainP2pError::invalid_p2p_message(format!(
+                "invalid: get_many of {} hashes exceeds the limit of {}",
+                dht_hashes.len(),
+                crate::wire::MAX_GET_MANY_BATCH
+            ))
+            .into());
+        }
+        let evt_sender = self.evt_sender.clone();
+        Ok(async move {
+            // A failure to get one hash is reported as `None` so that the
+            // requester can retry just that hash elsewhere.
+            let res: Vec<Option<WireOps>> =
+                futures::stream::iter(dht_hashes.into_iter().map(|dht_hash| {
+                    evt_sender
+                        .get(
+                            dna_hash.clone(),
+                            to_agent.clone(),
+                            dht_hash,
+                            options.clone(),
+                        )
+                        .map(|r| match r {
+                            Ok(ops) => Some(ops),
+                            Err(err) => {
+                                tracing::debug!(?err, "get_many item failed");
+                                None
+                            }
+                        })
+                }))
+                .buffered(10)
+                .collect()
+                .await;
+            holochain_serialized_bytes::encode(&res)
+                .map_err(HolochainP2pError::from)
+                .map_err(kitsune_p2p::KitsuneP2pError::from)
+        }
+        .instrument(tracing::debug_span!("incoming_get_many_task"))
+        .boxed()
+        .into())
+    }
+
+    /// receiving an incoming get_latest request from a remote node
+    fn handle_incoming_get_latest(
+        &mut self,
+        dna_hash: DnaHash,
+        to_agent: AgentPubKey,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: event::GetOptions,
+    ) -> kitsune_p2p::actor::KitsuneP2pHandlerResult<Vec<u8>> {
+        let evt_sender = self.evt_sender.clone();
+        Ok(async move {
+            let res = evt_sender
+                .get_latest(dna_hash, to_agent, action_hash, tie_break, options)
+                .await;
+            res.and_then(|r| Ok(holochain_serialized_bytes::encode(&r)?))
+                .map_err(kitsune_p2p::KitsuneP2pError::from)
+        }
+        .instrument(tracing::debug_span!("incoming_get_latest_task"))
+        .boxed()
+        .into())
+    }
+
     /// receiving an incoming get_meta request from a remote node
     fn handle_incoming_get_meta(
         &mut self,
@@ -792,10 +1047,12 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
         let space = DnaHash::from_kitsune(&space);
         let to_agent = AgentPubKey::from_kitsune(&to_agent);
 
-        let request =
-            crate::wire::WireMessage::decode(payload.as_ref()).map_err(HolochainP2pError::from)?;
+        let (request, trace_context) =
+            crate::wire::WireMessage::decode_with_trace_context(payload.as_ref())
+                .map_err(HolochainP2pError::from)?;
+        let span = remote_handler_span("call", trace_context);
 
-        match request {
+        let fut = span.in_scope(|| match request {
             crate::wire::WireMessage::CallRemote {
                 to_agent,
                 zome_call_params_serialized,
@@ -816,9 +1073,30 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
                     None => Err(HolochainP2pError::RoutingAgentError(to_agent).into()),
                 }
             }
+            crate::wire::WireMessage::RemoteSignalWithAck {
+                signal_id,
+                to_agent,
+                zome_call_params_serialized,
+                signature,
+            } => self.handle_incoming_remote_signal_with_ack(
+                space,
+                signal_id,
+                to_agent,
+                zome_call_params_serialized,
+                signature,
+            ),
             crate::wire::WireMessage::Get { dht_hash, options } => {
                 self.handle_incoming_get(space, to_agent, dht_hash, options)
             }
+            crate::wire::WireMessage::GetMany {
+                dht_hashes,
+                options,
+            } => self.handle_incoming_get_many(space, to_agent, dht_hashes, options),
+            crate::wire::WireMessage::GetLatest {
+                action_hash,
+                tie_break,
+                options,
+            } => self.handle_incoming_get_latest(space, to_agent, action_hash, tie_break, options),
             crate::wire::WireMessage::GetMeta { dht_hash, options } => {
                 self.handle_incoming_get_meta(space, to_agent, dht_hash, options)
             }
@@ -850,7 +1128,9 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
                 )
                 .into())
             }
-        }
+        })?;
+
+        Ok(fut.instrument(span).boxed().into())
     }
 
     /// Handle an incoming notify.
@@ -867,12 +1147,17 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
         let space = DnaHash::from_kitsune(&space);
         let to_agent = AgentPubKey::from_kitsune(&to_agent);
 
-        let request =
-            crate::wire::WireMessage::decode(payload.as_ref()).map_err(HolochainP2pError::from)?;
+        let (request, trace_context) =
+            crate::wire::WireMessage::decode_with_trace_context(payload.as_ref())
+                .map_err(HolochainP2pError::from)?;
+        let span = remote_handler_span("notify", trace_context);
 
-        match request {
+        let fut = span.in_scope(|| match request {
             // error on these call type messages
             crate::wire::WireMessage::Get { .. }
+            | crate::wire::WireMessage::RemoteSignalWithAck { .. }
+            | crate::wire::WireMessage::GetMany { .. }
+            | crate::wire::WireMessage::GetLatest { .. }
             | crate::wire::WireMessage::GetMeta { .. }
             | crate::wire::WireMessage::GetLinks { .. }
             | crate::wire::WireMessage::CountLinks { .. }
@@ -932,7 +1217,9 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
             crate::wire::WireMessage::PublishCountersign { flag, op } => {
                 self.handle_incoming_publish(space, false, flag, vec![op])
             }
-        }
+        })?;
+
+        Ok(fut.instrument(span).boxed().into())
     }
 
     #[cfg_attr(
@@ -1054,6 +1341,78 @@ impl kitsune_p2p::event::KitsuneP2pEventHandler for HolochainP2pActor {
     }
 }
 
+/// Group the hashes of a get_many request into batches, each for one of the
+/// `peers` to send it to, given the location of each hash and the storage arc
+/// of each peer.
+///
+/// Every hash goes to the peer that holds it and the most other hashes, so
+/// that as few requests as possible are sent. Hashes that no peer holds are
+/// left out, to be fetched on their own. Batches are no larger than
+/// [`MAX_GET_MANY_BATCH`](crate::wire::MAX_GET_MANY_BATCH).
+fn batch_by_authority(
+    locs: &[kitsune_p2p_types::dht_arc::DhtLocation],
+    peers: &[(
+        Arc<kitsune_p2p::KitsuneAgent>,
+        kitsune_p2p_types::dht_arc::DhtArc,
+    )],
+) -> Vec<(Arc<kitsune_p2p::KitsuneAgent>, Vec<usize>)> {
+    let held: Vec<usize> = peers
+        .iter()
+        .map(|(_, arc)| locs.iter().filter(|loc| arc.contains(**loc)).count())
+        .collect();
+
+    let mut indices: Vec<Vec<usize>> = vec![Vec::new(); peers.len()];
+    for (index, loc) in locs.iter().enumerate() {
+        // Ties go to the peer listed first.
+        let authority = peers
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, arc))| arc.contains(*loc))
+            .max_by_key(|(peer, _)| (held[*peer], std::cmp::Reverse(*peer)))
+            .map(|(peer, _)| peer);
+        if let Some(peer) = authority {
+            indices[peer].push(index);
+        }
+    }
+
+    peers
+        .iter()
+        .zip(indices)
+        .flat_map(|((agent, _), indices)| {
+            indices
+                .chunks(crate::wire::MAX_GET_MANY_BATCH)
+                .map(|chunk| (agent.clone(), chunk.to_vec()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Get a hash from whichever authority for its basis responds first.
+async fn get_from_basis(
+    kitsune_p2p: ghost_actor::GhostSender<kitsune_p2p::actor::KitsuneP2p>,
+    tuning_params: kitsune_p2p_types::config::KitsuneP2pTuningParams,
+    space: Arc<kitsune_p2p::KitsuneSpace>,
+    dht_hash: holo_hash::AnyDhtHash,
+    r_options: event::GetOptions,
+) -> HolochainP2pResult<Vec<WireOps>> {
+    let basis = dht_hash.to_kitsune();
+    let payload = crate::wire::WireMessage::get(dht_hash, r_options).encode()?;
+
+    let input = kitsune_p2p::actor::RpcMulti::new(&tuning_params, space, basis, payload);
+    let result = kitsune_p2p
+        .rpc_multi(input)
+        .instrument(tracing::debug_span!("rpc_multi"))
+        .await?;
+
+    let mut out = Vec::new();
+    for item in result {
+        let kitsune_p2p::actor::RpcMultiResponse { response, .. } = item;
+        out.push(SerializedBytes::from(UnsafeBytes::from(response)).try_into()?);
+    }
+
+    Ok(out)
+}
+
 macro_rules! timing_trace_out {
     ($code:expr, $($rest:tt)*) => {{
         let __start = std::time::Instant::now();
@@ -1204,6 +1563,91 @@ impl HolochainP2pHandler for HolochainP2pActor {
         )
     }
 
+    /// Dispatch an outgoing signal and collect acknowledgements, retrying
+    /// the agents that did not acknowledge until `retry_for` has elapsed.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(skip(self, to_agent_list), level = "trace")
+    )]
+    fn handle_send_remote_signal_with_ack(
+        &mut self,
+        dna_hash: DnaHash,
+        signal_id: RemoteSignalId,
+        to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
+        retry_for: std::time::Duration,
+    ) -> HolochainP2pHandlerResult<Vec<AgentPubKey>> {
+        let byte_count = to_agent_list
+            .first()
+            .map(|to_agent| to_agent.1 .0.len())
+            .unwrap_or_else(|| 0);
+        let space = dna_hash.into_kitsune();
+
+        let mut pending = Vec::with_capacity(to_agent_list.len());
+        for (to_agent, zome_call_params_serialized, signature) in to_agent_list {
+            let req = crate::wire::WireMessage::remote_signal_with_ack(
+                signal_id.clone(),
+                to_agent.clone(),
+                zome_call_params_serialized,
+                signature,
+            )
+            .encode()?;
+            pending.push((to_agent, req));
+        }
+
+        let kitsune_p2p = self.kitsune_p2p.clone();
+        timing_trace_out!(
+            async move {
+                let deadline = tokio::time::Instant::now() + retry_for;
+                let mut backoff = SIGNAL_RETRY_INITIAL_BACKOFF;
+                let mut delivered = Vec::new();
+                loop {
+                    let attempts =
+                        futures::future::join_all(pending.into_iter().map(|(to_agent, req)| {
+                            let kitsune_p2p = kitsune_p2p.clone();
+                            let space = space.clone();
+                            async move {
+                                let result = kitsune_p2p
+                                    .rpc_single(
+                                        space,
+                                        to_agent.clone().into_kitsune(),
+                                        req.clone(),
+                                        None,
+                                    )
+                                    .await;
+                                (to_agent, req, result)
+                            }
+                        }))
+                        .await;
+
+                    pending = Vec::new();
+                    for (to_agent, req, result) in attempts {
+                        match result {
+                            Ok(_) => delivered.push(to_agent),
+                            Err(e) => {
+                                tracing::debug!(
+                                    ?signal_id,
+                                    ?to_agent,
+                                    ?e,
+                                    "Remote signal not acknowledged"
+                                );
+                                pending.push((to_agent, req));
+                            }
+                        }
+                    }
+
+                    if pending.is_empty() || tokio::time::Instant::now() + backoff > deadline {
+                        break;
+                    }
+                    tokio::time::sleep(backoff).await;
+                    backoff = std::cmp::min(backoff * 2, SIGNAL_RETRY_MAX_BACKOFF);
+                }
+                Ok(delivered)
+            },
+            byte_count,
+            a = "send_remote_signal_with_ack"
+        )
+    }
+
     #[cfg_attr(
         feature = "instrument",
         tracing::instrument(skip(self), level = "trace")
@@ -1323,10 +1767,147 @@ impl HolochainP2pHandler for HolochainP2pActor {
         options: actor::GetOptions,
     ) -> HolochainP2pHandlerResult<Vec<WireOps>> {
         let space = dna_hash.into_kitsune();
-        let basis = dht_hash.to_kitsune();
         let r_options: event::GetOptions = (&options).into();
 
-        let payload = crate::wire::WireMessage::get(dht_hash, r_options).encode()?;
+        let kitsune_p2p = self.kitsune_p2p.clone();
+        let tuning_params = self.config.tuning_params.clone();
+        timing_trace_out!(
+            get_from_basis(kitsune_p2p, tuning_params, space, dht_hash, r_options),
+            a = "send_get"
+        )
+    }
+
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(skip(self), level = "trace")
+    )]
+    fn handle_get_many(
+        &mut self,
+        dna_hash: DnaHash,
+        dht_hashes: Vec<holo_hash::AnyDhtHash>,
+        options: actor::GetOptions,
+    ) -> HolochainP2pHandlerResult<Vec<HolochainP2pResult<Vec<WireOps>>>> {
+        let space = dna_hash.clone().into_kitsune();
+        let r_options: event::GetOptions = (&options).into();
+
+        let kitsune_p2p = self.kitsune_p2p.clone();
+        let evt_sender = self.evt_sender.clone();
+        let tuning_params = self.config.tuning_params.clone();
+        timing_trace_out!(
+            async move {
+                let mut out: Vec<Option<HolochainP2pResult<Vec<WireOps>>>> =
+                    iter::repeat_with(|| None).take(dht_hashes.len()).collect();
+
+                // Our own agents are authorities for some hashes, which the
+                // cascade finds with `authority_for_hash` and doesn't ask us
+                // for. The authorities for the rest are picked from a single
+                // query of the peer store.
+                let peers: Vec<_> = evt_sender
+                    .query_agent_info_signed(dna_hash.clone(), None, space.clone())
+                    .await
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|info| (info.agent.clone(), info.storage_arc()))
+                    .collect();
+                let locs: Vec<_> = dht_hashes.iter().map(|hash| hash.get_loc()).collect();
+                let requests = batch_by_authority(&locs, &peers);
+
+                let responses: Vec<(Vec<usize>, HolochainP2pResult<_>)> =
+                    futures::stream::iter(requests.into_iter().map(|(agent, indices)| {
+                        let kitsune_p2p = kitsune_p2p.clone();
+                        let space = space.clone();
+                        let payload = crate::wire::WireMessage::get_many(
+                            indices.iter().map(|i| dht_hashes[*i].clone()).collect(),
+                            r_options.clone(),
+                        )
+                        .encode();
+                        let count = indices.len();
+                        async move {
+                            let res = async move {
+                                let response =
+                                    kitsune_p2p.rpc_single(space, agent, payload?, None).await?;
+                                let ops: Vec<Option<WireOps>> =
+                                    holochain_serialized_bytes::decode(&response)?;
+                                if ops.len() != count {
+                                    return Err(HolochainP2pError::other(
+                                        "get_many response has the wrong number of results",
+                                    ));
+                                }
+                                Ok(ops)
+                            }
+                            .await;
+                            (indices, res)
+                        }
+                    }))
+                    .buffer_unordered(10)
+                    .collect()
+                    .await;
+
+                for (indices, res) in responses {
+                    match res {
+                        Ok(ops) => {
+                            for (index, ops) in indices.into_iter().zip(ops) {
+                                out[index] = ops.map(|ops| Ok(vec![ops]));
+                            }
+                        }
+                        Err(err) => {
+                            tracing::debug!(?err, "get_many batch failed, falling back to get");
+                        }
+                    }
+                }
+
+                // Anything that could not be fetched in a batch is fetched on
+                // its own, which may try several authorities.
+                let fallbacks: Vec<(usize, HolochainP2pResult<Vec<WireOps>>)> =
+                    futures::stream::iter(
+                        out.iter()
+                            .enumerate()
+                            .filter(|(_, res)| res.is_none())
+                            .map(|(index, _)| {
+                                let fut = get_from_basis(
+                                    kitsune_p2p.clone(),
+                                    tuning_params.clone(),
+                                    space.clone(),
+                                    dht_hashes[index].clone(),
+                                    r_options.clone(),
+                                );
+                                async move { (index, fut.await) }
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .buffer_unordered(10)
+                    .collect()
+                    .await;
+                for (index, res) in fallbacks {
+                    out[index] = Some(res);
+                }
+
+                Ok(out
+                    .into_iter()
+                    .map(|res| res.expect("every hash has a result"))
+                    .collect())
+            },
+            a = "send_get_many"
+        )
+    }
+
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(skip(self), level = "trace")
+    )]
+    fn handle_get_latest(
+        &mut self,
+        dna_hash: DnaHash,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: actor::GetOptions,
+    ) -> HolochainP2pHandlerResult<Vec<WireRecordOps>> {
+        let space = dna_hash.into_kitsune();
+        let basis = holo_hash::AnyDhtHash::from(action_hash.clone()).to_kitsune();
+        let r_options: event::GetOptions = (&options).into();
+
+        let payload =
+            crate::wire::WireMessage::get_latest(action_hash, tie_break, r_options).encode()?;
 
         let kitsune_p2p = self.kitsune_p2p.clone();
         let tuning_params = self.config.tuning_params.clone();
@@ -1339,15 +1920,18 @@ impl HolochainP2pHandler for HolochainP2pActor {
                     .instrument(tracing::debug_span!("rpc_multi"))
                     .await?;
 
+                // Every authority's steps are returned, the cascade merges
+                // them and walks the result itself.
                 let mut out = Vec::new();
                 for item in result {
                     let kitsune_p2p::actor::RpcMultiResponse { response, .. } = item;
-                    out.push(SerializedBytes::from(UnsafeBytes::from(response)).try_into()?);
+                    let steps: Vec<WireRecordOps> = holochain_serialized_bytes::decode(&response)?;
+                    out.extend(steps);
                 }
 
                 Ok(out)
             },
-            a = "send_get"
+            a = "send_get_latest"
         )
     }
 
@@ -1663,6 +2247,19 @@ impl HolochainP2pHandler for HolochainP2pActor {
         .into())
     }
 
+    fn handle_get_preflight_rejections(
+        &mut self,
+    ) -> HolochainP2pHandlerResult<Vec<PreflightRejection>> {
+        let rejections = self
+            .preflight_rejections
+            .lock()
+            .unwrap()
+            .iter()
+            .cloned()
+            .collect();
+        Ok(async move { Ok(rejections) }.boxed().into())
+    }
+
     fn handle_get_diagnostics(
         &mut self,
         dna_hash: DnaHash,
@@ -1679,3 +2276,128 @@ impl HolochainP2pHandler for HolochainP2pActor {
         .into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kitsune_p2p_types::dht_arc::{DhtArc, DhtLocation};
+
+    fn agent(n: u8) -> Arc<kitsune_p2p::KitsuneAgent> {
+        Arc::new(kitsune_p2p::KitsuneAgent::new(vec![n; 36]))
+    }
+
+    fn locs(locs: &[u32]) -> Vec<DhtLocation> {
+        locs.iter().copied().map(DhtLocation::new).collect()
+    }
+
+    #[test]
+    fn batch_by_authority_groups_hashes_held_by_the_same_peer() {
+        let peers = vec![
+            (agent(1), DhtArc::from_bounds(0u32, 100)),
+            (agent(2), DhtArc::from_bounds(50u32, 200)),
+        ];
+
+        // Both peers hold three of the hashes, so the hashes they both
+        // hold go to the first.
+        assert_eq!(
+            vec![(agent(1), vec![0, 1, 2]), (agent(2), vec![3])],
+            batch_by_authority(&locs(&[10, 60, 70, 150]), &peers),
+        );
+
+        // Otherwise they go to whichever peer holds more of them.
+        assert_eq!(
+            vec![(agent(2), vec![0, 1, 2])],
+            batch_by_authority(&locs(&[60, 70, 150]), &peers),
+        );
+    }
+
+    #[test]
+    fn batch_by_authority_leaves_out_hashes_no_peer_holds() {
+        let peers = vec![(agent(1), DhtArc::from_bounds(0u32, 100))];
+
+        assert_eq!(
+            vec![(agent(1), vec![1])],
+            batch_by_authority(&locs(&[300, 10, 400]), &peers),
+        );
+        assert!(batch_by_authority(&locs(&[10]), &[]).is_empty());
+    }
+
+    #[test]
+    fn batch_by_authority_splits_large_batches() {
+        let peers = vec![(agent(1), DhtArc::full(DhtLocation::new(0)))];
+        let locs = vec![DhtLocation::new(0); crate::wire::MAX_GET_MANY_BATCH + 1];
+
+        let batches = batch_by_authority(&locs, &peers);
+        assert_eq!(
+            vec![crate::wire::MAX_GET_MANY_BATCH, 1],
+            batches
+                .iter()
+                .map(|(_, indices)| indices.len())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            (0..locs.len()).collect::<Vec<_>>(),
+            batches
+                .into_iter()
+                .flat_map(|(_, indices)| indices)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn signal_key() -> (AgentPubKey, RemoteSignalId) {
+        (
+            AgentPubKey::from_raw_32(vec![1; 32]),
+            RemoteSignalId("signal".to_string()),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn duplicate_signals_wait_for_the_delivery_in_flight() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let received = ReceivedSignals::default();
+        let deliveries = Arc::new(AtomicUsize::new(0));
+        let deliver = |result: HolochainP2pResult<()>| {
+            let deliveries = deliveries.clone();
+            move || {
+                deliveries.fetch_add(1, Ordering::SeqCst);
+                async move { result }
+            }
+        };
+
+        let (finish, finished) = tokio::sync::oneshot::channel::<HolochainP2pResult<()>>();
+        let first = received.receive(signal_key(), {
+            let deliveries = deliveries.clone();
+            move || {
+                deliveries.fetch_add(1, Ordering::SeqCst);
+                async move { finished.await.unwrap() }
+            }
+        });
+        let duplicate = tokio::spawn(received.receive(signal_key(), deliver(Ok(()))));
+
+        // The duplicate isn't delivered, nor acknowledged while the first
+        // is being delivered.
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        assert!(!duplicate.is_finished());
+        assert_eq!(1, deliveries.load(Ordering::SeqCst));
+
+        // If the delivery fails, neither is acknowledged.
+        finish
+            .send(Err(HolochainP2pError::other("refused")))
+            .unwrap();
+        assert!(first.await.is_err());
+        assert!(duplicate.await.unwrap().is_err());
+
+        // So a retry is delivered, after which copies are acknowledged
+        // without being delivered.
+        received
+            .receive(signal_key(), deliver(Ok(())))
+            .await
+            .unwrap();
+        received
+            .receive(signal_key(), deliver(Ok(())))
+            .await
+            .unwrap();
+        assert_eq!(2, deliveries.load(Ordering::SeqCst));
+    }
+}
@@ -51,6 +51,16 @@ impl HolochainP2pHandler for StubNetwork {
         Err("stub".into())
     }
 
+    fn handle_send_remote_signal_with_ack(
+        &mut self,
+        dna_hash: DnaHash,
+        signal_id: RemoteSignalId,
+        to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
+        retry_for: std::time::Duration,
+    ) -> HolochainP2pHandlerResult<Vec<AgentPubKey>> {
+        Err("stub".into())
+    }
+
     fn handle_publish(
         &mut self,
         dna_hash: DnaHash,
@@ -84,6 +94,25 @@ impl HolochainP2pHandler for StubNetwork {
         Err("stub".into())
     }
 
+    fn handle_get_many(
+        &mut self,
+        dna_hash: DnaHash,
+        dht_hashes: Vec<holo_hash::AnyDhtHash>,
+        options: actor::GetOptions,
+    ) -> HolochainP2pHandlerResult<Vec<HolochainP2pResult<Vec<WireOps>>>> {
+        Err("stub".into())
+    }
+
+    fn handle_get_latest(
+        &mut self,
+        dna_hash: DnaHash,
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: actor::GetOptions,
+    ) -> HolochainP2pHandlerResult<Vec<WireRecordOps>> {
+        Err("stub".into())
+    }
+
     fn handle_get_meta(
         &mut self,
         dna_hash: DnaHash,
@@ -169,6 +198,12 @@ impl HolochainP2pHandler for StubNetwork {
         Err("stub".into())
     }
 
+    fn handle_get_preflight_rejections(
+        &mut self,
+    ) -> HolochainP2pHandlerResult<Vec<PreflightRejection>> {
+        Err("stub".into())
+    }
+
     fn handle_get_diagnostics(
         &mut self,
         dna_hash: DnaHash,
@@ -330,6 +365,90 @@ mod tests {
         r_task.await.unwrap();
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_send_remote_signal_with_ack_dedups() {
+        let (dna, a1, a2, _) = test_setup();
+        let keystore = test_keystore();
+        let (signal_url, _signal_srv_handle) = kitsune_p2p::test_util::start_signal_srv().await;
+
+        let (p2p, mut evt) = spawn_holochain_p2p(
+            KitsuneP2pConfig::from_signal_addr(signal_url),
+            TlsConfig::new_ephemeral().await.unwrap(),
+            kitsune_p2p::HostStub::new(),
+            NetworkCompatParams::default(),
+        )
+        .await
+        .unwrap();
+
+        let delivered_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let r_task = tokio::task::spawn({
+            let delivered_count = delivered_count.clone();
+            async move {
+                use tokio_stream::StreamExt;
+                while let Some(evt) = evt.next().await {
+                    use crate::types::event::HolochainP2pEvent::*;
+                    match evt {
+                        CallRemote { respond, .. } => {
+                            delivered_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                            respond.r(Ok(async move { Ok(UnsafeBytes::from(vec![]).into()) }
+                                .boxed()
+                                .into()));
+                        }
+                        SignNetworkData { respond, .. } => {
+                            respond.r(Ok(async move { Ok([0; 64].into()) }.boxed().into()));
+                        }
+                        PutAgentInfoSigned { respond, .. } => {
+                            respond.r(Ok(async move { Ok(vec![]) }.boxed().into()));
+                        }
+                        QueryPeerDensity { respond, .. } => {
+                            let view = test_peer_view();
+                            respond.r(Ok(async move { Ok(view) }.boxed().into()));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        });
+
+        p2p.join(dna.clone(), a1.clone(), None, None).await.unwrap();
+        p2p.join(dna.clone(), a2.clone(), None, None).await.unwrap();
+
+        let (bytes, bytes_hash) = ZomeCallParams {
+            provenance: a1.clone(),
+            cell_id: CellId::new(dna.clone(), a2.clone()),
+            zome_name: "".into(),
+            fn_name: "recv_remote_signal".into(),
+            cap_secret: None,
+            payload: ExternIO::encode(b"yippo").unwrap(),
+            nonce: Nonce256Bits::from([0; 32]),
+            expires_at: (Timestamp::now() + std::time::Duration::from_secs(10)).unwrap(),
+        }
+        .serialize_and_hash()
+        .unwrap();
+        let signature = a1.sign_raw(&keystore, bytes_hash.into()).await.unwrap();
+        let to_agent_list = vec![(a2.clone(), ExternIO(bytes), signature)];
+
+        let send = |signal_id: &str| {
+            p2p.send_remote_signal_with_ack(
+                dna.clone(),
+                RemoteSignalId(signal_id.to_string()),
+                to_agent_list.clone(),
+                std::time::Duration::from_secs(5),
+            )
+        };
+
+        // The same signal id is acknowledged every time but only delivered once.
+        assert_eq!(vec![a2.clone()], send("first").await.unwrap());
+        assert_eq!(vec![a2.clone()], send("first").await.unwrap());
+        assert_eq!(1, delivered_count.load(std::sync::atomic::Ordering::SeqCst));
+
+        assert_eq!(vec![a2.clone()], send("second").await.unwrap());
+        assert_eq!(2, delivered_count.load(std::sync::atomic::Ordering::SeqCst));
+
+        p2p.ghost_actor_shutdown().await.unwrap();
+        r_task.await.unwrap();
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_call_remote_interface() {
         let (dna, alice, bob, _) = test_setup();
@@ -776,6 +895,130 @@ mod tests {
         r_task.await.unwrap();
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_many_workflow() {
+        holochain_trace::test_run();
+
+        let (dna, a1, a2, _a3) = test_setup();
+        let (signal_url, _signal_srv_handle) = kitsune_p2p::test_util::start_signal_srv().await;
+
+        let mut params =
+            kitsune_p2p_types::config::tuning_params_struct::KitsuneP2pTuningParams::default();
+        params.default_rpc_multi_remote_agent_count = 1;
+        params.default_rpc_multi_remote_request_grace_ms = 100;
+        let mut config = KitsuneP2pConfig::from_signal_addr(signal_url);
+        config.tuning_params = Arc::new(params);
+        let (p2p, mut evt) = spawn_holochain_p2p(
+            config,
+            TlsConfig::new_ephemeral().await.unwrap(),
+            kitsune_p2p::HostStub::new(),
+            NetworkCompatParams::default(),
+        )
+        .await
+        .unwrap();
+
+        let hashes: Vec<_> = [b'e', b'f', b'g']
+            .into_iter()
+            .map(|c| {
+                holo_hash::AnyDhtHash::from_raw_36_and_type(
+                    vec![c; HOLO_HASH_UNTYPED_LEN],
+                    holo_hash::hash_type::AnyDht::Action,
+                )
+            })
+            .collect();
+        let ops: std::collections::HashMap<_, _> = hashes
+            .iter()
+            .map(|hash| {
+                let ops = WireOps::Record(WireRecordOps {
+                    action: Some(Judged::valid(SignedAction::new(
+                        fixt!(Action),
+                        fixt!(Signature),
+                    ))),
+                    deletes: vec![],
+                    updates: vec![],
+                    entry: None,
+                });
+                (hash.clone(), ops)
+            })
+            .collect();
+        let missing = hashes[1].clone();
+
+        // Until peers are stored every hash is fetched on its own, and once
+        // they are the hashes they hold are fetched in batches.
+        let peers = Arc::new(Mutex::new(Vec::new()));
+        let r_task = tokio::task::spawn({
+            let ops = ops.clone();
+            let missing = missing.clone();
+            let peers = peers.clone();
+            async move {
+                use tokio_stream::StreamExt;
+                while let Some(evt) = evt.next().await {
+                    use crate::types::event::HolochainP2pEvent::*;
+                    match evt {
+                        Get {
+                            dht_hash, respond, ..
+                        } => {
+                            let resp = if dht_hash == missing {
+                                Err("missing".into())
+                            } else {
+                                Ok(ops[&dht_hash].clone())
+                            };
+                            respond.r(Ok(async move { resp }.boxed().into()));
+                        }
+                        SignNetworkData { respond, .. } => {
+                            respond.r(Ok(async move { Ok([0; 64].into()) }.boxed().into()));
+                        }
+                        PutAgentInfoSigned {
+                            peer_data, respond, ..
+                        } => {
+                            peers.lock().unwrap().extend(peer_data);
+                            respond.r(Ok(async move { Ok(vec![]) }.boxed().into()));
+                        }
+                        QueryAgentInfoSigned { respond, .. } => {
+                            let peers = peers.lock().unwrap().clone();
+                            respond.r(Ok(async move { Ok(peers) }.boxed().into()));
+                        }
+                        QueryOpHashes { respond, .. } => {
+                            respond.r(Ok(async move { Ok(None) }.boxed().into()));
+                        }
+                        QueryPeerDensity { respond, .. } => {
+                            let view = test_peer_view();
+                            respond.r(Ok(async move { Ok(view) }.boxed().into()));
+                        }
+                        evt => tracing::trace!("unhandled: {:?}", evt),
+                    }
+                }
+            }
+        });
+
+        p2p.join(dna.clone(), a1.clone(), None, None).await.unwrap();
+        p2p.join(dna.clone(), a2.clone(), None, None).await.unwrap();
+
+        let res = p2p
+            .get_many(
+                dna.clone(),
+                hashes.clone(),
+                crate::actor::GetOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        // The results are in the order of the hashes, and the hash that no
+        // authority has doesn't fail the others.
+        assert_eq!(hashes.len(), res.len());
+        for (hash, res) in hashes.iter().zip(res) {
+            let res = res.unwrap_or_default();
+            if *hash == missing {
+                assert!(res.is_empty());
+            } else {
+                assert_eq!(vec![ops[hash].clone()], res);
+            }
+        }
+
+        p2p.ghost_actor_shutdown().await.unwrap();
+        r_task.await.unwrap();
+    }
+
     fn test_peer_view() -> PeerView {
         PeerViewQ::new(Topology::standard_epoch_full(), ArqStrat::default(), vec![]).into()
     }
@@ -281,6 +281,16 @@ ghost_actor::ghost_chan! {
             to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
         ) -> ();
 
+        /// Send a remote signal and wait for each recipient's conductor to
+        /// acknowledge it. Agents that don't acknowledge are retried until
+        /// `retry_for` has elapsed. Returns the agents that acknowledged.
+        fn send_remote_signal_with_ack(
+            dna_hash: DnaHash,
+            signal_id: RemoteSignalId,
+            to_agent_list: Vec<(AgentPubKey, ExternIO, Signature)>,
+            retry_for: std::time::Duration,
+        ) -> Vec<AgentPubKey>;
+
         /// Publish data to the correct neighborhood.
         fn publish(
             dna_hash: DnaHash,
@@ -308,6 +318,24 @@ ghost_actor::ghost_chan! {
             options: GetOptions,
         ) -> Vec<WireOps>;
 
+        /// Get many entries from the DHT, sending a single request to each
+        /// authority that holds several of them.
+        /// The results are in the same order as the requested hashes.
+        fn get_many(
+            dna_hash: DnaHash,
+            dht_hashes: Vec<holo_hash::AnyDhtHash>,
+            options: GetOptions,
+        ) -> Vec<HolochainP2pResult<Vec<WireOps>>>;
+
+        /// Ask the authorities for an action to follow its updates as far as
+        /// they can, returning the ops for each step in order.
+        fn get_latest(
+            dna_hash: DnaHash,
+            action_hash: ActionHash,
+            tie_break: UpdateTieBreak,
+            options: GetOptions,
+        ) -> Vec<WireRecordOps>;
+
         /// Get metadata from the DHT.
         fn get_meta(
             dna_hash: DnaHash,
@@ -369,6 +397,10 @@ ghost_actor::ghost_chan! {
 
         /// Get struct for diagnostic data
         fn get_diagnostics(dna_hash: DnaHash) -> KitsuneDiagnostics;
+
+        /// Get the peers most recently rejected during preflight
+        /// because of mismatched network compatibility params.
+        fn get_preflight_rejections() -> Vec<PreflightRejection>;
     }
 }
 
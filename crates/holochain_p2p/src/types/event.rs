@@ -197,6 +197,15 @@ ghost_actor::ghost_chan! {
             options: GetOptions,
         ) -> WireOps;
 
+        /// A remote node is asking us to follow the updates of an action.
+        fn get_latest(
+            dna_hash: DnaHash,
+            to_agent: AgentPubKey,
+            action_hash: ActionHash,
+            tie_break: UpdateTieBreak,
+            options: GetOptions,
+        ) -> Vec<WireRecordOps>;
+
         /// A remote node is requesting metadata from us.
         fn get_meta(
             dna_hash: DnaHash,
@@ -287,6 +296,7 @@ macro_rules! match_p2p_evt {
         match $h {
             HolochainP2pEvent::CallRemote { $i, .. } => { $($t)* }
             HolochainP2pEvent::Get { $i, .. } => { $($t)* }
+            HolochainP2pEvent::GetLatest { $i, .. } => { $($t)* }
             HolochainP2pEvent::GetMeta { $i, .. } => { $($t)* }
             HolochainP2pEvent::GetLinks { $i, .. } => { $($t)* }
             HolochainP2pEvent::CountLinks { $i, .. } => { $($t)* }
@@ -1,5 +1,8 @@
 use crate::*;
 
+/// The most hashes a single [`WireMessage::GetMany`] request may carry.
+pub const MAX_GET_MANY_BATCH: usize = 200;
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, SerializedBytes)]
 /// Struct for encoding DhtOp as bytes.
 pub struct WireDhtOpData {
@@ -32,6 +35,12 @@ pub enum WireMessage {
     CallRemoteMulti {
         to_agents: Vec<(holo_hash::AgentPubKey, ExternIO, Signature)>,
     },
+    RemoteSignalWithAck {
+        signal_id: RemoteSignalId,
+        to_agent: AgentPubKey,
+        zome_call_params_serialized: ExternIO,
+        signature: Signature,
+    },
     ValidationReceipts {
         receipts: ValidationReceiptBundle,
     },
@@ -39,6 +48,15 @@ pub enum WireMessage {
         dht_hash: holo_hash::AnyDhtHash,
         options: event::GetOptions,
     },
+    GetMany {
+        dht_hashes: Vec<holo_hash::AnyDhtHash>,
+        options: event::GetOptions,
+    },
+    GetLatest {
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: event::GetOptions,
+    },
     GetMeta {
         dht_hash: holo_hash::AnyDhtHash,
         options: event::GetMetaOptions,
@@ -68,14 +86,59 @@ pub enum WireMessage {
     },
 }
 
+/// A [`WireMessage`] sent from within an exported span, along with the
+/// trace context of that span as an extra `trace_context` field.
+///
+/// The message's own fields are flattened into the envelope, so conductors
+/// which don't trace decode it as a plain [`WireMessage`] and ignore the
+/// extra field.
+#[derive(Debug, serde::Serialize)]
+struct TracedWireMessage<'a> {
+    #[serde(flatten)]
+    message: &'a WireMessage,
+    #[serde(skip_serializing_if = "<[u8]>::is_empty", with = "serde_bytes")]
+    trace_context: &'a [u8],
+}
+
+/// The trace context field of an encoded [`WireMessage`], if it has one.
+#[derive(Debug, serde::Deserialize)]
+struct WireTraceContext {
+    #[serde(default, with = "serde_bytes")]
+    trace_context: Vec<u8>,
+}
+
 #[allow(missing_docs)]
 impl WireMessage {
+    /// Encode, along with the trace context of the current span if it has one.
     pub fn encode(&self) -> Result<Vec<u8>, SerializedBytesError> {
-        holochain_serialized_bytes::encode(&self)
+        use holochain_trace::OpenSpanExt;
+        self.encode_with_trace_context(&holochain_trace::tracing::Span::get_current_bytes())
+    }
+
+    /// Encode, along with the given trace context.
+    /// An empty trace context encodes exactly as [`WireMessage`] itself.
+    pub fn encode_with_trace_context(
+        &self,
+        trace_context: &[u8],
+    ) -> Result<Vec<u8>, SerializedBytesError> {
+        holochain_serialized_bytes::encode(&TracedWireMessage {
+            message: self,
+            trace_context,
+        })
     }
 
     pub fn decode(data: &[u8]) -> Result<Self, SerializedBytesError> {
-        holochain_serialized_bytes::decode(&data)
+        holochain_serialized_bytes::decode(data)
+    }
+
+    /// Decode, along with the trace context it was sent with.
+    /// The trace context is empty if the message was sent without one.
+    pub fn decode_with_trace_context(data: &[u8]) -> Result<(Self, Vec<u8>), SerializedBytesError> {
+        let message = Self::decode(data)?;
+        let trace_context = holochain_serialized_bytes::decode::<_, WireTraceContext>(data)
+            .map(|t| t.trace_context)
+            .unwrap_or_default();
+        Ok((message, trace_context))
     }
 
     pub fn publish_countersign(flag: bool, op: DhtOp) -> WireMessage {
@@ -102,6 +165,21 @@ impl WireMessage {
         Self::CallRemoteMulti { to_agents }
     }
 
+    /// For an outgoing remote signal that the recipient acknowledges.
+    pub fn remote_signal_with_ack(
+        signal_id: RemoteSignalId,
+        to_agent: holo_hash::AgentPubKey,
+        zome_call_params_serialized: ExternIO,
+        signature: Signature,
+    ) -> WireMessage {
+        Self::RemoteSignalWithAck {
+            signal_id,
+            to_agent,
+            zome_call_params_serialized,
+            signature,
+        }
+    }
+
     pub fn validation_receipts(receipts: ValidationReceiptBundle) -> WireMessage {
         Self::ValidationReceipts { receipts }
     }
@@ -110,6 +188,28 @@ impl WireMessage {
         Self::Get { dht_hash, options }
     }
 
+    pub fn get_many(
+        dht_hashes: Vec<holo_hash::AnyDhtHash>,
+        options: event::GetOptions,
+    ) -> WireMessage {
+        Self::GetMany {
+            dht_hashes,
+            options,
+        }
+    }
+
+    pub fn get_latest(
+        action_hash: ActionHash,
+        tie_break: UpdateTieBreak,
+        options: event::GetOptions,
+    ) -> WireMessage {
+        Self::GetLatest {
+            action_hash,
+            tie_break,
+            options,
+        }
+    }
+
     pub fn get_meta(
         dht_hash: holo_hash::AnyDhtHash,
         options: event::GetMetaOptions,
@@ -150,3 +250,43 @@ impl WireMessage {
         Self::CountersigningSessionNegotiation { message }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message() -> WireMessage {
+        WireMessage::get(
+            holo_hash::EntryHash::from_raw_32(vec![1; 32]).into(),
+            (&actor::GetOptions::default()).into(),
+        )
+    }
+
+    #[test]
+    fn trace_context_round_trips() {
+        let bytes = message().encode_with_trace_context(b"traceparent").unwrap();
+        let (decoded, trace_context) = WireMessage::decode_with_trace_context(&bytes).unwrap();
+        assert!(matches!(decoded, WireMessage::Get { .. }));
+        assert_eq!(b"traceparent".to_vec(), trace_context);
+    }
+
+    #[test]
+    fn traced_message_decodes_as_plain_message() {
+        // Conductors which don't trace decode with the plain `WireMessage`
+        // derive, so the extra field must not break them.
+        let bytes = message().encode_with_trace_context(b"traceparent").unwrap();
+        let decoded: WireMessage = holochain_serialized_bytes::decode(&bytes).unwrap();
+        assert!(matches!(decoded, WireMessage::Get { .. }));
+    }
+
+    #[test]
+    fn untraced_message_encodes_as_plain_message() {
+        let bytes = message().encode_with_trace_context(&[]).unwrap();
+        assert_eq!(
+            holochain_serialized_bytes::encode(&message()).unwrap(),
+            bytes
+        );
+        let (_, trace_context) = WireMessage::decode_with_trace_context(&bytes).unwrap();
+        assert!(trace_context.is_empty());
+    }
+}
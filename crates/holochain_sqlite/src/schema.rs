@@ -37,6 +37,10 @@ pub static SCHEMA_CELL: Lazy<Schema> = Lazy::new(|| Schema {
             forward: include_str!("sql/cell/schema/4-up.sql").into(),
             _schema: include_str!("sql/cell/schema/4.sql").into(),
         },
+        M {
+            forward: include_str!("sql/cell/schema/5-up.sql").into(),
+            _schema: include_str!("sql/cell/schema/5.sql").into(),
+        },
     ],
 });
 
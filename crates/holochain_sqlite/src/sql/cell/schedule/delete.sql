@@ -1,6 +1,5 @@
 DELETE FROM
   ScheduledFunctions
 WHERE
-  zome_name = :zome_name
-  AND scheduled_fn = :scheduled_fn
+  schedule_id = :schedule_id
   AND author = :author
@@ -1,6 +1,8 @@
 SELECT
+  schedule_id,
   zome_name,
   scheduled_fn,
+  payload,
   maybe_schedule
 FROM
   ScheduledFunctions
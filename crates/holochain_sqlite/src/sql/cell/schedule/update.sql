@@ -6,6 +6,5 @@ SET
 END = :end,
 ephemeral = :ephemeral
 WHERE
-  zome_name = :zome_name
-  AND scheduled_fn = :scheduled_fn
+  schedule_id = :schedule_id
   AND author = :author
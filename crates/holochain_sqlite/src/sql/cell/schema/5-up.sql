@@ -0,0 +1,26 @@
+-- no-sql-format --
+
+-- Scheduled functions are now identified by a schedule id, so that the same
+-- function can be scheduled multiple times with different payloads.
+CREATE TABLE ScheduledFunctionsNew (
+    author BLOB NOT NULL,
+    schedule_id TEXT NOT NULL,
+    zome_name TEXT NOT NULL,
+    scheduled_fn TEXT NOT NULL,
+    payload BLOB NULL,
+    maybe_schedule BLOB NOT NULL,
+    start INTEGER NOT NULL,
+    end INTEGER NOT NULL,
+    ephemeral BOOLEAN NOT NULL,
+    PRIMARY KEY (schedule_id, author) ON CONFLICT ROLLBACK
+);
+
+INSERT INTO ScheduledFunctionsNew
+    (author, schedule_id, zome_name, scheduled_fn, payload, maybe_schedule, start, end, ephemeral)
+SELECT
+    author, lower(hex(randomblob(16))), zome_name, scheduled_fn, NULL, maybe_schedule, start, end, ephemeral
+FROM ScheduledFunctions;
+
+DROP TABLE ScheduledFunctions;
+
+ALTER TABLE ScheduledFunctionsNew RENAME TO ScheduledFunctions;
@@ -1,7 +1,7 @@
 use crate::entry_def::EntryDefStoreKey;
 use crate::query::from_blob;
 use crate::query::to_blob;
-use crate::schedule::fn_is_scheduled;
+use crate::schedule::{schedule_exists, schedule_id_for_fn, scheduled_fn_from_parts};
 use crate::scratch::Scratch;
 use crate::validation_db::ValidationStage;
 use holo_hash::encode::blake2b_256;
@@ -948,9 +948,9 @@ pub fn reschedule_expired(
             },
             |row| {
                 Ok((
-                    ZomeName(row.get::<_, String>(0)?.into()),
-                    FunctionName(row.get(1)?),
-                    row.get(2)?,
+                    ScheduleId(row.get(0)?),
+                    scheduled_fn_from_parts(row.get(1)?, row.get(2)?, row.get(3)?),
+                    row.get(4)?,
                 ))
             },
         )?;
@@ -960,11 +960,12 @@ pub fn reschedule_expired(
         }
         ret
     };
-    for (zome_name, scheduled_fn, maybe_schedule) in rows {
+    for (schedule_id, scheduled_fn, maybe_schedule) in rows {
         schedule_fn(
             txn,
             author,
-            ScheduledFn::new(zome_name, scheduled_fn),
+            schedule_id,
+            scheduled_fn,
             from_blob(maybe_schedule)?,
             now,
         )?;
@@ -972,13 +973,19 @@ pub fn reschedule_expired(
     Ok(())
 }
 
+/// Schedule a function, or update its schedule.
+///
+/// A function scheduled without a payload that is already scheduled keeps its
+/// existing schedule id, otherwise the given `schedule_id` is used.
 pub fn schedule_fn(
     txn: &mut Transaction,
     author: &AgentPubKey,
+    schedule_id: ScheduleId,
     scheduled_fn: ScheduledFn,
     maybe_schedule: Option<Schedule>,
     now: Timestamp,
 ) -> StateMutationResult<()> {
+    let schedule_id = schedule_id_for_fn(txn, &scheduled_fn, author)?.unwrap_or(schedule_id);
     let (start, end, ephemeral) = match maybe_schedule {
         Some(Schedule::Persisted(ref schedule_string)) => {
             // If this cron doesn't parse cleanly we don't even want to
@@ -995,14 +1002,7 @@ pub fn schedule_fn(
             } else {
                 // If there are no further executions then scheduling is a
                 // delete and bail.
-                let _ = txn.execute(
-                    holochain_sqlite::sql::sql_cell::schedule::DELETE,
-                    named_params! {
-                        ":zome_name": scheduled_fn.zome_name().to_string(),
-                        ":scheduled_fn": scheduled_fn.fn_name().to_string(),
-                        ":author" : author,
-                    },
-                )?;
+                cancel_schedule(txn, author, &schedule_id)?;
                 return Ok(());
             };
             let end = start
@@ -1017,13 +1017,12 @@ pub fn schedule_fn(
         ),
         None => (now, Timestamp::max(), true),
     };
-    if fn_is_scheduled(txn, scheduled_fn.clone(), author)? {
+    if schedule_exists(txn, &schedule_id, author)? {
         txn.execute(
             holochain_sqlite::sql::sql_cell::schedule::UPDATE,
             named_params! {
-                ":zome_name": scheduled_fn.zome_name().to_string(),
+                ":schedule_id": schedule_id.0,
                 ":maybe_schedule": to_blob::<Option<Schedule>>(&maybe_schedule)?,
-                ":scheduled_fn": scheduled_fn.fn_name().to_string(),
                 ":start": start,
                 ":end": end,
                 ":ephemeral": ephemeral,
@@ -1032,9 +1031,11 @@ pub fn schedule_fn(
         )?;
     } else {
         sql_insert!(txn, ScheduledFunctions, {
+            "schedule_id": schedule_id.0,
             "zome_name": scheduled_fn.zome_name().to_string(),
             "maybe_schedule": to_blob::<Option<Schedule>>(&maybe_schedule)?,
             "scheduled_fn": scheduled_fn.fn_name().to_string(),
+            "payload": scheduled_fn.payload().map(|p| p.0.clone()),
             "start": start,
             "end": end,
             "ephemeral": ephemeral,
@@ -1044,6 +1045,22 @@ pub fn schedule_fn(
     Ok(())
 }
 
+/// Remove a schedule. Returns whether there was a schedule to remove.
+pub fn cancel_schedule(
+    txn: &mut Transaction,
+    author: &AgentPubKey,
+    schedule_id: &ScheduleId,
+) -> StateMutationResult<bool> {
+    let removed = txn.execute(
+        holochain_sqlite::sql::sql_cell::schedule::DELETE,
+        named_params! {
+            ":schedule_id": schedule_id.0,
+            ":author" : author,
+        },
+    )?;
+    Ok(removed > 0)
+}
+
 /// Force remove a countersigning session from the source chain.
 ///
 /// This is a dangerous operation and should only be used:
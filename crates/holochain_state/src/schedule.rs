@@ -30,16 +30,78 @@ pub fn fn_is_scheduled(
         .is_some())
 }
 
+/// The id of the existing schedule for a function scheduled without a payload.
+///
+/// Functions scheduled with a payload never share a schedule, so this is
+/// always `None` for them.
+pub fn schedule_id_for_fn(
+    txn: &Transaction,
+    scheduled_fn: &ScheduledFn,
+    author: &AgentPubKey,
+) -> StateMutationResult<Option<ScheduleId>> {
+    if scheduled_fn.payload().is_some() {
+        return Ok(None);
+    }
+    Ok(txn
+        .query_row(
+            "
+            SELECT schedule_id
+            FROM ScheduledFunctions
+            WHERE
+            zome_name=:zome_name
+            AND scheduled_fn=:scheduled_fn
+            AND payload IS NULL
+            AND author = :author
+            LIMIT 1
+            ",
+            named_params! {
+                ":zome_name": scheduled_fn.zome_name().to_string(),
+                ":scheduled_fn": scheduled_fn.fn_name().to_string(),
+                ":author": author,
+            },
+            |row| row.get::<_, String>(0),
+        )
+        .optional()?
+        .map(ScheduleId))
+}
+
+pub fn schedule_exists(
+    txn: &Transaction,
+    schedule_id: &ScheduleId,
+    author: &AgentPubKey,
+) -> StateMutationResult<bool> {
+    Ok(txn
+        .query_row(
+            "
+            SELECT schedule_id
+            FROM ScheduledFunctions
+            WHERE
+            schedule_id=:schedule_id
+            AND author = :author
+            LIMIT 1
+            ",
+            named_params! {
+                ":schedule_id": schedule_id.0,
+                ":author": author,
+            },
+            |row| row.get::<_, String>(0),
+        )
+        .optional()?
+        .is_some())
+}
+
 pub fn live_scheduled_fns(
     txn: &Transaction,
     now: Timestamp,
     author: &AgentPubKey,
-) -> StateMutationResult<Vec<(ScheduledFn, Option<Schedule>)>> {
+) -> StateMutationResult<Vec<(ScheduleId, ScheduledFn, Option<Schedule>)>> {
     let mut stmt = txn.prepare(
         "
         SELECT
+        schedule_id,
         zome_name,
         scheduled_fn,
+        payload,
         maybe_schedule
         FROM ScheduledFunctions
         WHERE
@@ -55,18 +117,87 @@ pub fn live_scheduled_fns(
         },
         |row| {
             Ok((
-                ScheduledFn::new(
-                    ZomeName(row.get::<_, String>(0)?.into()),
-                    FunctionName(row.get(1)?),
-                ),
-                row.get(2)?,
+                ScheduleId(row.get(0)?),
+                scheduled_fn_from_parts(row.get(1)?, row.get(2)?, row.get(3)?),
+                row.get(4)?,
+            ))
+        },
+    )?;
+    let mut ret = vec![];
+    for row in rows {
+        let (schedule_id, scheduled_fn, maybe_schedule_serialized) = row?;
+        ret.push((
+            schedule_id,
+            scheduled_fn,
+            from_blob(maybe_schedule_serialized)?,
+        ));
+    }
+    Ok(ret)
+}
+
+/// All schedules which have not completed or been cancelled, optionally
+/// limited to a single zome, ordered by when they will next be called.
+pub fn pending_schedules(
+    txn: &Transaction,
+    author: &AgentPubKey,
+    zome_name: Option<&ZomeName>,
+) -> StateMutationResult<Vec<PendingSchedule>> {
+    let mut stmt = txn.prepare(
+        "
+        SELECT
+        schedule_id,
+        zome_name,
+        scheduled_fn,
+        payload,
+        maybe_schedule,
+        start
+        FROM ScheduledFunctions
+        WHERE
+        author = :author
+        AND (:zome_name IS NULL OR zome_name = :zome_name)
+        ORDER BY start ASC",
+    )?;
+    let rows = stmt.query_map(
+        named_params! {
+            ":author": author,
+            ":zome_name": zome_name.map(|z| z.to_string()),
+        },
+        |row| {
+            Ok((
+                ScheduleId(row.get(0)?),
+                ZomeName(row.get::<_, String>(1)?.into()),
+                FunctionName(row.get(2)?),
+                row.get::<_, Option<Vec<u8>>>(3)?.map(ExternIO),
+                row.get(4)?,
+                row.get(5)?,
             ))
         },
     )?;
     let mut ret = vec![];
     for row in rows {
-        let (scheduled_fn, maybe_schedule_serialized) = row?;
-        ret.push((scheduled_fn, from_blob(maybe_schedule_serialized)?));
+        let (schedule_id, zome_name, fn_name, payload, maybe_schedule_serialized, next_call_at) =
+            row?;
+        ret.push(PendingSchedule {
+            schedule_id,
+            zome_name,
+            fn_name,
+            schedule: from_blob(maybe_schedule_serialized)?,
+            payload,
+            next_call_at,
+        });
     }
     Ok(ret)
 }
+
+pub(crate) fn scheduled_fn_from_parts(
+    zome_name: String,
+    fn_name: String,
+    payload: Option<Vec<u8>>,
+) -> ScheduledFn {
+    let zome_name = ZomeName(zome_name.into());
+    let fn_name = FunctionName(fn_name);
+    match payload {
+        Some(payload) => ScheduledFn::with_payload(zome_name, fn_name, ExternIO(payload)),
+        None => ScheduledFn::new(zome_name, fn_name),
+    }
+}
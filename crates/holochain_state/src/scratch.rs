@@ -24,7 +24,8 @@ pub struct Scratch {
     actions: Vec<SignedActionHashed>,
     entries: HashMap<EntryHash, Arc<Entry>>,
     chain_top_ordering: ChainTopOrdering,
-    scheduled_fns: Vec<ScheduledFn>,
+    scheduled_fns: Vec<(ScheduleId, ScheduledFn)>,
+    cancelled_schedules: Vec<ScheduleId>,
     chain_head: Option<(u32, usize)>,
 }
 
@@ -44,12 +45,20 @@ impl Scratch {
         }
     }
 
-    pub fn scheduled_fns(&self) -> &[ScheduledFn] {
+    pub fn scheduled_fns(&self) -> &[(ScheduleId, ScheduledFn)] {
         &self.scheduled_fns
     }
 
-    pub fn add_scheduled_fn(&mut self, scheduled_fn: ScheduledFn) {
-        self.scheduled_fns.push(scheduled_fn)
+    pub fn add_scheduled_fn(&mut self, schedule_id: ScheduleId, scheduled_fn: ScheduledFn) {
+        self.scheduled_fns.push((schedule_id, scheduled_fn))
+    }
+
+    pub fn cancelled_schedules(&self) -> &[ScheduleId] {
+        &self.cancelled_schedules
+    }
+
+    pub fn add_cancelled_schedule(&mut self, schedule_id: ScheduleId) {
+        self.cancelled_schedules.push(schedule_id)
     }
 
     pub fn chain_top_ordering(&self) -> ChainTopOrdering {
@@ -107,7 +116,9 @@ impl Scratch {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.actions.is_empty() && self.scheduled_fns.is_empty()
+        self.actions.is_empty()
+            && self.scheduled_fns.is_empty()
+            && self.cancelled_schedules.is_empty()
     }
 
     pub fn actions(&self) -> impl Iterator<Item = &SignedActionHashed> {
@@ -160,10 +171,14 @@ impl Scratch {
         Ok(r)
     }
 
-    pub fn drain_scheduled_fns(&mut self) -> impl Iterator<Item = ScheduledFn> + '_ {
+    pub fn drain_scheduled_fns(&mut self) -> impl Iterator<Item = (ScheduleId, ScheduledFn)> + '_ {
         self.scheduled_fns.drain(..)
     }
 
+    pub fn drain_cancelled_schedules(&mut self) -> impl Iterator<Item = ScheduleId> + '_ {
+        self.cancelled_schedules.drain(..)
+    }
+
     /// Drain out all the actions.
     pub fn drain_actions(&mut self) -> impl Iterator<Item = SignedActionHashed> + '_ {
         self.chain_head = None;
@@ -246,7 +246,7 @@ impl SourceChain {
             return Ok(Vec::new());
         }
 
-        let (scheduled_fns, actions, ops, entries, records) =
+        let (scheduled_fns, cancelled_schedules, actions, ops, entries, records) =
             self.scratch.apply_and_then(|scratch| {
                 let records: Vec<Record> = scratch.records().collect();
 
@@ -256,7 +256,15 @@ impl SourceChain {
                 // Drain out any entries.
                 let entries = scratch.drain_entries().collect::<Vec<_>>();
                 let scheduled_fns = scratch.drain_scheduled_fns().collect::<Vec<_>>();
-                SourceChainResult::Ok((scheduled_fns, actions, ops, entries, records))
+                let cancelled_schedules = scratch.drain_cancelled_schedules().collect::<Vec<_>>();
+                SourceChainResult::Ok((
+                    scheduled_fns,
+                    cancelled_schedules,
+                    actions,
+                    ops,
+                    entries,
+                    records,
+                ))
             })?;
 
         let maybe_countersigned_entry = entries
@@ -358,8 +366,11 @@ impl SourceChain {
         let chain_flush_result = self
             .vault
             .write_async_with_permit(write_permit, move |txn| {
-                for scheduled_fn in scheduled_fns {
-                    schedule_fn(txn, author.as_ref(), scheduled_fn, None, now)?;
+                for (schedule_id, scheduled_fn) in scheduled_fns {
+                    schedule_fn(txn, author.as_ref(), schedule_id, scheduled_fn, None, now)?;
+                }
+                for schedule_id in cancelled_schedules {
+                    cancel_schedule(txn, author.as_ref(), &schedule_id)?;
                 }
 
                 if actions.last().is_none() {
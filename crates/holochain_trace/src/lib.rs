@@ -100,6 +100,8 @@ mod writer;
 
 mod open;
 
+#[cfg(feature = "opentelemetry-on")]
+pub use open::{init_open_telemetry, init_open_telemetry_with_exporter, shutdown_open_telemetry};
 pub use open::{Config, Context, MsgWrap, OpenSpanExt};
 
 use crate::writer::InMemoryWriter;
@@ -351,5 +353,8 @@ pub mod errors {
         TracingFlame,
         #[error(transparent)]
         BadDirective(#[from] tracing_subscriber::filter::ParseError),
+        #[cfg(feature = "opentelemetry-on")]
+        #[error(transparent)]
+        OpenTelemetry(#[from] opentelemetry::trace::TraceError),
     }
 }
@@ -1,11 +1,17 @@
+#[cfg(not(feature = "opentelemetry-on"))]
 pub use off::*;
+#[cfg(feature = "opentelemetry-on")]
+pub use on::*;
 
 pub use context_wrap::MsgWrap;
 
 mod context_wrap;
 
+#[cfg(not(feature = "opentelemetry-on"))]
 #[allow(missing_docs)]
 mod off;
+#[cfg(feature = "opentelemetry-on")]
+mod on;
 
 /// Opentelemetry span extension trait.
 /// This trait provides helper methods to the
@@ -17,7 +23,8 @@ pub trait OpenSpanExt {
     /// Get the context of the current span.
     fn get_current_context() -> Context;
 
-    /// Get the current span as message pack bytes.
+    /// Get the context of the current span as bytes, for sending to
+    /// another process. These are empty if the span isn't being exported.
     fn get_current_bytes() -> Vec<u8>;
 
     /// Set the context of this span.
@@ -26,7 +33,8 @@ pub trait OpenSpanExt {
     /// Set the context of the current span.
     fn set_current_context(context: Context);
 
-    /// Set the current span context from message pack bytes.
+    /// Set the context of the current span from bytes received from
+    /// another process. Empty bytes leave the context unchanged.
     fn set_current_bytes(bytes: Vec<u8>);
 
     /// Display this spans context as a String.
@@ -0,0 +1,177 @@
+use super::*;
+use crate::errors::TracingError;
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::export::trace::SpanExporter;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use std::collections::HashMap;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{
+    filter::EnvFilter, layer::SubscriberExt, util::SubscriberInitExt, Layer, Registry,
+};
+
+/// The opentelemetry context of a span.
+#[derive(Debug, Clone, Default)]
+pub struct Context(opentelemetry::Context);
+
+impl Context {
+    /// Encode this context as W3C trace context headers, or as no bytes at
+    /// all if it doesn't belong to a recorded span.
+    fn to_bytes(&self) -> Vec<u8> {
+        if !self.0.span().span_context().is_valid() {
+            return Vec::with_capacity(0);
+        }
+        let mut carrier = HashMap::new();
+        TraceContextPropagator::new().inject_context(&self.0, &mut carrier);
+        serde_json::to_vec(&carrier).unwrap_or_default()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return None;
+        }
+        let carrier: HashMap<String, String> = serde_json::from_slice(bytes).ok()?;
+        Some(Self(TraceContextPropagator::new().extract(&carrier)))
+    }
+}
+
+/// Where and how spans are exported to an opentelemetry collector.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The url of the OTLP collector, e.g. `http://localhost:4317`.
+    /// If not set, the `OTEL_EXPORTER_OTLP_ENDPOINT` environment variable
+    /// is used, falling back to the OTLP default.
+    pub collector_endpoint: Option<String>,
+    /// The `service.name` that exported spans are tagged with.
+    pub service_name: String,
+    /// Which spans to export, as [`EnvFilter`] directives.
+    /// This is separate from `RUST_LOG` so that spans can be exported
+    /// without logging them to the console.
+    pub span_filter: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            collector_endpoint: None,
+            service_name: "holochain".to_string(),
+            span_filter: "info".to_string(),
+        }
+    }
+}
+
+impl Config {
+    fn resource(&self) -> opentelemetry_sdk::Resource {
+        opentelemetry_sdk::Resource::new(vec![opentelemetry::KeyValue::new(
+            "service.name",
+            self.service_name.clone(),
+        )])
+    }
+}
+
+/// Log to the console as with [`Output::Log`](crate::Output::Log), and export
+/// spans to the OTLP collector in the [`Config`].
+///
+/// Spans are exported in batches from a tokio task, so this must be called
+/// from within a tokio runtime.
+pub fn init_open_telemetry(config: Config) -> Result<(), TracingError> {
+    let mut exporter = opentelemetry_otlp::new_exporter().tonic();
+    if let Some(endpoint) = &config.collector_endpoint {
+        exporter = exporter.with_endpoint(endpoint.clone());
+    }
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(exporter)
+        .with_trace_config(opentelemetry_sdk::trace::config().with_resource(config.resource()))
+        .install_batch(opentelemetry_sdk::runtime::Tokio)?;
+    init_with_tracer(tracer, &config)
+}
+
+/// Like [`init_open_telemetry`], but export spans to the given exporter as
+/// soon as they end rather than to an OTLP collector.
+///
+/// This lets tests stand in for the collector, for example with the
+/// `InMemorySpanExporter` of `opentelemetry_sdk`.
+pub fn init_open_telemetry_with_exporter<E>(exporter: E, config: Config) -> Result<(), TracingError>
+where
+    E: SpanExporter + 'static,
+{
+    let provider = opentelemetry_sdk::trace::TracerProvider::builder()
+        .with_simple_exporter(exporter)
+        .with_config(opentelemetry_sdk::trace::config().with_resource(config.resource()))
+        .build();
+    let tracer = provider.tracer("holochain");
+    opentelemetry::global::set_tracer_provider(provider);
+    init_with_tracer(tracer, &config)
+}
+
+/// Export the spans which haven't been exported yet, and stop exporting.
+pub fn shutdown_open_telemetry() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+fn init_with_tracer(
+    tracer: opentelemetry_sdk::trace::Tracer,
+    config: &Config,
+) -> Result<(), TracingError> {
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+    Registry::default()
+        .with(crate::standard_layer(std::io::stderr)?)
+        .with(
+            tracing_opentelemetry::layer()
+                .with_tracer(tracer)
+                .with_filter(EnvFilter::try_new(&config.span_filter)?),
+        )
+        .init();
+    Ok(())
+}
+
+impl OpenSpanExt for tracing::Span {
+    fn get_current_context() -> Context {
+        tracing::Span::current().get_context()
+    }
+
+    fn get_context(&self) -> Context {
+        Context(OpenTelemetrySpanExt::context(self))
+    }
+
+    fn get_current_bytes() -> Vec<u8> {
+        tracing::Span::current().get_context().to_bytes()
+    }
+
+    fn set_context(&self, context: Context) {
+        self.set_parent(context.0);
+    }
+
+    fn set_current_context(context: Context) {
+        tracing::Span::current().set_context(context);
+    }
+
+    fn set_current_bytes(bytes: Vec<u8>) {
+        if let Some(context) = Context::from_bytes(&bytes) {
+            tracing::Span::set_current_context(context);
+        }
+    }
+
+    fn display_context(&self) -> String {
+        self.get_context().to_string()
+    }
+}
+
+impl std::fmt::Display for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let span = self.0.span();
+        let span_context = span.span_context();
+        if span_context.is_valid() {
+            write!(
+                f,
+                "trace_id={} span_id={}",
+                span_context.trace_id(),
+                span_context.span_id()
+            )
+        } else {
+            Ok(())
+        }
+    }
+}
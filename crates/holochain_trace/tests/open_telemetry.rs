@@ -1,83 +1,40 @@
-/*
-use holochain_trace::{span_context, Context, OpenSpanExt};
-use tokio::sync::mpsc;
-use tracing::*;
+#![cfg(feature = "opentelemetry-on")]
 
-#[tokio::test(threaded_scheduler)]
-async fn same_thread_test() {
-    holochain_trace::test_run_open().ok();
-    let span = debug_span!("span a");
-    let context = span.get_context();
-    let _g = span.enter();
+use holochain_trace::{Config, OpenSpanExt};
+use opentelemetry_sdk::testing::trace::InMemorySpanExporter;
 
-    span_context!(span, Level::DEBUG);
-    debug!(msg = "in span a");
+#[tokio::test(flavor = "multi_thread")]
+async fn context_crosses_process_boundary() {
+    let exporter = InMemorySpanExporter::default();
+    holochain_trace::init_open_telemetry_with_exporter(exporter.clone(), Config::default())
+        .unwrap();
 
-    let span = debug_span!("span b");
-    let _g = span.enter();
-    debug!("in span b");
-    span_context!(span, Level::DEBUG);
+    // Outside of a span there is no context to send.
+    assert!(tracing::Span::get_current_bytes().is_empty());
 
-    let span = debug_span!("span c");
-    span.set_context(context);
-    span_context!(span, Level::DEBUG);
-    let _g = span.enter();
-    debug!("in span c");
-}
-
-#[tokio::test(threaded_scheduler)]
-async fn cross_thread_test() {
-    holochain_trace::test_run_open().ok();
-    let (mut tx1, rx1) = mpsc::channel(100);
-    let (tx2, mut rx2) = mpsc::channel(100);
-    tokio::task::spawn(across_thread(rx1, tx2));
-    {
-        let span = debug_span!("from original thread");
-        let context = span.get_context();
-        let _g = span.enter();
-        span_context!(span, Level::DEBUG);
-        tx1.send(context).await.unwrap();
-    }
-    {
-        let context = rx2.recv().await.unwrap();
-
-        let span = debug_span!("original thread");
-        span.set_context(context);
-        span_context!(span, Level::DEBUG);
-        let _g = span.enter();
-        let span = debug_span!("inner");
-        let _g = span.enter();
-        span_context!(span, Level::DEBUG);
-    }
-    {
-        let context = rx2.recv().await.unwrap();
-
-        let span = debug_span!("original thread");
-        span.set_context(context);
-        span_context!(span, Level::DEBUG);
-        let _g = span.enter();
-    }
-}
-
-async fn across_thread(mut rx: mpsc::Receiver<Context>, mut tx: mpsc::Sender<Context>) {
-    {
-        let context = rx.recv().await.unwrap();
-        let span = debug_span!("across thread");
-        span.set_context(context);
-        span_context!(span, Level::DEBUG);
-        let _g = span.enter();
-        let span = debug_span!("inner");
+    let bytes = {
+        let span = tracing::info_span!("sender");
         let _g = span.enter();
-        span_context!(span, Level::DEBUG);
-        tx.send(span.get_context()).await.unwrap();
-    }
-    tokio::time::delay_for(std::time::Duration::from_millis(100)).await;
-    {
-        let span = debug_span!("from another thread");
-        let context = span.get_context();
+        tracing::Span::get_current_bytes()
+    };
+    assert!(!bytes.is_empty());
+
+    // The receiving span continues the trace of the sending span,
+    // as it would in a remote conductor.
+    tokio::task::spawn_blocking(move || {
+        let span = tracing::info_span!("receiver");
         let _g = span.enter();
-        span_context!(span, Level::DEBUG);
-        tx.send(context).await.unwrap();
-    }
+        tracing::Span::set_current_bytes(bytes);
+    })
+    .await
+    .unwrap();
+
+    let spans = exporter.get_finished_spans().unwrap();
+    let sender = spans.iter().find(|s| s.name == "sender").unwrap();
+    let receiver = spans.iter().find(|s| s.name == "receiver").unwrap();
+    assert_eq!(
+        sender.span_context.trace_id(),
+        receiver.span_context.trace_id()
+    );
+    assert_eq!(sender.span_context.span_id(), receiver.parent_span_id);
 }
-*/
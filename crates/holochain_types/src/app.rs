@@ -10,6 +10,7 @@
 mod app_bundle;
 mod app_manifest;
 mod error;
+mod wasm_limits;
 
 use crate::{dna::DnaBundle, prelude::*};
 pub use app_bundle::*;
@@ -24,6 +25,7 @@ use holochain_zome_types::cell::CloneId;
 use holochain_zome_types::prelude::*;
 use indexmap::IndexMap;
 use std::{collections::HashMap, path::PathBuf};
+pub use wasm_limits::*;
 
 /// The unique identifier for an installed app in this conductor
 pub type InstalledAppId = String;
@@ -187,6 +189,12 @@ pub struct InstallAppPayload {
     /// Use only in situations where you know that this is a throwaway key!
     #[serde(default)]
     pub allow_throwaway_random_agent_key: bool,
+
+    /// Optional: Limits on the resources that the zome calls of this app may use,
+    /// for the whole app or for particular roles.
+    /// Limits set for the app in the conductor config take precedence over these.
+    #[serde(default)]
+    pub wasm_limits: Option<AppWasmLimits>,
 }
 
 /// Alias
@@ -491,6 +499,10 @@ pub struct InstalledAppCommon {
 
     /// The timestamp when this app was installed
     pub installed_at: Timestamp,
+
+    /// The resource limits requested for the zome calls of this app when it was installed.
+    #[serde(default)]
+    pub wasm_limits: AppWasmLimits,
 }
 
 impl InstalledAppCommon {
@@ -516,6 +528,7 @@ impl InstalledAppCommon {
             role_assignments,
             manifest,
             installed_at,
+            wasm_limits: Default::default(),
         })
     }
 
@@ -598,6 +611,24 @@ impl InstalledAppCommon {
         )
     }
 
+    /// The name of the role that a cell of this app was provisioned or cloned for.
+    pub fn role_name_of_cell(&self, cell_id: &CellId) -> Option<&RoleName> {
+        if cell_id.agent_pubkey() != &self.agent_key {
+            return None;
+        }
+        let dna_hash = cell_id.dna_hash();
+        self.role_assignments
+            .iter()
+            .find(|(_, role)| {
+                role.as_primary().map_or(false, |p| {
+                    &p.base_dna_hash == dna_hash
+                        || p.clones.values().any(|d| d == dna_hash)
+                        || p.disabled_clones.values().any(|d| d == dna_hash)
+                })
+            })
+            .map(|(role_name, _)| role_name)
+    }
+
     /// Accessor
     pub fn clone_cell_ids(&self) -> impl Iterator<Item = CellId> + '_ {
         self.clone_cells().map(|(_, cell_id)| cell_id)
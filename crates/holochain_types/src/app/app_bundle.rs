@@ -1,6 +1,10 @@
 //! An App Bundle is an AppManifest bundled together with DNA bundles.
 
-use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    sync::Arc,
+};
 
 use super::{AppManifest, AppManifestValidated};
 use crate::prelude::*;
@@ -117,6 +121,37 @@ impl AppBundle {
             )
     }
 
+    /// Resolve the DNA of every role that has one, with the modifiers of the
+    /// role applied, along with the hash the DNA would have without them.
+    ///
+    /// Roles that use an existing cell have no DNA of their own and are left out.
+    pub async fn resolve_role_dnas(
+        &self,
+    ) -> AppBundleResult<BTreeMap<RoleName, (DnaFile, DnaHash)>> {
+        let AppManifestValidated { name: _, roles } = self.manifest().clone().validate()?;
+        let mut dnas = BTreeMap::new();
+        for (role_name, role) in roles {
+            let (location, modifiers) = match role {
+                AppRoleManifestValidated::Create {
+                    location,
+                    modifiers,
+                    ..
+                }
+                | AppRoleManifestValidated::CloneOnly {
+                    location,
+                    modifiers,
+                    ..
+                } => (location, modifiers),
+                AppRoleManifestValidated::UseExisting { .. } => continue,
+            };
+            dnas.insert(
+                role_name,
+                self.resolve_location(&location, modifiers).await?,
+            );
+        }
+        Ok(dnas)
+    }
+
     async fn resolve_cell(
         &self,
         dna_store: &impl DnaStore,
@@ -71,3 +71,23 @@ async fn provisioning_1_create() {
     };
     assert_eq!(resolution, expected);
 }
+
+/// Test that the DNA of each role is resolved with the role's modifiers applied
+#[tokio::test]
+async fn resolve_role_dnas_applies_modifiers() {
+    let modifiers = DnaModifiersOpt {
+        properties: None,
+        network_seed: Some("network_seed".into()),
+        origin_time: None,
+        quantum_time: None,
+    };
+    let (bundle, dna) = app_bundle_fixture(modifiers).await;
+    let original_hash = dna.dna_hash().clone();
+    let modified = dna.with_network_seed("network_seed".to_string()).await;
+
+    let dnas = bundle.resolve_role_dnas().await.unwrap();
+    assert_eq!(1, dnas.len());
+    let (dna_file, unmodified_hash) = &dnas["role_name"];
+    assert_eq!(modified.dna_hash(), dna_file.dna_hash());
+    assert_eq!(&original_hash, unmodified_hash);
+}
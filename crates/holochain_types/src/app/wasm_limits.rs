@@ -0,0 +1,118 @@
+use holochain_zome_types::prelude::RoleName;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Limits on the resources that zome calls may use.
+///
+/// Every limit is optional. A limit which is not set falls back to the limit
+/// set at the next level down, in this order:
+///
+/// 1. The role in the conductor config
+/// 2. The app in the conductor config
+/// 3. The role at app installation
+/// 4. The app at app installation
+/// 5. The conductor config default
+/// 6. The built-in limits of the conductor
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WasmLimits {
+    /// The wasm metering points that a single zome call may consume
+    /// before it is stopped.
+    #[serde(default)]
+    pub max_metering_points: Option<u64>,
+
+    /// The wall-clock time that the wasm of a zome call may run, including
+    /// the time its host functions spend waiting on the network, before the
+    /// call fails. Nothing the call wrote to the source chain is committed.
+    ///
+    /// The call fails once this time has passed, but wasm that is still
+    /// running is only stopped when it runs out of metering points, and it
+    /// counts towards `max_concurrent_calls` until it has stopped.
+    #[serde(default)]
+    pub max_call_duration: Option<Duration>,
+
+    /// The 64 KiB pages of memory that the wasm instance of a zome call may
+    /// grow to. The call fails if it needs more.
+    ///
+    /// With the `wasmer_wamr` feature, memory can't be capped as it grows,
+    /// so it's only checked once the call has finished.
+    #[serde(default)]
+    pub max_memory_pages: Option<u32>,
+
+    /// The zome calls that may run at the same time across the cells of
+    /// a role. Calls made beyond this are rejected, not queued.
+    ///
+    /// Calls made from one zome function to another don't count towards
+    /// this limit.
+    #[serde(default)]
+    pub max_concurrent_calls: Option<usize>,
+}
+
+impl WasmLimits {
+    /// Use the limits of `self`, falling back to those of `fallback` for
+    /// any limit which is not set.
+    pub fn or(self, fallback: &WasmLimits) -> WasmLimits {
+        WasmLimits {
+            max_metering_points: self.max_metering_points.or(fallback.max_metering_points),
+            max_call_duration: self.max_call_duration.or(fallback.max_call_duration),
+            max_memory_pages: self.max_memory_pages.or(fallback.max_memory_pages),
+            max_concurrent_calls: self.max_concurrent_calls.or(fallback.max_concurrent_calls),
+        }
+    }
+}
+
+/// The [`WasmLimits`] of an app, and of particular roles within it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AppWasmLimits {
+    /// Limits for all roles of the app.
+    #[serde(default)]
+    pub app: WasmLimits,
+
+    /// Limits for particular roles, which override those set for the app.
+    #[serde(default)]
+    pub roles: HashMap<RoleName, WasmLimits>,
+}
+
+impl AppWasmLimits {
+    /// The limits for a role of the app, falling back to those set for the
+    /// app for any limit not set for the role.
+    pub fn for_role(&self, role_name: &RoleName) -> WasmLimits {
+        self.roles
+            .get(role_name)
+            .cloned()
+            .unwrap_or_default()
+            .or(&self.app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_limits_override_app_limits() {
+        let limits = AppWasmLimits {
+            app: WasmLimits {
+                max_metering_points: Some(100),
+                max_concurrent_calls: Some(4),
+                ..Default::default()
+            },
+            roles: [(
+                "role".to_string(),
+                WasmLimits {
+                    max_metering_points: Some(10),
+                    ..Default::default()
+                },
+            )]
+            .into_iter()
+            .collect(),
+        };
+
+        let role = limits.for_role(&"role".to_string());
+        assert_eq!(Some(10), role.max_metering_points);
+        assert_eq!(Some(4), role.max_concurrent_calls);
+        assert_eq!(None, role.max_call_duration);
+
+        let other = limits.for_role(&"other".to_string());
+        assert_eq!(Some(100), other.max_metering_points);
+    }
+}
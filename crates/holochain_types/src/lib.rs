@@ -29,6 +29,7 @@ pub mod entry;
 pub mod link;
 mod macros;
 pub mod metadata;
+pub mod network;
 pub mod prelude;
 pub mod rate_limit;
 pub mod record;
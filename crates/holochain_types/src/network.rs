@@ -0,0 +1,19 @@
+//! Types describing the state of the conductor's network connections.
+
+use holochain_serialized_bytes::prelude::*;
+use holochain_zome_types::prelude::*;
+
+/// A peer whose connection preflight was rejected because its network
+/// compatibility params don't match ours, e.g. it is configured with a
+/// different `network_id`.
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes, PartialEq, Eq)]
+pub struct PreflightRejection {
+    /// The url of the rejected peer.
+    pub peer_url: String,
+
+    /// Why the peer was rejected.
+    pub reason: String,
+
+    /// When the peer was last rejected.
+    pub timestamp: Timestamp,
+}
@@ -65,6 +65,63 @@ impl Call {
     }
 }
 
+/// The most agents a single `call_remote_many` can call.
+pub const CALL_REMOTE_MANY_MAX_AGENTS: usize = 100;
+
+/// Input to `call_remote_many`: the same remote call made to several agents at once.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CallRemoteManyInput {
+    /// The agents to call. Duplicates are only called once, and there can be
+    /// no more than [`CALL_REMOTE_MANY_MAX_AGENTS`] of them.
+    pub agents: Vec<AgentPubKey>,
+    pub zome_name: ZomeName,
+    pub fn_name: FunctionName,
+    pub cap_secret: Option<CapSecret>,
+    pub payload: ExternIO,
+    /// How long to wait for responses before giving up on the remaining agents.
+    pub timeout: std::time::Duration,
+    /// Stop waiting as soon as this many agents have responded with
+    /// [`ZomeCallResponse::Ok`]. `None` waits for every agent or the timeout.
+    pub min_responses: Option<usize>,
+}
+
+impl CallRemoteManyInput {
+    pub fn new(
+        agents: Vec<AgentPubKey>,
+        zome_name: ZomeName,
+        fn_name: FunctionName,
+        cap_secret: Option<CapSecret>,
+        payload: ExternIO,
+        timeout: std::time::Duration,
+        min_responses: Option<usize>,
+    ) -> Self {
+        Self {
+            agents,
+            zome_name,
+            fn_name,
+            cap_secret,
+            payload,
+            timeout,
+            min_responses,
+        }
+    }
+}
+
+/// The outcome of calling a single agent as part of a `call_remote_many`.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum RemoteCallResult {
+    /// The agent answered, or the network reported an error for this agent.
+    Response(ZomeCallResponse),
+    /// The timeout elapsed before the agent answered.
+    TimedOut,
+    /// Enough other agents answered to meet `min_responses`, so this agent's
+    /// call was abandoned.
+    NotAwaited,
+}
+
+/// Per agent results of a `call_remote_many`.
+pub type CallRemoteManyOutput = std::collections::BTreeMap<AgentPubKey, RemoteCallResult>;
+
 #[allow(missing_docs)]
 pub trait CallbackResult: Sized {
     /// if a callback result is definitive we should halt any further iterations over remaining
@@ -163,6 +163,111 @@ impl GetInput {
     }
 }
 
+/// Zome input for get_many calls.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct GetManyInput {
+    /// The DHT hashes to get, in the order the results should be returned.
+    pub any_dht_hashes: Vec<holo_hash::AnyDhtHash>,
+    /// Options for the call, shared by every hash.
+    pub get_options: crate::entry::GetOptions,
+}
+
+impl GetManyInput {
+    /// Constructor.
+    pub fn new(
+        any_dht_hashes: Vec<holo_hash::AnyDhtHash>,
+        get_options: crate::entry::GetOptions,
+    ) -> Self {
+        Self {
+            any_dht_hashes,
+            get_options,
+        }
+    }
+}
+
+/// The result of getting one of the hashes in a get_many call.
+/// An error getting one hash is returned as a message instead of failing
+/// the whole call.
+pub type GetManyResult = Result<Option<crate::record::Record>, String>;
+
+/// How get_latest chooses between concurrent updates of the same action.
+///
+/// Every policy falls back to comparing action hashes, so all agents that
+/// can see the same updates choose the same one.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum UpdateTieBreak {
+    /// The update with the latest timestamp wins.
+    #[default]
+    Timestamp,
+    /// Updates by the author of the action being updated win over updates
+    /// by anyone else. Among those the latest timestamp wins.
+    Author,
+    /// The update with the highest action hash wins.
+    /// Authors choose their own timestamps, hashes they can't easily choose.
+    Hash,
+}
+
+impl UpdateTieBreak {
+    /// Sort the `updates` of an action authored by `author` so that the
+    /// preferred update comes first.
+    pub fn sort_updates(
+        &self,
+        author: &holo_hash::AgentPubKey,
+        updates: &mut [crate::record::SignedActionHashed],
+    ) {
+        updates.sort_by(|a, b| {
+            let by_hash = b.as_hash().cmp(a.as_hash());
+            let by_timestamp = b.action().timestamp().cmp(&a.action().timestamp());
+            match self {
+                Self::Timestamp => by_timestamp.then(by_hash),
+                Self::Author => (b.action().author() == author)
+                    .cmp(&(a.action().author() == author))
+                    .then(by_timestamp)
+                    .then(by_hash),
+                Self::Hash => by_hash,
+            }
+        });
+    }
+}
+
+/// The latest version of a record found by get_latest.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct LatestRecord {
+    /// The latest version that could be fetched.
+    pub record: crate::record::Record,
+    /// The update the tie-break chose after `record` but that couldn't be
+    /// fetched. Another update is never followed in its place, so `record` is
+    /// only the latest version when this is `None`. Calling get_latest again
+    /// from this hash carries on the walk.
+    pub continue_from: Option<holo_hash::ActionHash>,
+}
+
+/// Zome input for get_latest calls.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct GetLatestInput {
+    /// The action to start following updates from.
+    pub action_hash: holo_hash::ActionHash,
+    /// How to choose between concurrent updates.
+    pub tie_break: UpdateTieBreak,
+    /// Options for the call.
+    pub get_options: crate::entry::GetOptions,
+}
+
+impl GetLatestInput {
+    /// Constructor.
+    pub fn new(
+        action_hash: holo_hash::ActionHash,
+        tie_break: UpdateTieBreak,
+        get_options: crate::entry::GetOptions,
+    ) -> Self {
+        Self {
+            action_hash,
+            tie_break,
+            get_options,
+        }
+    }
+}
+
 /// Zome input type for all update operations.
 #[derive(PartialEq, Debug, Deserialize, Serialize, Clone)]
 pub struct UpdateInput {
@@ -41,23 +41,111 @@ impl From<Duration> for Schedule {
     }
 }
 
+/// Identifies a single schedule of a scheduled function.
+///
+/// Returned when scheduling a function with a payload, and used to cancel it.
+#[derive(
+    serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord,
+)]
+#[serde(transparent)]
+pub struct ScheduleId(pub String);
+
+impl std::fmt::Display for ScheduleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<String> for ScheduleId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
 /// A fully qualified scheduled function.
+///
+/// Functions scheduled without a payload are called with their `Option<Schedule>`
+/// and there can only be one schedule for them at a time. Functions scheduled with
+/// a payload are called with a [`ScheduledFnInput`] and each call to schedule them
+/// creates a new, independent schedule.
 #[derive(Debug, Clone, PartialEq)]
-pub struct ScheduledFn(ZomeName, FunctionName);
+pub struct ScheduledFn {
+    zome_name: ZomeName,
+    fn_name: FunctionName,
+    payload: Option<ExternIO>,
+}
 
 impl ScheduledFn {
     /// Constructor.
     pub fn new(zome_name: ZomeName, fn_name: FunctionName) -> Self {
-        Self(zome_name, fn_name)
+        Self {
+            zome_name,
+            fn_name,
+            payload: None,
+        }
+    }
+
+    /// Constructor for a function which will be called with a payload.
+    pub fn with_payload(zome_name: ZomeName, fn_name: FunctionName, payload: ExternIO) -> Self {
+        Self {
+            zome_name,
+            fn_name,
+            payload: Some(payload),
+        }
     }
 
     /// ZomeName accessor.
     pub fn zome_name(&self) -> &ZomeName {
-        &self.0
+        &self.zome_name
     }
 
     /// Function name accessor.
     pub fn fn_name(&self) -> &FunctionName {
-        &self.1
+        &self.fn_name
+    }
+
+    /// Payload accessor.
+    pub fn payload(&self) -> Option<&ExternIO> {
+        self.payload.as_ref()
     }
 }
+
+/// Input to the `schedule_with_payload` host function.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct ScheduleWithPayloadInput {
+    /// The function in the current zome to schedule.
+    pub fn_name: FunctionName,
+    /// The serialized payload the function will be called with.
+    pub payload: ExternIO,
+}
+
+/// The input to a function scheduled with a payload.
+///
+/// The function returns `Option<Schedule>` in the same way as functions scheduled
+/// without a payload.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct ScheduledFnInput {
+    /// The schedule that triggered this call.
+    pub schedule_id: ScheduleId,
+    /// The current schedule, `None` if this is the first call.
+    pub schedule: Option<Schedule>,
+    /// The payload given when the function was scheduled.
+    pub payload: ExternIO,
+}
+
+/// A schedule which has not yet been completed or cancelled.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct PendingSchedule {
+    /// The id to cancel this schedule with.
+    pub schedule_id: ScheduleId,
+    /// The zome of the scheduled function.
+    pub zome_name: ZomeName,
+    /// The scheduled function.
+    pub fn_name: FunctionName,
+    /// The current schedule, `None` if the function has not been called yet.
+    pub schedule: Option<Schedule>,
+    /// The payload the function will be called with, if any.
+    pub payload: Option<ExternIO>,
+    /// The earliest time the function will next be called.
+    pub next_call_at: Timestamp,
+}
@@ -29,3 +29,33 @@ pub struct RemoteSignal {
     /// The signal to send.
     pub signal: ExternIO,
 }
+
+/// Identifies a remote signal sent with acknowledgement, so that recipients
+/// can drop copies of a signal they have already received.
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct RemoteSignalId(pub String);
+
+/// Remote signal many agents and wait for their conductors to acknowledge it.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct RemoteSignalWithAck {
+    /// Agents to send the signal to.
+    pub agents: Vec<AgentPubKey>,
+    /// The signal to send.
+    pub signal: ExternIO,
+    /// How long to keep retrying agents that have not acknowledged the signal.
+    pub retry_for: std::time::Duration,
+}
+
+/// Which agents acknowledged a [`RemoteSignalWithAck`].
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct RemoteSignalDelivery {
+    /// The id the signal was sent with.
+    pub signal_id: RemoteSignalId,
+    /// Agents whose conductors acknowledged the signal.
+    pub delivered: Vec<AgentPubKey>,
+    /// Agents that could not be reached before retrying stopped.
+    pub undelivered: Vec<AgentPubKey>,
+}
@@ -59,17 +59,14 @@ fn bootstrap(bench: &mut Criterion) {
     group.bench_function(BenchmarkId::new("test", "put".to_string()), |b| {
         b.iter(|| {
             runtime.block_on(async {
-                let info = AgentInfoSigned::sign(
+                let info = kitsune_p2p_bootstrap::test_utils::signed_agent_info(
                     space.clone(),
-                    Arc::new(fixt!(KitsuneAgent, Unpredictable)),
                     ArqSize::from_half_len(u32::MAX / 4),
                     fixt!(UrlList, Empty),
                     0,
                     std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 + 60_000_000,
-                    |_| async move { Ok(Arc::new(fixt!(KitsuneSignature, Unpredictable))) },
                 )
-                .await
-                .unwrap();
+                .await;
                 let _: Option<()> = do_api(url.clone(), "put", info, &client)
                     .await
                     .unwrap()
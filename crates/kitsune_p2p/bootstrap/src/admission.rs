@@ -0,0 +1,260 @@
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
+use std::time::Duration;
+
+use kitsune_p2p_types::bin_types::KitsuneSpace;
+use parking_lot::Mutex;
+use tokio::time::Instant;
+use warp::http::StatusCode;
+use warp::Filter;
+
+static REJECTED_RATE_LIMITED: AtomicUsize = AtomicUsize::new(0);
+static REJECTED_SPACE_NOT_ALLOWED: AtomicUsize = AtomicUsize::new(0);
+static REJECTED_SPACE_FULL: AtomicUsize = AtomicUsize::new(0);
+
+/// Limits on what the bootstrap server accepts, to stop a single client
+/// from exhausting its memory. The default places no limits at all.
+#[derive(Clone, Debug, Default)]
+pub struct AdmissionConfig {
+    /// The number of requests a single IP address may make per
+    /// [`AdmissionConfig::rate_limit_window`].
+    pub max_requests_per_ip: Option<u32>,
+    /// The window over which [`AdmissionConfig::max_requests_per_ip`] is counted.
+    /// Defaults to one minute if unset.
+    pub rate_limit_window: Option<Duration>,
+    /// The maximum number of agents stored for a single space. Agents which
+    /// are already stored can still update their agent info when it is full.
+    pub max_agents_per_space: Option<usize>,
+    /// If set, only agent infos for these spaces are accepted.
+    pub allowed_spaces: Option<HashSet<Arc<KitsuneSpace>>>,
+}
+
+impl AdmissionConfig {
+    fn window(&self) -> Duration {
+        self.rate_limit_window.unwrap_or(Duration::from_secs(60))
+    }
+}
+
+/// Counts of requests rejected by the [`AdmissionConfig`] of any bootstrap
+/// server running in this process.
+///
+/// Servers also answer these with the `metrics` op.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RejectionMetrics {
+    /// Requests rejected because their IP address exceeded its rate limit.
+    pub rate_limited: usize,
+    /// Puts rejected because their space is not in the allow-list.
+    pub space_not_allowed: usize,
+    /// Puts rejected because their space already holds the maximum number of agents.
+    pub space_full: usize,
+}
+
+/// Get the current [`RejectionMetrics`].
+pub fn rejection_metrics() -> RejectionMetrics {
+    use std::sync::atomic::Ordering::Relaxed;
+    RejectionMetrics {
+        rate_limited: REJECTED_RATE_LIMITED.load(Relaxed),
+        space_not_allowed: REJECTED_SPACE_NOT_ALLOWED.load(Relaxed),
+        space_full: REJECTED_SPACE_FULL.load(Relaxed),
+    }
+}
+
+/// Why a request was refused admission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Rejected {
+    RateLimited,
+    SpaceNotAllowed,
+    SpaceFull,
+}
+
+impl warp::reject::Reject for Rejected {}
+
+impl Rejected {
+    fn count(self) {
+        let counter = match self {
+            Rejected::RateLimited => &REJECTED_RATE_LIMITED,
+            Rejected::SpaceNotAllowed => &REJECTED_SPACE_NOT_ALLOWED,
+            Rejected::SpaceFull => &REJECTED_SPACE_FULL,
+        };
+        counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    fn status(self) -> StatusCode {
+        match self {
+            Rejected::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            Rejected::SpaceNotAllowed => StatusCode::FORBIDDEN,
+            Rejected::SpaceFull => StatusCode::INSUFFICIENT_STORAGE,
+        }
+    }
+}
+
+/// The [`AdmissionConfig`] of a running server along with the request
+/// counts of the current rate limit window for each IP address.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Admission {
+    config: Arc<AdmissionConfig>,
+    windows: Arc<Mutex<HashMap<IpAddr, (Instant, u32)>>>,
+    exempt: Arc<HashSet<IpAddr>>,
+}
+
+impl Admission {
+    pub fn new(config: AdmissionConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            windows: Arc::new(Mutex::new(HashMap::new())),
+            exempt: Default::default(),
+        }
+    }
+
+    /// Never rate limit requests from these IP addresses.
+    pub fn exempting(self, exempt: HashSet<IpAddr>) -> Self {
+        Self {
+            exempt: Arc::new(exempt),
+            ..self
+        }
+    }
+
+    pub fn max_agents_per_space(&self) -> Option<usize> {
+        self.config.max_agents_per_space
+    }
+
+    /// Count a request from `ip`, rejecting it if that exceeds the rate limit.
+    fn check_rate(&self, ip: IpAddr) -> Result<(), Rejected> {
+        let max = match self.config.max_requests_per_ip {
+            Some(max) => max,
+            None => return Ok(()),
+        };
+        if self.exempt.contains(&ip) {
+            return Ok(());
+        }
+        let window = self.config.window();
+        let now = Instant::now();
+        let mut lock = self.windows.lock();
+        let (started, count) = lock.entry(ip).or_insert((now, 0));
+        if now.duration_since(*started) >= window {
+            *started = now;
+            *count = 0;
+        }
+        if *count >= max {
+            return Err(Rejected::RateLimited);
+        }
+        *count += 1;
+        Ok(())
+    }
+
+    pub fn check_space(&self, space: &Arc<KitsuneSpace>) -> Result<(), Rejected> {
+        match &self.config.allowed_spaces {
+            Some(allowed) if !allowed.contains(space) => Err(Rejected::SpaceNotAllowed),
+            _ => Ok(()),
+        }
+    }
+
+    /// Forget the IP addresses whose rate limit window has passed.
+    pub fn prune(&self) {
+        let window = self.config.window();
+        let now = Instant::now();
+        self.windows
+            .lock()
+            .retain(|_, (started, _)| now.duration_since(*started) < window);
+    }
+}
+
+/// Reject the request if its IP address has exceeded its rate limit.
+///
+/// Requests without a remote address, which only happens in tests, are
+/// always admitted.
+pub(crate) fn rate_limit(
+    admission: Admission,
+) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    warp::addr::remote()
+        .and_then(move |addr: Option<SocketAddr>| {
+            let admission = admission.clone();
+            async move {
+                match addr {
+                    Some(addr) => admission
+                        .check_rate(addr.ip())
+                        .map_err(warp::reject::custom),
+                    None => Ok(()),
+                }
+            }
+        })
+        .untuple_one()
+}
+
+/// Turn the rejections of the admission checks into responses with a
+/// matching status code, and count them.
+pub(crate) async fn handle_rejection(
+    rejection: warp::Rejection,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    match rejection.find::<Rejected>() {
+        Some(rejected) => {
+            rejected.count();
+            Ok(warp::reply::with_status(
+                format!("{rejected:?}"),
+                rejected.status(),
+            ))
+        }
+        None => Err(rejection),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limit() {
+        let admission = Admission::new(AdmissionConfig {
+            max_requests_per_ip: Some(2),
+            rate_limit_window: Some(Duration::from_millis(200)),
+            ..Default::default()
+        });
+        let filter = rate_limit(admission.clone())
+            .map(|| "ok")
+            .recover(handle_rejection);
+
+        let request = |ip: [u8; 4]| {
+            warp::test::request()
+                .method("POST")
+                .remote_addr(SocketAddr::from((ip, 1234)))
+        };
+
+        let before = rejection_metrics().rate_limited;
+        for _ in 0..2 {
+            let res = request([10, 0, 0, 1]).reply(&filter).await;
+            assert_eq!(res.status(), StatusCode::OK);
+        }
+        let res = request([10, 0, 0, 1]).reply(&filter).await;
+        assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(rejection_metrics().rate_limited > before);
+
+        // Other addresses have their own limit.
+        let res = request([10, 0, 0, 2]).reply(&filter).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // Exempt addresses have none.
+        let exempt = rate_limit(
+            admission
+                .clone()
+                .exempting([IpAddr::from([10, 0, 0, 9])].into()),
+        )
+        .map(|| "ok")
+        .recover(handle_rejection);
+        for _ in 0..3 {
+            let res = request([10, 0, 0, 9]).reply(&exempt).await;
+            assert_eq!(res.status(), StatusCode::OK);
+        }
+
+        // And the limit is lifted once the window has passed.
+        tokio::time::advance(Duration::from_millis(250)).await;
+        admission.prune();
+        assert!(!admission
+            .windows
+            .lock()
+            .contains_key(&IpAddr::from([10, 0, 0, 2])));
+        let res = request([10, 0, 0, 1]).reply(&filter).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
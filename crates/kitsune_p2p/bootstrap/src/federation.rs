@@ -0,0 +1,199 @@
+//! Replication of agent infos between bootstrap servers.
+//!
+//! Agent infos are signed by their agent, and every server checks that
+//! signature before it stores an agent info, so a server can accept them
+//! from its peers on the same terms as from the agents themselves. Peers are
+//! exempt from the rate limit of the servers they push to, as long as those
+//! servers also list them as peers. Every agent
+//! info which a server newly stores is pushed on to each of its peers with
+//! an ordinary `put`. A peer which already holds that agent info doesn't
+//! store it again, and so doesn't push it any further, which stops an agent
+//! info from bouncing between servers that peer with each other.
+//!
+//! Agent infos which were stored before two servers started to peer are not
+//! replicated, but agents publish their agent info again well before it
+//! expires, so peers catch up within one publishing interval.
+
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use super::*;
+
+/// The HTTP header name for setting the op on POST requests.
+const OP_HEADER: &str = "X-Op";
+
+/// The bootstrap servers which newly stored agent infos are pushed to.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Federation {
+    peers: Arc<Vec<String>>,
+    client: reqwest::Client,
+}
+
+impl Federation {
+    pub fn new(peers: Vec<String>) -> Self {
+        Self {
+            peers: Arc::new(peers),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// The IP addresses of the peers, which are exempt from the rate limit.
+    ///
+    /// A peer whose address can't be resolved is not exempt.
+    pub async fn peer_ips(&self) -> HashSet<IpAddr> {
+        let mut ips = HashSet::new();
+        for peer in self.peers.iter() {
+            let url = match reqwest::Url::parse(peer) {
+                Ok(url) => url,
+                Err(err) => {
+                    tracing::warn!(%peer, ?err, "Invalid bootstrap peer url");
+                    continue;
+                }
+            };
+            let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+                continue;
+            };
+            match tokio::net::lookup_host((host, port)).await {
+                Ok(addrs) => ips.extend(addrs.map(|addr| addr.ip())),
+                Err(err) => {
+                    tracing::warn!(%peer, ?err, "Failed to resolve bootstrap peer");
+                }
+            };
+        }
+        ips
+    }
+
+    /// Push an encoded agent info to every peer in the background.
+    ///
+    /// A peer which can't be reached misses the agent info, which it will
+    /// receive again the next time the agent publishes it.
+    pub fn replicate(&self, encoded: Vec<u8>) {
+        for peer in self.peers.iter() {
+            let request = self
+                .client
+                .post(peer.as_str())
+                .body(encoded.clone())
+                .header(OP_HEADER, "put")
+                .header(reqwest::header::CONTENT_TYPE, "application/octet");
+            let peer = peer.clone();
+            tokio::task::spawn(async move {
+                match request.send().await {
+                    Ok(res) if res.status().is_success() => {
+                        FEDERATED.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    }
+                    Ok(res) => {
+                        tracing::warn!(
+                            %peer,
+                            status = %res.status(),
+                            "Bootstrap peer refused a replicated agent info"
+                        );
+                    }
+                    Err(err) => {
+                        tracing::warn!(
+                            %peer,
+                            ?err,
+                            "Failed to replicate an agent info to bootstrap peer"
+                        );
+                    }
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use ::fixt::prelude::*;
+    use kitsune_p2p_bin_data::fixt::*;
+    use kitsune_p2p_types::agent_info::AgentInfoSigned;
+    use kitsune_p2p_types::bootstrap::{RandomLimit, RandomQuery};
+    use kitsune_p2p_types::dht::arq::ArqSize;
+    use kitsune_p2p_types::fixt::*;
+
+    use crate::BootstrapConfig;
+
+    async fn random(url: &str, query: RandomQuery) -> Vec<AgentInfoSigned> {
+        let mut body = Vec::new();
+        kitsune_p2p_types::codec::rmp_encode(&mut body, query).unwrap();
+        let res = reqwest::Client::new()
+            .post(url)
+            .body(body)
+            .header("X-Op", "random")
+            .send()
+            .await
+            .unwrap();
+        #[derive(serde::Deserialize)]
+        struct Bytes(#[serde(with = "serde_bytes")] Vec<u8>);
+        let result: Vec<Bytes> =
+            kitsune_p2p_types::codec::rmp_decode(&mut res.bytes().await.unwrap().as_ref()).unwrap();
+        result
+            .into_iter()
+            .map(|b| kitsune_p2p_types::codec::rmp_decode(&mut b.0.as_slice()).unwrap())
+            .collect()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_replicates_to_peers() {
+        let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+
+        let (driver_b, addr_b, shutdown_b) = crate::run_with_config(addr, Default::default())
+            .await
+            .unwrap();
+        tokio::spawn(driver_b);
+        let url_b = format!("http://{addr_b}");
+
+        // A pushes the agent infos it stores to B.
+        let (driver_a, addr_a, shutdown_a) = crate::run_with_config(
+            addr,
+            BootstrapConfig {
+                federation_peers: vec![url_b.clone()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        tokio::spawn(driver_a);
+        let url_a = format!("http://{addr_a}");
+
+        let space = Arc::new(fixt!(KitsuneSpace, Unpredictable));
+        let info = crate::test_utils::signed_agent_info(
+            space.clone(),
+            ArqSize::from_half_len(u32::MAX / 4),
+            fixt!(UrlList, Empty),
+            0,
+            std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 + 60_000_000,
+        )
+        .await;
+        let mut body = Vec::new();
+        kitsune_p2p_types::codec::rmp_encode(&mut body, &info).unwrap();
+        let res = reqwest::Client::new()
+            .post(url_a.as_str())
+            .body(body)
+            .header("X-Op", "put")
+            .send()
+            .await
+            .unwrap();
+        assert!(res.status().is_success());
+
+        let query = || RandomQuery {
+            space: space.clone(),
+            limit: RandomLimit(10),
+        };
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while random(&url_b, query()).await.is_empty() {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(vec![info], random(&url_b, query()).await);
+
+        shutdown_a();
+        shutdown_b();
+    }
+}
@@ -4,6 +4,8 @@
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicUsize;
 
+use admission::Admission;
+use federation::Federation;
 use kitsune_p2p_types::codec::rmp_decode;
 use kitsune_p2p_types::codec::rmp_encode;
 use store::Store;
@@ -11,9 +13,15 @@ use warp::{hyper::body::Bytes, Filter};
 
 static NOW: AtomicUsize = AtomicUsize::new(0);
 static RANDOM: AtomicUsize = AtomicUsize::new(0);
+static NEAR: AtomicUsize = AtomicUsize::new(0);
 static PUT: AtomicUsize = AtomicUsize::new(0);
+static FEDERATED: AtomicUsize = AtomicUsize::new(0);
 
+mod admission;
 mod clear;
+mod federation;
+mod metrics;
+mod near;
 mod now;
 mod proxy_list;
 mod put;
@@ -21,11 +29,19 @@ mod random;
 mod store;
 
 pub mod error;
+#[cfg(any(test, feature = "test_utils"))]
+pub mod test_utils;
+
+pub use admission::{rejection_metrics, AdmissionConfig, RejectionMetrics};
 
 /// No reason to accept a peer data bigger then 1KB.
 // TODO: Maybe even that's too high?
 const SIZE_LIMIT: u64 = 1024;
 
+/// The most agent infos a `random` or `near` query returns, whatever limit
+/// the client asks for.
+pub const QUERY_LIMIT_MAX: u32 = 64;
+
 /// how often should we prune the expired entries?
 pub const PRUNE_EXPIRED_FREQ: std::time::Duration = std::time::Duration::from_secs(5);
 
@@ -47,23 +63,141 @@ pub async fn run_with_prune_freq(
     proxy_list: Vec<String>,
     prune_frequency: std::time::Duration,
 ) -> Result<(BootstrapDriver, SocketAddr, BootstrapShutdown), String> {
+    run_with_admission(
+        addr,
+        proxy_list,
+        prune_frequency,
+        AdmissionConfig::default(),
+    )
+    .await
+}
+
+/// Run a bootstrap server with a set prune frequency, which limits the
+/// requests it accepts according to `admission`.
+pub async fn run_with_admission(
+    addr: impl Into<SocketAddr> + 'static,
+    proxy_list: Vec<String>,
+    prune_frequency: std::time::Duration,
+    admission: AdmissionConfig,
+) -> Result<(BootstrapDriver, SocketAddr, BootstrapShutdown), String> {
+    run_with_config(
+        addr,
+        BootstrapConfig {
+            proxy_list,
+            prune_frequency,
+            admission,
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+/// Run a bootstrap server which keeps the agent infos it is sent in the SQLite
+/// database at `database_path`, so that they are still served after a restart.
+///
+/// Expired agent infos are pruned from the database along with the ones in memory.
+#[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+pub async fn run_with_database(
+    addr: impl Into<SocketAddr> + 'static,
+    proxy_list: Vec<String>,
+    prune_frequency: std::time::Duration,
+    database_path: &std::path::Path,
+    admission: AdmissionConfig,
+) -> Result<(BootstrapDriver, SocketAddr, BootstrapShutdown), String> {
+    run_with_config(
+        addr,
+        BootstrapConfig {
+            proxy_list,
+            prune_frequency,
+            admission,
+            database_path: Some(database_path.to_owned()),
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+/// Everything a bootstrap server can be configured with.
+#[derive(Clone, Debug)]
+pub struct BootstrapConfig {
+    /// The proxy servers returned by the `proxy_list` op.
+    pub proxy_list: Vec<String>,
+    /// How often expired agent infos are pruned.
+    pub prune_frequency: std::time::Duration,
+    /// Limits on the requests the server accepts.
+    pub admission: AdmissionConfig,
+    /// The urls of other bootstrap servers to push every newly stored agent
+    /// info to. Peering is one way, so two servers which should share all
+    /// their agent infos list each other.
+    pub federation_peers: Vec<String>,
+    /// Keep the agent infos in the SQLite database at this path, so that
+    /// they are still served after a restart.
+    #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+    pub database_path: Option<std::path::PathBuf>,
+}
+
+impl Default for BootstrapConfig {
+    fn default() -> Self {
+        Self {
+            proxy_list: Vec::new(),
+            prune_frequency: PRUNE_EXPIRED_FREQ,
+            admission: AdmissionConfig::default(),
+            federation_peers: Vec::new(),
+            #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+            database_path: None,
+        }
+    }
+}
+
+/// Run a bootstrap server with the given [`BootstrapConfig`].
+pub async fn run_with_config(
+    addr: impl Into<SocketAddr> + 'static,
+    config: BootstrapConfig,
+) -> Result<(BootstrapDriver, SocketAddr, BootstrapShutdown), String> {
+    let BootstrapConfig {
+        proxy_list,
+        prune_frequency,
+        admission,
+        federation_peers,
+        #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+        database_path,
+    } = config;
+
+    #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+    let store = match database_path {
+        Some(path) => Store::open(proxy_list, &path)
+            .map_err(|e| format!("Failed to open the bootstrap database: {:?}", e))?,
+        None => Store::new(proxy_list),
+    };
+    #[cfg(not(any(feature = "sqlite", feature = "sqlite-encrypted")))]
     let store = Store::new(proxy_list);
 
+    let federation = Federation::new(federation_peers);
+    let admission = Admission::new(admission).exempting(federation.peer_ips().await);
+
     {
         let store = store.clone();
+        let admission = admission.clone();
         tokio::task::spawn(async move {
             loop {
                 tokio::time::sleep(prune_frequency).await;
                 store.prune();
+                admission.prune();
             }
         });
     }
 
-    let boot = now::now()
-        .or(put::put(store.clone()))
-        .or(random::random(store.clone()))
-        .or(proxy_list::proxy_list(store.clone()))
-        .or(clear::clear(store));
+    let boot = admission::rate_limit(admission.clone())
+        .and(
+            now::now()
+                .or(put::put(store.clone(), admission, federation))
+                .or(random::random(store.clone()))
+                .or(near::near(store.clone()))
+                .or(metrics::metrics())
+                .or(proxy_list::proxy_list(store.clone()))
+                .or(clear::clear(store)),
+        )
+        .recover(admission::handle_rejection);
 
     let (s, r) = tokio::sync::oneshot::channel();
     let shutdown = Box::new(move || {
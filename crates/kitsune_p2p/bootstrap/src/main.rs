@@ -12,6 +12,50 @@ struct Args {
     /// multiple times
     #[clap(short, long, verbatim_doc_comment)]
     proxy: Vec<String>,
+
+    /// keep the agent infos in this SQLite
+    /// database, so that they are still served
+    /// after a restart
+    #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+    #[clap(short, long, verbatim_doc_comment)]
+    database: Option<std::path::PathBuf>,
+
+    /// reject requests from an IP address
+    /// once it has made this many in the
+    /// last minute
+    #[clap(long, verbatim_doc_comment)]
+    rate_limit: Option<u32>,
+
+    /// reject agent infos for new agents
+    /// once a space holds this many agents
+    #[clap(long, verbatim_doc_comment)]
+    max_agents_per_space: Option<usize>,
+
+    /// only accept agent infos for this
+    /// base64 encoded space, can be
+    /// specified multiple times
+    #[clap(long, verbatim_doc_comment, value_parser = parse_space)]
+    allow_space: Vec<std::sync::Arc<kitsune_p2p_bin_data::KitsuneSpace>>,
+
+    /// push every agent info this server
+    /// stores to the bootstrap server at
+    /// this url, can be specified multiple
+    /// times
+    #[clap(long, verbatim_doc_comment)]
+    peer: Vec<String>,
+}
+
+fn parse_space(s: &str) -> Result<std::sync::Arc<kitsune_p2p_bin_data::KitsuneSpace>, String> {
+    use base64::Engine;
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(s)
+        .map_err(|e| e.to_string())?;
+    if bytes.len() != 36 {
+        return Err(format!("expected 36 bytes, got {}", bytes.len()));
+    }
+    Ok(std::sync::Arc::new(kitsune_p2p_bin_data::KitsuneSpace(
+        bytes,
+    )))
 }
 
 #[tokio::main(flavor = "multi_thread")]
@@ -27,7 +71,29 @@ async fn main() {
         .next()
         .unwrap();
 
-    match kitsune_p2p_bootstrap::run(addr, args.proxy).await {
+    let admission = kitsune_p2p_bootstrap::AdmissionConfig {
+        max_requests_per_ip: args.rate_limit,
+        rate_limit_window: None,
+        max_agents_per_space: args.max_agents_per_space,
+        allowed_spaces: if args.allow_space.is_empty() {
+            None
+        } else {
+            Some(args.allow_space.into_iter().collect())
+        },
+    };
+
+    let config = kitsune_p2p_bootstrap::BootstrapConfig {
+        proxy_list: args.proxy,
+        admission,
+        federation_peers: args.peer,
+        #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+        database_path: args.database,
+        ..Default::default()
+    };
+
+    let result = kitsune_p2p_bootstrap::run_with_config(addr, config).await;
+
+    match result {
         Ok((driver, addr, _shutdown)) => {
             println!("http://{}", addr);
             driver.await;
@@ -0,0 +1,54 @@
+use super::*;
+use warp::Filter;
+
+/// Serve the [`RejectionMetrics`](crate::RejectionMetrics) of this process.
+pub(crate) fn metrics(
+) -> impl Filter<Extract = impl warp::Reply + Sized, Error = warp::Rejection> + Clone {
+    warp::post()
+        .and(warp::header::exact("X-Op", "metrics"))
+        .and_then(rejections)
+}
+
+async fn rejections() -> Result<impl warp::Reply, warp::Rejection> {
+    let mut buf = Vec::new();
+    rmp_encode(&mut buf, crate::rejection_metrics()).map_err(|_| warp::reject())?;
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::admission::{handle_rejection, rate_limit, Admission};
+    use crate::{AdmissionConfig, RejectionMetrics};
+    use std::net::SocketAddr;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_metrics() {
+        let admission = Admission::new(AdmissionConfig {
+            max_requests_per_ip: Some(0),
+            ..Default::default()
+        });
+        let limited = rate_limit(admission).map(|| "ok").recover(handle_rejection);
+        let filter = super::metrics();
+        let get_metrics = || async {
+            let res = warp::test::request()
+                .method("POST")
+                .header("X-Op", "metrics")
+                .reply(&filter)
+                .await;
+            assert_eq!(res.status(), 200);
+            let metrics: RejectionMetrics = rmp_decode(&mut res.body().as_ref()).unwrap();
+            metrics
+        };
+
+        let before = get_metrics().await;
+        let res = warp::test::request()
+            .method("POST")
+            .remote_addr(SocketAddr::from(([10, 0, 0, 3], 1234)))
+            .reply(&limited)
+            .await;
+        assert_eq!(res.status(), 429);
+        // Other tests in this process may be counting rejections too.
+        assert!(get_metrics().await.rate_limited > before.rate_limited);
+    }
+}
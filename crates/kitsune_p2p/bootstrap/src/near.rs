@@ -0,0 +1,147 @@
+use crate::store::Store;
+
+use super::*;
+use kitsune_p2p_types::bootstrap::NearQuery;
+use warp::Filter;
+
+pub(crate) fn near(
+    store: Store,
+) -> impl Filter<Extract = impl warp::Reply + Sized, Error = warp::Rejection> + Clone {
+    warp::post()
+        .and(warp::header::exact("X-Op", "near"))
+        .and(warp::body::content_length_limit(SIZE_LIMIT))
+        .and(warp::body::bytes())
+        .and(with_store(store))
+        .and_then(near_info)
+}
+
+async fn near_info(query: Bytes, store: Store) -> Result<impl warp::Reply, warp::Rejection> {
+    let query: NearQuery =
+        rmp_decode(&mut AsRef::<[u8]>::as_ref(&query)).map_err(|_| warp::reject())?;
+    #[derive(serde::Serialize)]
+    struct Bin(#[serde(with = "serde_bytes")] Vec<u8>);
+    let result = store.near(query).into_iter().map(Bin).collect::<Vec<_>>();
+    let mut buf = Vec::with_capacity(result.len());
+    rmp_encode(&mut buf, result).map_err(|_| warp::reject())?;
+    NEAR.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use ::fixt::prelude::*;
+    use kitsune_p2p::{agent_store::AgentInfoSigned, KitsuneSpace};
+    use kitsune_p2p_bin_data::{fixt::*, KitsuneBinType};
+    use kitsune_p2p_types::{bootstrap::RandomLimit, dht::arq::ArqSize, tx_utils::TxUrl};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_near() {
+        let store = Store::new(vec![]);
+        let put_filter = crate::put::put(store.clone(), Default::default(), Default::default());
+        let filter = super::near(store.clone());
+        let space: Arc<KitsuneSpace> = Arc::new(fixt!(KitsuneSpace));
+        let mut peers = Vec::new();
+        for _ in 0..20 {
+            let info = crate::test_utils::signed_agent_info(
+                space.clone(),
+                ArqSize::from_half_len(u32::MAX / 8),
+                vec![TxUrl::from_str_panicking("fake:")],
+                0,
+                std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 + 60_000_000,
+            )
+            .await;
+
+            let mut buf = Vec::new();
+            rmp_encode(&mut buf, info.clone()).unwrap();
+            let res = warp::test::request()
+                .method("POST")
+                .header("Content-type", "application/octet")
+                .header("X-Op", "put")
+                .body(buf)
+                .reply(&put_filter)
+                .await;
+            assert_eq!(res.status(), 200);
+            peers.push(info);
+        }
+
+        // The location of the first agent is always covered by its own arc.
+        let basis = peers[0].agent.get_loc();
+        let covering = peers
+            .iter()
+            .filter(|p| p.storage_arq.to_dht_arc_std().contains(basis))
+            .count();
+
+        let query = NearQuery {
+            space,
+            basis,
+            limit: RandomLimit(20),
+        };
+        let mut buf = Vec::new();
+        rmp_encode(&mut buf, query).unwrap();
+
+        let res = warp::test::request()
+            .method("POST")
+            .header("Content-type", "application/octet")
+            .header("X-Op", "near")
+            .body(buf)
+            .reply(&filter)
+            .await;
+        assert_eq!(res.status(), 200);
+        #[derive(Debug, serde::Deserialize)]
+        struct Bytes(#[serde(with = "serde_bytes")] Vec<u8>);
+        let result: Vec<Bytes> = rmp_decode(&mut res.body().as_ref()).unwrap();
+        let result: Vec<AgentInfoSigned> = result
+            .into_iter()
+            .map(|bytes| rmp_decode(&mut AsRef::<[u8]>::as_ref(&bytes.0)).unwrap())
+            .collect();
+
+        assert_eq!(covering, result.len());
+        assert!(result.contains(&peers[0]));
+        for peer in &result {
+            assert!(peer.storage_arq.to_dht_arc_std().contains(basis));
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_near_limit_is_capped() {
+        let store = Store::new(vec![]);
+        let filter = super::near(store.clone());
+        let space: Arc<KitsuneSpace> = Arc::new(fixt!(KitsuneSpace));
+        for _ in 0..crate::QUERY_LIMIT_MAX + 1 {
+            let info = crate::test_utils::signed_agent_info(
+                space.clone(),
+                ArqSize::from_half_len(kitsune_p2p_types::dht_arc::MAX_HALF_LENGTH),
+                vec![TxUrl::from_str_panicking("fake:")],
+                0,
+                std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 + 60_000_000,
+            )
+            .await;
+            let mut enc = Vec::new();
+            rmp_encode(&mut enc, info).unwrap();
+            store.put(crate::store::StoreEntry::parse(enc).unwrap());
+        }
+
+        let query = NearQuery {
+            space,
+            basis: 0.into(),
+            limit: RandomLimit(u32::MAX),
+        };
+        let mut buf = Vec::new();
+        rmp_encode(&mut buf, query).unwrap();
+        let res = warp::test::request()
+            .method("POST")
+            .header("Content-type", "application/octet")
+            .header("X-Op", "near")
+            .body(buf)
+            .reply(&filter)
+            .await;
+        assert_eq!(res.status(), 200);
+        #[derive(Debug, serde::Deserialize)]
+        struct Bytes(#[serde(with = "serde_bytes")] Vec<u8>);
+        let result: Vec<Bytes> = rmp_decode(&mut res.body().as_ref()).unwrap();
+        assert_eq!(crate::QUERY_LIMIT_MAX as usize, result.len());
+    }
+}
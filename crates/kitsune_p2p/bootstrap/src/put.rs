@@ -1,20 +1,31 @@
-use crate::store::{Store, StoreEntry};
+use crate::admission::{Admission, Rejected};
+use crate::federation::Federation;
+use crate::store::{PutOutcome, Store, StoreEntry};
 
 use super::*;
 use warp::Filter;
 
 pub(crate) fn put(
     store: Store,
+    admission: Admission,
+    federation: Federation,
 ) -> impl Filter<Extract = impl warp::Reply + Sized, Error = warp::Rejection> + Clone {
     warp::post()
         .and(warp::header::exact("X-Op", "put"))
         .and(warp::body::content_length_limit(SIZE_LIMIT))
         .and(warp::body::bytes())
         .and(with_store(store))
+        .and(warp::any().map(move || admission.clone()))
+        .and(warp::any().map(move || federation.clone()))
         .and_then(put_info)
 }
 
-async fn put_info(peer: Bytes, store: Store) -> Result<impl warp::Reply, warp::Rejection> {
+async fn put_info(
+    peer: Bytes,
+    store: Store,
+    admission: Admission,
+    federation: Federation,
+) -> Result<impl warp::Reply, warp::Rejection> {
     #[derive(Debug)]
     struct BadDecode(#[allow(dead_code)] String);
     impl warp::reject::Reject for BadDecode {}
@@ -25,7 +36,13 @@ async fn put_info(peer: Bytes, store: Store) -> Result<impl warp::Reply, warp::R
         impl warp::reject::Reject for Invalid {}
         return Err(Invalid.into());
     }
-    store.put(peer);
+    admission.check_space(&peer.space)?;
+    let encoded = peer.encoded.clone();
+    match store.put_limited(peer, admission.max_agents_per_space()) {
+        PutOutcome::Stored => federation.replicate(encoded),
+        PutOutcome::NotNewer => (),
+        PutOutcome::SpaceFull => return Err(Rejected::SpaceFull.into()),
+    }
     PUT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
     let mut buf = Vec::with_capacity(1);
     rmp_encode(&mut buf, ()).map_err(|_| warp::reject())?;
@@ -33,8 +50,7 @@ async fn put_info(peer: Bytes, store: Store) -> Result<impl warp::Reply, warp::R
 }
 
 fn valid(peer: &StoreEntry) -> bool {
-    // TODO: actually verify signature... just checking size for now
-    if peer.signature.len() != 64 {
+    if !peer.verify_signature() {
         return false;
     }
     // Verify time
@@ -57,19 +73,16 @@ mod tests {
     #[tokio::test(flavor = "multi_thread")]
     async fn test_put() {
         let store = Store::new(vec![]);
-        let filter = put(store.clone());
+        let filter = put(store.clone(), Default::default(), Default::default());
 
-        let info = kitsune_p2p_types::agent_info::AgentInfoSigned::sign(
+        let info = crate::test_utils::signed_agent_info(
             Arc::new(fixt!(KitsuneSpace, Unpredictable)),
-            Arc::new(fixt!(KitsuneAgent, Unpredictable)),
             ArqSize::from_half_len(u32::MAX / 4),
             fixt!(UrlList, Empty),
             0,
             std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 + 60_000_000,
-            |_| async move { Ok(Arc::new(fixt!(KitsuneSignature, Unpredictable))) },
         )
-        .await
-        .unwrap();
+        .await;
         let mut buf = Vec::new();
         rmp_encode(&mut buf, info.clone()).unwrap();
 
@@ -95,4 +108,104 @@ mod tests {
             info_as_entry,
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_put_rejects_forged_signature() {
+        let store = Store::new(vec![]);
+        let filter = put(store.clone(), Default::default(), Default::default());
+
+        // Signed by a key other than the agent's.
+        let info = kitsune_p2p_types::agent_info::AgentInfoSigned::sign(
+            Arc::new(fixt!(KitsuneSpace, Unpredictable)),
+            Arc::new(fixt!(KitsuneAgent, Unpredictable)),
+            ArqSize::from_half_len(u32::MAX / 4),
+            fixt!(UrlList, Empty),
+            0,
+            std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 + 60_000_000,
+            |_| async move { Ok(Arc::new(fixt!(KitsuneSignature, Unpredictable))) },
+        )
+        .await
+        .unwrap();
+        let mut buf = Vec::new();
+        rmp_encode(&mut buf, info).unwrap();
+
+        let res = warp::test::request()
+            .method("POST")
+            .header("Content-type", "application/octet")
+            .header("X-Op", "put")
+            .body(buf)
+            .reply(&filter)
+            .await;
+        assert_ne!(res.status(), 200);
+        assert!(store.all().is_empty());
+    }
+
+    async fn signed_info(
+        space: Arc<kitsune_p2p_types::bin_types::KitsuneSpace>,
+    ) -> kitsune_p2p_types::agent_info::AgentInfoSigned {
+        crate::test_utils::signed_agent_info(
+            space,
+            ArqSize::from_half_len(u32::MAX / 4),
+            fixt!(UrlList, Empty),
+            0,
+            std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 + 60_000_000,
+        )
+        .await
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_put_admission() {
+        let allowed_space = Arc::new(fixt!(KitsuneSpace, Unpredictable));
+        let store = Store::new(vec![]);
+        let filter = put(
+            store.clone(),
+            Admission::new(crate::AdmissionConfig {
+                max_agents_per_space: Some(2),
+                allowed_spaces: Some([allowed_space.clone()].into_iter().collect()),
+                ..Default::default()
+            }),
+            Default::default(),
+        )
+        .recover(crate::admission::handle_rejection);
+
+        let put_status = |info: kitsune_p2p_types::agent_info::AgentInfoSigned| {
+            let filter = filter.clone();
+            async move {
+                let mut buf = Vec::new();
+                rmp_encode(&mut buf, info).unwrap();
+                warp::test::request()
+                    .method("POST")
+                    .header("Content-type", "application/octet")
+                    .header("X-Op", "put")
+                    .body(buf)
+                    .reply(&filter)
+                    .await
+                    .status()
+            }
+        };
+
+        let before = crate::rejection_metrics();
+
+        let other_space = signed_info(Arc::new(fixt!(KitsuneSpace, Unpredictable))).await;
+        assert_eq!(put_status(other_space).await, 403);
+
+        let first = signed_info(allowed_space.clone()).await;
+        assert_eq!(put_status(first.clone()).await, 200);
+        assert_eq!(
+            put_status(signed_info(allowed_space.clone()).await).await,
+            200
+        );
+        assert_eq!(
+            put_status(signed_info(allowed_space.clone()).await).await,
+            507
+        );
+
+        // Agents which are already stored can still be updated in a full space.
+        assert_eq!(put_status(first).await, 200);
+        assert_eq!(store.all().get(allowed_space.as_ref()).unwrap().len(), 2);
+
+        let after = crate::rejection_metrics();
+        assert!(after.space_not_allowed > before.space_not_allowed);
+        assert!(after.space_full > before.space_full);
+    }
 }
@@ -38,7 +38,7 @@ mod tests {
     use kitsune_p2p_types::{bootstrap::RandomLimit, dht::arq::ArqSize, tx_utils::TxUrl};
 
     async fn put(store: Store, peers: Vec<AgentInfoSigned>) {
-        let filter = crate::put::put(store);
+        let filter = crate::put::put(store, Default::default(), Default::default());
 
         for peer in peers {
             let mut buf = Vec::new();
@@ -60,17 +60,14 @@ mod tests {
         let store = Store::new(vec![]);
         let filter = super::random(store.clone());
         let space: Arc<KitsuneSpace> = Arc::new(fixt!(KitsuneSpace));
-        let offline_peer = AgentInfoSigned::sign(
+        let offline_peer = crate::test_utils::signed_agent_info(
             space.clone(),
-            Arc::new(fixt!(KitsuneAgent, Unpredictable)),
             ArqSize::from_half_len(u32::MAX / 4),
             vec![], // no url means offline
             0,
             std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 + 60_000_000,
-            |_| async move { Ok(Arc::new(fixt!(KitsuneSignature, Unpredictable))) },
         )
-        .await
-        .unwrap();
+        .await;
         put(store.clone(), vec![offline_peer.clone()]).await;
 
         let query = RandomQuery {
@@ -111,17 +108,14 @@ mod tests {
         let space: Arc<KitsuneSpace> = Arc::new(fixt!(KitsuneSpace));
         let mut peers = Vec::new();
         for _ in 0..20 {
-            let info = AgentInfoSigned::sign(
+            let info = crate::test_utils::signed_agent_info(
                 space.clone(),
-                Arc::new(fixt!(KitsuneAgent, Unpredictable)),
                 ArqSize::from_half_len(u32::MAX / 4),
                 vec![TxUrl::from_str_panicking("fake:")],
                 0,
                 std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 + 60_000_000,
-                |_| async move { Ok(Arc::new(fixt!(KitsuneSignature, Unpredictable))) },
             )
-            .await
-            .unwrap();
+            .await;
             peers.push(info);
         }
         put(store.clone(), peers.clone()).await;
@@ -1,19 +1,24 @@
 use std::{collections::HashMap, sync::Arc};
 
 use kitsune_p2p_types::{
-    bin_types::{KitsuneAgent, KitsuneSignature, KitsuneSpace},
-    bootstrap::RandomQuery,
+    bin_types::{KitsuneAgent, KitsuneBinType, KitsuneSignature, KitsuneSpace},
+    bootstrap::{NearQuery, RandomQuery},
+    dht_arc::DhtArc,
 };
 use parking_lot::RwLock;
 use rand::seq::IteratorRandom;
 
-#[derive(Debug)]
-#[cfg_attr(test, derive(PartialEq, Clone))]
+#[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+mod db;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
 pub(crate) struct StoreEntry {
     pub encoded: Vec<u8>,
     pub signature: Arc<KitsuneSignature>,
     pub space: Arc<KitsuneSpace>,
     pub agent: Arc<KitsuneAgent>,
+    pub storage_arc: DhtArc,
     pub signed_at_ms: u64,
     pub expires_at_ms: u64,
 }
@@ -37,68 +42,168 @@ impl StoreEntry {
             ));
         }
 
+        let mut bytes: &[u8] = &info.meta_info;
+        let meta: kitsune_p2p_types::agent_info::agent_info_helper::AgentMetaInfoEncode =
+            kitsune_p2p_types::codec::rmp_decode(&mut bytes)?;
+        let storage_arc = meta.arq_size.to_arq(agent.get_loc()).to_dht_arc_std();
+
         Ok(StoreEntry {
             encoded,
             signature,
             space: info.space,
             agent,
+            storage_arc,
             signed_at_ms: info.signed_at_ms,
             expires_at_ms: info.signed_at_ms + info.expires_after_ms,
         })
     }
+
+    /// Whether the agent info is signed by its agent.
+    pub fn verify_signature(&self) -> bool {
+        let mut bytes: &[u8] = &self.encoded;
+        let signed: kitsune_p2p_types::agent_info::agent_info_helper::AgentInfoSignedEncode =
+            match kitsune_p2p_types::codec::rmp_decode(&mut bytes) {
+                Ok(signed) => signed,
+                Err(_) => return false,
+            };
+        let key = match <[u8; 32]>::try_from(self.agent.get_bytes())
+            .ok()
+            .and_then(|key| ed25519_dalek::VerifyingKey::from_bytes(&key).ok())
+        {
+            Some(key) => key,
+            None => return false,
+        };
+        let signature = match ed25519_dalek::Signature::from_slice(&self.signature.0) {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+        key.verify_strict(&signed.agent_info, &signature).is_ok()
+    }
+}
+
+/// What became of an entry passed to [`Store::put_limited`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PutOutcome {
+    /// The entry is now the one stored for its agent.
+    Stored,
+    /// An entry for the agent signed at the same time or later is already stored.
+    NotNewer,
+    /// The entry was refused because its space is full.
+    SpaceFull,
 }
 
 type AgentMap = HashMap<Arc<KitsuneAgent>, StoreEntry>;
 type SpaceMap = HashMap<Arc<KitsuneSpace>, AgentMap>;
 
 #[derive(Clone, Debug)]
-pub(crate) struct Store(Arc<RwLock<SpaceMap>>, Arc<Vec<String>>);
+pub(crate) struct Store {
+    spaces: Arc<RwLock<SpaceMap>>,
+    proxy_list: Arc<Vec<String>>,
+    #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+    db: Option<Arc<db::StoreDbWriter>>,
+}
 
 impl Store {
     pub fn new(proxy_list: Vec<String>) -> Self {
-        Self(Arc::new(RwLock::new(HashMap::new())), Arc::new(proxy_list))
+        Self {
+            spaces: Arc::new(RwLock::new(HashMap::new())),
+            proxy_list: Arc::new(proxy_list),
+            #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+            db: None,
+        }
+    }
+
+    /// A store which keeps a copy of every entry in the SQLite database at
+    /// `path`, starting with the unexpired entries already in it.
+    ///
+    /// The copies are written in the background, so requests never wait on
+    /// the database.
+    #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+    pub fn open(proxy_list: Vec<String>, path: &std::path::Path) -> std::io::Result<Self> {
+        let db = db::StoreDb::open(path).map_err(std::io::Error::other)?;
+        let now = now_ms();
+        db.prune(now).map_err(std::io::Error::other)?;
+        let entries = db.load(now).map_err(std::io::Error::other)?;
+        let store = Self {
+            db: Some(Arc::new(db::StoreDbWriter::spawn(db)?)),
+            ..Self::new(proxy_list)
+        };
+        for entry in entries {
+            store.put_in_memory(entry);
+        }
+        Ok(store)
     }
 
     pub fn proxy_list(&self) -> Arc<Vec<String>> {
-        self.1.clone()
+        self.proxy_list.clone()
     }
 
     pub fn prune(&self) {
-        let now = std::time::UNIX_EPOCH
-            .elapsed()
-            .expect("Bootstrap server time set before epoch")
-            .as_millis() as u64;
+        let now = now_ms();
 
-        self.0.write().retain(|_, map| {
+        self.spaces.write().retain(|_, map| {
             map.retain(|_, info| info.expires_at_ms >= now);
             !map.is_empty()
         });
+
+        #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+        if let Some(db) = &self.db {
+            db.send(db::DbWrite::Prune(now));
+        }
     }
 
+    #[cfg(test)]
     pub fn put(&self, entry: StoreEntry) {
-        let mut lock = self.0.write();
-        let space_map = lock.entry(entry.space.clone()).or_default();
-        match space_map.entry(entry.agent.clone()) {
-            std::collections::hash_map::Entry::Occupied(mut e) => {
-                if entry.signed_at_ms > e.get().signed_at_ms {
-                    e.insert(entry);
-                }
-            }
-            std::collections::hash_map::Entry::Vacant(e) => {
-                e.insert(entry);
+        self.put_limited(entry, None);
+    }
+
+    /// Store an entry unless a newer one for the same agent is already stored.
+    /// An entry for an agent which isn't stored yet is refused if its space
+    /// already holds `max_agents_per_space` agents.
+    ///
+    /// An entry that can't be written to the database is still served, but
+    /// won't be loaded again after a restart.
+    pub fn put_limited(
+        &self,
+        entry: StoreEntry,
+        max_agents_per_space: Option<usize>,
+    ) -> PutOutcome {
+        #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+        let db_entry = self.db.as_ref().map(|_| entry.clone());
+
+        let outcome = {
+            let mut lock = self.spaces.write();
+            let space_map = lock.entry(entry.space.clone()).or_default();
+            if max_agents_per_space.is_some_and(|max| space_map.len() >= max)
+                && !space_map.contains_key(&entry.agent)
+            {
+                PutOutcome::SpaceFull
+            } else if insert_if_newer(space_map, entry) {
+                PutOutcome::Stored
+            } else {
+                PutOutcome::NotNewer
             }
+        };
+
+        #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+        if let (PutOutcome::Stored, Some(db), Some(entry)) = (outcome, &self.db, db_entry) {
+            db.send(db::DbWrite::Put(entry));
         }
+
+        outcome
+    }
+
+    #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+    fn put_in_memory(&self, entry: StoreEntry) {
+        let mut lock = self.spaces.write();
+        insert_if_newer(lock.entry(entry.space.clone()).or_default(), entry);
     }
 
     pub fn random(&self, query: RandomQuery) -> Vec<Vec<u8>> {
-        // TODO: Max this limit
-        let limit = query.limit.0 as usize;
+        let limit = query.limit.0.min(crate::QUERY_LIMIT_MAX) as usize;
         let mut rng = rand::thread_rng();
-        let now = std::time::UNIX_EPOCH
-            .elapsed()
-            .expect("Bootstrap server time set before epoch")
-            .as_millis() as u64;
-        self.0
+        let now = now_ms();
+        self.spaces
             .read()
             .get(query.space.as_ref())
             .map(|space| {
@@ -115,12 +220,129 @@ impl Store {
             .unwrap_or_default()
     }
 
+    /// Up to `limit`, and at most [`QUERY_LIMIT_MAX`](crate::QUERY_LIMIT_MAX),
+    /// random unexpired entries whose storage arcs cover the basis.
+    pub fn near(&self, query: NearQuery) -> Vec<Vec<u8>> {
+        let limit = query.limit.0.min(crate::QUERY_LIMIT_MAX) as usize;
+        let mut rng = rand::thread_rng();
+        let now = now_ms();
+        self.spaces
+            .read()
+            .get(query.space.as_ref())
+            .map(|space| {
+                space
+                    .values()
+                    .filter(|i| i.expires_at_ms > now && i.storage_arc.contains(query.basis))
+                    .map(|i| i.encoded.to_vec())
+                    .choose_multiple(&mut rng, limit)
+            })
+            .unwrap_or_default()
+    }
+
     pub fn clear(&self) {
-        self.0.write().clear()
+        self.spaces.write().clear();
+
+        #[cfg(any(feature = "sqlite", feature = "sqlite-encrypted"))]
+        if let Some(db) = &self.db {
+            db.send(db::DbWrite::Clear);
+        }
     }
 
     #[cfg(test)]
     pub fn all(&self) -> HashMap<Arc<KitsuneSpace>, HashMap<Arc<KitsuneAgent>, StoreEntry>> {
-        self.0.read().clone()
+        self.spaces.read().clone()
+    }
+}
+
+/// Returns false if a newer entry for the agent is already stored.
+fn insert_if_newer(space_map: &mut AgentMap, entry: StoreEntry) -> bool {
+    match space_map.entry(entry.agent.clone()) {
+        std::collections::hash_map::Entry::Occupied(mut e) => {
+            if entry.signed_at_ms > e.get().signed_at_ms {
+                e.insert(entry);
+                true
+            } else {
+                false
+            }
+        }
+        std::collections::hash_map::Entry::Vacant(e) => {
+            e.insert(entry);
+            true
+        }
+    }
+}
+
+fn now_ms() -> u64 {
+    std::time::UNIX_EPOCH
+        .elapsed()
+        .expect("Bootstrap server time set before epoch")
+        .as_millis() as u64
+}
+
+#[cfg(all(test, any(feature = "sqlite", feature = "sqlite-encrypted")))]
+mod tests {
+    use super::*;
+    use ::fixt::prelude::*;
+    use kitsune_p2p_bin_data::fixt::*;
+    use kitsune_p2p_types::{dht::arq::ArqSize, fixt::*};
+
+    async fn entry(
+        space: Arc<KitsuneSpace>,
+        agent: Arc<KitsuneAgent>,
+        signed_at_ms: u64,
+        expires_at_ms: u64,
+    ) -> StoreEntry {
+        let info = kitsune_p2p_types::agent_info::AgentInfoSigned::sign(
+            space,
+            agent,
+            ArqSize::from_half_len(u32::MAX / 4),
+            fixt!(UrlList, Empty),
+            signed_at_ms,
+            expires_at_ms,
+            |_| async move { Ok(Arc::new(fixt!(KitsuneSignature, Unpredictable))) },
+        )
+        .await
+        .unwrap();
+        let mut enc = Vec::new();
+        kitsune_p2p_types::codec::rmp_encode(&mut enc, &info).unwrap();
+        StoreEntry::parse(enc).unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_entries_survive_reopening() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bootstrap.sqlite3");
+        let space = Arc::new(fixt!(KitsuneSpace, Unpredictable));
+        let agent = Arc::new(fixt!(KitsuneAgent, Unpredictable));
+        let now = now_ms();
+
+        let older = entry(space.clone(), agent.clone(), now - 2, now + 60_000).await;
+        let newer = entry(space.clone(), agent.clone(), now - 1, now + 60_000).await;
+        let expired = entry(
+            space.clone(),
+            Arc::new(fixt!(KitsuneAgent, Unpredictable)),
+            now - 60_000,
+            now - 1,
+        )
+        .await;
+
+        {
+            let store = Store::open(vec![], &path).unwrap();
+            store.put(newer.clone());
+            // An older entry for the same agent doesn't replace the newer one.
+            store.put(older);
+            store.put(expired);
+        }
+
+        let store = Store::open(vec!["proxy".into()], &path).unwrap();
+        let all = store.all();
+        assert_eq!(1, all.len());
+        assert_eq!(1, all[&space].len());
+        assert_eq!(newer, all[&space][&agent]);
+        assert_eq!(vec!["proxy".to_string()], *store.proxy_list());
+
+        store.clear();
+        drop(store);
+        assert!(Store::open(vec![], &path).unwrap().all().is_empty());
     }
 }
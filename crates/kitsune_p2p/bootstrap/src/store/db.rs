@@ -0,0 +1,148 @@
+//! SQLite persistence for the bootstrap [`Store`](super::Store).
+//!
+//! The in-memory map still serves every query. The database only holds a copy
+//! of the signed agent infos, so that a restarted server can load them again.
+
+use super::StoreEntry;
+use kitsune_p2p_types::bin_types::KitsuneBinType;
+use parking_lot::Mutex;
+use rusqlite::{params, Connection};
+use std::path::Path;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS agent_info (
+        space           BLOB    NOT NULL,
+        agent           BLOB    NOT NULL,
+        signed_at_ms    INTEGER NOT NULL,
+        expires_at_ms   INTEGER NOT NULL,
+        encoded         BLOB    NOT NULL,
+        PRIMARY KEY (space, agent)
+    );
+    CREATE INDEX IF NOT EXISTS agent_info_expires_at_ms ON agent_info (expires_at_ms);
+";
+
+#[derive(Debug)]
+pub(crate) struct StoreDb(Mutex<Connection>);
+
+impl StoreDb {
+    /// Open or create the database at `path`.
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self(Mutex::new(conn)))
+    }
+
+    /// Every stored entry that hasn't expired by `now_ms`.
+    ///
+    /// Rows that no longer parse are skipped, since they can't be served anyway.
+    pub fn load(&self, now_ms: u64) -> rusqlite::Result<Vec<StoreEntry>> {
+        let conn = self.0.lock();
+        let mut stmt = conn.prepare("SELECT encoded FROM agent_info WHERE expires_at_ms >= ?1")?;
+        let rows = stmt.query_map(params![now_ms as i64], |row| row.get::<_, Vec<u8>>(0))?;
+        let mut entries = Vec::new();
+        for encoded in rows {
+            if let Ok(entry) = StoreEntry::parse(encoded?) {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Store an entry unless a newer one for the same agent is already stored.
+    pub fn put(&self, entry: &StoreEntry) -> rusqlite::Result<()> {
+        self.0.lock().execute(
+            "INSERT INTO agent_info (space, agent, signed_at_ms, expires_at_ms, encoded)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT (space, agent) DO UPDATE SET
+                signed_at_ms = excluded.signed_at_ms,
+                expires_at_ms = excluded.expires_at_ms,
+                encoded = excluded.encoded
+            WHERE excluded.signed_at_ms > agent_info.signed_at_ms",
+            params![
+                entry.space.get_bytes(),
+                entry.agent.get_bytes(),
+                entry.signed_at_ms as i64,
+                entry.expires_at_ms as i64,
+                entry.encoded,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Delete the entries that expired before `now_ms`.
+    pub fn prune(&self, now_ms: u64) -> rusqlite::Result<()> {
+        self.0.lock().execute(
+            "DELETE FROM agent_info WHERE expires_at_ms < ?1",
+            params![now_ms as i64],
+        )?;
+        Ok(())
+    }
+
+    /// Delete every entry.
+    pub fn clear(&self) -> rusqlite::Result<()> {
+        self.0.lock().execute("DELETE FROM agent_info", [])?;
+        Ok(())
+    }
+}
+
+/// A write to the database, made by the [`StoreDbWriter`].
+#[derive(Debug)]
+pub(crate) enum DbWrite {
+    Put(StoreEntry),
+    Prune(u64),
+    Clear,
+}
+
+/// Makes the writes to a [`StoreDb`] on a thread of its own, in the order
+/// they were sent, so that no request waits on SQLite.
+///
+/// Dropping the writer waits for the writes already sent to finish.
+#[derive(Debug)]
+pub(crate) struct StoreDbWriter {
+    sender: Option<std::sync::mpsc::Sender<DbWrite>>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl StoreDbWriter {
+    pub fn spawn(db: StoreDb) -> std::io::Result<Self> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let thread = std::thread::Builder::new()
+            .name("bootstrap-db-writer".to_string())
+            .spawn(move || {
+                for write in receiver {
+                    let result = match &write {
+                        DbWrite::Put(entry) => db.put(entry),
+                        DbWrite::Prune(now_ms) => db.prune(*now_ms),
+                        DbWrite::Clear => db.clear(),
+                    };
+                    if let Err(err) = result {
+                        tracing::warn!(?err, ?write, "Failed to write to the bootstrap database");
+                    }
+                }
+            })?;
+        Ok(Self {
+            sender: Some(sender),
+            thread: Some(thread),
+        })
+    }
+
+    pub fn send(&self, write: DbWrite) {
+        if let Some(sender) = &self.sender {
+            if sender.send(write).is_err() {
+                tracing::warn!("The bootstrap database writer has stopped");
+            }
+        }
+    }
+}
+
+impl Drop for StoreDbWriter {
+    fn drop(&mut self) {
+        // Closing the channel ends the thread once it has made every write.
+        self.sender.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Helpers for testing against a bootstrap server, which only accepts agent
+//! infos that are signed by their agent.
+
+use std::sync::Arc;
+
+use ed25519_dalek::{Signer, SigningKey};
+use kitsune_p2p_types::agent_info::AgentInfoSigned;
+use kitsune_p2p_types::bin_types::{KitsuneAgent, KitsuneBinType, KitsuneSignature, KitsuneSpace};
+use kitsune_p2p_types::dht::arq::ArqSize;
+use kitsune_p2p_types::tx_utils::TxUrl;
+
+/// Sign an agent info for a new agent with a random key.
+pub async fn signed_agent_info(
+    space: Arc<KitsuneSpace>,
+    arq_size: ArqSize,
+    url_list: Vec<TxUrl>,
+    signed_at_ms: u64,
+    expires_at_ms: u64,
+) -> AgentInfoSigned {
+    let key = SigningKey::from_bytes(&rand::random());
+    signed_agent_info_with_key(&key, space, arq_size, url_list, signed_at_ms, expires_at_ms).await
+}
+
+/// Sign an agent info for the agent with this key.
+pub async fn signed_agent_info_with_key(
+    key: &SigningKey,
+    space: Arc<KitsuneSpace>,
+    arq_size: ArqSize,
+    url_list: Vec<TxUrl>,
+    signed_at_ms: u64,
+    expires_at_ms: u64,
+) -> AgentInfoSigned {
+    let agent = Arc::new(KitsuneAgent::new(key.verifying_key().to_bytes().to_vec()));
+    AgentInfoSigned::sign(
+        space,
+        agent,
+        arq_size,
+        url_list,
+        signed_at_ms,
+        expires_at_ms,
+        |data| {
+            let signature = Arc::new(KitsuneSignature(key.sign(data).to_vec()));
+            async move { Ok(signature) }
+        },
+    )
+    .await
+    .unwrap()
+}
@@ -1,7 +1,7 @@
 use kitsune_p2p_bootstrap::error::BootstrapClientError;
 use kitsune_p2p_bootstrap::error::BootstrapClientResult;
 use kitsune_p2p_types::agent_info::AgentInfoSigned;
-use kitsune_p2p_types::bootstrap::RandomQuery;
+use kitsune_p2p_types::bootstrap::{NearQuery, RandomQuery};
 use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::sync::OnceLock;
@@ -10,19 +10,21 @@ use url2::Url2;
 pub mod prelude {
     pub use kitsune_p2p_bootstrap::error::*;
 
-    pub use super::{now, now_once, proxy_list, put, random, BootstrapNet};
+    pub use super::{near, now, now_once, proxy_list, put, random, with_failover, BootstrapNet};
 }
 
 /// The "net" flag / bucket to use when talking to the bootstrap server.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum BootstrapNet {
     Tx5,
+    Quic,
 }
 
 impl BootstrapNet {
     fn value(&self) -> &'static str {
         match self {
             BootstrapNet::Tx5 => "tx5",
+            BootstrapNet::Quic => "quic",
         }
     }
 }
@@ -45,6 +47,8 @@ const OP_PUT: &str = "put";
 const OP_NOW: &str = "now";
 /// The header op to tell the service to return a random set of agents in a specific space.
 const OP_RANDOM: &str = "random";
+/// The header op to tell the service to return agents whose storage arcs cover a location.
+const OP_NEAR: &str = "near";
 /// The header op to fetch the proxy_list from the bootstrap service
 const OP_PROXY_LIST: &str = "proxy_list";
 
@@ -190,6 +194,30 @@ pub async fn random(
     Ok(ret?)
 }
 
+/// `do_api` wrapper around the `near` op.
+///
+/// Fetches up to `limit` agent infos from the `space` whose storage arcs cover the `basis`,
+/// i.e. the agents which should be authorities for that location.
+///
+/// If more than `limit` agents cover the basis then the bootstrap service picks `limit` of them
+/// at random, for the same reasons as in [`random`].
+pub async fn near(
+    url: Option<Url2>,
+    query: NearQuery,
+    net: BootstrapNet,
+) -> BootstrapClientResult<Vec<AgentInfoSigned>> {
+    let outer_vec: Vec<serde_bytes::ByteBuf> = match do_api(url, OP_NEAR, query, net).await {
+        Ok(Some(v)) => v,
+        Ok(None) => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    let ret: Result<Vec<AgentInfoSigned>, _> = outer_vec
+        .into_iter()
+        .map(|bytes| kitsune_p2p_types::codec::rmp_decode(&mut AsRef::<[u8]>::as_ref(&bytes)))
+        .collect();
+    Ok(ret?)
+}
+
 /// `do_api` wrapper around the `proxy_list` op.
 ///
 /// Fetches the list of proxy servers currently stored in the bootstrap service.
@@ -203,6 +231,28 @@ pub async fn proxy_list(url: Url2, net: BootstrapNet) -> BootstrapClientResult<V
         .collect())
 }
 
+/// Call one of the op wrappers above against each of `urls` in turn, until
+/// one of them succeeds.
+///
+/// Returns the error from the last url if none of them succeed, or calls
+/// `f` with `None` if `urls` is empty, just as with a missing url.
+pub async fn with_failover<T, F, Fut>(urls: &[Url2], mut f: F) -> BootstrapClientResult<T>
+where
+    F: FnMut(Option<Url2>) -> Fut,
+    Fut: std::future::Future<Output = BootstrapClientResult<T>>,
+{
+    let (last, rest) = match urls.split_last() {
+        Some(split) => split,
+        None => return f(None).await,
+    };
+    for url in rest {
+        if let Ok(out) = f(Some(url.clone())).await {
+            return Ok(out);
+        }
+    }
+    f(Some(last.clone())).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -395,6 +445,28 @@ mod tests {
         abort_handle.abort();
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_failover() {
+        let (addr, abort_handle) = start_bootstrap().await;
+
+        // Nothing listens on the first url, so the second one is used.
+        let dead = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let dead_url = url2::url2!("http://{:?}", dead.local_addr().unwrap());
+        drop(dead);
+        let urls = vec![dead_url.clone(), url2::url2!("http://{:?}", addr)];
+
+        with_failover(&urls, |url| now(url, BootstrapNet::Tx5))
+            .await
+            .unwrap();
+        assert!(
+            with_failover(&[dead_url], |url| now(url, BootstrapNet::Tx5))
+                .await
+                .is_err()
+        );
+
+        abort_handle.abort();
+    }
+
     async fn start_bootstrap() -> (SocketAddr, AbortHandle) {
         let (bs_driver, bs_addr, shutdown) =
             kitsune_p2p_bootstrap::run("127.0.0.1:0".parse::<SocketAddr>().unwrap(), vec![])
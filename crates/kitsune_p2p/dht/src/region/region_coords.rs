@@ -19,7 +19,10 @@ use crate::spacetime::*;
     serde::Deserialize,
     serde::Serialize,
 )]
-#[cfg_attr(feature = "fuzzing", derive(proptest_derive::Arbitrary))]
+#[cfg_attr(
+    feature = "fuzzing",
+    derive(arbitrary::Arbitrary, proptest_derive::Arbitrary)
+)]
 pub struct RegionCoords {
     /// The space segment
     pub space: SpaceSegment,
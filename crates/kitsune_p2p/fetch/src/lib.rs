@@ -3,7 +3,7 @@
 
 //! Kitsune P2p Fetch Queue Logic
 
-use kitsune_p2p_types::{fetch_pool::GossipType, KOpHash, KSpace};
+use kitsune_p2p_types::{dht::region::RegionCoords, fetch_pool::GossipType, KOpHash, KSpace};
 
 mod backoff;
 mod pool;
@@ -32,6 +32,11 @@ pub use source::FetchSource;
 pub enum FetchKey {
     /// Fetch via op hash.
     Op(KOpHash),
+
+    /// Fetch all ops within a region, as identified by historical gossip.
+    /// The source responds with op data tagged with these coords, the last
+    /// of which marks the region as complete.
+    Region(RegionCoords),
 }
 
 /// A fetch "unit" that can be de-duplicated.
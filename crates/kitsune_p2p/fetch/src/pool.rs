@@ -381,6 +381,9 @@ impl State {
                         let h = hash.to_string();
                         format!("{}..{}", &h[0..4], &h[h.len() - 4..])
                     }
+                    FetchKey::Region(coords) => {
+                        format!("r{}/{}", *coords.space.offset, *coords.time.offset)
+                    }
                 };
 
                 let size = v.size.unwrap_or_default().get();
@@ -532,6 +535,35 @@ mod tests {
         assert_eq!(1, q.queue.front().unwrap().1.sources.len());
     }
 
+    #[test]
+    fn state_dedups_region_keys() {
+        let mut q = State::default();
+        let cfg = TestFetchConfig(1, 1);
+
+        for key in [test_key_op(1), test_key_region(1)] {
+            for source in [test_source(1), test_source(2)] {
+                q.push(
+                    &cfg,
+                    FetchPoolPush {
+                        key: key.clone(),
+                        source,
+                        ..test_req_op(1, None, test_source(0))
+                    },
+                );
+            }
+        }
+
+        // Each kind of key is tracked separately, and pushing the same key again merges the sources
+        assert_eq!(2, q.queue.len());
+        for key in [test_key_op(1), test_key_region(1)] {
+            assert_eq!(2, q.queue.get(&key).unwrap().sources.len());
+        }
+
+        assert!(q.remove(&test_key_region(1)).is_some());
+        assert_eq!(1, q.queue.len());
+        assert!(q.queue.get(&test_key_region(1)).is_none());
+    }
+
     #[test]
     fn queue_push() {
         let mut q = State::default();
@@ -3,6 +3,7 @@
 use crate::source::FetchSource;
 use crate::{FetchContext, FetchKey, FetchPoolPush, TransferMethod};
 use kitsune_p2p_types::bin_types::{KitsuneAgent, KitsuneBinType, KitsuneOpHash, KitsuneSpace};
+use kitsune_p2p_types::dht::prelude::{RegionCoords, SpaceSegment, TimeSegment};
 use kitsune_p2p_types::{KOpHash, KSpace};
 use std::sync::Arc;
 
@@ -34,6 +35,14 @@ pub fn test_key_op(n: u8) -> FetchKey {
     FetchKey::Op(test_key_hash(n))
 }
 
+/// Create a sample FetchKey::Region.
+pub fn test_key_region(n: u8) -> FetchKey {
+    FetchKey::Region(RegionCoords::new(
+        SpaceSegment::new(8, n as u32),
+        TimeSegment::new(4, n as u32),
+    ))
+}
+
 /// Create a sample FetchPoolPush keyed with a FetchKey::Op.
 pub fn test_req_op(n: u8, context: Option<FetchContext>, source: FetchSource) -> FetchPoolPush {
     FetchPoolPush {
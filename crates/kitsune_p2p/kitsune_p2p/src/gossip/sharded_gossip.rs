@@ -1003,11 +1003,17 @@ impl ShardedGossipLocal {
                     }
                 };
 
+                // The ops in the regions which differ are already queued as region
+                // fetches, so the hashes the partner sends for them aren't needed.
+                let fetching_regions = self.gossip_type == GossipType::Historical
+                    && self.tuning_params.gossip_fetch_regions_via_pool;
+
                 // TODO: come back to this later after implementing batching for
                 //      region gossip, for now I just don't care about the state,
                 //      and just want to handle the incoming ops.
                 if (self.gossip_type == GossipType::Historical || state.is_some())
                     && !ops.is_empty()
+                    && !fetching_regions
                 {
                     if let Some(state) = state.as_ref() {
                         // NOTE: we could probably make a better choice than "any arbitrary remote agent".
@@ -116,7 +116,7 @@ impl ShardedGossipLocal {
 
             self.inner.share_mut(|i, _| {
                 if let Some(round) = i.round_map.get_mut(peer_cert) {
-                    round.region_diffs = Some((our_region_diff.clone(), their_region_diff));
+                    round.region_diffs = Some((our_region_diff.clone(), their_region_diff.clone()));
                     round.regions_are_queued = true;
                     i.metrics.write().update_current_round(
                         peer_cert,
@@ -132,6 +132,10 @@ impl ShardedGossipLocal {
                 Ok(())
             })?;
 
+            if self.tuning_params.gossip_fetch_regions_via_pool {
+                self.fetch_incoming_regions(&state, their_region_diff);
+            }
+
             // This is a good place to see all the region data go by.
             // Note, this is a LOT of output!
             // tracing::info!("region diffs ({}): {:?}", diff_regions.len(), diff_regions);
@@ -149,6 +153,31 @@ impl ShardedGossipLocal {
         }
     }
 
+    /// Queue the regions our partner holds data for as fetches in the fetch pool.
+    ///
+    /// Our own regions are still sent to the partner as op hashes, so the round
+    /// completes the same way whether or not the partner fetches by region.
+    fn fetch_incoming_regions(&self, state: &RoundState, regions: Vec<Region>) {
+        if let Some(agent) = state.remote_agent_list.first() {
+            let source = FetchSource::Agent(agent.agent.clone());
+            for region in regions {
+                self.fetch_pool.push(FetchPoolPush {
+                    key: FetchKey::Region(region.coords),
+                    context: None,
+                    space: self.space.clone(),
+                    source: source.clone(),
+                    size: Some((region.data.size as usize).into()),
+                    transfer_method: TransferMethod::Gossip(GossipType::Historical),
+                });
+            }
+        } else if !regions.is_empty() {
+            tracing::warn!(
+                "Regions were diffed for a round with no remote agent(s). {} regions dropped!",
+                regions.len()
+            );
+        }
+    }
+
     pub(super) async fn process_next_region_batch(
         &self,
         state: RoundState,
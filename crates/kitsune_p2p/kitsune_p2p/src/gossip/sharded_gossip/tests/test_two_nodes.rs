@@ -257,6 +257,63 @@ async fn missing_ops_finishes() {
         .unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread")]
+/// This test checks that op hashes sent in a historical round are not
+/// fetched when the regions they are in are fetched through the fetch pool.
+async fn missing_ops_are_not_fetched_when_fetching_regions() {
+    let mut u = arbitrary::Unstructured::new(&NOISE);
+    let cert = NodeCert::arbitrary(&mut u).unwrap();
+    let agents = agents_with_infos(1).await;
+
+    let mut bob = setup_standard_player(
+        ShardedGossipLocalState {
+            round_map: maplit::hashmap! {
+                cert.clone() => RoundState {
+                    remote_agent_list: vec![agents[0].1.clone()],
+                    common_arq_set: Arc::new(ArqSet::full_std()),
+                    num_expected_op_blooms: 1,
+                    received_all_incoming_op_blooms: true,
+                    has_pending_historical_op_data: false,
+                    regions_are_queued: true,
+                    id: nanoid::nanoid!(),
+                    last_touch: Instant::now(),
+                    round_timeout: std::time::Duration::MAX,
+                    bloom_batch_cursor: None,
+                    ops_batch_queue: OpsBatchQueue::new(),
+                    region_set_sent: None,
+                    region_diffs: Default::default(),
+                }
+            }
+            .into(),
+            ..Default::default()
+        },
+        vec![],
+    )
+    .await;
+    let mut tuning_params =
+        kitsune_p2p_types::config::tuning_params_struct::KitsuneP2pTuningParams::default();
+    tuning_params.gossip_fetch_regions_via_pool = true;
+    bob.tuning_params = Arc::new(tuning_params);
+
+    let incoming = ShardedGossipWire::MissingOpHashes(MissingOpHashes {
+        ops: vec![OpHashSized::new(
+            Arc::new(KitsuneOpHash::new(vec![1; 36])),
+            None,
+        )],
+        finished: MissingOpsStatus::AllComplete as u8,
+    });
+
+    let mut agent_info_session =
+        AgentInfoSession::new(bob.query_agents_by_local_agents().await.unwrap(), vec![]);
+
+    bob.process_incoming(cert.clone(), incoming, &mut agent_info_session)
+        .await
+        .unwrap();
+
+    // The op is left to the region fetches, which are queued as the round starts.
+    assert!(bob.fetch_pool.is_empty());
+}
+
 #[tokio::test(flavor = "multi_thread")]
 /// This test checks that a missing ops message that is
 /// marked as finished doesn't finish the round when
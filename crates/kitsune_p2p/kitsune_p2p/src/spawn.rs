@@ -58,7 +58,7 @@ pub async fn spawn_kitsune_p2p(
 
     // Create the network. Any events it sends will have to wait to be processed until Kitsune has finished initialising
     // but everything that is needed to construct the network is available now.
-    let (ep_hnd, ep_evt, bootstrap_net, maybe_peer_url) = create_meta_net(
+    let (ep_hnd, ep_evt, bootstrap_nets, local_urls) = create_meta_net(
         &config,
         tls_config,
         internal_sender.clone(),
@@ -77,8 +77,8 @@ pub async fn spawn_kitsune_p2p(
                 self_host_api,
                 ep_hnd,
                 ep_evt,
-                bootstrap_net,
-                maybe_peer_url,
+                bootstrap_nets,
+                local_urls,
             )
             .await?,
         ),
@@ -130,11 +130,11 @@ pub(crate) struct KitsuneP2pActor {
         )>,
     >,
     config: Arc<KitsuneP2pConfig>,
-    bootstrap_net: BootstrapNet,
+    bootstrap_nets: Vec<BootstrapNet>,
     bandwidth_throttles: BandwidthThrottles,
     parallel_notify_permit: Arc<tokio::sync::Semaphore>,
     fetch_pool: FetchPool,
-    local_url: Arc<std::sync::Mutex<Option<String>>>,
+    local_urls: Arc<std::sync::Mutex<Vec<String>>>,
 }
 
 impl KitsuneP2pActor {
@@ -147,10 +147,10 @@ impl KitsuneP2pActor {
         self_host_api: HostApiLegacy,
         ep_hnd: MetaNet,
         ep_evt: MetaNetEvtRecv,
-        bootstrap_net: BootstrapNet,
-        maybe_peer_url: Option<String>,
+        bootstrap_nets: Vec<BootstrapNet>,
+        local_urls: Vec<String>,
     ) -> KitsuneP2pResult<Self> {
-        let local_url = Arc::new(std::sync::Mutex::new(maybe_peer_url));
+        let local_urls = Arc::new(std::sync::Mutex::new(local_urls));
 
         crate::types::metrics::init();
 
@@ -192,66 +192,137 @@ impl KitsuneP2pActor {
             host_api: direct_host_api,
             spaces: HashMap::new(),
             config: Arc::new(config),
-            bootstrap_net,
+            bootstrap_nets,
             bandwidth_throttles,
             parallel_notify_permit,
             fetch_pool,
-            local_url,
+            local_urls,
         })
     }
 }
 
 pub(super) async fn create_meta_net(
     config: &KitsuneP2pConfig,
-    _tls_config: tls::TlsConfig,
+    tls_config: tls::TlsConfig,
     internal_sender: ghost_actor::GhostSender<Internal>,
     host: HostApiLegacy,
     preflight_user_data: PreflightUserData,
-) -> KitsuneP2pResult<(MetaNet, MetaNetEvtRecv, BootstrapNet, Option<String>)> {
-    let mut ep_hnd = None;
-    let mut ep_evt = None;
-    let mut bootstrap_net = None;
-    let mut maybe_peer_url = None;
-
-    if ep_hnd.is_none() && config.is_tx5() {
-        tracing::trace!("tx5");
-        let mut tune: kitsune_p2p_types::config::tuning_params_struct::KitsuneP2pTuningParams =
-            (*config.tuning_params).clone();
-        let (signal_url, webrtc_config) = match config.transport_pool.first().unwrap() {
-            TransportConfig::WebRTC {
-                signal_url,
-                webrtc_config,
-            } => {
+) -> KitsuneP2pResult<(MetaNet, MetaNetEvtRecv, Vec<BootstrapNet>, Vec<String>)> {
+    // every backend gets its own copy of the preflight user data
+    let PreflightUserData { bytes, comparator } = preflight_user_data;
+    #[allow(clippy::type_complexity)]
+    let comparator: Arc<dyn Fn(&str, &[u8]) -> Result<(), String> + Send + Sync> =
+        Arc::from(comparator);
+    let user_data = || {
+        let comparator = comparator.clone();
+        PreflightUserData {
+            bytes: bytes.clone(),
+            comparator: Box::new(move |url, data| comparator(url, data)),
+        }
+    };
+
+    let mut nets = Vec::new();
+    let mut bootstrap_nets = Vec::new();
+    let mut peer_urls = Vec::new();
+    let mut have_webrtc = false;
+
+    for transport in config.transport_pool.iter() {
+        let (net, evt, net_urls, net_kind) = match transport {
+            TransportConfig::WebRTC { webrtc_config, .. } => {
+                // all webrtc transports share one endpoint,
+                // which listens on each of the signal servers
+                if have_webrtc {
+                    continue;
+                }
+                have_webrtc = true;
+                tracing::trace!("tx5");
+
+                let mut signal_urls = Vec::new();
+                for t in config.transport_pool.iter() {
+                    if let TransportConfig::WebRTC {
+                        signal_url,
+                        webrtc_config: other_config,
+                    } = t
+                    {
+                        if other_config != webrtc_config {
+                            tracing::warn!(%signal_url, "ignoring webrtc_config that differs from the first WebRTC transport");
+                        }
+                        signal_urls.push(signal_url.clone());
+                    }
+                }
                 let webrtc_config = webrtc_config
                     .as_ref()
                     .map(|c| serde_json::to_string(&c).expect("Can Serialize JSON"))
                     .unwrap_or_else(|| DEFAULT_WEBRTC_CONFIG.to_string());
-                (signal_url.clone(), webrtc_config)
+
+                let (h, e, p) = MetaNet::new_tx5(
+                    config.tuning_params.clone(),
+                    host.clone(),
+                    internal_sender.clone(),
+                    signal_urls,
+                    webrtc_config,
+                    user_data(),
+                )
+                .await?;
+                (h, e, p, BootstrapNet::Tx5)
             }
             TransportConfig::Mem {} => {
+                tracing::trace!("tx5 mem");
+                let mut tune: kitsune_p2p_types::config::tuning_params_struct::KitsuneP2pTuningParams =
+                    (*config.tuning_params).clone();
                 tune.tx5_backend_module = "mem".to_string();
-                ("wss://fake.fake".to_string(), "{}".to_string())
+                let (h, e, p) = MetaNet::new_tx5(
+                    Arc::new(tune),
+                    host.clone(),
+                    internal_sender.clone(),
+                    vec!["wss://fake.fake".to_string()],
+                    "{}".to_string(),
+                    user_data(),
+                )
+                .await?;
+                (h, e, p, BootstrapNet::Tx5)
+            }
+            TransportConfig::Quic {
+                bind_to,
+                override_host,
+                override_port,
+            } => {
+                tracing::trace!("quic");
+                let (h, e, p) = MetaNet::new_quic(
+                    config.tuning_params.clone(),
+                    host.clone(),
+                    internal_sender.clone(),
+                    tls_config.clone(),
+                    kitsune_p2p_types::config::cnv_bind_to(bind_to),
+                    override_host.clone(),
+                    *override_port,
+                    user_data(),
+                )
+                .await?;
+                (h, e, p, BootstrapNet::Quic)
             }
         };
-        let (h, e, p) = MetaNet::new_tx5(
-            Arc::new(tune),
-            host.clone(),
-            internal_sender.clone(),
-            signal_url,
-            webrtc_config,
-            preflight_user_data,
-        )
-        .await?;
-        ep_hnd = Some(h);
-        ep_evt = Some(e);
-        bootstrap_net = Some(BootstrapNet::Tx5);
-        maybe_peer_url = p;
+
+        // agent infos are published to the bucket of every transport,
+        // so that peers sharing any one of them with us can find us
+        if !bootstrap_nets.contains(&net_kind) {
+            bootstrap_nets.push(net_kind);
+        }
+        peer_urls.extend(net_urls);
+        nets.push((net, evt));
     }
 
-    match (ep_hnd, ep_evt, bootstrap_net) {
-        (Some(h), Some(e), Some(n)) => Ok((h, e, n, maybe_peer_url)),
-        _ => Err("Network config has no valid transport".into()),
+    if bootstrap_nets.is_empty() {
+        return Err("Network config has no valid transport".into());
     }
+
+    let (ep_hnd, ep_evt) = if nets.len() == 1 {
+        nets.remove(0)
+    } else {
+        MetaNet::new_multi(&config.tuning_params, nets)
+    };
+
+    Ok((ep_hnd, ep_evt, bootstrap_nets, peer_urls))
 }
 
 use crate::spawn::actor::fetch::{FetchResponseConfig, FetchTask};
@@ -626,11 +697,11 @@ impl KitsuneP2pHandler for KitsuneP2pActor {
         let ep_hnd = self.ep_hnd.clone();
         let host = self.host_api.clone().api;
         let config = Arc::clone(&self.config);
-        let bootstrap_net = self.bootstrap_net;
+        let bootstrap_nets = self.bootstrap_nets.clone();
         let bandwidth_throttles = self.bandwidth_throttles.clone();
         let parallel_notify_permit = self.parallel_notify_permit.clone();
         let fetch_pool = self.fetch_pool.clone();
-        let local_url = self.local_url.clone();
+        let local_urls = self.local_urls.clone();
 
         let space_sender = match self.spaces.entry(space.clone()) {
             Entry::Occupied(entry) => entry.into_mut(),
@@ -640,11 +711,11 @@ impl KitsuneP2pHandler for KitsuneP2pActor {
                     ep_hnd,
                     host,
                     config,
-                    bootstrap_net,
+                    bootstrap_nets,
                     bandwidth_throttles,
                     parallel_notify_permit,
                     fetch_pool,
-                    local_url,
+                    local_urls,
                 )
                 .await
                 .expect("cannot fail to create space");
@@ -1023,6 +1094,7 @@ mod tests {
     use ghost_actor::actor_builder::GhostActorBuilder;
     use kitsune_p2p_bootstrap_client::BootstrapNet;
     use kitsune_p2p_types::config::KitsuneP2pConfig;
+    use kitsune_p2p_types::config::TransportConfig;
     use kitsune_p2p_types::tls::TlsConfig;
     use url2::url2;
 
@@ -1032,10 +1104,57 @@ mod tests {
 
         let config = KitsuneP2pConfig::from_signal_addr(signal_addr);
 
-        let (meta_net, _, bootstrap_net) = test_create_meta_net(config).await.unwrap();
+        let (meta_net, _, bootstrap_nets, _) = test_create_meta_net(config).await.unwrap();
 
         // Not the most interesting check but we mostly care that the above function produces a result given a valid config.
-        assert_eq!(BootstrapNet::Tx5, bootstrap_net);
+        assert_eq!(vec![BootstrapNet::Tx5], bootstrap_nets);
+
+        meta_net.close(0, "test").await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_quic_meta_net() {
+        let mut config = KitsuneP2pConfig::mem();
+        config.transport_pool = vec![TransportConfig::Quic {
+            bind_to: Some(url2!("kitsune-quic://127.0.0.1:0")),
+            override_host: None,
+            override_port: None,
+        }];
+
+        let (meta_net, _, bootstrap_nets, _) = test_create_meta_net(config).await.unwrap();
+
+        assert_eq!(vec![BootstrapNet::Quic], bootstrap_nets);
+
+        meta_net.close(0, "test").await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_multi_transport_meta_net() {
+        let (signal_addr_1, _sig_hnd_1) = start_signal_srv().await;
+        let (signal_addr_2, _sig_hnd_2) = start_signal_srv().await;
+
+        let mut config = KitsuneP2pConfig::from_signal_addr(signal_addr_1);
+        config.transport_pool.push(TransportConfig::WebRTC {
+            signal_url: format!("ws://{:?}", signal_addr_2),
+            webrtc_config: None,
+        });
+        config.transport_pool.push(TransportConfig::Quic {
+            bind_to: Some(url2!("kitsune-quic://127.0.0.1:0")),
+            override_host: None,
+            override_port: None,
+        });
+
+        let (meta_net, _, bootstrap_nets, local_urls) = test_create_meta_net(config).await.unwrap();
+
+        // one bucket per kind of transport, in the order they are configured
+        assert_eq!(vec![BootstrapNet::Tx5, BootstrapNet::Quic], bootstrap_nets);
+        assert!(matches!(meta_net, MetaNet::Multi { .. }));
+
+        // one url per signal server, plus the quic one
+        assert_eq!(3, local_urls.len(), "{local_urls:?}");
+        assert!(local_urls[0].starts_with(&format!("ws://{:?}", signal_addr_1)));
+        assert!(local_urls[1].starts_with(&format!("ws://{:?}", signal_addr_2)));
+        assert!(local_urls[2].starts_with("kitsune-quic://127.0.0.1:"));
 
         meta_net.close(0, "test").await;
     }
@@ -1047,17 +1166,17 @@ mod tests {
         let mut config = KitsuneP2pConfig::from_signal_addr(signal_addr);
         config.bootstrap_service = Some(url2!("ws://not-a-bootstrap.test"));
 
-        let (meta_net, _, bootstrap_net) = test_create_meta_net(config).await.unwrap();
+        let (meta_net, _, bootstrap_nets, _) = test_create_meta_net(config).await.unwrap();
 
         // Not the most interesting check but we mostly care that the above function produces a result given a valid config.
-        assert_eq!(BootstrapNet::Tx5, bootstrap_net);
+        assert_eq!(vec![BootstrapNet::Tx5], bootstrap_nets);
 
         meta_net.close(0, "test").await;
     }
 
     async fn test_create_meta_net(
         config: KitsuneP2pConfig,
-    ) -> KitsuneP2pResult<(MetaNet, MetaNetEvtRecv, BootstrapNet)> {
+    ) -> KitsuneP2pResult<(MetaNet, MetaNetEvtRecv, Vec<BootstrapNet>, Vec<String>)> {
         let builder = GhostActorBuilder::new();
 
         let internal_sender = builder
@@ -1078,6 +1197,5 @@ mod tests {
             PreflightUserData::default(),
         )
         .await
-        .map(|(n, r, b, _)| (n, r, b))
     }
 }
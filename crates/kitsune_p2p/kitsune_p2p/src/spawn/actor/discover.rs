@@ -206,8 +206,12 @@ pub(crate) fn peer_connect(
     timeout: KitsuneTimeout,
 ) -> impl Future<Output = PeerDiscoverResult> + 'static + Send {
     let agent = agent_info_signed.agent.clone();
-    let url = agent_info_signed
+    let urls = agent_info_signed
         .url_list
+        .iter()
+        .map(|u| u.to_string())
+        .collect::<Vec<_>>();
+    let url = urls
         .first()
         .cloned()
         .ok_or_else(|| KitsuneP2pError::from("no url - agent is likely offline"));
@@ -220,11 +224,9 @@ pub(crate) fn peer_connect(
             return Ok(PeerDiscoverResult::OkShortcut);
         }
 
-        // attempt an outgoing connection
-        let con_hnd = inner
-            .ep_hnd
-            .get_connection(url.to_string(), timeout)
-            .await?;
+        // attempt an outgoing connection, falling back
+        // to the other urls the peer advertises
+        let con_hnd = inner.ep_hnd.get_connection_any(urls, timeout).await?;
 
         // return the result
         Ok(PeerDiscoverResult::OkRemote {
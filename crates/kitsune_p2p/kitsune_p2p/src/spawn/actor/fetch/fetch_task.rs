@@ -30,16 +30,21 @@ impl FetchTask {
                     let list = fetch_pool.get_items_to_fetch();
 
                     for (key, space, source, context) in list {
-                        let FetchKey::Op(op_hash) = &key;
-
-                        if let Ok(mut res) = host
-                            .check_op_data(space.clone(), vec![op_hash.clone()], context)
-                            .await
-                        {
-                            if res.len() == 1 && res.remove(0) {
-                                fetch_pool.remove(&key);
-                                continue;
-                            }
+                        let already_held = match &key {
+                            FetchKey::Op(op_hash) => match host
+                                .check_op_data(space.clone(), vec![op_hash.clone()], context)
+                                .await
+                            {
+                                Ok(mut res) => res.len() == 1 && res.remove(0),
+                                Err(_) => false,
+                            },
+                            // Regions are only queued when gossip found them to differ
+                            FetchKey::Region(_) => false,
+                        };
+
+                        if already_held {
+                            fetch_pool.remove(&key);
+                            continue;
                         }
 
                         if let Err(err) = internal_sender.fetch(key, space, source).await {
@@ -75,9 +80,9 @@ mod tests {
     use futures::FutureExt;
     use ghost_actor::actor_builder::GhostActorBuilder;
     use ghost_actor::{GhostControlSender, GhostSender};
-    use kitsune_p2p_fetch::test_utils::{test_key_hash, test_req_op, test_source};
+    use kitsune_p2p_fetch::test_utils::{test_key_hash, test_key_region, test_req_op, test_source};
     use kitsune_p2p_fetch::FetchSource;
-    use kitsune_p2p_fetch::{FetchKey, FetchPool};
+    use kitsune_p2p_fetch::{FetchKey, FetchPool, FetchPoolPush};
     use kitsune_p2p_types::config::KitsuneP2pConfig;
     use kitsune_p2p_types::KOpHash;
     use parking_lot::{Mutex, RwLock};
@@ -175,6 +180,31 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn fetch_region_without_checking_op_data() {
+        let (_task, fetch_pool, internal_sender_test, _held_op_data, check_op_data_call_count) =
+            setup(InternalStub::new()).await;
+
+        fetch_pool.push(FetchPoolPush {
+            key: test_key_region(1),
+            ..test_req_op(1, None, test_source(1))
+        });
+
+        let fetched = wait_for_fetch_n(internal_sender_test.clone(), 1).await;
+
+        // The region gets fetched, without checking for any ops
+        assert_eq!(
+            test_key_region(1),
+            fetched.into_iter().flatten().next().unwrap().0
+        );
+        assert_eq!(0, check_op_data_call_count.load(Ordering::SeqCst));
+
+        internal_sender_test
+            .ghost_actor_shutdown_immediate()
+            .await
+            .unwrap();
+    }
+
     async fn setup(
         task: InternalStub,
     ) -> (
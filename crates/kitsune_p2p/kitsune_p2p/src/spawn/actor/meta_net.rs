@@ -35,6 +35,8 @@ use tx5::PeerUrl;
 use crate::spawn::actor::UNAUTHORIZED_DISCONNECT_CODE;
 use crate::spawn::actor::UNAUTHORIZED_DISCONNECT_REASON;
 
+pub mod quic;
+
 kitsune_p2p_types::write_codec_enum! {
     /// KitsuneP2p WebRTC wrapper enum.
     codec WireWrap {
@@ -91,7 +93,7 @@ pub struct PreflightUserData {
     /// Typically this will be a closure that captures the bytes sent, so that the two values can
     /// be compared.
     #[allow(clippy::type_complexity)]
-    pub comparator: Box<dyn Fn(&tx5::PeerUrl, &[u8]) -> Result<(), String> + Send + Sync + 'static>,
+    pub comparator: Box<dyn Fn(&str, &[u8]) -> Result<(), String> + Send + Sync + 'static>,
 }
 
 impl Default for PreflightUserData {
@@ -110,6 +112,77 @@ fn next_msg_id() -> u64 {
     MSG_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
 }
 
+/// Generate the preflight data we send to remotes.
+fn preflight_gen(
+    i_s: &ghost_actor::GhostSender<crate::spawn::Internal>,
+    user_data_sent: &[u8],
+) -> futures::future::BoxFuture<'static, std::io::Result<Vec<u8>>> {
+    let i_s = i_s.clone();
+    let user_data_sent = user_data_sent.to_vec();
+    Box::pin(async move {
+        let agent_list = i_s
+            .get_all_local_joined_agent_infos()
+            .await
+            .unwrap_or_default();
+        PreflightData::v0(KITSUNE_PROTOCOL_VERSION, agent_list, user_data_sent).encode_vec()
+    })
+}
+
+/// Check the preflight data received from a remote, storing
+/// any agent infos it carries if the check passes.
+#[allow(clippy::type_complexity)]
+fn preflight_check(
+    e_s: &futures::channel::mpsc::Sender<KitsuneP2pEvent>,
+    user_data_cmp: &(dyn Fn(&str, &[u8]) -> Result<(), String> + Send + Sync + 'static),
+    url: &str,
+    data: &[u8],
+) -> futures::future::BoxFuture<'static, std::io::Result<()>> {
+    let e_s = e_s.clone();
+    match PreflightData::decode_ref(data) {
+        Ok((
+            _,
+            PreflightData::V0(V0 {
+                kitsune_protocol_version,
+                peer_list,
+                user_data: user_data_bytes_received,
+            }),
+        )) => {
+            if kitsune_protocol_version != KITSUNE_PROTOCOL_VERSION {
+                tracing::warn!(
+                    ?url,
+                    "kitsune protocol version mismatch: ours = {}, theirs = {}",
+                    KITSUNE_PROTOCOL_VERSION,
+                    kitsune_protocol_version,
+                );
+                return box_fut_plain(Err(std::io::Error::other(
+                    "kitsune protocol version mismatch",
+                )));
+            }
+
+            if let Err(reason) = user_data_cmp(url, &user_data_bytes_received) {
+                tracing::warn!(?url, %reason, "preflight user_data mismatch");
+                return box_fut_plain(Err(std::io::Error::other("preflight user_data mismatch")));
+            }
+            Box::pin(async move {
+                if let Err(err) = e_s
+                    .put_agent_info_signed(PutAgentInfoSignedEvt {
+                        peer_data: peer_list,
+                    })
+                    .await
+                {
+                    tracing::warn!(?err, "error processing incoming agent info unsolicited");
+                }
+                Ok(())
+            })
+        }
+        Err(err) => {
+            tracing::warn!(?err, ?url, "Could not decode PreflightData");
+            box_fut_plain(Err(std::io::Error::other("Could not decode PreflightData")))
+        }
+        _ => box_fut_plain(Err(std::io::Error::other("Unexpected wire message"))),
+    }
+}
+
 pub type RespondFut = std::pin::Pin<Box<dyn std::future::Future<Output = ()> + 'static + Send>>;
 
 pub type Respond = Box<dyn FnOnce(wire::Wire) -> RespondFut + 'static + Send>;
@@ -268,16 +341,16 @@ pub type MetaNetEvtRecv = futures::channel::mpsc::Receiver<MetaNetEvt>;
 type ResStore = Arc<Mutex<HashMap<u64, tokio::sync::oneshot::Sender<wire::Wire>>>>;
 
 struct MetricSendGuard {
-    rem_id: tx5::PubKey,
+    rem_id: String,
     is_error: bool,
     byte_count: u64,
     start_time: std::time::Instant,
 }
 
 impl MetricSendGuard {
-    pub fn new(rem_id: tx5::PubKey, byte_count: u64) -> Self {
+    pub fn new(rem_id: impl std::fmt::Debug, byte_count: u64) -> Self {
         Self {
-            rem_id,
+            rem_id: format!("{:?}", rem_id),
             is_error: true,
             byte_count,
             start_time: std::time::Instant::now(),
@@ -294,14 +367,14 @@ impl Drop for MetricSendGuard {
         crate::metrics::METRIC_MSG_OUT_BYTE.record(
             self.byte_count,
             &[
-                opentelemetry_api::KeyValue::new("remote_id", format!("{:?}", self.rem_id)),
+                opentelemetry_api::KeyValue::new("remote_id", self.rem_id.clone()),
                 opentelemetry_api::KeyValue::new("is_error", self.is_error),
             ],
         );
         crate::metrics::METRIC_MSG_OUT_TIME.record(
             self.start_time.elapsed().as_secs_f64(),
             &[
-                opentelemetry_api::KeyValue::new("remote_id", format!("{:?}", self.rem_id)),
+                opentelemetry_api::KeyValue::new("remote_id", self.rem_id.clone()),
                 opentelemetry_api::KeyValue::new("is_error", self.is_error),
             ],
         );
@@ -318,6 +391,18 @@ pub enum MetaNetCon {
         tun: KitsuneP2pTuningParams,
     },
 
+    Quic {
+        host: HostApiLegacy,
+        ep: Arc<quic::QuicEndpoint>,
+        rem_url: String,
+        res: ResStore,
+        tun: KitsuneP2pTuningParams,
+    },
+
+    /// Connections to the same peer over several transports,
+    /// tried in order until one of them succeeds.
+    Fallback { cons: Arc<[MetaNetCon]> },
+
     #[cfg(test)]
     Test {
         state: Arc<parking_lot::RwLock<MetaNetConTest>>,
@@ -328,6 +413,19 @@ impl PartialEq for MetaNetCon {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (MetaNetCon::Tx5 { ep: a, .. }, MetaNetCon::Tx5 { ep: b, .. }) => Arc::ptr_eq(a, b),
+            (
+                MetaNetCon::Quic {
+                    ep: a,
+                    rem_url: a_url,
+                    ..
+                },
+                MetaNetCon::Quic {
+                    ep: b,
+                    rem_url: b_url,
+                    ..
+                },
+            ) => Arc::ptr_eq(a, b) && a_url == b_url,
+            (MetaNetCon::Fallback { cons: a }, MetaNetCon::Fallback { cons: b }) => a == b,
             _ => false,
         }
     }
@@ -337,6 +435,19 @@ impl Eq for MetaNetCon {}
 
 impl MetaNetCon {
     pub async fn close(&self, code: u32, reason: &str) {
+        {
+            if let MetaNetCon::Fallback { cons } = self {
+                for con in cons.iter() {
+                    con.close_single();
+                }
+                return;
+            }
+        }
+
+        self.close_single();
+    }
+
+    fn close_single(&self) {
         #[cfg(test)]
         {
             if let MetaNetCon::Test { state } = self {
@@ -354,6 +465,13 @@ impl MetaNetCon {
                 return;
             }
         }
+
+        {
+            if let MetaNetCon::Quic { ep, rem_url, .. } = self {
+                ep.close_peer(rem_url);
+                return;
+            }
+        }
     }
 
     pub fn is_closed(&self) -> bool {
@@ -364,6 +482,12 @@ impl MetaNetCon {
             }
         }
 
+        {
+            if let MetaNetCon::Fallback { cons } = self {
+                return cons.iter().all(|con| con.is_closed());
+            }
+        }
+
         {
             // NOTE - tx5 connections are never exactly "closed"
             //        since it's more of a message queue...
@@ -373,9 +497,11 @@ impl MetaNetCon {
 
     async fn wire_is_authorized(&self, payload: &wire::Wire, now: Timestamp) -> MetaNetAuth {
         match self {
-            MetaNetCon::Tx5 { host, .. } => {
+            MetaNetCon::Tx5 { host, .. } | MetaNetCon::Quic { host, .. } => {
                 nodespace_is_authorized(host, self.peer_id(), payload.maybe_space(), now).await
             }
+            // each of the fallback connections is checked when it is used
+            MetaNetCon::Fallback { .. } => MetaNetAuth::Authorized,
             #[cfg(test)]
             MetaNetCon::Test { .. } => MetaNetAuth::Authorized,
         }
@@ -383,6 +509,31 @@ impl MetaNetCon {
 
     #[cfg_attr(feature = "instrument", tracing::instrument(skip_all))]
     pub async fn notify(&self, payload: &wire::Wire, timeout: KitsuneTimeout) -> KitsuneResult<()> {
+        let cons = match self {
+            MetaNetCon::Fallback { cons } => cons,
+            _ => return self.notify_single(payload, timeout).await,
+        };
+
+        let mut last_err = None;
+        for (idx, con) in cons.iter().enumerate() {
+            let timeout = fallback_timeout(&timeout, cons.len() - idx);
+            match con.notify_single(payload, timeout).await {
+                Ok(()) => return Ok(()),
+                Err(err) => {
+                    tracing::debug!(?err, ?con, "notify failed, trying next transport");
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| "no connection to notify".into()))
+    }
+
+    async fn notify_single(
+        &self,
+        payload: &wire::Wire,
+        timeout: KitsuneTimeout,
+    ) -> KitsuneResult<()> {
         let start = std::time::Instant::now();
         let msg_id = next_msg_id();
 
@@ -423,6 +574,24 @@ impl MetaNetCon {
                         }
                     }
 
+                    {
+                        if let MetaNetCon::Quic { ep, rem_url, .. } = self {
+                            let wire = payload.encode_vec().map_err(KitsuneError::other)?;
+                            let wrap = WireWrap::notify(msg_id, WireData(wire));
+
+                            let data = wrap.encode_vec().map_err(KitsuneError::other)?;
+
+                            let mut metric_guard =
+                                MetricSendGuard::new(self.peer_id(), data.len() as u64);
+
+                            timeout.mix("quic notify", ep.send(rem_url, data)).await?;
+
+                            metric_guard.set_is_error(false);
+
+                            return Ok(());
+                        }
+                    }
+
                     return Err("invalid features".into());
                 }
                 MetaNetAuth::UnauthorizedIgnore => {
@@ -448,6 +617,35 @@ impl MetaNetCon {
         &self,
         payload: &wire::Wire,
         timeout: KitsuneTimeout,
+    ) -> KitsuneResult<wire::Wire> {
+        let cons = match self {
+            MetaNetCon::Fallback { cons } => cons,
+            _ => return self.request_single(payload, timeout).await,
+        };
+
+        let mut last_err = None;
+        for (idx, con) in cons.iter().enumerate() {
+            let timeout = fallback_timeout(&timeout, cons.len() - idx);
+            match con.request_single(payload, timeout).await {
+                Ok(resp) => return Ok(resp),
+                // the peer told us no, another transport won't change that
+                Err(err) if matches!(err.kind(), KitsuneErrorKind::Unauthorized) => {
+                    return Err(err)
+                }
+                Err(err) => {
+                    tracing::debug!(?err, ?con, "request failed, trying next transport");
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| "no connection to request".into()))
+    }
+
+    async fn request_single(
+        &self,
+        payload: &wire::Wire,
+        timeout: KitsuneTimeout,
     ) -> KitsuneResult<wire::Wire> {
         let start = std::time::Instant::now();
         let msg_id = next_msg_id();
@@ -492,6 +690,39 @@ impl MetaNetCon {
                         }
                     }
 
+                    {
+                        if let MetaNetCon::Quic {
+                            ep,
+                            rem_url,
+                            res: res_store,
+                            ..
+                        } = self
+                        {
+                            let (s, r) = tokio::sync::oneshot::channel();
+                            res_store.lock().insert(msg_id, s);
+
+                            let res_store = res_store.clone();
+                            tokio::task::spawn(async move {
+                                tokio::time::sleep(timeout.time_remaining()).await;
+                                res_store.lock().remove(&msg_id);
+                            });
+
+                            let wire = payload.encode_vec().map_err(KitsuneError::other)?;
+                            let wrap = WireWrap::request(msg_id, WireData(wire));
+                            let data = wrap.encode_vec().map_err(KitsuneError::other)?;
+
+                            let mut metric_guard =
+                                MetricSendGuard::new(self.peer_id(), data.len() as u64);
+
+                            timeout.mix("quic request", ep.send(rem_url, data)).await?;
+
+                            let resp = r.await.map_err(|_| KitsuneError::other("timeout"))?;
+
+                            metric_guard.set_is_error(false);
+                            return Ok(resp);
+                        }
+                    }
+
                     return Err("invalid features".into());
                 }
                 MetaNetAuth::UnauthorizedIgnore => {
@@ -521,16 +752,39 @@ impl MetaNetCon {
             }
         }
 
+        {
+            // a node is identified by its preferred transport
+            if let MetaNetCon::Fallback { cons } = self {
+                if let Some(con) = cons.first() {
+                    return con.peer_id();
+                }
+            }
+        }
+
         {
             if let MetaNetCon::Tx5 { rem_url, .. } = self {
                 return rem_url.pub_key().0.clone().into();
             }
         }
 
+        {
+            if let MetaNetCon::Quic { rem_url, .. } = self {
+                if let Ok((_, _, digest)) = quic::parse_peer_url(rem_url) {
+                    return digest.into();
+                }
+            }
+        }
+
         panic!("invalid features");
     }
 }
 
+/// Split what is left of `timeout` evenly between the `remaining`
+/// fallback attempts, so one unreachable transport can't use it all up.
+fn fallback_timeout(timeout: &KitsuneTimeout, remaining: usize) -> KitsuneTimeout {
+    KitsuneTimeout::new(timeout.time_remaining() / remaining.max(1) as u32)
+}
+
 #[cfg(test)]
 #[derive(Debug)]
 pub struct MetaNetConTest {
@@ -577,18 +831,33 @@ pub enum MetaNet {
         res: ResStore,
         tun: KitsuneP2pTuningParams,
     },
+
+    /// Direct quic abstraction
+    Quic {
+        host: HostApiLegacy,
+        ep: Arc<quic::QuicEndpoint>,
+        res: ResStore,
+        tun: KitsuneP2pTuningParams,
+    },
+
+    /// Several of the above, listening at the same time,
+    /// in order of preference.
+    Multi { nets: Arc<[MetaNet]> },
 }
 
 impl MetaNet {
     /// Construct abstraction with tx5 backend.
+    ///
+    /// The endpoint listens on each of the `signal_urls`, the returned
+    /// list holds the peer urls of every listener that came up.
     pub async fn new_tx5(
         tuning_params: KitsuneP2pTuningParams,
         host: HostApiLegacy,
         kitsune_internal_sender: ghost_actor::GhostSender<crate::spawn::Internal>,
-        signal_url: String,
+        signal_urls: Vec<String>,
         webrtc_config: String,
         preflight_user_data: PreflightUserData,
-    ) -> KitsuneP2pResult<(Self, MetaNetEvtRecv, Option<String>)> {
+    ) -> KitsuneP2pResult<(Self, MetaNetEvtRecv, Vec<String>)> {
         use kitsune_p2p_types::codec::{rmp_decode, rmp_encode};
 
         let (mut evt_send, evt_recv) =
@@ -627,72 +896,9 @@ impl MetaNet {
             backoff_start: std::time::Duration::from_secs(tuning_params.tx5_backoff_start_s as u64),
             backoff_max: std::time::Duration::from_secs(tuning_params.tx5_backoff_max_s as u64),
             preflight: Some((
-                Arc::new(move |_| {
-                    let i_s = kitsune_internal_sender.clone();
-                    let user_data_sent = user_data_sent.clone();
-
-                    Box::pin(async move {
-                        let agent_list = i_s
-                            .get_all_local_joined_agent_infos()
-                            .await
-                            .unwrap_or_default();
-                        PreflightData::v0(KITSUNE_PROTOCOL_VERSION, agent_list, user_data_sent)
-                            .encode_vec()
-                    })
-                }),
+                Arc::new(move |_| preflight_gen(&kitsune_internal_sender, &user_data_sent)),
                 Arc::new(move |url, data| {
-                    let e_s = evt_sender.clone();
-                    let url = url.clone();
-                    match PreflightData::decode_ref(&data) {
-                        Ok((
-                            _,
-                            PreflightData::V0(V0 {
-                                kitsune_protocol_version,
-                                peer_list,
-                                user_data: user_data_bytes_received,
-                            }),
-                        )) => {
-                            if kitsune_protocol_version != KITSUNE_PROTOCOL_VERSION {
-                                tracing::warn!(
-                                    ?url,
-                                    "kitsune protocol version mismatch: ours = {}, theirs = {}",
-                                    KITSUNE_PROTOCOL_VERSION,
-                                    kitsune_protocol_version,
-                                );
-                                return box_fut_plain(Err(std::io::Error::other(
-                                    "kitsune protocol version mismatch",
-                                )));
-                            }
-
-                            if let Err(reason) = user_data_cmp(&url, &user_data_bytes_received) {
-                                tracing::warn!(?url, "tx5 preflight user_data mismatch");
-                                return box_fut_plain(Err(std::io::Error::other(
-                                    "tx5 preflight user_data mismatch",
-                                )));
-                            }
-                            Box::pin(async move {
-                                if let Err(err) = e_s
-                                    .put_agent_info_signed(PutAgentInfoSignedEvt {
-                                        peer_data: peer_list,
-                                    })
-                                    .await
-                                {
-                                    tracing::warn!(
-                                        ?err,
-                                        "error processing incoming agent info unsolicited"
-                                    );
-                                }
-                                Ok(())
-                            })
-                        }
-                        Err(err) => {
-                            tracing::warn!(?err, ?url, "Could not decode PreflightData");
-                            box_fut_plain(Err(std::io::Error::other(
-                                "Could not decode PreflightData",
-                            )))
-                        }
-                        _ => box_fut_plain(Err(std::io::Error::other("Unexpected wire message"))),
-                    }
+                    preflight_check(&evt_sender, &*user_data_cmp, url.as_ref(), &data)
                 }),
             )),
             backend_module,
@@ -712,13 +918,32 @@ impl MetaNet {
         {
             tracing::warn!(?err, "Tx5InitConfig failed, you must be running multiple conductors in the same process. Be aware they will all share whichever Tx5InitConfig was first to be registered.");
         }
-        let (ep_hnd, mut ep_evt) = tx5::Endpoint::new(Arc::new(tx5_config));
+        let (ep_hnd, ep_evt) = tx5::Endpoint::new(Arc::new(tx5_config));
         let ep_hnd = Arc::new(ep_hnd);
 
-        let maybe_peer_url = ep_hnd
-            .listen(tx5::SigUrl::parse(&signal_url)?)
+        let ep_evt = futures::stream::unfold(ep_evt, |mut ep_evt| async move {
+            ep_evt.recv().await.map(|evt| (evt, ep_evt))
+        })
+        .boxed();
+        // let tests inject faults into the messages of the mem backend
+        #[cfg(feature = "test_utils")]
+        let ep_evt = if tuning_params.tx5_backend_module == "mem" {
+            crate::test_util::mem_faults::intercept(ep_hnd.clone(), ep_evt)
+        } else {
+            ep_evt
+        };
+        let mut ep_evt = ep_evt;
+
+        let mut listens = Vec::with_capacity(signal_urls.len());
+        for signal_url in signal_urls.iter() {
+            listens.push(ep_hnd.listen(tx5::SigUrl::parse(signal_url)?));
+        }
+        let peer_urls = futures::future::join_all(listens)
             .await
-            .map(|p| p.to_string());
+            .into_iter()
+            .flatten()
+            .map(|p| p.to_string())
+            .collect();
 
         let res_store = Arc::new(Mutex::new(HashMap::new()));
 
@@ -727,7 +952,7 @@ impl MetaNet {
         let tuning_params2 = tuning_params.clone();
         let spawn_host = host.clone();
         tokio::task::spawn(async move {
-            while let Some(evt) = ep_evt.recv().await {
+            while let Some(evt) = ep_evt.next().await {
                 match evt {
                     tx5::EndpointEvent::ListeningAddressOpen { local_url } => {
                         tracing::info!(%local_url, "listening open");
@@ -896,7 +1121,253 @@ impl MetaNet {
                 tun: tuning_params,
             },
             evt_recv,
-            maybe_peer_url,
+            peer_urls,
+        ))
+    }
+
+    /// Combine several meta nets into one that listens on all of them.
+    ///
+    /// Events from all the nets are merged into the returned receiver.
+    pub fn new_multi(
+        tuning_params: &KitsuneP2pTuningParams,
+        nets: Vec<(Self, MetaNetEvtRecv)>,
+    ) -> (Self, MetaNetEvtRecv) {
+        let (mut evt_send, evt_recv) =
+            futures::channel::mpsc::channel(tuning_params.concurrent_limit_per_thread);
+
+        let (nets, recvs): (Vec<_>, Vec<_>) = nets.into_iter().unzip();
+
+        tokio::task::spawn(async move {
+            let mut recv = futures::stream::select_all(recvs);
+            while let Some(evt) = recv.next().await {
+                if evt_send.send(evt).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        (MetaNet::Multi { nets: nets.into() }, evt_recv)
+    }
+
+    /// The backends making up this meta net.
+    fn leaves(&self) -> &[MetaNet] {
+        match self {
+            MetaNet::Multi { nets } => nets,
+            _ => std::slice::from_ref(self),
+        }
+    }
+
+    /// How well suited this backend is to reach `remote_url`.
+    /// Zero if it cannot reach it at all.
+    fn url_affinity(&self, remote_url: &str) -> u8 {
+        {
+            if let MetaNet::Tx5 { ep, tun, .. } = self {
+                let peer_url = match PeerUrl::parse(remote_url) {
+                    Ok(peer_url) => peer_url,
+                    Err(_) => return 0,
+                };
+                let sig_url = peer_url.to_sig();
+                if ep
+                    .get_listening_addresses()
+                    .iter()
+                    .any(|l| l.to_sig() == sig_url)
+                {
+                    return 2;
+                }
+                // the mem backend can only reach peers on its own fake signal server
+                return if tun.tx5_backend_module == "mem" {
+                    0
+                } else {
+                    1
+                };
+            }
+        }
+
+        {
+            if let MetaNet::Quic { .. } = self {
+                return if quic::parse_peer_url(remote_url).is_ok() {
+                    1
+                } else {
+                    0
+                };
+            }
+        }
+
+        {
+            if let MetaNet::Multi { nets } = self {
+                return nets
+                    .iter()
+                    .map(|net| net.url_affinity(remote_url))
+                    .max()
+                    .unwrap_or(0);
+            }
+        }
+
+        0
+    }
+
+    /// Construct abstraction with a direct quic backend.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn new_quic(
+        tuning_params: KitsuneP2pTuningParams,
+        host: HostApiLegacy,
+        kitsune_internal_sender: ghost_actor::GhostSender<crate::spawn::Internal>,
+        tls_config: tls::TlsConfig,
+        bind_to: TxUrl,
+        override_host: Option<String>,
+        override_port: Option<u16>,
+        preflight_user_data: PreflightUserData,
+    ) -> KitsuneP2pResult<(Self, MetaNetEvtRecv, Vec<String>)> {
+        let (mut evt_send, evt_recv) =
+            futures::channel::mpsc::channel(tuning_params.concurrent_limit_per_thread);
+
+        let PreflightUserData {
+            bytes: user_data_sent,
+            comparator: user_data_cmp,
+        } = preflight_user_data;
+
+        let evt_sender = host.legacy.clone();
+        let quic_config = quic::QuicConfig {
+            bind_to: quic::resolve_bind_to(bind_to.as_url2()).await?,
+            override_host,
+            override_port,
+            tls: tls_config,
+            tuning_params: tuning_params.clone(),
+            preflight: (
+                Arc::new(move |_| preflight_gen(&kitsune_internal_sender, &user_data_sent)),
+                Arc::new(move |url, data| {
+                    preflight_check(&evt_sender, &*user_data_cmp, url, &data)
+                }),
+            ),
+        };
+
+        let (ep_hnd, mut ep_evt) = quic::QuicEndpoint::bind(quic_config).await?;
+        let local_url = ep_hnd.local_url();
+
+        tracing::info!(%local_url, "meta net startup quic");
+
+        let res_store: ResStore = Arc::new(Mutex::new(HashMap::new()));
+
+        let ep_hnd2 = ep_hnd.clone();
+        let res_store2 = res_store.clone();
+        let tuning_params2 = tuning_params.clone();
+        let spawn_host = host.clone();
+        tokio::task::spawn(async move {
+            let mk_con = |peer_url: &str| MetaNetCon::Quic {
+                host: spawn_host.clone(),
+                ep: ep_hnd2.clone(),
+                rem_url: peer_url.to_string(),
+                res: res_store2.clone(),
+                tun: tuning_params2.clone(),
+            };
+
+            while let Some(evt) = ep_evt.recv().await {
+                let evt = match evt {
+                    quic::QuicEvent::Connected { peer_url } => {
+                        tracing::debug!(%peer_url, "peer connected");
+                        MetaNetEvt::Connected {
+                            con: mk_con(&peer_url),
+                            remote_url: peer_url,
+                        }
+                    }
+                    quic::QuicEvent::Disconnected { peer_url } => {
+                        tracing::debug!(%peer_url, "peer disconnected");
+                        MetaNetEvt::Disconnected {
+                            con: mk_con(&peer_url),
+                            remote_url: peer_url,
+                        }
+                    }
+                    quic::QuicEvent::Message { peer_url, message } => {
+                        let (msg_id, data, is_request) =
+                            match WireWrap::decode(&mut std::io::Cursor::new(&message)) {
+                                Ok(WireWrap::Notify(Notify { msg_id, data })) => {
+                                    (msg_id, data, false)
+                                }
+                                Ok(WireWrap::Request(Request { msg_id, data })) => {
+                                    (msg_id, data, true)
+                                }
+                                Ok(WireWrap::Response(Response { msg_id, data })) => {
+                                    if let Some(s) = res_store2.lock().remove(&msg_id) {
+                                        match wire::Wire::decode_ref(&data) {
+                                            Ok((_, data)) => {
+                                                let _ = s.send(data);
+                                            }
+                                            Err(err) => {
+                                                tracing::error!(?err, "decoding error");
+                                                ep_hnd2.close_peer(&peer_url);
+                                            }
+                                        }
+                                    } else {
+                                        tracing::debug!(%msg_id, "response mismatch");
+                                    }
+                                    continue;
+                                }
+                                Err(err) => {
+                                    tracing::error!(?err, "decoding error");
+                                    ep_hnd2.close_peer(&peer_url);
+                                    continue;
+                                }
+                            };
+
+                        let data = match wire::Wire::decode_ref(&data) {
+                            Ok((_, data)) => data,
+                            Err(err) => {
+                                tracing::error!(?err, "decoding error");
+                                ep_hnd2.close_peer(&peer_url);
+                                continue;
+                            }
+                        };
+
+                        if is_request {
+                            let ep_hnd = ep_hnd2.clone();
+                            let peer_url2 = peer_url.clone();
+                            let respond: Respond = Box::new(move |data| {
+                                let out: RespondFut = Box::pin(async move {
+                                    let wire = match data.encode_vec() {
+                                        Ok(wire) => wire,
+                                        Err(_) => return,
+                                    };
+                                    let wrap = WireWrap::response(msg_id, WireData(wire));
+                                    let data = match wrap.encode_vec() {
+                                        Ok(data) => data,
+                                        Err(_) => return,
+                                    };
+                                    let _ = ep_hnd.send(&peer_url2, data).await;
+                                });
+                                out
+                            });
+                            MetaNetEvt::Request {
+                                con: mk_con(&peer_url),
+                                remote_url: peer_url,
+                                data,
+                                respond,
+                            }
+                        } else {
+                            tracing::trace!(%msg_id, ?data, "received notify");
+                            MetaNetEvt::Notify {
+                                con: mk_con(&peer_url),
+                                remote_url: peer_url,
+                                data,
+                            }
+                        }
+                    }
+                };
+
+                if evt_send.send(evt).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok((
+            MetaNet::Quic {
+                host,
+                ep: ep_hnd,
+                res: res_store,
+                tun: tuning_params,
+            },
+            evt_recv,
+            vec![local_url],
         ))
     }
 
@@ -907,35 +1378,57 @@ impl MetaNet {
     ) -> KitsuneResult<()> {
         let msg_id = next_msg_id();
 
-        {
-            if let MetaNet::Tx5 { ep, .. } = self {
-                let wire = payload.encode_vec().map_err(KitsuneError::other)?;
-                let wrap = WireWrap::notify(msg_id, WireData(wire));
+        let wire = payload.encode_vec().map_err(KitsuneError::other)?;
+        let wrap = WireWrap::notify(msg_id, WireData(wire));
+        let data = wrap.encode_vec().map_err(KitsuneError::other)?;
+
+        for net in self.leaves() {
+            {
+                if let MetaNet::Tx5 { ep, .. } = net {
+                    ep.broadcast(data.as_slice()).await;
+                    continue;
+                }
+            }
 
-                let data = wrap.encode_vec().map_err(KitsuneError::other)?;
-                ep.broadcast(data.as_slice()).await;
-                return Ok(());
+            {
+                if let MetaNet::Quic { ep, .. } = net {
+                    ep.broadcast(data.as_slice()).await;
+                    continue;
+                }
             }
+
+            return Err("invalid features".into());
         }
 
-        Err("invalid features".into())
+        Ok(())
     }
 
     pub async fn close(&self, code: u32, reason: &str) {
+        for net in self.leaves() {
+            if let MetaNet::Quic { ep, .. } = net {
+                ep.close();
+            }
+        }
 
         // TODO - currently no way to shutdown tx5
     }
 
     pub fn close_peer_con(&self, peer_url: TxUrl) -> KitsuneResult<()> {
-        {
+        for net in self.leaves() {
             // Even if tx5 is enabled, check that the peer_url is a ws or wss url to the signal server
             if peer_url.scheme() == "ws" || peer_url.scheme() == "wss" {
-                if let MetaNet::Tx5 { ep, .. } = self {
+                if let MetaNet::Tx5 { ep, .. } = net {
                     let peer_url =
                         PeerUrl::parse(peer_url.to_string()).map_err(KitsuneError::other)?;
                     ep.close(&peer_url);
                 }
             }
+
+            if peer_url.scheme() == quic::SCHEME {
+                if let MetaNet::Quic { ep, .. } = net {
+                    ep.close_peer(peer_url.as_str());
+                }
+            }
         }
 
         Ok(())
@@ -946,6 +1439,39 @@ impl MetaNet {
         remote_url: String,
         timeout: KitsuneTimeout,
     ) -> KitsuneResult<MetaNetCon> {
+        self.connection_for(remote_url)
+    }
+
+    /// Get a connection to a peer advertising all of `remote_urls`.
+    ///
+    /// Urls we have a transport for are tried in the order given,
+    /// falling back to the next one if sending fails.
+    pub async fn get_connection_any(
+        &self,
+        remote_urls: Vec<String>,
+        timeout: KitsuneTimeout,
+    ) -> KitsuneResult<MetaNetCon> {
+        let mut cons = Vec::with_capacity(remote_urls.len());
+        let mut last_err = None;
+        for remote_url in remote_urls {
+            match self.connection_for(remote_url) {
+                Ok(con) => {
+                    if !cons.contains(&con) {
+                        cons.push(con);
+                    }
+                }
+                Err(err) => last_err = Some(err),
+            }
+        }
+
+        match cons.len() {
+            0 => Err(last_err.unwrap_or_else(|| "no url - agent is likely offline".into())),
+            1 => Ok(cons.remove(0)),
+            _ => Ok(MetaNetCon::Fallback { cons: cons.into() }),
+        }
+    }
+
+    fn connection_for(&self, remote_url: String) -> KitsuneResult<MetaNetCon> {
         {
             if let MetaNet::Tx5 {
                 host, ep, res, tun, ..
@@ -961,6 +1487,41 @@ impl MetaNet {
             }
         }
 
+        {
+            if let MetaNet::Quic {
+                host, ep, res, tun, ..
+            } = self
+            {
+                quic::parse_peer_url(&remote_url)?;
+                return Ok(MetaNetCon::Quic {
+                    host: host.clone(),
+                    ep: ep.clone(),
+                    rem_url: remote_url,
+                    res: res.clone(),
+                    tun: tun.clone(),
+                });
+            }
+        }
+
+        {
+            if let MetaNet::Multi { nets } = self {
+                // prefer the backend best suited to the url, then the earliest
+                let mut best: Option<&MetaNet> = None;
+                let mut best_affinity = 0;
+                for net in nets.iter() {
+                    let affinity = net.url_affinity(&remote_url);
+                    if affinity > best_affinity {
+                        best = Some(net);
+                        best_affinity = affinity;
+                    }
+                }
+                return match best {
+                    Some(net) => net.connection_for(remote_url),
+                    None => Err(format!("no transport for url: {remote_url}").into()),
+                };
+            }
+        }
+
         Err("invalid features".into())
     }
 
@@ -983,6 +1544,32 @@ impl MetaNet {
             }
         }
 
+        {
+            if let MetaNet::Quic { ep, .. } = self {
+                let stats = ep.get_stats();
+                return async move { Ok(stats) }.boxed();
+            }
+        }
+
+        {
+            if let MetaNet::Multi { nets } = self {
+                // the first transport's stats stay at the top level,
+                // so existing consumers keep working
+                let all =
+                    futures::future::try_join_all(nets.iter().map(|net| net.dump_network_stats()));
+                return async move {
+                    let mut all = all.await?.into_iter();
+                    let mut stats = all.next().unwrap_or_else(|| serde_json::json!({}));
+                    let rest = all.collect::<Vec<_>>();
+                    if let Some(obj) = stats.as_object_mut() {
+                        obj.insert("transports".to_string(), rest.into());
+                    }
+                    Ok(stats)
+                }
+                .boxed();
+            }
+        }
+
         async move { Err("invalid features".into()) }.boxed()
     }
 }
@@ -0,0 +1,573 @@
+//! Direct QUIC backend for [`MetaNet`](super::MetaNet).
+//!
+//! Nodes bind a static UDP address and dial each other directly, there is
+//! no signal server involved. Peer urls take the form
+//! `kitsune-quic://<host>:<port>/<digest>`, where `<digest>` is the base64url
+//! encoded blake2b digest of the node's TLS certificate. The digest is
+//! verified when dialing, and doubles as the remote [`NodeCert`].
+//!
+//! Every message is sent on its own unidirectional stream. The first stream
+//! each side opens on a new connection carries the sender's advertised peer
+//! url followed by the preflight data, and no further messages are delivered
+//! until it has been accepted. Inbound connections are addressed by that
+//! advertised url, not by the ephemeral address they were dialed from.
+
+use futures::future::BoxFuture;
+use kitsune_p2p_types::config::KitsuneP2pTuningParams;
+use kitsune_p2p_types::dependencies::rustls;
+use kitsune_p2p_types::tls::TlsConfig;
+use kitsune_p2p_types::*;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+/// Url scheme used by the quic transport.
+pub const SCHEME: &str = "kitsune-quic";
+
+/// Alpn protocol identifier, bump on breaking wire changes.
+const ALPN_KITSUNE_QUIC_0: &[u8] = b"kitsune-quic/0";
+
+/// Peers are identified by cert digest, not by dns name,
+/// so every connection uses this same server name.
+const SERVER_NAME: &str = "kitsune.quic";
+
+/// Connection close code for a failed preflight.
+const CLOSE_PREFLIGHT: u32 = 1;
+
+/// Connection close code for a local close request.
+const CLOSE_LOCAL: u32 = 2;
+
+/// Generate the preflight bytes to send to a remote.
+pub type PreflightSend =
+    Arc<dyn Fn(&str) -> BoxFuture<'static, std::io::Result<Vec<u8>>> + 'static + Send + Sync>;
+
+/// Check the preflight bytes received from a remote.
+pub type PreflightCheck =
+    Arc<dyn Fn(&str, Vec<u8>) -> BoxFuture<'static, std::io::Result<()>> + 'static + Send + Sync>;
+
+/// Configuration for a [`QuicEndpoint`].
+pub struct QuicConfig {
+    /// The local address to bind.
+    pub bind_to: SocketAddr,
+
+    /// Host to advertise in our peer url instead of the bound ip.
+    pub override_host: Option<String>,
+
+    /// Port to advertise in our peer url instead of the bound port.
+    pub override_port: Option<u16>,
+
+    /// Our tls certificate, its digest becomes our node id.
+    pub tls: TlsConfig,
+
+    /// Tuning params.
+    pub tuning_params: KitsuneP2pTuningParams,
+
+    /// Preflight hooks.
+    pub preflight: (PreflightSend, PreflightCheck),
+}
+
+/// Events emitted by a [`QuicEndpoint`].
+#[derive(Debug)]
+pub enum QuicEvent {
+    /// A connection passed preflight and is ready for use.
+    Connected {
+        /// Url of the remote peer.
+        peer_url: String,
+    },
+
+    /// A connection was closed.
+    Disconnected {
+        /// Url of the remote peer.
+        peer_url: String,
+    },
+
+    /// A message was received.
+    Message {
+        /// Url of the remote peer.
+        peer_url: String,
+
+        /// The message content.
+        message: Vec<u8>,
+    },
+}
+
+/// Receiver for [`QuicEvent`]s.
+pub type QuicEventRecv = tokio::sync::mpsc::Receiver<QuicEvent>;
+
+/// Build the peer url for a remote address and cert digest.
+pub fn peer_url(host: &str, port: u16, digest: &[u8; 32]) -> String {
+    use base64::Engine;
+    let digest = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(digest);
+    format!("{SCHEME}://{host}:{port}/{digest}")
+}
+
+/// Split a peer url into its host, port and cert digest.
+pub fn parse_peer_url(url: &str) -> KitsuneResult<(String, u16, Arc<[u8; 32]>)> {
+    use base64::Engine;
+
+    let url = url2::Url2::try_parse(url).map_err(KitsuneError::other)?;
+    if url.scheme() != SCHEME {
+        return Err(format!("expected {SCHEME} url, got: {url}").into());
+    }
+    let host = url
+        .host_str()
+        .ok_or_else(|| KitsuneError::from(format!("no host in quic url: {url}")))?
+        .to_string();
+    let port = url
+        .port()
+        .ok_or_else(|| KitsuneError::from(format!("no port in quic url: {url}")))?;
+    let digest = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(url.path().trim_start_matches('/'))
+        .map_err(KitsuneError::other)?;
+    let digest: [u8; 32] = digest
+        .try_into()
+        .map_err(|_| KitsuneError::from(format!("bad cert digest in quic url: {url}")))?;
+    Ok((host, port, Arc::new(digest)))
+}
+
+/// Resolve a `kitsune-quic://host:port` bind url to a socket address.
+pub async fn resolve_bind_to(bind_to: &url2::Url2) -> KitsuneResult<SocketAddr> {
+    let host = bind_to.host_str().unwrap_or("0.0.0.0");
+    let port = bind_to.port().unwrap_or(0);
+    tokio::net::lookup_host((host, port))
+        .await
+        .map_err(KitsuneError::other)?
+        .next()
+        .ok_or_else(|| format!("could not resolve bind address: {bind_to}").into())
+}
+
+/// Prefix the preflight data with our advertised url.
+fn encode_preflight(local_url: &str, preflight: &[u8]) -> Vec<u8> {
+    let url = local_url.as_bytes();
+    let mut out = Vec::with_capacity(2 + url.len() + preflight.len());
+    out.extend_from_slice(&(url.len() as u16).to_be_bytes());
+    out.extend_from_slice(url);
+    out.extend_from_slice(preflight);
+    out
+}
+
+/// Split the first stream of a connection into the remote's advertised url
+/// and its preflight data.
+fn decode_preflight(mut data: Vec<u8>) -> KitsuneResult<(String, Vec<u8>)> {
+    if data.len() < 2 {
+        return Err("quic preflight too short".into());
+    }
+    let url_len = u16::from_be_bytes([data[0], data[1]]) as usize;
+    if data.len() < 2 + url_len {
+        return Err("quic preflight too short".into());
+    }
+    let preflight = data.split_off(2 + url_len);
+    let url = String::from_utf8(data.split_off(2)).map_err(KitsuneError::other)?;
+    Ok((url, preflight))
+}
+
+/// blake2b digest of a der encoded certificate,
+/// the same digest lair reports for our own cert.
+fn cert_digest(cert: &[u8]) -> Arc<[u8; 32]> {
+    let hash = blake2b_simd::Params::new().hash_length(32).hash(cert);
+    let mut out = [0; 32];
+    out.copy_from_slice(hash.as_bytes());
+    Arc::new(out)
+}
+
+/// Digest of the certificate the remote presented on this connection.
+fn remote_digest(con: &quinn::Connection) -> KitsuneResult<Arc<[u8; 32]>> {
+    con.peer_identity()
+        .and_then(|id| id.downcast::<Vec<rustls::Certificate>>().ok())
+        .and_then(|certs| certs.first().map(|c| cert_digest(&c.0)))
+        .ok_or_else(|| "remote presented no tls certificate".into())
+}
+
+/// Open connections, keyed by remote cert digest, with the remote url.
+type ConMap = HashMap<Arc<[u8; 32]>, (String, quinn::Connection)>;
+
+/// A quic endpoint, listening for and dialing direct peer connections.
+pub struct QuicEndpoint {
+    ep: quinn::Endpoint,
+    local_url: String,
+    advertise: (String, u16),
+    cons: Mutex<ConMap>,
+    evt_send: tokio::sync::mpsc::Sender<QuicEvent>,
+    preflight: (PreflightSend, PreflightCheck),
+    message_size_max: usize,
+}
+
+impl std::fmt::Debug for QuicEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QuicEndpoint")
+            .field("local_url", &self.local_url)
+            .finish()
+    }
+}
+
+impl QuicEndpoint {
+    /// Bind a new endpoint and start accepting connections.
+    pub async fn bind(config: QuicConfig) -> KitsuneResult<(Arc<Self>, QuicEventRecv)> {
+        let QuicConfig {
+            bind_to,
+            override_host,
+            override_port,
+            tls,
+            tuning_params,
+            preflight,
+        } = config;
+
+        let (tls_srv, tls_cli) =
+            tls::gen_tls_configs(ALPN_KITSUNE_QUIC_0, &tls, tuning_params.clone())?;
+
+        let mut transport = quinn::TransportConfig::default();
+        transport
+            .keep_alive_interval(Some(std::time::Duration::from_millis(
+                tuning_params.quic_keep_alive_interval_ms as u64,
+            )))
+            .max_idle_timeout(Some(
+                quinn::IdleTimeout::try_from(std::time::Duration::from_millis(
+                    tuning_params.quic_max_idle_timeout_ms as u64,
+                ))
+                .map_err(KitsuneError::other)?,
+            ));
+        let transport = Arc::new(transport);
+
+        let mut srv_config = quinn::ServerConfig::with_crypto(tls_srv);
+        srv_config.transport_config(transport.clone());
+        let mut cli_config = quinn::ClientConfig::new(tls_cli);
+        cli_config.transport_config(transport);
+
+        let mut ep = quinn::Endpoint::server(srv_config, bind_to).map_err(KitsuneError::other)?;
+        ep.set_default_client_config(cli_config);
+
+        let bound = ep.local_addr().map_err(KitsuneError::other)?;
+        let host = match override_host {
+            Some(host) => host,
+            None if bound.ip().is_unspecified() => {
+                tracing::warn!(
+                    %bound,
+                    "quic transport bound to an unspecified address without override_host, \
+                    advertising loopback, remote peers will not be able to reach this node"
+                );
+                if bound.is_ipv4() { "127.0.0.1" } else { "::1" }.to_string()
+            }
+            None => bound.ip().to_string(),
+        };
+        let port = override_port.unwrap_or_else(|| bound.port());
+        let local_url = peer_url(&host, port, &tls.cert_digest);
+
+        let (evt_send, evt_recv) =
+            tokio::sync::mpsc::channel(tuning_params.concurrent_limit_per_thread);
+
+        let this = Arc::new(Self {
+            ep,
+            local_url,
+            advertise: (host, port),
+            cons: Mutex::new(HashMap::new()),
+            evt_send,
+            preflight,
+            message_size_max: tuning_params.quic_message_size_max as usize,
+        });
+
+        let weak = Arc::downgrade(&this);
+        let ep = this.ep.clone();
+        tokio::task::spawn(async move {
+            while let Some(connecting) = ep.accept().await {
+                let weak = weak.clone();
+                tokio::task::spawn(async move {
+                    let remote_addr = connecting.remote_address();
+                    let con = match connecting.await {
+                        Ok(con) => con,
+                        Err(err) => {
+                            tracing::debug!(?err, %remote_addr, "quic incoming connection failed");
+                            return;
+                        }
+                    };
+                    let digest = match remote_digest(&con) {
+                        Ok(digest) => digest,
+                        Err(err) => {
+                            tracing::debug!(?err, %remote_addr, "quic incoming connection rejected");
+                            con.close(CLOSE_PREFLIGHT.into(), b"no cert");
+                            return;
+                        }
+                    };
+                    let Some(this) = weak.upgrade() else {
+                        return;
+                    };
+                    let (peer_url, preflight) = match this.read_preflight(&con).await {
+                        Ok(read) => read,
+                        Err(err) => {
+                            tracing::debug!(?err, %remote_addr, "quic incoming preflight read failed");
+                            con.close(CLOSE_PREFLIGHT.into(), b"preflight read failed");
+                            return;
+                        }
+                    };
+                    if !matches!(parse_peer_url(&peer_url), Ok((_, _, d)) if d == digest) {
+                        tracing::debug!(%remote_addr, %peer_url, "quic incoming advertised url does not match cert");
+                        con.close(CLOSE_PREFLIGHT.into(), b"advertised url mismatch");
+                        return;
+                    }
+                    if let Err(err) = this
+                        .establish(con, digest, peer_url.clone(), Some(preflight))
+                        .await
+                    {
+                        tracing::debug!(?err, %peer_url, "quic incoming preflight send failed");
+                    }
+                });
+            }
+            tracing::info!("quic endpoint closed");
+        });
+
+        Ok((this, evt_recv))
+    }
+
+    /// The url at which this endpoint can be reached.
+    pub fn local_url(&self) -> String {
+        self.local_url.clone()
+    }
+
+    /// Send a message to a remote peer, dialing if not yet connected.
+    pub async fn send(self: &Arc<Self>, peer_url: &str, data: Vec<u8>) -> KitsuneResult<()> {
+        let con = self.get_or_dial(peer_url).await?;
+        let mut stream = con.open_uni().await.map_err(KitsuneError::other)?;
+        stream.write_all(&data).await.map_err(KitsuneError::other)?;
+        stream.finish().await.map_err(KitsuneError::other)?;
+        Ok(())
+    }
+
+    /// Send a message to every currently connected peer.
+    pub async fn broadcast(self: &Arc<Self>, data: &[u8]) {
+        let urls = self
+            .cons
+            .lock()
+            .values()
+            .map(|(url, _)| url.clone())
+            .collect::<Vec<_>>();
+        futures::future::join_all(urls.iter().map(|url| async move {
+            if let Err(err) = self.send(url, data.to_vec()).await {
+                tracing::debug!(?err, %url, "quic broadcast send failed");
+            }
+        }))
+        .await;
+    }
+
+    /// Close any connection to the given peer.
+    pub fn close_peer(&self, peer_url: &str) {
+        if let Ok((_, _, digest)) = parse_peer_url(peer_url) {
+            if let Some((_, con)) = self.cons.lock().remove(&digest) {
+                con.close(CLOSE_LOCAL.into(), b"closed");
+            }
+        }
+    }
+
+    /// Close the endpoint and every connection on it.
+    pub fn close(&self) {
+        self.cons.lock().clear();
+        self.ep.close(CLOSE_LOCAL.into(), b"shutdown");
+    }
+
+    /// Summary of open connections, for `dump_network_stats`.
+    pub fn get_stats(&self) -> serde_json::Value {
+        let cons = self
+            .cons
+            .lock()
+            .values()
+            .map(|(url, con)| {
+                let stats = con.stats();
+                serde_json::json!({
+                    "peer_url": url,
+                    "rtt_ms": con.rtt().as_millis() as u64,
+                    "sent_bytes": stats.udp_tx.bytes,
+                    "recv_bytes": stats.udp_rx.bytes,
+                    "lost_packets": stats.path.lost_packets,
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::json!({
+            "backend": "quic",
+            "local_url": self.local_url,
+            "connections": cons,
+        })
+    }
+
+    async fn get_or_dial(self: &Arc<Self>, peer_url: &str) -> KitsuneResult<quinn::Connection> {
+        let (host, port, digest) = parse_peer_url(peer_url)?;
+
+        if let Some((_, con)) = self.cons.lock().get(&digest) {
+            if con.close_reason().is_none() {
+                return Ok(con.clone());
+            }
+        }
+
+        if (host.as_str(), port) == (self.advertise.0.as_str(), self.advertise.1) {
+            return Err("refusing to dial our own quic url".into());
+        }
+
+        let addr = tokio::net::lookup_host((host.as_str(), port))
+            .await
+            .map_err(KitsuneError::other)?
+            .next()
+            .ok_or_else(|| KitsuneError::from(format!("could not resolve {peer_url}")))?;
+
+        let con = self
+            .ep
+            .connect(addr, SERVER_NAME)
+            .map_err(KitsuneError::other)?
+            .await
+            .map_err(KitsuneError::other)?;
+
+        if remote_digest(&con)? != digest {
+            con.close(CLOSE_PREFLIGHT.into(), b"cert digest mismatch");
+            return Err(format!("remote cert does not match url: {peer_url}").into());
+        }
+
+        self.establish(con.clone(), digest, peer_url.to_string(), None)
+            .await?;
+        Ok(con)
+    }
+
+    /// Send our preflight, register the connection, and spawn the reader
+    /// that checks the remote's preflight before forwarding messages.
+    /// Inbound connections pass the remote preflight they have already read.
+    async fn establish(
+        self: &Arc<Self>,
+        con: quinn::Connection,
+        digest: Arc<[u8; 32]>,
+        peer_url: String,
+        remote_preflight: Option<Vec<u8>>,
+    ) -> KitsuneResult<()> {
+        let preflight = (self.preflight.0)(&peer_url)
+            .await
+            .map_err(KitsuneError::other)?;
+        let mut stream = con.open_uni().await.map_err(KitsuneError::other)?;
+        stream
+            .write_all(&encode_preflight(&self.local_url, &preflight))
+            .await
+            .map_err(KitsuneError::other)?;
+        stream.finish().await.map_err(KitsuneError::other)?;
+
+        self.cons
+            .lock()
+            .insert(digest.clone(), (peer_url.clone(), con.clone()));
+
+        let this = self.clone();
+        tokio::task::spawn(async move {
+            this.read_loop(&con, &peer_url, remote_preflight).await;
+
+            {
+                let mut cons = this.cons.lock();
+                if matches!(cons.get(&digest), Some((_, c)) if c.stable_id() == con.stable_id()) {
+                    cons.remove(&digest);
+                }
+            }
+            let _ = this
+                .evt_send
+                .send(QuicEvent::Disconnected { peer_url })
+                .await;
+        });
+
+        Ok(())
+    }
+
+    /// Read the first stream of a connection, holding the remote's
+    /// advertised url and its preflight data.
+    async fn read_preflight(&self, con: &quinn::Connection) -> KitsuneResult<(String, Vec<u8>)> {
+        let mut stream = con.accept_uni().await.map_err(KitsuneError::other)?;
+        let data = stream
+            .read_to_end(self.message_size_max)
+            .await
+            .map_err(KitsuneError::other)?;
+        decode_preflight(data)
+    }
+
+    async fn read_loop(&self, con: &quinn::Connection, peer_url: &str, preflight: Option<Vec<u8>>) {
+        let preflight = match preflight {
+            Some(preflight) => preflight,
+            // We dialed the url, so it's the one to use,
+            // whatever the remote advertises.
+            None => match self.read_preflight(con).await {
+                Ok((_, preflight)) => preflight,
+                Err(err) => {
+                    tracing::debug!(?err, %peer_url, "quic preflight read failed");
+                    con.close(CLOSE_PREFLIGHT.into(), b"preflight read failed");
+                    return;
+                }
+            },
+        };
+        if let Err(err) = (self.preflight.1)(peer_url, preflight).await {
+            tracing::warn!(?err, %peer_url, "quic preflight check failed");
+            con.close(CLOSE_PREFLIGHT.into(), b"preflight check failed");
+            return;
+        }
+
+        if self
+            .evt_send
+            .send(QuicEvent::Connected {
+                peer_url: peer_url.to_string(),
+            })
+            .await
+            .is_err()
+        {
+            return;
+        }
+
+        loop {
+            let mut stream = match con.accept_uni().await {
+                Ok(stream) => stream,
+                Err(err) => {
+                    tracing::debug!(?err, %peer_url, "quic connection closed");
+                    return;
+                }
+            };
+            let message = match stream.read_to_end(self.message_size_max).await {
+                Ok(message) => message,
+                Err(err) => {
+                    tracing::debug!(?err, %peer_url, "quic message read failed");
+                    continue;
+                }
+            };
+            tracing::trace!(%peer_url, byte_count = message.len(), "received bytes");
+            if self
+                .evt_send
+                .send(QuicEvent::Message {
+                    peer_url: peer_url.to_string(),
+                    message,
+                })
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peer_url_round_trip() {
+        let digest = [7; 32];
+        let url = peer_url("127.0.0.1", 5788, &digest);
+        assert!(url.starts_with("kitsune-quic://127.0.0.1:5788/"));
+
+        let (host, port, parsed) = parse_peer_url(&url).unwrap();
+        assert_eq!("127.0.0.1", host);
+        assert_eq!(5788, port);
+        assert_eq!(digest, *parsed);
+    }
+
+    #[test]
+    fn preflight_round_trip() {
+        let url = peer_url("10.0.0.1", 5788, &[7; 32]);
+        let data = encode_preflight(&url, &[1, 2, 3]);
+        assert_eq!((url, vec![1, 2, 3]), decode_preflight(data).unwrap());
+
+        assert!(decode_preflight(vec![0]).is_err());
+        assert!(decode_preflight(vec![0, 9, b'a']).is_err());
+    }
+
+    #[test]
+    fn parse_peer_url_rejects_other_schemes() {
+        assert!(parse_peer_url("ws://127.0.0.1:5788/AAAA").is_err());
+        assert!(parse_peer_url("kitsune-quic://127.0.0.1:5788/short").is_err());
+    }
+}
@@ -407,7 +407,7 @@ impl Test {
 
 struct Setup2Nodes {
     tuning_params: KitsuneP2pTuningParams,
-    _sig_hnd: sbd_server::SbdServer,
+    _sig_hnd: Option<sbd_server::SbdServer>,
     pub addr1: String,
     pub send1: MetaNet,
     pub addr2: String,
@@ -445,14 +445,14 @@ impl Setup2Nodes {
                 legacy: evt_sender.clone(),
             },
             i_s.clone(),
-            format!("ws://{sig_addr}"),
+            vec![format!("ws://{sig_addr}")],
             "{}".to_string(),
             user_data_a,
         )
         .await
         .unwrap();
 
-        let addr1 = addr1.unwrap();
+        let addr1 = addr1.into_iter().next().unwrap();
 
         test.spawn_receiver(recv1);
 
@@ -465,20 +465,61 @@ impl Setup2Nodes {
                 legacy: evt_sender.clone(),
             },
             i_s.clone(),
-            format!("ws://{sig_addr}"),
+            vec![format!("ws://{sig_addr}")],
             "{}".to_string(),
             user_data_b,
         )
         .await
         .unwrap();
 
-        let addr2 = addr2.unwrap();
+        let addr2 = addr2.into_iter().next().unwrap();
 
         test.spawn_receiver(recv2);
 
         Self {
             tuning_params,
-            _sig_hnd,
+            _sig_hnd: Some(_sig_hnd),
+            addr1,
+            send1,
+            addr2,
+            send2,
+        }
+    }
+
+    pub async fn new_quic(test: Test) -> Self {
+        Self::new_quic_with_user_data(
+            test,
+            PreflightUserData::default(),
+            PreflightUserData::default(),
+        )
+        .await
+    }
+
+    pub async fn new_quic_with_user_data(
+        test: Test,
+        user_data_a: PreflightUserData,
+        user_data_b: PreflightUserData,
+    ) -> Self {
+        let tuning_params =
+            Arc::new(config::tuning_params_struct::KitsuneP2pTuningParams::default());
+
+        let (test, i_s, evt_sender) = test.spawn().await;
+
+        let mut nodes = Vec::new();
+        for user_data in [user_data_a, user_data_b] {
+            let (send, recv, addr) =
+                quic_net(&test, &i_s, &evt_sender, &tuning_params, user_data).await;
+
+            test.spawn_receiver(recv);
+            nodes.push((send, addr));
+        }
+
+        let (send2, addr2) = nodes.pop().unwrap();
+        let (send1, addr1) = nodes.pop().unwrap();
+
+        Self {
+            tuning_params,
+            _sig_hnd: None,
             addr1,
             send1,
             addr2,
@@ -493,6 +534,32 @@ impl Setup2Nodes {
     }
 }
 
+/// bind a quic meta net on a random local port
+async fn quic_net(
+    test: &RunningTest,
+    i_s: &ghost_actor::GhostSender<Internal>,
+    evt_sender: &futures::channel::mpsc::Sender<KitsuneP2pEvent>,
+    tuning_params: &KitsuneP2pTuningParams,
+    user_data: PreflightUserData,
+) -> (MetaNet, MetaNetEvtRecv, String) {
+    let (send, recv, addr) = MetaNet::new_quic(
+        tuning_params.clone(),
+        HostApiLegacy {
+            api: Arc::new(test.clone()),
+            legacy: evt_sender.clone(),
+        },
+        i_s.clone(),
+        tls::TlsConfig::new_ephemeral().await.unwrap(),
+        TxUrl::from_str_panicking("kitsune-quic://127.0.0.1:0"),
+        None,
+        None,
+        user_data,
+    )
+    .await
+    .unwrap();
+    (send, recv, addr.into_iter().next().unwrap())
+}
+
 /// notify helper
 struct Notify(Arc<tokio::sync::Notify>);
 
@@ -1005,3 +1072,296 @@ async fn request_timeout() {
 
     nodes.shutdown().await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn quic_basic_notify() {
+    let (recv_not, recv_wait) = notify_pair();
+
+    let mut test = Test::default();
+
+    test.recv = Arc::new(move |evt| {
+        if let MetaNetEvt::Notify { data, .. } = evt {
+            assert!(matches!(
+                data,
+                wire::Wire::Failure(wire::Failure {
+                    reason,
+                }) if reason == "Hello World!",
+            ));
+            recv_not.notify();
+        }
+    });
+
+    let nodes = Setup2Nodes::new_quic(test).await;
+    assert!(nodes.addr2.starts_with("kitsune-quic://127.0.0.1:"));
+
+    let con = nodes
+        .send1
+        .get_connection(nodes.addr2.clone(), nodes.tuning_params.implicit_timeout())
+        .await
+        .unwrap();
+
+    con.notify(
+        &wire::Wire::failure("Hello World!".into()),
+        nodes.tuning_params.implicit_timeout(),
+    )
+    .await
+    .unwrap();
+
+    tokio::time::timeout(std::time::Duration::from_secs(10), recv_wait)
+        .await
+        .unwrap();
+
+    nodes.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn quic_basic_request() {
+    let mut test = Test::default();
+
+    test.recv = Arc::new(move |evt| {
+        if let MetaNetEvt::Request { data, respond, .. } = evt {
+            assert!(matches!(
+                data,
+                wire::Wire::Failure(wire::Failure {
+                    reason,
+                }) if reason == "hello",
+            ));
+            tokio::task::spawn(respond(wire::Wire::failure("world".into())));
+        }
+    });
+
+    let nodes = Setup2Nodes::new_quic(test).await;
+
+    let con = nodes
+        .send1
+        .get_connection(nodes.addr2.clone(), nodes.tuning_params.implicit_timeout())
+        .await
+        .unwrap();
+
+    let resp = con
+        .request(
+            &wire::Wire::failure("hello".into()),
+            nodes.tuning_params.implicit_timeout(),
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        resp,
+        wire::Wire::Failure(wire::Failure {
+            reason,
+        }) if reason == "world",
+    ));
+
+    nodes.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn quic_inbound_peer_is_addressed_by_its_advertised_url() {
+    let (recv_not, recv_wait) = notify_pair();
+    let remote_url = Arc::new(parking_lot::Mutex::new(None));
+
+    let mut test = Test::default();
+
+    let remote_url2 = remote_url.clone();
+    test.recv = Arc::new(move |evt| {
+        if let MetaNetEvt::Notify { remote_url, .. } = evt {
+            *remote_url2.lock() = Some(remote_url);
+            recv_not.notify();
+        }
+    });
+
+    let nodes = Setup2Nodes::new_quic(test).await;
+
+    let con = nodes
+        .send1
+        .get_connection(nodes.addr2.clone(), nodes.tuning_params.implicit_timeout())
+        .await
+        .unwrap();
+
+    con.notify(
+        &wire::Wire::failure("Hello World!".into()),
+        nodes.tuning_params.implicit_timeout(),
+    )
+    .await
+    .unwrap();
+
+    tokio::time::timeout(std::time::Duration::from_secs(10), recv_wait)
+        .await
+        .unwrap();
+
+    // Not the ephemeral address the connection was dialed from.
+    assert_eq!(Some(nodes.addr1.clone()), *remote_url.lock());
+
+    nodes.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn quic_preflight_user_data_mismatch() {
+    let (recv_not, recv_wait) = notify_pair();
+
+    let mut test = Test::default();
+
+    test.recv = Arc::new(move |evt| {
+        if let MetaNetEvt::Notify { .. } = evt {
+            recv_not.notify();
+        }
+    });
+
+    let ud1 = PreflightUserData {
+        bytes: vec![1, 2, 3],
+        comparator: Box::new(|_, r| {
+            (r == [1, 2, 3])
+                .then_some(())
+                .ok_or("preflight mismatch".into())
+        }),
+    };
+    let ud2 = PreflightUserData {
+        bytes: vec![9, 8, 7],
+        comparator: Box::new(|_, r| {
+            (r == [9, 8, 7])
+                .then_some(())
+                .ok_or("preflight mismatch".into())
+        }),
+    };
+
+    let nodes = Setup2Nodes::new_quic_with_user_data(test, ud1, ud2).await;
+
+    let con = nodes
+        .send1
+        .get_connection(nodes.addr2.clone(), nodes.tuning_params.implicit_timeout())
+        .await
+        .unwrap();
+
+    // the send itself may succeed before the remote rejects
+    // our preflight, but the message must never be delivered
+    let _ = con
+        .notify(
+            &wire::Wire::failure("Hello World!".into()),
+            nodes.tuning_params.implicit_timeout(),
+        )
+        .await;
+
+    tokio::time::timeout(std::time::Duration::from_millis(500), recv_wait)
+        .await
+        .unwrap_err();
+
+    nodes.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn multi_listens_on_all_transports() {
+    let recv_count = Arc::new(atomic::AtomicUsize::new(0));
+    let (recv_not, recv_wait) = notify_pair();
+
+    let mut test = Test::default();
+
+    let recv_count2 = recv_count.clone();
+    test.recv = Arc::new(move |evt| {
+        if let MetaNetEvt::Notify { .. } = evt {
+            if recv_count2.fetch_add(1, atomic::Ordering::SeqCst) == 1 {
+                recv_not.notify();
+            }
+        }
+    });
+
+    let tuning_params = Arc::new(config::tuning_params_struct::KitsuneP2pTuningParams::default());
+    let (test, i_s, evt_sender) = test.spawn().await;
+
+    let (send1, recv1, _) = quic_net(
+        &test,
+        &i_s,
+        &evt_sender,
+        &tuning_params,
+        PreflightUserData::default(),
+    )
+    .await;
+    test.spawn_receiver(recv1);
+
+    let (net_a, recv_a, addr_a) = quic_net(
+        &test,
+        &i_s,
+        &evt_sender,
+        &tuning_params,
+        PreflightUserData::default(),
+    )
+    .await;
+    let (net_b, recv_b, addr_b) = quic_net(
+        &test,
+        &i_s,
+        &evt_sender,
+        &tuning_params,
+        PreflightUserData::default(),
+    )
+    .await;
+    let (send2, recv2) = MetaNet::new_multi(&tuning_params, vec![(net_a, recv_a), (net_b, recv_b)]);
+    test.spawn_receiver(recv2);
+
+    for addr in [addr_a, addr_b] {
+        let con = send1
+            .get_connection(addr, tuning_params.implicit_timeout())
+            .await
+            .unwrap();
+        con.notify(
+            &wire::Wire::failure("Hello World!".into()),
+            tuning_params.implicit_timeout(),
+        )
+        .await
+        .unwrap();
+    }
+
+    tokio::time::timeout(std::time::Duration::from_secs(10), recv_wait)
+        .await
+        .unwrap();
+    assert_eq!(2, recv_count.load(atomic::Ordering::SeqCst));
+
+    send1.close(0, "").await;
+    send2.close(0, "").await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fallback_to_next_url_on_send_failure() {
+    let (recv_not, recv_wait) = notify_pair();
+
+    let mut test = Test::default();
+
+    test.recv = Arc::new(move |evt| {
+        if let MetaNetEvt::Notify { .. } = evt {
+            recv_not.notify();
+        }
+    });
+
+    let nodes = Setup2Nodes::new_quic(test).await;
+
+    // an address nobody is listening on any more
+    let dead_port = std::net::UdpSocket::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let (_, _, digest) = quic::parse_peer_url(&nodes.addr2).unwrap();
+    let dead_addr = quic::peer_url("127.0.0.1", dead_port, &digest);
+
+    let con = nodes
+        .send1
+        .get_connection_any(
+            vec![dead_addr, nodes.addr2.clone()],
+            KitsuneTimeout::from_millis(6000),
+        )
+        .await
+        .unwrap();
+    assert!(matches!(con, MetaNetCon::Fallback { .. }));
+
+    con.notify(
+        &wire::Wire::failure("Hello World!".into()),
+        KitsuneTimeout::from_millis(6000),
+    )
+    .await
+    .unwrap();
+
+    tokio::time::timeout(std::time::Duration::from_secs(10), recv_wait)
+        .await
+        .unwrap();
+
+    nodes.shutdown().await;
+}
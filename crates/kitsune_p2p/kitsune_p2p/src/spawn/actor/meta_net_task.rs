@@ -17,6 +17,7 @@ use ghost_actor::{GhostError, GhostSender};
 use kitsune_p2p_fetch::{FetchKey, FetchPool, FetchResponseQueue};
 use kitsune_p2p_timestamp::Timestamp;
 use kitsune_p2p_types::config::KitsuneP2pConfig;
+use kitsune_p2p_types::dht::region::RegionCoords;
 use std::error::Error;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -320,6 +321,64 @@ impl MetaNetTask {
         respond(resp).await;
     }
 
+    /// Respond to a fetch of a whole region by pushing all the ops we hold in it.
+    /// Each op is tagged with the region, and the last one marks it complete.
+    async fn respond_to_region_fetch(
+        &self,
+        space: Arc<KitsuneSpace>,
+        coords: RegionCoords,
+        con: &MetaNetCon,
+        url: &str,
+    ) -> MetaNetTaskResult<()> {
+        let op_hash_list: Vec<_> = match self
+            .host
+            .query_op_hashes_by_region(space.clone(), coords)
+            .await
+        {
+            Ok(hashes) => hashes.into_iter().map(|h| h.into_inner().0).collect(),
+            Err(err) => {
+                tracing::warn!(?err, "failed to query op hashes for region fetch");
+                return Ok(());
+            }
+        };
+
+        if op_hash_list.is_empty() {
+            return Ok(());
+        }
+
+        match self
+            .host
+            .legacy
+            .fetch_op_data(FetchOpDataEvt {
+                space: space.clone(),
+                query: FetchOpDataEvtQuery::Hashes {
+                    op_hash_list,
+                    include_limbo: false,
+                },
+            })
+            .await
+        {
+            Ok(list) => {
+                let count = list.len();
+                for (i, (_hash, op)) in list.into_iter().enumerate() {
+                    self.fetch_response_queue.enqueue_op(
+                        space.clone(),
+                        (con.clone(), url.to_string(), Some((coords, i + 1 == count))),
+                        op,
+                    );
+                }
+            }
+            Err(KitsuneP2pError::GhostError(GhostError::Disconnected)) => {
+                return Err(MetaNetTaskError::RequiredChannelClosed)
+            }
+            Err(err) => {
+                tracing::warn!(?err, "failed to fetch op data for region fetch");
+            }
+        }
+
+        Ok(())
+    }
+
     async fn handle_notify(
         &self,
         url: String,
@@ -488,9 +547,17 @@ impl MetaNetTask {
             wire::Wire::FetchOp(wire::FetchOp { fetch_list }) => {
                 for (space, key_list) in fetch_list {
                     let mut hashes = Vec::new();
+                    let mut regions = Vec::new();
                     for key in key_list {
-                        let FetchKey::Op(op_hash) = key;
-                        hashes.push(op_hash);
+                        match key {
+                            FetchKey::Op(op_hash) => hashes.push(op_hash),
+                            FetchKey::Region(coords) => regions.push(coords),
+                        }
+                    }
+
+                    for coords in regions {
+                        self.respond_to_region_fetch(space.clone(), coords, &con, &url)
+                            .await?;
                     }
 
                     if !hashes.is_empty() {
@@ -543,7 +610,11 @@ impl MetaNetTask {
                             }
                         };
 
-                        let key = FetchKey::Op(op_hash.clone());
+                        // Ops pushed in response to a region fetch are tracked by their region
+                        let key = match op.region {
+                            Some((coords, _)) => FetchKey::Region(coords),
+                            None => FetchKey::Op(op_hash.clone()),
+                        };
                         let fetch_context = match self.fetch_pool.check_item(&key) {
                             (true, maybe_fetch_context) => maybe_fetch_context,
                             (false, _) => {
@@ -578,7 +649,18 @@ impl MetaNetTask {
                         // Now that the host is holding the op, remove it from the fetch pool. Any sooner and we might queue the op for fetching again.
                         // We don't need to wait for validation to complete, at least with respect to gossip, because we don't ask for unvalidated
                         // ops during gossip. (See crates/holochain/src/conductor/kitsune_host_impl/query_region_set.rs)
-                        self.fetch_pool.remove(&key);
+                        // A region is only complete once its final op has arrived.
+                        match op.region {
+                            Some((_, is_final)) => {
+                                self.fetch_pool.remove(&FetchKey::Op(op_hash.clone()));
+                                if is_final {
+                                    self.fetch_pool.remove(&key);
+                                }
+                            }
+                            None => {
+                                self.fetch_pool.remove(&key);
+                            }
+                        }
 
                         // trigger any delegation that is pending on having this data
                         if let Err(err) = self
@@ -671,9 +753,11 @@ mod tests {
     use ghost_actor::{GhostControlSender, GhostSender};
     use kitsune_p2p::KitsuneBinType;
     use kitsune_p2p_block::{Block, BlockTarget, NodeBlockReason, NodeId};
-    use kitsune_p2p_fetch::test_utils::{test_key_op, test_req_op, test_source, test_space};
+    use kitsune_p2p_fetch::test_utils::{
+        test_key_op, test_key_region, test_req_op, test_source, test_space,
+    };
     use kitsune_p2p_fetch::TransferMethod;
-    use kitsune_p2p_fetch::{FetchPool, FetchResponseQueue};
+    use kitsune_p2p_fetch::{FetchKey, FetchPool, FetchPoolPush, FetchResponseQueue};
     use kitsune_p2p_timestamp::{InclusiveTimestampInterval, Timestamp};
     use kitsune_p2p_types::bin_types::NodeCert;
     use kitsune_p2p_types::config::KitsuneP2pConfig;
@@ -1880,6 +1964,52 @@ mod tests {
         assert_eq!(1, host_receiver_stub.receive_ops_calls.read().len());
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn send_notify_push_op_data_completes_region_on_final_op() {
+        let (mut ep_evt_send, _, _, host_receiver_stub, _, _, fetch_pool, _) = setup().await;
+
+        let key = test_key_region(1);
+        let FetchKey::Region(coords) = key.clone() else {
+            unreachable!()
+        };
+        fetch_pool.push(FetchPoolPush {
+            key,
+            ..test_req_op(1, None, test_source(2))
+        });
+        assert_eq!(1, fetch_pool.len());
+
+        ep_evt_send
+            .send(MetaNetEvt::Notify {
+                remote_url: "".to_string(),
+                con: mk_test_con(),
+                data: wire::Wire::PushOpData(wire::PushOpData {
+                    op_data_list: vec![(
+                        test_space(1),
+                        vec![
+                            PushOpItem {
+                                op_data: KitsuneOpData::new(vec![1, 4, 10]),
+                                region: Some((coords, false)),
+                            },
+                            PushOpItem {
+                                op_data: KitsuneOpData::new(vec![1, 4, 20]),
+                                region: Some((coords, true)),
+                            },
+                        ],
+                    )],
+                }),
+            })
+            .await
+            .unwrap();
+
+        wait_for_condition(|| fetch_pool.is_empty())
+            .await
+            .expect("Timed out waiting for region to complete");
+
+        // Both ops are received, and the region is only removed with the final one
+        assert!(fetch_pool.is_empty());
+        assert_eq!(2, host_receiver_stub.receive_ops_calls.read().len());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn send_notify_push_op_data_fails_independently_on_op_hash_error() {
         let (mut ep_evt_send, _, _, host_receiver_stub, host_stub, _, fetch_pool, _) =
@@ -121,11 +121,11 @@ pub(crate) async fn spawn_space(
     ep_hnd: MetaNet,
     host: HostApi,
     config: Arc<KitsuneP2pConfig>,
-    bootstrap_net: BootstrapNet,
+    bootstrap_nets: Vec<BootstrapNet>,
     bandwidth_throttles: BandwidthThrottles,
     parallel_notify_permit: Arc<tokio::sync::Semaphore>,
     fetch_pool: FetchPool,
-    local_url: Arc<std::sync::Mutex<Option<String>>>,
+    local_urls: Arc<std::sync::Mutex<Vec<String>>>,
 ) -> KitsuneP2pResult<(
     ghost_actor::GhostSender<KitsuneP2p>,
     ghost_actor::GhostSender<SpaceInternal>,
@@ -153,11 +153,11 @@ pub(crate) async fn spawn_space(
         host,
         ep_hnd,
         config,
-        bootstrap_net,
+        bootstrap_nets,
         bandwidth_throttles,
         parallel_notify_permit,
         fetch_pool,
-        local_url,
+        local_urls,
     )));
 
     Ok((sender, i_s, evt_recv))
@@ -175,7 +175,7 @@ impl SpaceInternalHandler for Space {
                 module.local_agent_join(agent.clone());
             }
         }
-        *self.ro_inner.local_url.lock().unwrap() = Some(local_url);
+        merge_local_url(&mut self.ro_inner.local_urls.lock().unwrap(), local_url);
         self.handle_update_agent_info()
     }
 
@@ -204,10 +204,10 @@ impl SpaceInternalHandler for Space {
     }
 
     fn handle_update_agent_info(&mut self) -> SpaceInternalHandlerResult<()> {
-        let local_url = match self.ro_inner.get_local_url() {
-            None => return Ok(async move { Ok(()) }.boxed().into()),
-            Some(local_url) => local_url,
-        };
+        let local_urls = self.ro_inner.get_local_urls();
+        if local_urls.is_empty() {
+            return Ok(async move { Ok(()) }.boxed().into());
+        }
         let space = self.space.clone();
         let mut mdns_handles = self.mdns_handles.clone();
         let mut agent_list = Vec::with_capacity(self.local_joined_agents.len());
@@ -215,9 +215,9 @@ impl SpaceInternalHandler for Space {
             let arq = self.get_agent_arq(&agent);
             agent_list.push((agent, arq));
         }
-        let bootstrap_net = self.ro_inner.bootstrap_net;
+        let bootstrap_nets = self.ro_inner.bootstrap_nets.clone();
         let evt_sender = self.host_api.legacy.clone();
-        let bootstrap_service = self.config.bootstrap_service.clone();
+        let bootstrap_services = self.config.bootstrap_services();
         let expires_after = self.config.tuning_params.agent_info_expires_after_ms as u64;
         #[cfg(feature = "unstable-sharding")]
         let dynamic_arcs = self.config.tuning_params.gossip_dynamic_arcs;
@@ -225,20 +225,23 @@ impl SpaceInternalHandler for Space {
         let dynamic_arcs = false;
         let internal_sender = self.i_s.clone();
         Ok(async move {
-            let urls = vec![TxUrl::try_from(local_url)?];
+            let urls = local_urls
+                .into_iter()
+                .map(TxUrl::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
             let mut peer_data = Vec::with_capacity(agent_list.len());
             for (agent, arq) in agent_list {
                 let input = UpdateAgentInfoInput {
                     expires_after,
                     space: space.clone(),
                     agent,
-                    bootstrap_net,
+                    bootstrap_nets: &bootstrap_nets,
                     arq,
                     urls: &urls,
                     evt_sender: &evt_sender,
                     internal_sender: &internal_sender,
                     _mdns_handles: &mut mdns_handles,
-                    bootstrap_service: &bootstrap_service,
+                    bootstrap_services: &bootstrap_services,
                     dynamic_arcs,
                 };
                 peer_data.push(update_single_agent_info(input).await?);
@@ -256,16 +259,16 @@ impl SpaceInternalHandler for Space {
         &mut self,
         agent: Arc<KitsuneAgent>,
     ) -> SpaceInternalHandlerResult<()> {
-        let local_url = match self.ro_inner.get_local_url() {
-            None => return Ok(async move { Ok(()) }.boxed().into()),
-            Some(local_url) => local_url,
-        };
+        let local_urls = self.ro_inner.get_local_urls();
+        if local_urls.is_empty() {
+            return Ok(async move { Ok(()) }.boxed().into());
+        }
         let space = self.space.clone();
-        let bootstrap_net = self.ro_inner.bootstrap_net;
+        let bootstrap_nets = self.ro_inner.bootstrap_nets.clone();
         let mut mdns_handles = self.mdns_handles.clone();
         let evt_sender = self.host_api.legacy.clone();
         let internal_sender = self.i_s.clone();
-        let bootstrap_service = self.config.bootstrap_service.clone();
+        let bootstrap_services = self.config.bootstrap_services();
         let expires_after = self.config.tuning_params.agent_info_expires_after_ms as u64;
         #[cfg(feature = "unstable-sharding")]
         let dynamic_arcs = self.config.tuning_params.gossip_dynamic_arcs;
@@ -274,18 +277,21 @@ impl SpaceInternalHandler for Space {
         let arc = self.get_agent_arq(&agent);
 
         Ok(async move {
-            let urls = vec![TxUrl::try_from(local_url)?];
+            let urls = local_urls
+                .into_iter()
+                .map(TxUrl::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
             let input = UpdateAgentInfoInput {
                 expires_after,
                 space: space.clone(),
                 agent,
-                bootstrap_net,
+                bootstrap_nets: &bootstrap_nets,
                 arq: arc,
                 urls: &urls,
                 evt_sender: &evt_sender,
                 internal_sender: &internal_sender,
                 _mdns_handles: &mut mdns_handles,
-                bootstrap_service: &bootstrap_service,
+                bootstrap_services: &bootstrap_services,
                 dynamic_arcs,
             };
             let peer_data = vec![update_single_agent_info(input).await?];
@@ -663,13 +669,13 @@ struct UpdateAgentInfoInput<'borrow> {
     expires_after: u64,
     space: Arc<KitsuneSpace>,
     agent: Arc<KitsuneAgent>,
-    bootstrap_net: BootstrapNet,
+    bootstrap_nets: &'borrow [BootstrapNet],
     arq: Arq,
     urls: &'borrow Vec<TxUrl>,
     evt_sender: &'borrow futures::channel::mpsc::Sender<KitsuneP2pEvent>,
     internal_sender: &'borrow ghost_actor::GhostSender<SpaceInternal>,
     _mdns_handles: &'borrow mut HashMap<Vec<u8>, Arc<AtomicBool>>,
-    bootstrap_service: &'borrow Option<Url2>,
+    bootstrap_services: &'borrow [Url2],
     dynamic_arcs: bool,
 }
 
@@ -708,13 +714,13 @@ async fn update_single_agent_info(
         expires_after,
         space,
         agent,
-        bootstrap_net,
+        bootstrap_nets,
         mut arq,
         urls,
         evt_sender,
         internal_sender,
         _mdns_handles,
-        bootstrap_service,
+        bootstrap_services,
         dynamic_arcs,
     } = input;
 
@@ -725,7 +731,8 @@ async fn update_single_agent_info(
     // Update the agents arc through the internal sender.
     internal_sender.update_agent_arc(agent.clone(), arq).await?;
 
-    let signed_at_ms = kitsune_p2p_bootstrap_client::now_once(None, bootstrap_net).await?;
+    // Without a url the net is never sent, so any will do.
+    let signed_at_ms = kitsune_p2p_bootstrap_client::now_once(None, BootstrapNet::Tx5).await?;
     let expires_at_ms = signed_at_ms + expires_after;
 
     let agent_info_signed = AgentInfoSigned::sign(
@@ -766,17 +773,35 @@ async fn update_single_agent_info(
     }
 
     // bootstrap stuff
-    {
-        kitsune_p2p_bootstrap_client::put(
-            bootstrap_service.clone(),
-            agent_info_signed.clone(),
-            bootstrap_net,
-        )
-        .await?;
-    }
+    put_to_bootstrap(bootstrap_services, &agent_info_signed, bootstrap_nets).await?;
     Ok(agent_info_signed)
 }
 
+/// Put an agent info into the bootstrap bucket of every kind of transport we
+/// listen on, so that peers which share only one of them with us can find it.
+async fn put_to_bootstrap(
+    bootstrap_services: &[Url2],
+    agent_info_signed: &AgentInfoSigned,
+    bootstrap_nets: &[BootstrapNet],
+) -> KitsuneP2pResult<()> {
+    let mut result = Ok(());
+    for net in bootstrap_nets {
+        if let Err(err) = kitsune_p2p_bootstrap_client::with_failover(bootstrap_services, |url| {
+            kitsune_p2p_bootstrap_client::put(url, agent_info_signed.clone(), *net)
+        })
+        .await
+        {
+            tracing::debug!(
+                ?err,
+                ?net,
+                "Failed to put agent info to the bootstrap service"
+            );
+            result = Err(err.into());
+        }
+    }
+    result
+}
+
 use crate::spawn::actor::space::agent_info_update::AgentInfoUpdateTask;
 use crate::spawn::actor::space::bootstrap_task::BootstrapTask;
 use ghost_actor::dependencies::must_future::MustBoxFuture;
@@ -1237,6 +1262,31 @@ impl KitsuneP2pHandler for Space {
     }
 }
 
+/// Record a new listening address.
+///
+/// A listener that comes back with a new address (same scheme, host
+/// and port) replaces its old entry in place, so the order of
+/// preference across transports is kept. Anything else is appended.
+fn merge_local_url(local_urls: &mut Vec<String>, local_url: String) {
+    fn listener(url: &str) -> Option<(String, Option<String>, Option<u16>)> {
+        let url = url2::Url2::try_parse(url).ok()?;
+        Some((
+            url.scheme().to_string(),
+            url.host_str().map(|h| h.to_string()),
+            url.port_or_known_default(),
+        ))
+    }
+
+    let key = listener(&local_url);
+    match local_urls
+        .iter_mut()
+        .find(|u| key.is_some() && listener(u) == key)
+    {
+        Some(existing) => *existing = local_url,
+        None => local_urls.push(local_url),
+    }
+}
+
 pub(crate) struct PendingDelegate {
     pub(crate) space: KSpace,
     pub(crate) basis: KBasis,
@@ -1247,7 +1297,7 @@ pub(crate) struct PendingDelegate {
 }
 
 pub(crate) struct SpaceReadOnlyInner {
-    pub(crate) local_url: Arc<std::sync::Mutex<Option<String>>>,
+    pub(crate) local_urls: Arc<std::sync::Mutex<Vec<String>>>,
     pub(crate) space: Arc<KitsuneSpace>,
     #[allow(dead_code)]
     pub(crate) i_s: ghost_actor::GhostSender<SpaceInternal>,
@@ -1255,7 +1305,7 @@ pub(crate) struct SpaceReadOnlyInner {
     pub(crate) ep_hnd: MetaNet,
     #[allow(dead_code)]
     pub(crate) config: Arc<KitsuneP2pConfig>,
-    pub(crate) bootstrap_net: BootstrapNet,
+    pub(crate) bootstrap_nets: Vec<BootstrapNet>,
     pub(crate) parallel_notify_permit: Arc<tokio::sync::Semaphore>,
     pub(crate) metrics: MetricsSync,
     pub(crate) metric_exchange: MetricExchangeSync,
@@ -1265,8 +1315,8 @@ pub(crate) struct SpaceReadOnlyInner {
 }
 
 impl SpaceReadOnlyInner {
-    pub(crate) fn get_local_url(&self) -> Option<String> {
-        self.local_url.lock().unwrap().clone()
+    pub(crate) fn get_local_urls(&self) -> Vec<String> {
+        self.local_urls.lock().unwrap().clone()
     }
 
     pub(crate) fn publish_pending_delegate(
@@ -1339,11 +1389,11 @@ impl Space {
         host_api: HostApiLegacy,
         ep_hnd: MetaNet,
         config: Arc<KitsuneP2pConfig>,
-        bootstrap_net: BootstrapNet,
+        bootstrap_nets: Vec<BootstrapNet>,
         bandwidth_throttles: BandwidthThrottles,
         parallel_notify_permit: Arc<tokio::sync::Semaphore>,
         fetch_pool: FetchPool,
-        local_url: Arc<std::sync::Mutex<Option<String>>>,
+        local_urls: Arc<std::sync::Mutex<Vec<String>>>,
     ) -> Self {
         let metrics = MetricsSync::default();
 
@@ -1431,16 +1481,15 @@ impl Space {
             ),
         );
 
-        if config.bootstrap_service.is_some() {
+        let bootstrap_services = config.bootstrap_services();
+        if !bootstrap_services.is_empty() {
             // spawn the periodic bootstrap pull
             BootstrapTask::spawn(
                 i_s.clone(),
                 host_api.legacy.clone(),
                 space.clone(),
-                // TODO: refactor to take just the Url. We know that nothing will
-                //       happen if this is None.
-                config.bootstrap_service.clone(),
-                bootstrap_net,
+                bootstrap_services,
+                bootstrap_nets.clone(),
                 config
                     .tuning_params
                     .bootstrap_check_delay_backoff_multiplier,
@@ -1449,13 +1498,13 @@ impl Space {
         }
 
         let ro_inner = Arc::new(SpaceReadOnlyInner {
-            local_url,
+            local_urls,
             space: space.clone(),
             i_s: i_s.clone(),
             host_api: host_api.clone(),
             ep_hnd,
             config: config.clone(),
-            bootstrap_net,
+            bootstrap_nets,
             parallel_notify_permit,
             metrics,
             metric_exchange,
@@ -1491,14 +1540,16 @@ impl Space {
         agent: Arc<KitsuneAgent>,
     ) -> KitsuneP2pHandlerResult<()> {
         let space = self.space.clone();
-        let bootstrap_net = self.ro_inner.bootstrap_net;
+        let bootstrap_nets = self.ro_inner.bootstrap_nets.clone();
         let evt_sender = self.host_api.legacy.clone();
-        let bootstrap_service = self.config.bootstrap_service.clone();
+        let bootstrap_services = self.config.bootstrap_services();
         let expires_after = self.config.tuning_params.agent_info_expires_after_ms as u64;
         let host = self.host_api.clone();
 
         Ok(async move {
-            let signed_at_ms = kitsune_p2p_bootstrap_client::now_once(None, bootstrap_net).await?;
+            // Without a url the net is never sent, so any will do.
+            let signed_at_ms =
+                kitsune_p2p_bootstrap_client::now_once(None, BootstrapNet::Tx5).await?;
             let expires_at_ms = signed_at_ms + expires_after;
             let agent_info_signed = AgentInfoSigned::sign(
                 space.clone(),
@@ -1537,12 +1588,8 @@ impl Space {
             // Push to the network as well
 
             {
-                match kitsune_p2p_bootstrap_client::put(
-                    bootstrap_service.clone(),
-                    agent_info_signed,
-                    bootstrap_net,
-                )
-                .await
+                match put_to_bootstrap(&bootstrap_services, &agent_info_signed, &bootstrap_nets)
+                    .await
                 {
                     Ok(_) => {
                         tracing::debug!("Successfully publish agent info to the bootstrap service");
@@ -1606,3 +1653,84 @@ async fn put_local_agent_info(
         .await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::merge_local_url;
+    use super::put_to_bootstrap;
+    use crate::test_util::data::mk_agent_info;
+    use kitsune_p2p_bootstrap_client::BootstrapNet;
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Serve bootstrap puts, recording the net each was made to.
+    async fn record_put_nets() -> (url2::Url2, Arc<parking_lot::Mutex<Vec<String>>>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = url2::url2!("http://{}", listener.local_addr().unwrap());
+        let nets = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let nets2 = nets.clone();
+        tokio::task::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut req = Vec::new();
+                let mut buf = [0; 4096];
+                // The put body is small, so wait for the end of the headers
+                // and then whatever of the body is left.
+                while !req.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let read = socket.read(&mut buf).await.unwrap();
+                    req.extend_from_slice(&buf[..read]);
+                }
+                let req = String::from_utf8_lossy(&req).to_string();
+                let net = req
+                    .split_whitespace()
+                    .nth(1)
+                    .and_then(|path| path.split("net=").nth(1))
+                    .unwrap_or_default()
+                    .to_string();
+                nets2.lock().push(net);
+                // Respond with a messagepack nil, the unit returned by a put.
+                socket
+                    .write_all(
+                        b"HTTP/1.1 200 OK\r\ncontent-length: 1\r\nconnection: close\r\n\r\n\xc0",
+                    )
+                    .await
+                    .unwrap();
+                let _ = socket.shutdown().await;
+            }
+        });
+        (url, nets)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn put_to_bootstrap_puts_to_the_bucket_of_every_transport() {
+        let (url, nets) = record_put_nets().await;
+
+        put_to_bootstrap(
+            &[url],
+            &mk_agent_info(1).await,
+            &[BootstrapNet::Quic, BootstrapNet::Tx5],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(vec!["quic".to_string(), "tx5".to_string()], *nets.lock());
+    }
+
+    #[test]
+    fn merge_local_url_keeps_one_url_per_listener() {
+        let mut urls = Vec::new();
+        merge_local_url(&mut urls, "wss://sig1.test:443/aaaa".to_string());
+        merge_local_url(&mut urls, "wss://sig2.test/bbbb".to_string());
+        merge_local_url(&mut urls, "kitsune-quic://1.2.3.4:5678/cccc".to_string());
+        // reconnecting to the first signal server replaces its entry in place
+        merge_local_url(&mut urls, "wss://sig1.test/dddd".to_string());
+
+        assert_eq!(
+            vec![
+                "wss://sig1.test/dddd".to_string(),
+                "wss://sig2.test/bbbb".to_string(),
+                "kitsune-quic://1.2.3.4:5678/cccc".to_string(),
+            ],
+            urls
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::event::{KitsuneP2pEvent, KitsuneP2pEventSender, PutAgentInfoSignedEvt};
+use crate::event::{KitsuneP2pEvent, KitsuneP2pEventSender, PutAgentInfoSignedEvt, QueryAgentsEvt};
 use crate::spawn::actor::space::{SpaceInternal, SpaceInternalSender};
 use crate::{KitsuneP2pError, KitsuneP2pResult, KitsuneSpace};
 use futures::channel::mpsc::Sender;
@@ -7,7 +7,8 @@ use futures::FutureExt;
 use ghost_actor::{GhostControlSender, GhostError, GhostSender};
 use kitsune_p2p_bootstrap_client::BootstrapNet;
 use kitsune_p2p_types::agent_info::AgentInfoSigned;
-use kitsune_p2p_types::bootstrap::RandomQuery;
+use kitsune_p2p_types::bin_types::KitsuneBinType;
+use kitsune_p2p_types::bootstrap::{NearQuery, RandomQuery};
 use parking_lot::RwLock;
 use std::sync::Arc;
 use std::time::Duration;
@@ -15,6 +16,11 @@ use url2::Url2;
 
 const MAX_AGENTS_PER_QUERY: u32 = 8;
 
+/// While the host knows fewer peers than this, also ask the bootstrap service
+/// for the agents whose arcs cover the locations of our local agents. Random
+/// samples rarely include those in large spaces.
+const FEW_PEERS: u32 = 8;
+
 pub(super) struct BootstrapTask {
     is_finished: bool,
     current_delay: Duration,
@@ -24,18 +30,65 @@ pub(super) struct BootstrapTask {
 // Trait for the bootstrap query to allow mocking in tests
 trait BootstrapService: Send {
     fn random(&self, query: RandomQuery) -> BoxFuture<KitsuneP2pResult<Vec<AgentInfoSigned>>>;
+    fn near(&self, query: NearQuery) -> BoxFuture<KitsuneP2pResult<Vec<AgentInfoSigned>>>;
 }
 
 struct DefaultBootstrapService {
-    url: Option<Url2>,
-    net: BootstrapNet,
+    urls: Vec<Url2>,
+    nets: Vec<BootstrapNet>,
+}
+
+impl DefaultBootstrapService {
+    /// Run a query against the bucket of every kind of transport we listen
+    /// on, and merge the agents found. Only fails if every query fails.
+    async fn query_each_net<Q, F, Fut>(
+        &self,
+        query: Q,
+        f: F,
+    ) -> KitsuneP2pResult<Vec<AgentInfoSigned>>
+    where
+        Q: Clone,
+        F: Fn(Option<Url2>, Q, BootstrapNet) -> Fut,
+        Fut: std::future::Future<
+            Output = kitsune_p2p_bootstrap_client::prelude::BootstrapClientResult<
+                Vec<AgentInfoSigned>,
+            >,
+        >,
+    {
+        let mut agents: Vec<AgentInfoSigned> = Vec::new();
+        let mut last_err = None;
+        for net in &self.nets {
+            match kitsune_p2p_bootstrap_client::with_failover(&self.urls, |url| {
+                f(url, query.clone(), *net)
+            })
+            .await
+            {
+                Ok(found) => {
+                    for info in found {
+                        if !agents.iter().any(|a| a.agent == info.agent) {
+                            agents.push(info);
+                        }
+                    }
+                }
+                Err(err) => last_err = Some(err),
+            }
+        }
+        match last_err {
+            Some(err) if agents.is_empty() => Err(err.into()),
+            _ => Ok(agents),
+        }
+    }
 }
 
 impl BootstrapService for DefaultBootstrapService {
     fn random(&self, query: RandomQuery) -> BoxFuture<KitsuneP2pResult<Vec<AgentInfoSigned>>> {
-        async move {
-            Ok(kitsune_p2p_bootstrap_client::random(self.url.clone(), query, self.net).await?)
-        }.boxed()
+        self.query_each_net(query, kitsune_p2p_bootstrap_client::random)
+            .boxed()
+    }
+
+    fn near(&self, query: NearQuery) -> BoxFuture<KitsuneP2pResult<Vec<AgentInfoSigned>>> {
+        self.query_each_net(query, kitsune_p2p_bootstrap_client::near)
+            .boxed()
     }
 }
 
@@ -44,8 +97,8 @@ impl BootstrapTask {
         internal_sender: GhostSender<SpaceInternal>,
         host_sender: Sender<KitsuneP2pEvent>,
         space: Arc<KitsuneSpace>,
-        bootstrap_service: Option<Url2>,
-        bootstrap_net: BootstrapNet,
+        bootstrap_services: Vec<Url2>,
+        bootstrap_nets: Vec<BootstrapNet>,
         bootstrap_check_delay_backoff_multiplier: u32,
         mut bootstrap_max_delay_s: u32,
     ) -> Arc<RwLock<Self>> {
@@ -60,8 +113,8 @@ impl BootstrapTask {
         }));
 
         let bootstrap_query = DefaultBootstrapService {
-            url: bootstrap_service,
-            net: bootstrap_net,
+            urls: bootstrap_services,
+            nets: bootstrap_nets,
         };
 
         BootstrapTask::spawn_inner(
@@ -119,12 +172,23 @@ impl BootstrapTask {
                     Err(e) => {
                         tracing::error!(msg = "Failed to get peers from bootstrap", ?e);
                     }
-                    Ok(list) => {
+                    Ok(mut list) => {
                         if list.len() > MAX_AGENTS_PER_QUERY as usize {
                             tracing::warn!("Expected no more than {} agents from the bootstrap server but got {}", MAX_AGENTS_PER_QUERY, list.len());
                             continue;
                         }
 
+                        if has_few_peers(&host_sender, &space).await {
+                            for item in
+                                query_near_local_agents(&*bootstrap_query, &internal_sender, &space)
+                                    .await
+                            {
+                                if !list.iter().any(|i| i.agent == item.agent) {
+                                    list.push(item);
+                                }
+                            }
+                        }
+
                         if !internal_sender.ghost_actor_is_active() {
                             break;
                         }
@@ -167,6 +231,61 @@ impl BootstrapTask {
     }
 }
 
+/// Whether the host knows fewer than [`FEW_PEERS`] agents in the space.
+async fn has_few_peers(host_sender: &Sender<KitsuneP2pEvent>, space: &Arc<KitsuneSpace>) -> bool {
+    match host_sender
+        .query_agents(QueryAgentsEvt::new(space.clone()).limit(FEW_PEERS))
+        .await
+    {
+        Ok(agents) => agents.len() < FEW_PEERS as usize,
+        Err(err) => {
+            tracing::error!(?err, "Failed to count the agents known to the host");
+            false
+        }
+    }
+}
+
+/// Ask the bootstrap service for the agents whose arcs cover the locations of
+/// our local agents, i.e. the authorities for the data we hold ourselves.
+async fn query_near_local_agents(
+    bootstrap_query: &(impl BootstrapService + Sync),
+    internal_sender: &GhostSender<SpaceInternal>,
+    space: &Arc<KitsuneSpace>,
+) -> Vec<AgentInfoSigned> {
+    let local_agents = match internal_sender.get_all_local_joined_agent_infos().await {
+        Ok(local_agents) => local_agents,
+        Err(err) => {
+            tracing::error!(?err, "Failed to get the local agents");
+            return Vec::new();
+        }
+    };
+
+    let mut found = Vec::new();
+    for local_agent in local_agents {
+        match bootstrap_query
+            .near(NearQuery {
+                space: space.clone(),
+                basis: local_agent.agent.get_loc(),
+                limit: MAX_AGENTS_PER_QUERY.into(),
+            })
+            .await
+        {
+            Err(e) => {
+                tracing::error!(msg = "Failed to get nearby peers from bootstrap", ?e);
+            }
+            Ok(list) if list.len() > MAX_AGENTS_PER_QUERY as usize => {
+                tracing::warn!(
+                    "Expected no more than {} agents from the bootstrap server but got {}",
+                    MAX_AGENTS_PER_QUERY,
+                    list.len()
+                );
+            }
+            Ok(list) => found.extend(list),
+        }
+    }
+    found
+}
+
 #[cfg(test)]
 mod tests {
     use crate::event::PutAgentInfoSignedEvt;
@@ -191,12 +310,14 @@ mod tests {
     use kitsune_p2p_bootstrap_client::prelude::BootstrapClientError;
     use kitsune_p2p_fetch::FetchContext;
     use kitsune_p2p_types::agent_info::AgentInfoSigned;
-    use kitsune_p2p_types::bootstrap::RandomQuery;
+    use kitsune_p2p_types::bin_types::KitsuneBinType;
+    use kitsune_p2p_types::bootstrap::{NearQuery, RandomQuery};
     use kitsune_p2p_types::dht::Arq;
+    use kitsune_p2p_types::dht_arc::DhtLocation;
     use kitsune_p2p_types::fixt::AgentInfoSignedFixturator;
     use kitsune_p2p_types::KOpHash;
     use parking_lot::RwLock;
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
     use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
     use std::sync::Arc;
     use std::time::{Duration, Instant};
@@ -499,6 +620,46 @@ mod tests {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn bootstrap_task_queries_near_local_agents_when_host_has_few_peers() {
+        let local_agent = fixt!(AgentInfoSigned);
+        let agents = vec![fixt!(AgentInfoSigned)];
+        let near_agents = vec![fixt!(AgentInfoSigned), fixt!(AgentInfoSigned)];
+        let (test_sender, mut host_stub, _) = setup_with_near_agents(
+            DummySpaceInternalImpl::new([local_agent.clone()].into_iter().collect()),
+            agents.clone(),
+            [(local_agent.agent.get_loc(), near_agents.clone())].into(),
+            2,
+            false,
+        )
+        .await;
+
+        // While the host knows enough peers only the random agents are relayed.
+        let evt = host_stub.next_event(Duration::from_secs(5)).await;
+        assert_eq!(agents, evt.peer_data);
+
+        *host_stub.known_agent_count.write() = Some(1);
+
+        // The next query may already have been made before the host reported few peers.
+        let evt = tokio::time::timeout(Duration::from_secs(30), async {
+            loop {
+                let evt = host_stub.next_event(Duration::from_secs(5)).await;
+                if evt.peer_data.len() > agents.len() {
+                    return evt;
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(3, evt.peer_data.len());
+        for agent in agents.iter().chain(near_agents.iter()) {
+            assert!(evt.peer_data.contains(agent));
+        }
+
+        test_sender.ghost_actor_shutdown_immediate().await.unwrap();
+    }
+
     async fn setup(
         task: DummySpaceInternalImpl,
         agents: Vec<AgentInfoSigned>,
@@ -508,6 +669,27 @@ mod tests {
         GhostSender<SpaceInternal>,
         LegacyHostStub,
         Arc<RwLock<BootstrapTask>>,
+    ) {
+        setup_with_near_agents(
+            task,
+            agents,
+            HashMap::new(),
+            delay_multiplier,
+            bootstrap_every_other_call_fails,
+        )
+        .await
+    }
+
+    async fn setup_with_near_agents(
+        task: DummySpaceInternalImpl,
+        agents: Vec<AgentInfoSigned>,
+        near_agents: HashMap<DhtLocation, Vec<AgentInfoSigned>>,
+        delay_multiplier: u32,
+        bootstrap_every_other_call_fails: bool,
+    ) -> (
+        GhostSender<SpaceInternal>,
+        LegacyHostStub,
+        Arc<RwLock<BootstrapTask>>,
     ) {
         let builder = GhostActorBuilder::new();
 
@@ -535,6 +717,7 @@ mod tests {
             Arc::new(space),
             Box::new(TestBootstrapService::new(
                 agents,
+                near_agents,
                 bootstrap_every_other_call_fails,
             )),
             delay_multiplier,
@@ -547,12 +730,14 @@ mod tests {
 
     struct DummySpaceInternalImpl {
         local_agents: HashSet<KAgent>,
+        local_agent_infos: Vec<AgentInfoSigned>,
     }
 
     impl DummySpaceInternalImpl {
         fn new(local_agents: HashSet<AgentInfoSigned>) -> Self {
             DummySpaceInternalImpl {
-                local_agents: local_agents.into_iter().map(|a| a.agent.clone()).collect(),
+                local_agents: local_agents.iter().map(|a| a.agent.clone()).collect(),
+                local_agent_infos: local_agents.into_iter().collect(),
             }
         }
     }
@@ -594,7 +779,9 @@ mod tests {
         fn handle_get_all_local_joined_agent_infos(
             &mut self,
         ) -> SpaceInternalHandlerResult<Vec<AgentInfoSigned>> {
-            unreachable!()
+            let local_agent_infos = self.local_agent_infos.clone();
+
+            Ok(async move { Ok(local_agent_infos) }.boxed().into())
         }
 
         fn handle_is_agent_local(&mut self, agent: KAgent) -> SpaceInternalHandlerResult<bool> {
@@ -688,12 +875,18 @@ mod tests {
         every_other_call_fails: bool,
         call_count: AtomicU32,
         agents: Vec<AgentInfoSigned>,
+        near_agents: HashMap<DhtLocation, Vec<AgentInfoSigned>>,
     }
 
     impl TestBootstrapService {
-        fn new(agents: Vec<AgentInfoSigned>, every_other_call_fails: bool) -> Self {
+        fn new(
+            agents: Vec<AgentInfoSigned>,
+            near_agents: HashMap<DhtLocation, Vec<AgentInfoSigned>>,
+            every_other_call_fails: bool,
+        ) -> Self {
             TestBootstrapService {
                 agents,
+                near_agents,
                 call_count: AtomicU32::new(0),
                 every_other_call_fails,
             }
@@ -715,5 +908,15 @@ mod tests {
 
             async move { Ok(self.agents.clone()) }.boxed()
         }
+
+        fn near(&self, query: NearQuery) -> BoxFuture<KitsuneP2pResult<Vec<AgentInfoSigned>>> {
+            let agents = self
+                .near_agents
+                .get(&query.basis)
+                .cloned()
+                .unwrap_or_default();
+
+            async move { Ok(agents) }.boxed()
+        }
     }
 }
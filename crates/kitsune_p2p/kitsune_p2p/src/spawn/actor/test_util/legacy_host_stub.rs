@@ -19,6 +19,8 @@ use tokio::time::error::Elapsed;
 pub struct LegacyHostStub {
     pub respond_with_error: Arc<AtomicBool>,
     pub respond_with_error_count: Arc<AtomicUsize>,
+    /// If set, `query_agents` returns no more than this many agents.
+    pub known_agent_count: Arc<parking_lot::RwLock<Option<u32>>>,
 
     pub put_agent_info_signed_calls: Arc<parking_lot::RwLock<Vec<PutAgentInfoSignedEvt>>>,
     pub notify_calls: Arc<parking_lot::RwLock<Vec<(KSpace, KAgent, Payload)>>>,
@@ -40,10 +42,12 @@ impl LegacyHostStub {
 
         let respond_with_error = Arc::new(AtomicBool::new(false));
         let respond_with_error_count = Arc::new(AtomicUsize::new(0));
+        let known_agent_count = Arc::new(parking_lot::RwLock::new(None));
 
         let handle = tokio::spawn({
             let task_respond_with_error = respond_with_error.clone();
             let task_respond_with_error_count = respond_with_error_count.clone();
+            let task_known_agent_count = known_agent_count.clone();
 
             let task_put_agent_info_signed_calls = put_agent_info_signed_calls.clone();
             let task_notify_calls = notify_calls.clone();
@@ -96,7 +100,10 @@ impl LegacyHostStub {
                                 continue;
                             }
 
-                            let len = input.limit.unwrap();
+                            let mut len = input.limit.unwrap();
+                            if let Some(known) = *task_known_agent_count.read() {
+                                len = len.min(known);
+                            }
 
                             respond.unwrap().respond(Ok(async move {
                                 let mut agents = vec![];
@@ -195,6 +202,7 @@ impl LegacyHostStub {
         LegacyHostStub {
             respond_with_error,
             respond_with_error_count,
+            known_agent_count,
             put_agent_info_signed_calls,
             notify_calls,
             receive_ops_calls,
@@ -91,3 +91,5 @@ pub(crate) use harness_actor::*;
 pub(crate) mod scenario_def_local;
 
 pub mod data;
+
+pub mod mem_faults;
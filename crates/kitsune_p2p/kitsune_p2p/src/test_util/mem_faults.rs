@@ -0,0 +1,354 @@
+//! Faults injected into the messages sent between nodes over the
+//! in-memory tx5 backend, so tests can exercise an unreliable network.
+//!
+//! Faults are set per direction between two nodes, identified by any of
+//! their peer urls, and can be changed while the nodes are running.
+//! They belong to the [`MemFaults`] they were set on, and stop applying
+//! once it's dropped, so they can't outlive the test that set them.
+//! Nodes using any other backend are not affected.
+
+use crate::*;
+use futures::stream::BoxStream;
+use futures::stream::StreamExt;
+use kitsune_p2p_types::bin_types::NodeCert;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+/// What happens to the messages sent from one node to another.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemFault {
+    /// The chance, from 0.0 to 1.0, that a message is dropped.
+    pub drop_rate: f64,
+    /// The time each message is held back before it is received.
+    pub delay: Duration,
+    /// Up to this much more time is added to the delay of each message
+    /// at random, so messages may be received out of order.
+    pub jitter: Duration,
+    /// The chance, from 0.0 to 1.0, that a message is received twice.
+    pub duplicate_rate: f64,
+}
+
+impl MemFault {
+    /// Drop every message, as if the nodes were partitioned.
+    pub fn partition() -> Self {
+        Self {
+            drop_rate: 1.0,
+            ..Default::default()
+        }
+    }
+
+    /// Whether this fault leaves messages untouched.
+    pub fn is_noop(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// The delay of each copy of a message that is received,
+    /// which is none if the message is dropped.
+    fn deliveries(&self, rng: &mut impl Rng) -> Vec<Duration> {
+        if rng.gen_bool(self.drop_rate.clamp(0.0, 1.0)) {
+            return Vec::new();
+        }
+        let copies = if rng.gen_bool(self.duplicate_rate.clamp(0.0, 1.0)) {
+            2
+        } else {
+            1
+        };
+        (0..copies)
+            .map(|_| self.delay + self.jitter.mul_f64(rng.gen::<f64>()))
+            .collect()
+    }
+}
+
+type FaultMap = HashMap<(NodeCert, NodeCert), MemFault>;
+
+/// Every [`MemFaults`] that hasn't been dropped yet.
+static SCOPES: Lazy<Mutex<Vec<Weak<RwLock<FaultMap>>>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// A set of faults between nodes, which apply for as long as it, or any
+/// clone of it, is alive.
+#[derive(Clone, Debug)]
+pub struct MemFaults(Arc<RwLock<FaultMap>>);
+
+impl Default for MemFaults {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemFaults {
+    /// Start a new, empty set of faults.
+    pub fn new() -> Self {
+        let faults = Arc::new(RwLock::new(HashMap::new()));
+        SCOPES.lock().push(Arc::downgrade(&faults));
+        Self(faults)
+    }
+
+    /// Apply `fault` to the messages sent from the node at `from_url`
+    /// to the node at `to_url`, replacing any fault set before.
+    pub fn set(&self, from_url: &str, to_url: &str, fault: MemFault) -> KitsuneResult<()> {
+        let key = (node_cert(from_url)?, node_cert(to_url)?);
+        if fault.is_noop() {
+            self.0.write().remove(&key);
+        } else {
+            self.0.write().insert(key, fault);
+        }
+        Ok(())
+    }
+
+    /// Stop applying faults to the messages sent from the node at `from_url`
+    /// to the node at `to_url`.
+    pub fn clear(&self, from_url: &str, to_url: &str) -> KitsuneResult<()> {
+        self.set(from_url, to_url, MemFault::default())
+    }
+}
+
+fn node_cert(url: &str) -> KitsuneResult<NodeCert> {
+    let peer_url = tx5::PeerUrl::parse(url).map_err(KitsuneError::other)?;
+    Ok(peer_url.pub_key().0.clone().into())
+}
+
+/// The fault set for messages from `from` to `to` by any live [`MemFaults`].
+fn find_fault(from: &NodeCert, to: &NodeCert) -> Option<MemFault> {
+    let key = (from.clone(), to.clone());
+    let mut scopes = SCOPES.lock();
+    scopes.retain(|faults| faults.strong_count() > 0);
+    scopes
+        .iter()
+        .filter_map(Weak::upgrade)
+        .find_map(|faults| faults.read().get(&key).cloned())
+}
+
+/// Apply the faults set for messages received by `ep` to its events.
+pub(crate) fn intercept(
+    ep: Arc<tx5::Endpoint>,
+    events: BoxStream<'static, tx5::EndpointEvent>,
+) -> BoxStream<'static, tx5::EndpointEvent> {
+    intercept_for(
+        move || {
+            ep.get_listening_addresses()
+                .first()
+                .map(|local| local.pub_key().0.clone().into())
+        },
+        events,
+    )
+}
+
+/// Apply the faults set for messages received by the node that
+/// `local_cert` returns to its events.
+fn intercept_for(
+    local_cert: impl Fn() -> Option<NodeCert> + Send + 'static,
+    mut events: BoxStream<'static, tx5::EndpointEvent>,
+) -> BoxStream<'static, tx5::EndpointEvent> {
+    let (send, recv) = tokio::sync::mpsc::unbounded_channel();
+
+    tokio::task::spawn(async move {
+        while let Some(evt) = events.next().await {
+            let (peer_url, message) = match evt {
+                tx5::EndpointEvent::Message { peer_url, message } => (peer_url, message),
+                evt => {
+                    if send.send(evt).is_err() {
+                        break;
+                    }
+                    continue;
+                }
+            };
+
+            let from: NodeCert = peer_url.pub_key().0.clone().into();
+            let fault = match local_cert().and_then(|local| find_fault(&from, &local)) {
+                Some(fault) => fault,
+                None => {
+                    if send
+                        .send(tx5::EndpointEvent::Message { peer_url, message })
+                        .is_err()
+                    {
+                        break;
+                    }
+                    continue;
+                }
+            };
+
+            let delays = fault.deliveries(&mut rand::thread_rng());
+            match delays.len() {
+                0 => tracing::trace!(%peer_url, "mem fault dropped message"),
+                1 => (),
+                _ => tracing::trace!(%peer_url, "mem fault duplicated message"),
+            }
+
+            for delay in delays {
+                let evt = tx5::EndpointEvent::Message {
+                    peer_url: peer_url.clone(),
+                    message: message.clone(),
+                };
+                if delay.is_zero() {
+                    if send.send(evt).is_err() {
+                        return;
+                    }
+                } else {
+                    let send = send.clone();
+                    tokio::task::spawn(async move {
+                        tokio::time::sleep(delay).await;
+                        let _ = send.send(evt);
+                    });
+                }
+            }
+        }
+    });
+
+    futures::stream::unfold(recv, |mut recv| async move {
+        recv.recv().await.map(|evt| (evt, recv))
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer_url(key: u8) -> tx5::PeerUrl {
+        tx5::SigUrl::parse("ws://localhost:1")
+            .unwrap()
+            .to_peer(tx5::PubKey(Arc::new([key; 32])))
+    }
+
+    fn cert(key: u8) -> NodeCert {
+        NodeCert::from(Arc::new([key; 32]))
+    }
+
+    /// Intercept the messages received by the node with key 0,
+    /// returning a sender to feed it messages from other nodes.
+    fn intercepted() -> (
+        futures::channel::mpsc::UnboundedSender<tx5::EndpointEvent>,
+        BoxStream<'static, tx5::EndpointEvent>,
+    ) {
+        let (send, recv) = futures::channel::mpsc::unbounded();
+        (send, intercept_for(|| Some(cert(0)), recv.boxed()))
+    }
+
+    fn message(from: u8, message: u8) -> tx5::EndpointEvent {
+        tx5::EndpointEvent::Message {
+            peer_url: peer_url(from),
+            message: vec![message],
+        }
+    }
+
+    async fn next_message(events: &mut BoxStream<'static, tx5::EndpointEvent>) -> (NodeCert, u8) {
+        match events.next().await {
+            Some(tx5::EndpointEvent::Message { peer_url, message }) => {
+                (peer_url.pub_key().0.clone().into(), message[0])
+            }
+            evt => panic!("expected a message, got {evt:?}"),
+        }
+    }
+
+    #[test]
+    fn faults_apply_in_one_direction() {
+        let faults = MemFaults::new();
+        faults
+            .set(
+                peer_url(1).as_ref(),
+                peer_url(2).as_ref(),
+                MemFault::partition(),
+            )
+            .unwrap();
+        assert_eq!(Some(MemFault::partition()), find_fault(&cert(1), &cert(2)));
+        assert_eq!(None, find_fault(&cert(2), &cert(1)));
+
+        faults
+            .clear(peer_url(1).as_ref(), peer_url(2).as_ref())
+            .unwrap();
+        assert_eq!(None, find_fault(&cert(1), &cert(2)));
+    }
+
+    #[test]
+    fn faults_stop_applying_when_dropped() {
+        let faults = MemFaults::new();
+        faults
+            .set(
+                peer_url(3).as_ref(),
+                peer_url(4).as_ref(),
+                MemFault::partition(),
+            )
+            .unwrap();
+        let clone = faults.clone();
+        drop(faults);
+        assert_eq!(Some(MemFault::partition()), find_fault(&cert(3), &cert(4)));
+
+        drop(clone);
+        assert_eq!(None, find_fault(&cert(3), &cert(4)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn dropped_messages_are_not_received() {
+        let faults = MemFaults::new();
+        faults
+            .set(
+                peer_url(10).as_ref(),
+                peer_url(0).as_ref(),
+                MemFault::partition(),
+            )
+            .unwrap();
+        let (send, mut events) = intercepted();
+
+        send.unbounded_send(message(10, 1)).unwrap();
+        send.unbounded_send(message(11, 2)).unwrap();
+
+        // Only the message from the node without a fault is received.
+        assert_eq!((cert(11), 2), next_message(&mut events).await);
+        drop(send);
+        assert!(events.next().await.is_none());
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn delayed_messages_are_received_late() {
+        let faults = MemFaults::new();
+        faults
+            .set(
+                peer_url(20).as_ref(),
+                peer_url(0).as_ref(),
+                MemFault {
+                    delay: Duration::from_secs(10),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let (send, mut events) = intercepted();
+        let start = tokio::time::Instant::now();
+
+        send.unbounded_send(message(20, 1)).unwrap();
+        send.unbounded_send(message(21, 2)).unwrap();
+
+        // The message without a fault overtakes the delayed one.
+        assert_eq!((cert(21), 2), next_message(&mut events).await);
+        assert_eq!((cert(20), 1), next_message(&mut events).await);
+        assert!(start.elapsed() >= Duration::from_secs(10));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn duplicated_messages_are_received_twice() {
+        let faults = MemFaults::new();
+        faults
+            .set(
+                peer_url(30).as_ref(),
+                peer_url(0).as_ref(),
+                MemFault {
+                    duplicate_rate: 1.0,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let (send, mut events) = intercepted();
+
+        send.unbounded_send(message(30, 1)).unwrap();
+        drop(send);
+
+        assert_eq!((cert(30), 1), next_message(&mut events).await);
+        assert_eq!((cert(30), 1), next_message(&mut events).await);
+        assert!(events.next().await.is_none());
+    }
+}
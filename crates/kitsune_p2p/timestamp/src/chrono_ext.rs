@@ -102,9 +102,12 @@ impl Timestamp {
     ///
     /// This is behind a feature because we need Timestamp to be WASM compatible, and
     /// chrono doesn't have a now() implementation for WASM.
+    ///
+    /// On a thread which has entered a [`sim_clock`](crate::sim_clock),
+    /// this is the simulated time instead.
     #[cfg(feature = "now")]
     pub fn now() -> Timestamp {
-        Timestamp::from(chrono::offset::Utc::now())
+        crate::sim_clock::now().unwrap_or_else(|| Timestamp::from(chrono::offset::Utc::now()))
     }
     /// Compute signed difference between two Timestamp, returning `None` if overflow occurred, or
     /// Some(chrono::Duration).  Produces Duration for differences of up to +/- i64::MIN/MAX
@@ -24,6 +24,9 @@ mod chrono_ext;
 #[cfg(feature = "fuzzing")]
 pub mod noise;
 
+#[cfg(feature = "now")]
+pub mod sim_clock;
+
 /// One million
 pub(crate) const MM: i64 = 1_000_000;
 
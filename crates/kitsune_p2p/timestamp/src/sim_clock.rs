@@ -0,0 +1,264 @@
+//! A simulated clock which replaces the system time of [`Timestamp::now`]
+//! on the threads that have entered it, so that tests can control the
+//! passing of time.
+//!
+//! A clock is only seen by the threads it has been entered on, so tests
+//! which run one don't affect the other tests in the same process.
+//! The clock only moves when it is advanced.
+//!
+//! Only code which reads [`Timestamp::now`] or sleeps with [`sleep`] follows
+//! the clock. Anything waiting on tokio time, such as gossip rounds and
+//! network timeouts, still waits in real time.
+
+use crate::Timestamp;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+thread_local! {
+    /// The clock entered on this thread, if any.
+    static CURRENT: RefCell<Option<SimClock>> = const { RefCell::new(None) };
+}
+
+/// A simulated clock, which can be shared between threads by cloning it.
+#[derive(Clone, Debug)]
+pub struct SimClock(Arc<Inner>);
+
+#[derive(Debug)]
+struct Inner {
+    /// The simulated time, in microseconds since the UNIX epoch.
+    now: AtomicI64,
+    sleepers: Mutex<Sleepers>,
+}
+
+/// The sleeps on a clock which haven't completed yet.
+#[derive(Debug, Default)]
+struct Sleepers {
+    next_id: u64,
+    /// The sleeps waiting for the clock to reach their deadline.
+    waiting: HashMap<u64, (Timestamp, Waker)>,
+    /// The sleeps which have come due but whose tasks haven't resumed yet.
+    due: HashSet<u64>,
+}
+
+impl SimClock {
+    /// A clock which starts at `at`.
+    pub fn new(at: Timestamp) -> Self {
+        Self(Arc::new(Inner {
+            now: AtomicI64::new(at.0),
+            sleepers: Mutex::new(Sleepers::default()),
+        }))
+    }
+
+    /// The simulated time.
+    pub fn now(&self) -> Timestamp {
+        Timestamp(self.0.now.load(Ordering::SeqCst))
+    }
+
+    /// Move the clock forward by `by`, waking the tasks sleeping until any
+    /// time up to the new time. Returns the new time.
+    pub fn advance(&self, by: Duration) -> Timestamp {
+        let by = i64::try_from(by.as_micros()).unwrap_or(i64::MAX);
+        let mut sleepers = self.sleepers();
+        let now = Timestamp(
+            self.0
+                .now
+                .fetch_add(by, Ordering::SeqCst)
+                .saturating_add(by),
+        );
+        let due: Vec<u64> = sleepers
+            .waiting
+            .iter()
+            .filter(|(_, (deadline, _))| *deadline <= now)
+            .map(|(id, _)| *id)
+            .collect();
+        let mut wakers = Vec::with_capacity(due.len());
+        for id in due {
+            if let Some((_, waker)) = sleepers.waiting.remove(&id) {
+                sleepers.due.insert(id);
+                wakers.push(waker);
+            }
+        }
+        drop(sleepers);
+        for waker in wakers {
+            waker.wake();
+        }
+        now
+    }
+
+    /// The number of sleeps which have come due, but whose tasks haven't
+    /// resumed yet.
+    pub fn due_sleeps(&self) -> usize {
+        self.sleepers().due.len()
+    }
+
+    /// Sleep until this clock has moved forward by `dur`.
+    pub fn sleep(&self, dur: Duration) -> SimSleep {
+        let deadline = (self.now() + dur).unwrap_or(Timestamp::MAX);
+        let mut sleepers = self.sleepers();
+        let id = sleepers.next_id;
+        sleepers.next_id += 1;
+        SimSleep {
+            clock: self.clone(),
+            deadline,
+            id,
+        }
+    }
+
+    /// Make this the clock of the current thread, until the returned guard
+    /// is dropped.
+    pub fn enter(&self) -> SimClockGuard {
+        SimClockGuard(set_current(Some(self.clone())))
+    }
+
+    fn sleepers(&self) -> std::sync::MutexGuard<'_, Sleepers> {
+        self.0.sleepers.lock().expect("sim clock sleepers poisoned")
+    }
+}
+
+/// Restores the clock that the current thread had before
+/// [`SimClock::enter`] when dropped.
+#[derive(Debug)]
+pub struct SimClockGuard(Option<SimClock>);
+
+impl Drop for SimClockGuard {
+    fn drop(&mut self) {
+        set_current(self.0.take());
+    }
+}
+
+/// Set the clock of the current thread, returning the one it had before.
+/// Threads which a runtime starts can enter a clock for good with this.
+pub fn set_current(clock: Option<SimClock>) -> Option<SimClock> {
+    CURRENT.with(|current| std::mem::replace(&mut *current.borrow_mut(), clock))
+}
+
+/// The clock of the current thread, if it has entered one.
+pub fn current() -> Option<SimClock> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// The simulated time, if the current thread has entered a clock.
+pub fn now() -> Option<Timestamp> {
+    CURRENT.with(|current| current.borrow().as_ref().map(SimClock::now))
+}
+
+/// Sleep until the clock of the current thread has moved forward by `dur`.
+/// Returns `None` if the thread hasn't entered a clock, in which case the
+/// caller should sleep in real time instead.
+pub fn sleep(dur: Duration) -> Option<SimSleep> {
+    current().map(|clock| clock.sleep(dur))
+}
+
+/// A future which completes once a clock reaches a time.
+#[derive(Debug)]
+pub struct SimSleep {
+    clock: SimClock,
+    deadline: Timestamp,
+    id: u64,
+}
+
+impl Future for SimSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // The time is read under the lock which `advance` holds while it
+        // moves the clock, so a sleep can't miss its wake up.
+        let mut sleepers = self.clock.sleepers();
+        if self.clock.now() >= self.deadline {
+            sleepers.waiting.remove(&self.id);
+            sleepers.due.remove(&self.id);
+            Poll::Ready(())
+        } else {
+            sleepers
+                .waiting
+                .insert(self.id, (self.deadline, cx.waker().clone()));
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for SimSleep {
+    fn drop(&mut self) {
+        let mut sleepers = self.clock.sleepers();
+        sleepers.waiting.remove(&self.id);
+        sleepers.due.remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::task::Wake;
+
+    struct CountWakes(AtomicUsize);
+
+    impl Wake for CountWakes {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn now_follows_the_entered_clock() {
+        assert_eq!(None, now());
+        assert!(sleep(Duration::from_secs(1)).is_none());
+
+        let clock = SimClock::new(Timestamp::HOLOCHAIN_EPOCH);
+        {
+            let _guard = clock.enter();
+            assert_eq!(Timestamp::HOLOCHAIN_EPOCH, Timestamp::now());
+
+            // Other threads keep the system time.
+            std::thread::spawn(|| assert_eq!(None, now()))
+                .join()
+                .unwrap();
+        }
+        assert_eq!(None, now());
+        assert_ne!(Timestamp::HOLOCHAIN_EPOCH, Timestamp::now());
+    }
+
+    #[test]
+    fn sleeps_complete_when_the_clock_reaches_them() {
+        let clock = SimClock::new(Timestamp::HOLOCHAIN_EPOCH);
+        let wakes = Arc::new(CountWakes(Default::default()));
+        let waker = Waker::from(wakes.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut sleep = clock.sleep(Duration::from_secs(10));
+
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_pending());
+        assert_eq!(
+            (Timestamp::HOLOCHAIN_EPOCH + Duration::from_secs(5)).unwrap(),
+            clock.advance(Duration::from_secs(5))
+        );
+        assert_eq!(0, wakes.0.load(Ordering::SeqCst));
+        assert_eq!(0, clock.due_sleeps());
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(1, wakes.0.load(Ordering::SeqCst));
+        assert_eq!(1, clock.due_sleeps());
+
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_ready());
+        assert_eq!(0, clock.due_sleeps());
+    }
+
+    #[test]
+    fn dropped_sleeps_are_not_due() {
+        let clock = SimClock::new(Timestamp::HOLOCHAIN_EPOCH);
+        let waker = Waker::from(Arc::new(CountWakes(Default::default())));
+        let mut cx = Context::from_waker(&waker);
+        let mut sleep = clock.sleep(Duration::from_secs(1));
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_pending());
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(1, clock.due_sleeps());
+        drop(sleep);
+        assert_eq!(0, clock.due_sleeps());
+    }
+}
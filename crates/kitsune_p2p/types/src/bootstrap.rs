@@ -1,5 +1,6 @@
 //! Types for the bootstrap server
 use crate::bin_types::{KitsuneBinType, KitsuneSpace};
+use crate::dht_arc::DhtLocation;
 use crate::tx_utils::TxUrl;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -29,6 +30,17 @@ impl Default for RandomQuery {
     }
 }
 
+/// Struct to be encoded for the `near` op.
+#[derive(serde::Deserialize, serde::Serialize, Clone)]
+pub struct NearQuery {
+    /// The space to get agents from.
+    pub space: Arc<KitsuneSpace>,
+    /// The location which the storage arcs of the agents must cover.
+    pub basis: DhtLocation,
+    /// The maximum number of agents to retrieve for this query.
+    pub limit: RandomLimit,
+}
+
 #[derive(serde::Deserialize, serde::Serialize, derive_more::From, derive_more::Into, Clone)]
 /// Limit of random peers to return.
 pub struct RandomLimit(pub u32);
@@ -173,6 +173,20 @@ pub mod tuning_params_struct {
         /// when possible.
         gossip_max_batch_size: u32 = 1_000_000,
 
+        /// Should historical gossip fetch differing regions through the fetch pool?
+        ///
+        /// When enabled, this side of a historical round queues the regions its
+        /// partner holds data for as region fetches, rather than fetching each
+        /// of the op hashes the partner sends for those regions. Region fetches
+        /// then share the fetch pool's source backoff and rotation with all
+        /// other fetches. The op hashes in our own regions are still sent to the
+        /// partner, so it receives our data whether or not it has this enabled.
+        ///
+        /// The partner must run a version which can serve region fetches.
+        ///
+        /// [Default: false]
+        gossip_fetch_regions_via_pool: bool = false,
+
         /// Should gossip dynamically resize storage arcs?
         ///
         /// This is an unstable feature must be enabled with the "unstable-sharding" Cargo feature.
@@ -286,6 +300,20 @@ pub mod tuning_params_struct {
         /// Tx5 ban time in seconds.
         tx5_ban_time_s: u32 = 10,
 
+        /// How often the quic transport sends keep-alive packets
+        /// on otherwise idle connections.
+        /// [Default: 15 seconds]
+        quic_keep_alive_interval_ms: u32 = 1000 * 15,
+
+        /// How long a quic connection may go without receiving
+        /// anything from the remote before it is closed.
+        /// [Default: 60 seconds]
+        quic_max_idle_timeout_ms: u32 = 1000 * 60,
+
+        /// Maximum size of an individual message on the quic transport.
+        /// [Default: 16 MiB]
+        quic_message_size_max: u32 = 16 * 1024 * 1024,
+
         /// Tx5 min ephemeral port
         tx5_min_ephemeral_udp_port: u16 = 1,
 
@@ -401,12 +429,23 @@ pub type KitsuneP2pTuningParams = std::sync::Arc<tuning_params_struct::KitsuneP2
 /// Configure the kitsune actor.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
 pub struct KitsuneP2pConfig {
-    /// List of sub-transports to be included in this pool
+    /// List of sub-transports to be included in this pool.
+    ///
+    /// The node listens on every transport in the pool at once and
+    /// advertises a url for each of them. Earlier entries are preferred
+    /// when dialing, later entries are used as fallbacks.
+    /// All `WebRTC` entries share a single endpoint, which listens on
+    /// each of the configured signal servers.
     pub transport_pool: Vec<TransportConfig>,
 
     /// The service used for peers to discover each before they are peers.
     pub bootstrap_service: Option<Url2>,
 
+    /// Further bootstrap services to fall back to, in order, when the
+    /// `bootstrap_service` can't be reached.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bootstrap_service_fallbacks: Vec<Url2>,
+
     /// Network tuning parameters. These are managed loosely,
     /// as they are subject to change. If you specify a tuning parameter
     /// that no longer exists, or a value that does not parse,
@@ -432,6 +471,7 @@ impl KitsuneP2pConfig {
         Self {
             transport_pool: vec![TransportConfig::Mem {}],
             bootstrap_service: None,
+            bootstrap_service_fallbacks: Vec::new(),
             tuning_params: KitsuneP2pTuningParams::default(),
             tracing_scope: None,
         }
@@ -446,14 +486,16 @@ impl KitsuneP2pConfig {
                 webrtc_config: None,
             }],
             bootstrap_service: None,
+            bootstrap_service_fallbacks: Vec::new(),
             tuning_params: KitsuneP2pTuningParams::default(),
             tracing_scope: None,
         }
     }
 }
 
-#[allow(dead_code)]
-fn cnv_bind_to(bind_to: &Option<url2::Url2>) -> TxUrl {
+/// Resolve an optional quic `bind_to` url, defaulting to all interfaces
+/// on an os-assigned port.
+pub fn cnv_bind_to(bind_to: &Option<url2::Url2>) -> TxUrl {
     match bind_to {
         Some(bind_to) => bind_to.clone().into(),
         None => TxUrl::from_str_panicking("kitsune-quic://0.0.0.0:0"),
@@ -464,12 +506,26 @@ impl KitsuneP2pConfig {
     /// This config is making use of tx5 transport
     #[allow(dead_code)] // because of feature flipping
     pub fn is_tx5(&self) -> bool {
-        {
-            if let Some(t) = self.transport_pool.first() {
-                return matches!(t, TransportConfig::Mem {} | TransportConfig::WebRTC { .. });
-            }
-        }
-        false
+        self.transport_pool
+            .iter()
+            .any(|t| matches!(t, TransportConfig::Mem {} | TransportConfig::WebRTC { .. }))
+    }
+
+    /// This config is making use of the direct quic transport
+    pub fn is_quic(&self) -> bool {
+        self.transport_pool
+            .iter()
+            .any(|t| matches!(t, TransportConfig::Quic { .. }))
+    }
+
+    /// The `bootstrap_service` followed by the `bootstrap_service_fallbacks`,
+    /// in the order they should be tried.
+    pub fn bootstrap_services(&self) -> Vec<Url2> {
+        self.bootstrap_service
+            .iter()
+            .chain(self.bootstrap_service_fallbacks.iter())
+            .cloned()
+            .collect()
     }
 
     /// Return a copy with the tuning params altered
@@ -502,4 +558,22 @@ pub enum TransportConfig {
     /// A transport that uses the local memory transport protocol
     /// (this is mainly for testing)
     Mem {},
+
+    /// Configure to use direct QUIC connections for kitsune networking.
+    ///
+    /// No signal server is involved: nodes bind a static UDP address and
+    /// dial each other on the address advertised in their agent info.
+    /// This is intended for always-on, publicly reachable nodes.
+    Quic {
+        /// The local address to bind, e.g. `kitsune-quic://0.0.0.0:5788`.
+        /// [Default: `kitsune-quic://0.0.0.0:0`]
+        bind_to: Option<Url2>,
+
+        /// Advertise this host instead of the bound one, for example
+        /// when the node sits behind a static NAT or binds `0.0.0.0`.
+        override_host: Option<String>,
+
+        /// Advertise this port instead of the bound one.
+        override_port: Option<u16>,
+    },
 }
@@ -46,7 +46,7 @@ impl ProxyUrl {
             };
         }
         let full = url2::try_url2!("{}", full).map_err(|_| err!("parse"))?;
-        if full.scheme() == "wss" || full.scheme() == "ws" {
+        if full.scheme() == "wss" || full.scheme() == "ws" || full.scheme() == "kitsune-quic" {
             return Ok(Self {
                 full: full.clone(),
                 base: full,
@@ -142,8 +142,8 @@ impl ProxyUrl {
     /// Extract the cert digest from the url
     pub fn digest(&self) -> KitsuneResult<CertDigest> {
         let scheme = self.full.scheme();
-        if scheme == "wss" || scheme == "ws" {
-            // override for tx5
+        if scheme == "wss" || scheme == "ws" || scheme == "kitsune-quic" {
+            // override for tx5 and direct quic, which carry the digest in the path
             if let Some(mut i) = self.full.path_segments() {
                 if let Some(u) = i.next() {
                     let digest = base64::prelude::BASE64_URL_SAFE_NO_PAD
@@ -258,4 +258,15 @@ mod tests {
         assert_eq!(TEST_FULL, u.as_full_str());
         assert_eq!(TEST_BASE, u.as_base_str());
     }
+
+    #[test]
+    fn proxy_url_direct_quic_digest() {
+        let full = format!("kitsune-quic://1.2.3.4:443/{TEST_CERT}");
+        let u = ProxyUrl::from_full(&full).unwrap();
+        assert_eq!(full, u.as_full_str());
+        let cert_digest = base64::prelude::BASE64_URL_SAFE_NO_PAD
+            .decode(TEST_CERT)
+            .unwrap();
+        assert_eq!(CertDigest::from_slice(&cert_digest), u.digest().unwrap());
+    }
 }
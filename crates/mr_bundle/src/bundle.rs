@@ -1,15 +1,18 @@
 use crate::{
     error::{BundleError, MrBundleResult},
+    indexed::{IndexedBundle, IndexedResources},
     location::Location,
     manifest::Manifest,
     resource::ResourceBytes,
+    signature::{BundleSignature, BundleSignatures, SigningKey},
 };
 use holochain_util::ffs;
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{de::DeserializeOwned, ser::SerializeStruct, Deserialize, Serialize};
 use std::{
     borrow::Cow,
     collections::{BTreeMap, HashMap, HashSet},
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 pub type ResourceMap = BTreeMap<PathBuf, ResourceBytes>;
@@ -19,7 +22,7 @@ pub type ResourceMap = BTreeMap<PathBuf, ResourceBytes>;
 /// by the receiver.
 ///
 /// The manifest may describe locations of resources not included in the Bundle.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Bundle<M>
 where
@@ -34,6 +37,18 @@ where
     /// are always relative paths (relative to the root_dir).
     resources: ResourceMap,
 
+    /// The resources of a bundle read from an indexed bundle, which are left
+    /// there until they are resolved. A bundle holds its resources either
+    /// here or in `resources`, never both.
+    #[serde(skip)]
+    #[cfg_attr(feature = "fuzzing", arbitrary(default))]
+    indexed: Option<Arc<IndexedResources>>,
+
+    /// Publisher signatures over the manifest and resources.
+    /// Bundles encoded before signing was supported have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signatures: Option<BundleSignatures>,
+
     /// Since the Manifest may contain local paths referencing unbundled files,
     /// on the local filesystem, we must have an absolute path at runtime for
     /// normalizing those locations.
@@ -48,6 +63,28 @@ where
     root_dir: Option<PathBuf>,
 }
 
+/// Validate that each resource path is one of the bundled locations of the
+/// manifest.
+pub(crate) fn check_bundled_paths<'a, M: Manifest>(
+    manifest: &M,
+    resource_paths: impl IntoIterator<Item = &'a PathBuf>,
+) -> MrBundleResult<()> {
+    let manifest_paths: HashSet<_> = manifest
+        .locations()
+        .into_iter()
+        .filter_map(|loc| match loc {
+            Location::Bundled(path) => Some(path),
+            _ => None,
+        })
+        .collect();
+    for resource_path in resource_paths {
+        if !manifest_paths.contains(resource_path) {
+            return Err(BundleError::BundledPathNotInManifest(resource_path.clone()).into());
+        }
+    }
+    Ok(())
+}
+
 impl<M> Bundle<M>
 where
     M: Manifest,
@@ -83,30 +120,40 @@ where
         root_dir: Option<PathBuf>,
     ) -> MrBundleResult<Self> {
         let resources: ResourceMap = resources.into_iter().collect();
-        let manifest_paths: HashSet<_> = manifest
-            .locations()
-            .into_iter()
-            .filter_map(|loc| match loc {
-                Location::Bundled(path) => Some(path),
-                _ => None,
-            })
-            .collect();
-
-        // Validate that each resource path is contained in the manifest
-        for (resource_path, _) in resources.iter() {
-            if !manifest_paths.contains(resource_path) {
-                return Err(BundleError::BundledPathNotInManifest(resource_path.clone()).into());
-            }
-        }
+        check_bundled_paths(&manifest, resources.keys())?;
 
-        let resources = resources.into_iter().collect();
         Ok(Self {
             manifest,
             resources,
+            indexed: None,
+            signatures: None,
             root_dir,
         })
     }
 
+    /// Create an unsigned bundle whose resources are read from an indexed
+    /// bundle as they are resolved.
+    pub(crate) fn from_indexed(manifest: M, indexed: IndexedResources) -> MrBundleResult<Self> {
+        check_bundled_paths(&manifest, indexed.paths())?;
+        Ok(Self {
+            indexed: Some(Arc::new(indexed)),
+            ..Self::from_parts(manifest, ResourceMap::new(), None)?
+        })
+    }
+
+    /// Create a bundle with signatures that were made over the same manifest
+    /// and resources, such as when reading an indexed bundle.
+    pub(crate) fn from_signed_parts(
+        manifest: M,
+        resources: ResourceMap,
+        signatures: Option<BundleSignatures>,
+    ) -> MrBundleResult<Self> {
+        Ok(Self {
+            signatures,
+            ..Self::from_parts(manifest, resources, None)?
+        })
+    }
+
     /// Accessor for the Manifest
     pub fn manifest(&self) -> &M {
         &self.manifest
@@ -114,12 +161,70 @@ where
 
     /// Return a new Bundle with an updated manifest, subject to the same
     /// validation constraints as creating a new Bundle from scratch.
+    ///
+    /// The new Bundle is unsigned, since the signatures don't cover the new
+    /// manifest.
     pub fn update_manifest(self, manifest: M) -> MrBundleResult<Self> {
-        Self::from_parts(manifest, self.resources, self.root_dir)
+        if let Some(indexed) = &self.indexed {
+            check_bundled_paths(&manifest, indexed.paths())?;
+        }
+        Ok(Self {
+            indexed: self.indexed,
+            ..Self::from_parts(manifest, self.resources, self.root_dir)?
+        })
     }
 
-    /// Load a Bundle into memory from a file
+    /// The publisher signatures over this bundle.
+    pub fn signatures(&self) -> &[BundleSignature] {
+        self.signatures
+            .as_ref()
+            .map(|signed| signed.signatures.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The signatures along with the encoded manifest they were made over.
+    pub fn bundle_signatures(&self) -> Option<&BundleSignatures> {
+        self.signatures.as_ref()
+    }
+
+    /// Sign the manifest and resources with a publisher's key,
+    /// replacing any earlier signature by the same publisher.
+    ///
+    /// The first signature fixes the encoded manifest that every signature
+    /// covers, so later signatures match it however the manifest would be
+    /// encoded now.
+    pub fn sign(self, key: &SigningKey) -> MrBundleResult<Self> {
+        let mut this = self.into_loaded()?;
+        let signatures = match this.signatures.take() {
+            Some(signatures) => signatures,
+            None => BundleSignatures::new(&this.manifest)?,
+        };
+        let signatures = this.signatures.insert(signatures);
+        signatures.sign(key, &this.resources);
+        Ok(this)
+    }
+
+    /// Check every signature and return the public keys of the publishers
+    /// that signed this bundle, which is empty for an unsigned bundle.
+    ///
+    /// Any signature that doesn't match is an error, since it means the bundle
+    /// was modified after it was signed. So is a manifest that differs from
+    /// the encoded manifest the signatures cover.
+    pub fn verify_signatures(&self) -> MrBundleResult<Vec<[u8; 32]>> {
+        match &self.signatures {
+            Some(signatures) => signatures.verify(&self.manifest, &*self.bundled_resources()?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Load a Bundle into memory from a file.
+    ///
+    /// Only the index of an unsigned indexed bundle is read, and each of its
+    /// resources is read from the file when it is resolved.
     pub async fn read_from_file(path: &Path) -> MrBundleResult<Self> {
+        if crate::indexed::is_indexed_file(path) {
+            return Self::open_indexed(path);
+        }
         Self::decode(&ffs::read(path).await?)
     }
 
@@ -128,15 +233,32 @@ where
         Ok(ffs::write(path, &self.encode()?).await?)
     }
 
+    /// Write a Bundle to a file as an indexed bundle, whose resources can be
+    /// read one at a time with an [`IndexedBundle`](crate::IndexedBundle)
+    pub async fn write_indexed_to_file(&self, path: &Path) -> MrBundleResult<()> {
+        Ok(ffs::write(path, &self.encode_indexed()?).await?)
+    }
+
     /// Retrieve the bytes for a resource at a Location, downloading it if
     /// necessary
     pub async fn resolve(&self, location: &Location) -> MrBundleResult<Cow<'_, ResourceBytes>> {
         let bytes = match &location.normalize(self.root_dir.as_ref())? {
-            Location::Bundled(path) => Cow::Borrowed(
-                self.resources
-                    .get(path)
-                    .ok_or_else(|| BundleError::BundledResourceMissing(path.clone()))?,
-            ),
+            Location::Bundled(path) => match &self.indexed {
+                Some(indexed) if indexed.contains(path) => {
+                    let indexed = indexed.clone();
+                    let path = path.clone();
+                    Cow::Owned(
+                        tokio::task::spawn_blocking(move || indexed.read(&path))
+                            .await
+                            .map_err(std::io::Error::from)??,
+                    )
+                }
+                _ => Cow::Borrowed(
+                    self.resources
+                        .get(path)
+                        .ok_or_else(|| BundleError::BundledResourceMissing(path.clone()))?,
+                ),
+            },
             Location::Path(path) => Cow::Owned(crate::location::resolve_local(path).await?),
             Location::Url(url) => Cow::Owned(crate::location::resolve_remote(url).await?),
         };
@@ -169,9 +291,21 @@ where
 
     /// Access the map of resources included in this bundle
     /// Bundled resources are also accessible via `resolve` or `resolve_all`,
-    /// but using this method prevents a Clone
-    pub fn bundled_resources(&self) -> &ResourceMap {
-        &self.resources
+    /// but using this method prevents a Clone, unless the resources are still
+    /// in an indexed bundle, in which case they are all read from it first.
+    pub fn bundled_resources(&self) -> MrBundleResult<Cow<'_, ResourceMap>> {
+        match &self.indexed {
+            Some(indexed) => Ok(Cow::Owned(indexed.read_all()?)),
+            None => Ok(Cow::Borrowed(&self.resources)),
+        }
+    }
+
+    /// Read any resources left in an indexed bundle into memory.
+    fn into_loaded(mut self) -> MrBundleResult<Self> {
+        if let Some(indexed) = self.indexed.take() {
+            self.resources = indexed.read_all()?;
+        }
+        Ok(self)
     }
 
     /// An arbitrary and opaque encoding of the bundle data into a byte array
@@ -179,9 +313,17 @@ where
         crate::encode(self)
     }
 
-    /// Decode bytes produced by [`encode`](Bundle::encode)
+    /// Decode bytes produced by [`encode`](Bundle::encode) or
+    /// [`encode_indexed`](Bundle::encode_indexed)
+    ///
+    /// The resources of an unsigned indexed bundle are only decompressed
+    /// when they are resolved.
     pub fn decode(bytes: &[u8]) -> MrBundleResult<Self> {
-        crate::decode(bytes)
+        if crate::is_indexed(bytes) {
+            Self::decode_indexed(bytes)
+        } else {
+            crate::decode(bytes)
+        }
     }
 
     /// Given that the Manifest is located at the given absolute `path`, find
@@ -202,6 +344,40 @@ where
     }
 }
 
+impl<M: Manifest> Serialize for Bundle<M> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Resources still in an indexed bundle are encoded like any other.
+        let resources = self
+            .bundled_resources()
+            .map_err(serde::ser::Error::custom)?;
+        let mut state =
+            serializer.serialize_struct("Bundle", 2 + usize::from(self.signatures.is_some()))?;
+        state.serialize_field("manifest", &self.manifest)?;
+        state.serialize_field("resources", &*resources)?;
+        match &self.signatures {
+            Some(signatures) => state.serialize_field("signatures", signatures)?,
+            None => state.skip_field("signatures")?,
+        }
+        state.end()
+    }
+}
+
+impl<M: Manifest> PartialEq for Bundle<M> {
+    fn eq(&self, other: &Self) -> bool {
+        // Bundles are equal however their resources are held. Resources which
+        // can't be read from an indexed bundle aren't equal to anything.
+        self.manifest == other.manifest
+            && self.signatures == other.signatures
+            && self.root_dir == other.root_dir
+            && match (self.bundled_resources(), other.bundled_resources()) {
+                (Ok(resources), Ok(other_resources)) => resources == other_resources,
+                _ => false,
+            }
+    }
+}
+
+impl<M: Manifest> Eq for Bundle<M> {}
+
 /// A manifest bundled together, optionally, with the Resources that it describes.
 /// The manifest may be of any format. This is useful for deserializing a bundle of
 /// an outdated format, so that it may be modified to fit the supported format.
@@ -221,6 +397,9 @@ pub struct RawBundle<M> {
 impl<M: serde::de::DeserializeOwned> RawBundle<M> {
     /// Load a Bundle into memory from a file
     pub async fn read_from_file(path: &Path) -> MrBundleResult<Self> {
+        if crate::indexed::is_indexed_file(path) {
+            return IndexedBundle::open(path)?.into_raw_bundle();
+        }
         crate::decode(&ffs::read(path).await?)
     }
 }
@@ -250,6 +429,28 @@ mod tests {
         }
     }
 
+    /// A manifest with named fields, which can be encoded as a map or an array.
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct NamedManifest {
+        locations: Vec<Location>,
+    }
+
+    impl Manifest for NamedManifest {
+        fn locations(&self) -> Vec<Location> {
+            self.locations.clone()
+        }
+
+        #[cfg(feature = "packing")]
+        fn path() -> PathBuf {
+            unimplemented!()
+        }
+
+        #[cfg(feature = "packing")]
+        fn bundle_extension() -> &'static str {
+            unimplemented!()
+        }
+    }
+
     #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
     struct Thing(u32);
 
@@ -269,4 +470,87 @@ mod tests {
             Err(MrBundleError::BundleError(BundleError::BundledPathNotInManifest(path))) if path == PathBuf::from("3.thing")
         );
     }
+
+    #[test]
+    fn bundle_signatures() {
+        let manifest = TestManifest(vec![Location::Bundled("1.thing".into())]);
+        let bundle =
+            Bundle::new_unchecked(manifest.clone(), vec![("1.thing".into(), vec![1].into())])
+                .unwrap();
+        assert!(bundle.verify_signatures().unwrap().is_empty());
+
+        let alice = SigningKey::from_bytes(&[1; 32]);
+        let bob = SigningKey::from_bytes(&[2; 32]);
+        let signed = bundle.sign(&alice).unwrap().sign(&bob).unwrap();
+
+        // Signatures survive encoding, and signing again replaces a signature.
+        let signed = Bundle::<TestManifest>::decode(&signed.encode().unwrap())
+            .unwrap()
+            .sign(&alice)
+            .unwrap();
+        assert_eq!(2, signed.signatures().len());
+        assert_eq!(
+            vec![
+                bob.verifying_key().to_bytes(),
+                alice.verifying_key().to_bytes()
+            ],
+            signed.verify_signatures().unwrap()
+        );
+
+        // Tampering with a resource invalidates the signatures.
+        let mut tampered = signed.clone();
+        tampered.resources.insert("1.thing".into(), vec![2].into());
+        matches::assert_matches!(
+            tampered.verify_signatures(),
+            Err(MrBundleError::BundleError(BundleError::InvalidSignature(publisher))) if publisher == bob.verifying_key().to_bytes()
+        );
+
+        // Swapping in a different manifest is caught even though the
+        // signatures still match the manifest they were made over.
+        let mut tampered = signed.clone();
+        tampered.manifest = TestManifest(vec![
+            Location::Bundled("1.thing".into()),
+            Location::Bundled("2.thing".into()),
+        ]);
+        matches::assert_matches!(
+            tampered.verify_signatures(),
+            Err(MrBundleError::BundleError(
+                BundleError::SignedManifestMismatch
+            ))
+        );
+
+        // Updating the manifest drops the signatures.
+        assert!(signed
+            .update_manifest(manifest)
+            .unwrap()
+            .signatures()
+            .is_empty());
+    }
+
+    #[test]
+    fn bundle_signatures_cover_the_manifest_as_encoded() {
+        let manifest = NamedManifest {
+            locations: vec![Location::Bundled("1.thing".into())],
+        };
+        let mut bundle =
+            Bundle::new_unchecked(manifest.clone(), vec![("1.thing".into(), vec![1].into())])
+                .unwrap();
+
+        // The manifest encoded as an array rather than the map it would be
+        // encoded as now, as by another version of the manifest type.
+        let encoded = rmp_serde::to_vec(&manifest).unwrap();
+        assert_ne!(rmp_serde::to_vec_named(&manifest).unwrap(), encoded);
+        bundle.signatures = Some(BundleSignatures {
+            manifest: encoded.into(),
+            signatures: Vec::new(),
+        });
+
+        let alice = SigningKey::from_bytes(&[1; 32]);
+        let signed = bundle.sign(&alice).unwrap();
+        let signed = Bundle::<NamedManifest>::decode(&signed.encode().unwrap()).unwrap();
+        assert_eq!(
+            vec![alice.verifying_key().to_bytes()],
+            signed.verify_signatures().unwrap()
+        );
+    }
 }
@@ -52,6 +52,21 @@ pub enum BundleError {
         "Cannot use relative paths for local locations. The following local path is relative: {0}"
     )]
     RelativeLocalPath(std::path::PathBuf),
+
+    #[error(
+        "The signature by publisher 0x{} does not match the bundle, which has been modified since it was signed",
+        holochain_util::hex::bytes_to_hex(.0, false)
+    )]
+    InvalidSignature([u8; 32]),
+
+    #[error("The manifest of this bundle is not the one its publishers signed")]
+    SignedManifestMismatch,
+
+    #[error("This is not an indexed bundle")]
+    NotIndexed,
+
+    #[error("The bundled resource '{0}' in this indexed bundle is corrupt")]
+    IndexedResourceCorrupt(std::path::PathBuf),
 }
 pub type BundleResult<T> = Result<T, BundleError>;
 
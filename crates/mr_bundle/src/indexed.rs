@@ -0,0 +1,652 @@
+//! An alternative bundle encoding whose resources can be read one at a time.
+//!
+//! The default encoding compresses the whole bundle as a single gzip stream,
+//! so nothing can be read without decompressing everything. An indexed bundle
+//! instead compresses each resource separately and ends with an index of
+//! where each one is, which is read first:
+//!
+//! ```text
+//! magic (8 bytes) | index offset (u64 LE) | resource ... | resource | index
+//! ```
+//!
+//! The index holds the manifest and any signatures, so those can be read
+//! without touching the resources at all. Each resource and the index is
+//! compressed with gzip. [`Bundle::decode`] accepts either encoding.
+
+use crate::{
+    bundle::{check_bundled_paths, ResourceMap},
+    error::{BundleError, MrBundleError, MrBundleResult},
+    resource::ResourceBytes,
+    signature::{BundleSignature, BundleSignatures},
+    Bundle, Manifest, RawBundle,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::{Cursor, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// The bytes an indexed bundle starts with.
+///
+/// Gzip streams start with `0x1f 0x8b`, so an indexed bundle can't be
+/// mistaken for a bundle with the default encoding.
+pub const INDEXED_BUNDLE_MAGIC: &[u8; 8] = b"mrbndl\x00\x01";
+
+/// The magic followed by the offset of the index.
+const HEADER_LEN: u64 = INDEXED_BUNDLE_MAGIC.len() as u64 + 8;
+
+/// The most memory reserved up front for reading a resource. The lengths in
+/// the index come from the bundle, so they aren't trusted for allocation.
+const MAX_RESOURCE_PREALLOCATION: u64 = 16 * 1024 * 1024;
+
+/// Whether these bytes, the start of a bundle, are from an indexed bundle.
+pub fn is_indexed(bytes: &[u8]) -> bool {
+    bytes.starts_with(INDEXED_BUNDLE_MAGIC)
+}
+
+/// Whether the file at `path` is an indexed bundle.
+/// A file which can't be read is not.
+pub(crate) fn is_indexed_file(path: &Path) -> bool {
+    let mut magic = [0; INDEXED_BUNDLE_MAGIC.len()];
+    std::fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .is_ok()
+        && is_indexed(&magic)
+}
+
+/// Where a resource is in an indexed bundle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct ResourceEntry {
+    /// Where the compressed resource starts.
+    offset: u64,
+    /// The length of the compressed resource.
+    compressed_len: u64,
+    /// The length of the resource itself.
+    len: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Index<M> {
+    manifest: M,
+    resources: BTreeMap<PathBuf, ResourceEntry>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signatures: Option<BundleSignatures>,
+}
+
+/// Writes an indexed bundle one resource at a time, so that only one buffer
+/// of any resource is held in memory.
+///
+/// ```ignore
+/// let mut writer = IndexedBundleWriter::new(std::fs::File::create("app.webhapp")?)?;
+/// writer.add_resource("ui.zip".into(), std::fs::File::open("ui.zip")?)?;
+/// writer.add_resource("app.happ".into(), std::fs::File::open("app.happ")?)?;
+/// writer.finish(&manifest, None)?;
+/// ```
+pub struct IndexedBundleWriter<W: Write + Seek> {
+    out: W,
+    start: u64,
+    position: u64,
+    resources: BTreeMap<PathBuf, ResourceEntry>,
+}
+
+impl<W: Write + Seek> IndexedBundleWriter<W> {
+    /// Start writing an indexed bundle at the current position of `out`.
+    pub fn new(mut out: W) -> MrBundleResult<Self> {
+        let start = out.stream_position()?;
+        out.write_all(INDEXED_BUNDLE_MAGIC)?;
+        // Overwritten with the index offset by `finish`.
+        out.write_all(&0u64.to_le_bytes())?;
+        Ok(Self {
+            out,
+            start,
+            position: start + HEADER_LEN,
+            resources: BTreeMap::new(),
+        })
+    }
+
+    /// Compress a resource into the bundle, replacing any earlier resource
+    /// at the same path.
+    pub fn add_resource(&mut self, path: PathBuf, mut resource: impl Read) -> MrBundleResult<()> {
+        let mut encoder = flate2::write::GzEncoder::new(
+            CountingWriter::new(&mut self.out),
+            flate2::Compression::default(),
+        );
+        let len = std::io::copy(&mut resource, &mut encoder)?;
+        let compressed_len = encoder.finish()?.count;
+        self.resources.insert(
+            path,
+            ResourceEntry {
+                offset: self.position - self.start,
+                compressed_len,
+                len,
+            },
+        );
+        self.position += compressed_len;
+        Ok(())
+    }
+
+    /// Write the index and return the output.
+    ///
+    /// Every resource must be one of the bundled locations of the manifest.
+    pub fn finish<M: Manifest>(
+        mut self,
+        manifest: &M,
+        signatures: Option<&BundleSignatures>,
+    ) -> MrBundleResult<W> {
+        check_bundled_paths(manifest, self.resources.keys())?;
+        let index = Index {
+            manifest,
+            resources: std::mem::take(&mut self.resources),
+            signatures: signatures.cloned(),
+        };
+        let mut encoder =
+            flate2::write::GzEncoder::new(&mut self.out, flate2::Compression::default());
+        encoder.write_all(&rmp_serde::to_vec_named(&index)?)?;
+        encoder.finish()?;
+        let end = self.out.stream_position()?;
+        self.out.seek(SeekFrom::Start(
+            self.start + INDEXED_BUNDLE_MAGIC.len() as u64,
+        ))?;
+        self.out
+            .write_all(&(self.position - self.start).to_le_bytes())?;
+        self.out.seek(SeekFrom::Start(end))?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// Counts the bytes written through it.
+struct CountingWriter<W> {
+    inner: W,
+    count: u64,
+}
+
+impl<W> CountingWriter<W> {
+    fn new(inner: W) -> Self {
+        Self { inner, count: 0 }
+    }
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Where the resources of an indexed bundle are read from.
+#[derive(Debug)]
+enum IndexedSource {
+    File(PathBuf),
+    Bytes(Arc<[u8]>),
+}
+
+/// The resources of an indexed bundle which a [`Bundle`] leaves where they
+/// are, so that each is only read and decompressed when it is resolved.
+#[derive(Debug)]
+pub(crate) struct IndexedResources {
+    source: IndexedSource,
+    start: u64,
+    entries: BTreeMap<PathBuf, ResourceEntry>,
+}
+
+impl IndexedResources {
+    /// The paths of the resources.
+    pub(crate) fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.entries.keys()
+    }
+
+    /// Whether there is a resource at this path.
+    pub(crate) fn contains(&self, path: &Path) -> bool {
+        self.entries.contains_key(path)
+    }
+
+    /// Read and decompress a resource. This is blocking.
+    pub(crate) fn read(&self, path: &Path) -> MrBundleResult<ResourceBytes> {
+        let entry = self
+            .entries
+            .get(path)
+            .ok_or_else(|| BundleError::BundledResourceMissing(path.to_path_buf()))?;
+        match &self.source {
+            IndexedSource::File(file) => {
+                read_entry(&mut std::fs::File::open(file)?, self.start, entry, path)
+            }
+            IndexedSource::Bytes(bytes) => {
+                read_entry(&mut Cursor::new(&bytes[..]), self.start, entry, path)
+            }
+        }
+    }
+
+    /// Read and decompress every resource. This is blocking.
+    pub(crate) fn read_all(&self) -> MrBundleResult<ResourceMap> {
+        self.paths()
+            .map(|path| Ok((path.clone(), self.read(path)?)))
+            .collect()
+    }
+}
+
+/// Decompress the resource at `entry` into `out`, returning its length.
+fn copy_entry(
+    reader: &mut (impl Read + Seek),
+    start: u64,
+    entry: &ResourceEntry,
+    path: &Path,
+    out: &mut impl Write,
+) -> MrBundleResult<u64> {
+    reader.seek(SeekFrom::Start(start + entry.offset))?;
+    let mut decoder = flate2::read::GzDecoder::new(reader.take(entry.compressed_len));
+    let len = std::io::copy(&mut decoder, out)?;
+    if len != entry.len {
+        return Err(BundleError::IndexedResourceCorrupt(path.to_path_buf()).into());
+    }
+    Ok(len)
+}
+
+/// Decompress the resource at `entry` into memory.
+fn read_entry(
+    reader: &mut (impl Read + Seek),
+    start: u64,
+    entry: &ResourceEntry,
+    path: &Path,
+) -> MrBundleResult<ResourceBytes> {
+    let mut bytes = Vec::with_capacity(entry.len.min(MAX_RESOURCE_PREALLOCATION) as usize);
+    copy_entry(reader, start, entry, path, &mut bytes)?;
+    Ok(bytes.into())
+}
+
+/// An indexed bundle whose resources are read on demand.
+///
+/// Opening the bundle only reads its index, so the manifest of a large bundle
+/// is cheap to get, and each resource is only decompressed when asked for.
+/// All the reads are blocking.
+#[derive(Debug)]
+pub struct IndexedBundle<M, R = std::fs::File> {
+    reader: R,
+    start: u64,
+    index: Index<M>,
+}
+
+impl<M: DeserializeOwned> IndexedBundle<M> {
+    /// Open an indexed bundle file.
+    pub fn open(path: &Path) -> MrBundleResult<Self> {
+        Self::new(std::fs::File::open(path)?)
+    }
+}
+
+impl<M: DeserializeOwned, R: Read + Seek> IndexedBundle<M, R> {
+    /// Read the index of the indexed bundle at the current position of `reader`.
+    pub fn new(mut reader: R) -> MrBundleResult<Self> {
+        let start = reader.stream_position()?;
+        let mut header = [0; HEADER_LEN as usize];
+        reader
+            .read_exact(&mut header)
+            .map_err(|_| BundleError::NotIndexed)?;
+        let (magic, index_offset) = header.split_at(INDEXED_BUNDLE_MAGIC.len());
+        if !is_indexed(magic) {
+            return Err(BundleError::NotIndexed.into());
+        }
+        let index_offset = u64::from_le_bytes(index_offset.try_into().expect("8 bytes"));
+        reader.seek(SeekFrom::Start(start + index_offset))?;
+        let index =
+            rmp_serde::from_read(flate2::read::GzDecoder::new(&mut reader)).map_err(|e| {
+                MrBundleError::MsgpackDecodeError(std::any::type_name::<M>().to_string(), e)
+            })?;
+        Ok(Self {
+            reader,
+            start,
+            index,
+        })
+    }
+
+    /// The manifest describing the resources in this bundle.
+    pub fn manifest(&self) -> &M {
+        &self.index.manifest
+    }
+
+    /// The publisher signatures over this bundle, which are only checked
+    /// when it is loaded with [`IndexedBundle::into_bundle`].
+    pub fn signatures(&self) -> &[BundleSignature] {
+        self.index
+            .signatures
+            .as_ref()
+            .map(|signed| signed.signatures.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The paths of the resources in this bundle.
+    pub fn resource_paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.index.resources.keys()
+    }
+
+    /// The length of a resource without reading it.
+    pub fn resource_len(&self, path: &Path) -> Option<u64> {
+        self.index.resources.get(path).map(|entry| entry.len)
+    }
+
+    fn entry(&self, path: &Path) -> MrBundleResult<ResourceEntry> {
+        Ok(*self
+            .index
+            .resources
+            .get(path)
+            .ok_or_else(|| BundleError::BundledResourceMissing(path.to_path_buf()))?)
+    }
+
+    /// Decompress a resource into `out`, returning its length.
+    pub fn copy_resource(&mut self, path: &Path, out: &mut impl Write) -> MrBundleResult<u64> {
+        let entry = self.entry(path)?;
+        copy_entry(&mut self.reader, self.start, &entry, path, out)
+    }
+
+    /// Read a whole resource into memory.
+    pub fn read_resource(&mut self, path: &Path) -> MrBundleResult<ResourceBytes> {
+        let entry = self.entry(path)?;
+        read_entry(&mut self.reader, self.start, &entry, path)
+    }
+
+    fn read_all_resources(&mut self) -> MrBundleResult<ResourceMap> {
+        let paths: Vec<PathBuf> = self.index.resources.keys().cloned().collect();
+        paths
+            .into_iter()
+            .map(|path| {
+                let resource = self.read_resource(&path)?;
+                Ok((path, resource))
+            })
+            .collect()
+    }
+
+    /// Read every resource into a [`RawBundle`].
+    pub fn into_raw_bundle(mut self) -> MrBundleResult<RawBundle<M>> {
+        let resources = self.read_all_resources()?;
+        Ok(RawBundle {
+            manifest: self.index.manifest,
+            resources,
+        })
+    }
+}
+
+impl<M: Manifest, R: Read + Seek> IndexedBundle<M, R> {
+    /// Read every resource into a [`Bundle`], keeping the signatures.
+    pub fn into_bundle(mut self) -> MrBundleResult<Bundle<M>> {
+        let resources = self.read_all_resources()?;
+        Bundle::from_signed_parts(self.index.manifest, resources, self.index.signatures)
+    }
+
+    /// A [`Bundle`] which reads each resource from `source` when it is
+    /// resolved.
+    fn into_lazy_bundle(self, source: IndexedSource) -> MrBundleResult<Bundle<M>> {
+        // The signatures are checked against every resource, which must not
+        // be read again from the source afterwards, in case it has changed.
+        if self.index.signatures.is_some() {
+            return self.into_bundle();
+        }
+        Bundle::from_indexed(
+            self.index.manifest,
+            IndexedResources {
+                source,
+                start: self.start,
+                entries: self.index.resources,
+            },
+        )
+    }
+}
+
+impl<M: Manifest> Bundle<M> {
+    /// Open an indexed bundle file, only reading its index until its
+    /// resources are resolved. A signed bundle is read whole.
+    pub(crate) fn open_indexed(path: &Path) -> MrBundleResult<Self> {
+        IndexedBundle::open(path)?.into_lazy_bundle(IndexedSource::File(path.to_path_buf()))
+    }
+
+    /// Decode an indexed bundle, only decompressing its index until its
+    /// resources are resolved. A signed bundle is decompressed whole.
+    pub(crate) fn decode_indexed(bytes: &[u8]) -> MrBundleResult<Self> {
+        let bytes: Arc<[u8]> = bytes.into();
+        IndexedBundle::new(Cursor::new(bytes.clone()))?
+            .into_lazy_bundle(IndexedSource::Bytes(bytes))
+    }
+}
+
+impl<M: Manifest> Bundle<M> {
+    /// Encode the bundle as an indexed bundle, whose resources can be read
+    /// one at a time with an [`IndexedBundle`].
+    pub fn encode_indexed(&self) -> MrBundleResult<Vec<u8>> {
+        let mut writer = IndexedBundleWriter::new(Cursor::new(Vec::new()))?;
+        for (path, resource) in self.bundled_resources()?.iter() {
+            writer.add_resource(path.clone(), resource.inner())?;
+        }
+        Ok(writer
+            .finish(self.manifest(), self.bundle_signatures())?
+            .into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::MrBundleError, location::Location, SigningKey};
+
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct TestManifest(Vec<Location>);
+
+    impl Manifest for TestManifest {
+        fn locations(&self) -> Vec<Location> {
+            self.0.clone()
+        }
+
+        #[cfg(feature = "packing")]
+        fn path() -> PathBuf {
+            unimplemented!()
+        }
+
+        #[cfg(feature = "packing")]
+        fn bundle_extension() -> &'static str {
+            unimplemented!()
+        }
+    }
+
+    fn test_bundle() -> Bundle<TestManifest> {
+        let manifest = TestManifest(vec![
+            Location::Bundled("small.thing".into()),
+            Location::Bundled("nested/large.thing".into()),
+            Location::Path("local.thing".into()),
+        ]);
+        let large: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        Bundle::new_unchecked(
+            manifest,
+            vec![
+                ("small.thing".into(), vec![1, 2, 3].into()),
+                ("nested/large.thing".into(), large.into()),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn indexed_bundle_round_trip() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let bundle = test_bundle().sign(&key).unwrap();
+        let bytes = bundle.encode_indexed().unwrap();
+        assert!(is_indexed(&bytes));
+        assert!(!is_indexed(&bundle.encode().unwrap()));
+
+        // The manifest and each resource can be read without the others.
+        let mut indexed = IndexedBundle::<TestManifest, _>::new(Cursor::new(&bytes)).unwrap();
+        assert_eq!(bundle.manifest(), indexed.manifest());
+        assert_eq!(bundle.signatures(), indexed.signatures());
+        assert_eq!(
+            vec![&PathBuf::from("nested/large.thing"), &"small.thing".into()],
+            indexed.resource_paths().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(3), indexed.resource_len(Path::new("small.thing")));
+        assert_eq!(
+            ResourceBytes::from(vec![1, 2, 3]),
+            indexed.read_resource(Path::new("small.thing")).unwrap()
+        );
+        let mut large = Vec::new();
+        assert_eq!(
+            200_000,
+            indexed
+                .copy_resource(Path::new("nested/large.thing"), &mut large)
+                .unwrap()
+        );
+        assert_eq!(
+            &bundle.bundled_resources().unwrap()[Path::new("nested/large.thing")],
+            &ResourceBytes::from(large)
+        );
+        matches::assert_matches!(
+            indexed.read_resource(Path::new("local.thing")),
+            Err(MrBundleError::BundleError(
+                BundleError::BundledResourceMissing(_)
+            ))
+        );
+
+        // Decoding either encoding gives the same bundle, still signed.
+        let decoded = Bundle::<TestManifest>::decode(&bytes).unwrap();
+        assert_eq!(bundle, decoded);
+        assert_eq!(
+            vec![key.verifying_key().to_bytes()],
+            decoded.verify_signatures().unwrap()
+        );
+    }
+
+    #[test]
+    fn indexed_bundle_at_an_offset() {
+        let bundle = test_bundle();
+        let mut out = Cursor::new(b"prefix".to_vec());
+        out.seek(SeekFrom::End(0)).unwrap();
+        let mut writer = IndexedBundleWriter::new(out).unwrap();
+        for (path, resource) in bundle.bundled_resources().unwrap().iter() {
+            writer.add_resource(path.clone(), resource.inner()).unwrap();
+        }
+        let mut reader = writer.finish(bundle.manifest(), None).unwrap();
+        reader.seek(SeekFrom::Start(6)).unwrap();
+        let indexed = IndexedBundle::<TestManifest, _>::new(reader).unwrap();
+        assert_eq!(bundle, indexed.into_bundle().unwrap());
+    }
+
+    #[test]
+    fn indexed_bundle_errors() {
+        let bundle = test_bundle();
+
+        // Resources must be bundled locations of the manifest.
+        let mut writer = IndexedBundleWriter::new(Cursor::new(Vec::new())).unwrap();
+        writer
+            .add_resource("stray.thing".into(), &[0u8][..])
+            .unwrap();
+        matches::assert_matches!(
+            writer.finish(bundle.manifest(), None),
+            Err(MrBundleError::BundleError(
+                BundleError::BundledPathNotInManifest(_)
+            ))
+        );
+
+        matches::assert_matches!(
+            IndexedBundle::<TestManifest, _>::new(Cursor::new(bundle.encode().unwrap())),
+            Err(MrBundleError::BundleError(BundleError::NotIndexed))
+        );
+
+        // A resource which doesn't decompress to its indexed length is corrupt.
+        let mut bytes = bundle.encode_indexed().unwrap();
+        let mut indexed = IndexedBundle::<TestManifest, _>::new(Cursor::new(&bytes)).unwrap();
+        let entry = indexed.index.resources[Path::new("small.thing")];
+        indexed
+            .index
+            .resources
+            .get_mut(Path::new("small.thing"))
+            .unwrap()
+            .len += 1;
+        matches::assert_matches!(
+            indexed.read_resource(Path::new("small.thing")),
+            Err(MrBundleError::BundleError(
+                BundleError::IndexedResourceCorrupt(_)
+            ))
+        );
+
+        // An indexed length too large to allocate is not trusted.
+        indexed
+            .index
+            .resources
+            .get_mut(Path::new("small.thing"))
+            .unwrap()
+            .len = u64::MAX;
+        matches::assert_matches!(
+            indexed.read_resource(Path::new("small.thing")),
+            Err(MrBundleError::BundleError(
+                BundleError::IndexedResourceCorrupt(_)
+            ))
+        );
+
+        // Tampering with a resource is caught by its gzip checksum.
+        let last = (entry.offset + entry.compressed_len) as usize - 9;
+        bytes[last] ^= 0xff;
+        let mut indexed = IndexedBundle::<TestManifest, _>::new(Cursor::new(&bytes)).unwrap();
+        assert!(indexed.read_resource(Path::new("small.thing")).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn indexed_bundle_files_are_read_on_demand() {
+        let bundle = test_bundle();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.bundle");
+        bundle.write_indexed_to_file(&path).await.unwrap();
+
+        let from_file = Bundle::<TestManifest>::read_from_file(&path).await.unwrap();
+        assert_eq!(bundle, from_file);
+
+        // Only the resource which was tampered with after the bundle was read
+        // fails to resolve, since each is read from the file when resolved.
+        let mut bytes = std::fs::read(&path).unwrap();
+        let entry = IndexedBundle::<TestManifest, _>::new(Cursor::new(&bytes))
+            .unwrap()
+            .index
+            .resources[Path::new("nested/large.thing")];
+        bytes[(entry.offset + entry.compressed_len) as usize - 9] ^= 0xff;
+        std::fs::write(&path, &bytes).unwrap();
+        assert_eq!(
+            ResourceBytes::from(vec![1, 2, 3]),
+            *from_file
+                .resolve(&Location::Bundled("small.thing".into()))
+                .await
+                .unwrap()
+        );
+        assert!(from_file
+            .resolve(&Location::Bundled("nested/large.thing".into()))
+            .await
+            .is_err());
+        assert!(from_file.bundled_resources().is_err());
+        assert!(from_file.encode().is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn signed_indexed_bundle_files_are_read_whole() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let bundle = test_bundle().sign(&key).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.bundle");
+        bundle.write_indexed_to_file(&path).await.unwrap();
+
+        // The resources the signatures were checked against are kept, since
+        // the file could change after it was read.
+        let from_file = Bundle::<TestManifest>::read_from_file(&path).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(bundle, from_file);
+        assert_eq!(
+            vec![key.verifying_key().to_bytes()],
+            from_file.verify_signatures().unwrap()
+        );
+        assert_eq!(
+            ResourceBytes::from(vec![1, 2, 3]),
+            *from_file
+                .resolve(&Location::Bundled("small.thing".into()))
+                .await
+                .unwrap()
+        );
+    }
+}
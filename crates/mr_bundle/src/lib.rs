@@ -5,6 +5,13 @@
 //! opaque resources in the form of [`ResourceBytes`].
 //! A Bundle can be serialized and written to a file.
 //!
+//! A Bundle can be [signed](Bundle::sign) by any number of publishers, whose
+//! ed25519 signatures cover the manifest and all the bundled resources.
+//!
+//! A Bundle can also be [encoded as an indexed bundle](Bundle::encode_indexed),
+//! whose manifest and resources can be read one at a time with an
+//! [`IndexedBundle`] instead of all being loaded into memory.
+//!
 //! A Bundle can also be [packed](Bundle::pack_yaml) and [unpacked](Bundle::unpack_yaml),
 //! via the `"packing"` feature.
 //! Bundle packing is performed by following the [`Location`]s specified in the
@@ -17,9 +24,11 @@
 mod bundle;
 mod encoding;
 pub mod error;
+mod indexed;
 mod location;
 mod manifest;
 mod resource;
+mod signature;
 pub(crate) mod util;
 
 #[cfg(feature = "packing")]
@@ -27,6 +36,8 @@ mod packing;
 
 pub use bundle::{Bundle, RawBundle};
 pub use encoding::{decode, encode};
+pub use indexed::{is_indexed, IndexedBundle, IndexedBundleWriter, INDEXED_BUNDLE_MAGIC};
 pub use location::Location;
 pub use manifest::Manifest;
 pub use resource::ResourceBytes;
+pub use signature::{BundleSignature, BundleSignatures, SigningKey, VerifyingKey};
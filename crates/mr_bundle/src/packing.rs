@@ -1,9 +1,9 @@
 use super::Bundle;
 use crate::{
-    bundle::ResourceMap,
+    bundle::{check_bundled_paths, ResourceMap},
     error::{MrBundleResult, PackingError, UnpackingError, UnpackingResult},
     util::prune_path,
-    Manifest, RawBundle,
+    IndexedBundleWriter, Manifest, RawBundle,
 };
 use holochain_util::ffs;
 use std::path::Path;
@@ -17,7 +17,7 @@ impl<M: Manifest> Bundle<M> {
     pub async fn unpack_yaml(&self, base_path: &Path, force: bool) -> MrBundleResult<()> {
         unpack_yaml(
             self.manifest(),
-            self.bundled_resources(),
+            &*self.bundled_resources()?,
             base_path,
             M::path().as_ref(),
             force,
@@ -50,6 +50,34 @@ impl<M: Manifest> Bundle<M> {
         .collect::<Result<Vec<_>, _>>()?;
         Bundle::new(manifest, resources, base_path)
     }
+
+    /// Pack a previously unpacked directory straight into an indexed bundle
+    /// file at `target_path`, returning the manifest.
+    ///
+    /// Unlike [`pack_yaml`](Bundle::pack_yaml), the resources are streamed
+    /// into the file one at a time rather than all being read into memory.
+    /// This does blocking IO.
+    pub fn pack_yaml_indexed(manifest_path: &Path, target_path: &Path) -> MrBundleResult<M> {
+        let manifest_path = ffs::sync::canonicalize(manifest_path)?;
+        let manifest_yaml = ffs::sync::read_to_string(&manifest_path).map_err(|err| {
+            PackingError::BadManifestPath(manifest_path.clone(), err.into_inner())
+        })?;
+        let manifest: M = serde_yaml::from_str(&manifest_yaml).map_err(UnpackingError::from)?;
+        let base_path = prune_path(manifest_path.clone(), M::path())?;
+        let relative_paths = manifest.bundled_paths();
+        check_bundled_paths(&manifest, &relative_paths)?;
+        let mut writer =
+            IndexedBundleWriter::new(std::io::BufWriter::new(std::fs::File::create(target_path)?))?;
+        for relative_path in relative_paths {
+            let resource_path = ffs::sync::canonicalize(base_path.join(&relative_path))?;
+            writer.add_resource(relative_path, std::fs::File::open(resource_path)?)?;
+        }
+        writer
+            .finish(&manifest, None)?
+            .into_inner()
+            .map_err(|e| e.into_error())?;
+        Ok(manifest)
+    }
 }
 
 impl<M: serde::Serialize> RawBundle<M> {
@@ -0,0 +1,135 @@
+use crate::{
+    bundle::ResourceMap,
+    error::{BundleError, MrBundleError, MrBundleResult},
+    resource::ResourceBytes,
+    Manifest,
+};
+use serde::{Deserialize, Serialize};
+
+/// Re-export of the ed25519 key types used to sign and verify bundles.
+pub use ed25519_dalek::{SigningKey, VerifyingKey};
+
+/// Prefixed to the signed content so that a bundle signature can't be
+/// mistaken for a signature over anything else.
+const SIGNATURE_CONTEXT: &[u8] = b"mr_bundle signature v1\0";
+
+/// The publisher signatures on a [`Bundle`](crate::Bundle), along with the
+/// manifest exactly as it was encoded when the bundle was first signed.
+///
+/// Signatures are made over these bytes rather than over a fresh encoding of
+/// the manifest, which could differ between versions of the manifest type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub struct BundleSignatures {
+    /// The encoded manifest that was signed.
+    pub manifest: ResourceBytes,
+
+    /// The signatures, one per publisher.
+    pub signatures: Vec<BundleSignature>,
+}
+
+impl BundleSignatures {
+    /// Start signing a bundle with this manifest.
+    pub(crate) fn new<M: Serialize>(manifest: &M) -> MrBundleResult<Self> {
+        Ok(Self {
+            manifest: rmp_serde::to_vec_named(manifest)?.into(),
+            signatures: Vec::new(),
+        })
+    }
+
+    /// Add a publisher's signature, replacing any earlier signature by the
+    /// same publisher.
+    pub(crate) fn sign(&mut self, key: &SigningKey, resources: &ResourceMap) {
+        use ed25519_dalek::Signer;
+        let signature = BundleSignature {
+            publisher: key.verifying_key().to_bytes(),
+            signature: key
+                .sign(&signed_content(&self.manifest, resources))
+                .to_bytes(),
+        };
+        self.signatures
+            .retain(|existing| existing.publisher != signature.publisher);
+        self.signatures.push(signature);
+    }
+
+    /// Check every signature against the signed manifest and the resources,
+    /// and that `manifest` is the one that was signed. Returns the publishers.
+    pub(crate) fn verify<M: Manifest>(
+        &self,
+        manifest: &M,
+        resources: &ResourceMap,
+    ) -> MrBundleResult<Vec<[u8; 32]>> {
+        let content = signed_content(&self.manifest, resources);
+        let mut publishers = Vec::with_capacity(self.signatures.len());
+        for signature in &self.signatures {
+            if !signature.verify(&content) {
+                return Err(BundleError::InvalidSignature(signature.publisher).into());
+            }
+            publishers.push(signature.publisher);
+        }
+        let signed_manifest: M = rmp_serde::from_slice(&self.manifest).map_err(|e| {
+            MrBundleError::MsgpackDecodeError(std::any::type_name::<M>().to_string(), e)
+        })?;
+        if signed_manifest != *manifest {
+            return Err(BundleError::SignedManifestMismatch.into());
+        }
+        Ok(publishers)
+    }
+}
+
+/// A publisher's ed25519 signature over the manifest and resources of a
+/// [`Bundle`](crate::Bundle).
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub struct BundleSignature {
+    /// The public key of the publisher.
+    #[serde(with = "serde_bytes")]
+    pub publisher: [u8; 32],
+
+    /// The signature.
+    #[serde(with = "serde_bytes")]
+    pub signature: [u8; 64],
+}
+
+impl BundleSignature {
+    /// Whether this is a valid signature of the signed content.
+    fn verify(&self, content: &[u8]) -> bool {
+        let publisher = match VerifyingKey::from_bytes(&self.publisher) {
+            Ok(publisher) => publisher,
+            Err(_) => return false,
+        };
+        let signature = ed25519_dalek::Signature::from_bytes(&self.signature);
+        publisher.verify_strict(content, &signature).is_ok()
+    }
+}
+
+impl std::fmt::Debug for BundleSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BundleSignature")
+            .field(
+                "publisher",
+                &holochain_util::hex::many_bytes_string(&self.publisher),
+            )
+            .field(
+                "signature",
+                &holochain_util::hex::many_bytes_string(&self.signature),
+            )
+            .finish()
+    }
+}
+
+/// The bytes a publisher signs: the encoded manifest and then each resource
+/// by path, every part prefixed with its length.
+fn signed_content(manifest: &[u8], resources: &ResourceMap) -> Vec<u8> {
+    fn push(content: &mut Vec<u8>, part: &[u8]) {
+        content.extend((part.len() as u64).to_le_bytes());
+        content.extend(part);
+    }
+    let mut content = SIGNATURE_CONTEXT.to_vec();
+    push(&mut content, manifest);
+    for (path, resource) in resources {
+        push(&mut content, path.to_string_lossy().as_bytes());
+        push(&mut content, resource);
+    }
+    content
+}
@@ -82,6 +82,7 @@ async fn resource_resolution() {
     assert_eq!(
         bundle
             .bundled_resources()
+            .unwrap()
             .iter()
             .collect::<HashSet<(&PathBuf, &ResourceBytes)>>(),
         maplit::hashset![(&bundled_path, &bundled_thing_encoded)]
@@ -164,6 +165,7 @@ async fn unpack_roundtrip() {
     assert_eq!(
         bundle
             .bundled_resources()
+            .unwrap()
             .iter()
             .collect::<HashSet<(&PathBuf, &ResourceBytes)>>(),
         maplit::hashset![(&bundled_path, &bundled_thing_encoded)]
@@ -181,4 +183,26 @@ async fn unpack_roundtrip() {
         .unwrap();
 
     assert_eq!(bundle, reconstructed);
+
+    // Packing straight into an indexed bundle gives the same contents
+    let indexed_path = dir_path.join("indexed.bundle");
+    let manifest = Bundle::<TestManifest>::pack_yaml_indexed(
+        &unpacked_dir.join("test-manifest.yaml"),
+        &indexed_path,
+    )
+    .unwrap();
+    assert_eq!(bundle.manifest(), &manifest);
+    let mut indexed = mr_bundle::IndexedBundle::<TestManifest>::open(&indexed_path).unwrap();
+    assert_eq!(
+        bundled_thing_encoded,
+        indexed.read_resource(&bundled_path).unwrap()
+    );
+    let from_file = Bundle::<TestManifest>::read_from_file(&indexed_path)
+        .await
+        .unwrap();
+    assert_eq!(bundle.manifest(), from_file.manifest());
+    assert_eq!(
+        bundle.bundled_resources().unwrap(),
+        from_file.bundled_resources().unwrap()
+    );
 }
@@ -26,6 +26,7 @@ pub enum TestWasm {
     Anchor,
     AppValidation,
     Bench,
+    Blob,
     Capability,
     Clone,
     CounterSigning,
@@ -138,6 +139,7 @@ impl From<TestWasm> for ZomeName {
             TestWasm::Anchor => "anchor",
             TestWasm::AppValidation => "app_validation",
             TestWasm::Bench => "bench",
+            TestWasm::Blob => "blob",
             TestWasm::Capability => "capability",
             TestWasm::Clone => "clone",
             TestWasm::CounterSigning => "countersigning",
@@ -215,6 +217,7 @@ impl From<TestWasm> for PathBuf {
             TestWasm::Anchor => "wasm32-unknown-unknown/release/test_wasm_anchor.wasm",
             TestWasm::AppValidation => "wasm32-unknown-unknown/release/test_wasm_app_validation.wasm",
             TestWasm::Bench => "wasm32-unknown-unknown/release/test_wasm_bench.wasm",
+            TestWasm::Blob => "wasm32-unknown-unknown/release/test_wasm_blob.wasm",
             TestWasm::Capability => "wasm32-unknown-unknown/release/test_wasm_capability.wasm",
             TestWasm::Clone => "wasm32-unknown-unknown/release/test_wasm_clone.wasm",
             TestWasm::CounterSigning => {
@@ -0,0 +1,61 @@
+use crate::integrity::*;
+use hdk::blob::BlobCreated;
+use hdk::prelude::*;
+
+#[hdk_extern]
+fn create_test_blob((data, chunk_size): (Vec<u8>, u32)) -> ExternResult<BlobCreated> {
+    create_blob(
+        &data,
+        Some(chunk_size),
+        Some("application/octet-stream".to_string()),
+        EntryTypes::BlobChunk,
+        EntryTypes::BlobManifest,
+    )
+}
+
+#[hdk_extern]
+fn read_test_blob(manifest_hash: EntryHash) -> ExternResult<Option<Vec<u8>>> {
+    get_blob(manifest_hash, GetOptions::network())
+}
+
+/// Reads the blob a window of chunks at a time and returns the chunk lengths.
+#[hdk_extern]
+fn read_test_blob_chunks(manifest_hash: EntryHash) -> ExternResult<Vec<usize>> {
+    let manifest = get_blob_manifest(manifest_hash, GetOptions::network())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("No manifest".to_string())
+    ))?;
+    BlobReader::new(manifest, GetOptions::network())
+        .with_window(2)
+        .map(|chunk| chunk.map(|c| c.len()))
+        .collect()
+}
+
+/// Commits one chunk per call, as a client uploading a large blob would.
+#[hdk_extern]
+fn create_test_blob_chunk(data: Vec<u8>) -> ExternResult<EntryHash> {
+    create_blob_chunk(data, EntryTypes::BlobChunk)
+}
+
+#[hdk_extern]
+fn create_test_blob_manifest(manifest: BlobManifest) -> ExternResult<BlobCreated> {
+    create_blob_manifest(manifest, EntryTypes::BlobManifest)
+}
+
+/// Reads `count` chunks starting at chunk `start`, as a client paging a blob would.
+#[hdk_extern]
+fn read_test_blob_page(
+    (manifest_hash, start, count): (EntryHash, usize, usize),
+) -> ExternResult<Vec<u8>> {
+    let manifest = get_blob_manifest(manifest_hash, GetOptions::network())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("No manifest".to_string())
+    ))?;
+    let mut page = Vec::new();
+    for chunk in BlobReader::new(manifest, GetOptions::network())
+        .with_window(count)
+        .starting_at(start)
+        .take(count)
+    {
+        page.extend(chunk?);
+    }
+    Ok(page)
+}
@@ -0,0 +1,19 @@
+use hdi::prelude::*;
+
+#[hdk_entry_types]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    BlobChunk(BlobChunk),
+    BlobManifest(BlobManifest),
+}
+
+#[cfg_attr(feature = "integrity", hdk_extern)]
+pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
+    match op.flattened::<EntryTypes, ()>()? {
+        FlatOp::StoreEntry(OpEntry::CreateEntry { app_entry, .. }) => match app_entry {
+            EntryTypes::BlobChunk(chunk) => Ok(validate_blob_chunk(&chunk)),
+            EntryTypes::BlobManifest(manifest) => Ok(validate_blob_manifest(&manifest)),
+        },
+        _ => Ok(ValidateCallbackResult::Valid),
+    }
+}
@@ -97,6 +97,13 @@ fn get_post(hash: ActionHash) -> ExternResult<Option<Record>> {
     get(hash, GetOptions::local())
 }
 
+#[hdk_extern]
+fn get_many_posts(hashes: Vec<ActionHash>) -> ExternResult<Vec<Option<Record>>> {
+    get_many(hashes, GetOptions::network())?
+        .into_iter()
+        .collect()
+}
+
 #[hdk_extern]
 fn create_msg(_: ()) -> ExternResult<ActionHash> {
     use EntryTypes::*;
@@ -37,3 +37,33 @@ fn children_details(path_string: String) -> ExternResult<LinkDetails> {
         .typed(LinkTypes::Path)?
         .children_details()
 }
+
+#[hdk_extern]
+fn leaves((path_string, depth): (String, usize)) -> ExternResult<Vec<Path>> {
+    Ok(
+        path_leaves(&Path::from(path_string).typed(LinkTypes::Path)?, depth)?
+            .into_iter()
+            .map(|typed_path| typed_path.path)
+            .collect(),
+    )
+}
+
+fn time_index(path_string: String) -> ExternResult<TimeIndex> {
+    Ok(TimeIndex::new(
+        Path::from(path_string).typed(LinkTypes::Path)?,
+    ))
+}
+
+#[hdk_extern]
+fn time_index_at(
+    (path_string, timestamp, tag): (String, Timestamp, String),
+) -> ExternResult<ActionHash> {
+    time_index(path_string)?.index(agent_info()?.agent_initial_pubkey, timestamp, tag)
+}
+
+#[hdk_extern]
+fn time_index_range(
+    (path_string, from, until, limit): (String, Timestamp, Timestamp, usize),
+) -> ExternResult<Vec<TimeIndexItem>> {
+    time_index(path_string)?.get_range(from, until, limit)
+}
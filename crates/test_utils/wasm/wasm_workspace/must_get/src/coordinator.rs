@@ -17,6 +17,11 @@ fn must_get_action(action_hash: ActionHash) -> ExternResult<SignedActionHashed>
     hdk::prelude::must_get_action(action_hash)
 }
 
+#[hdk_extern]
+fn must_get_actions(action_hashes: Vec<ActionHash>) -> ExternResult<Vec<SignedActionHashed>> {
+    hdk::prelude::must_get_actions(action_hashes)
+}
+
 #[hdk_extern]
 fn must_get_entry(entry_hash: EntryHash) -> ExternResult<EntryHashed> {
     hdk::prelude::must_get_entry(entry_hash)
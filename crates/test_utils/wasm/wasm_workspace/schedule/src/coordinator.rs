@@ -77,6 +77,28 @@ fn schedule(_: ()) -> ExternResult<()> {
     Ok(())
 }
 
+#[hdk_extern(infallible)]
+fn remind(input: ScheduledFnInput) -> Option<Schedule> {
+    let message: String = input.payload.decode().ok()?;
+    create_entry(EntryTypes::Reminder(Reminder(message))).ok();
+    None
+}
+
+#[hdk_extern]
+fn schedule_reminder(message: String) -> ExternResult<ScheduleId> {
+    hdk::prelude::schedule_with_payload("remind", message)
+}
+
+#[hdk_extern]
+fn cancel_reminder(schedule_id: ScheduleId) -> ExternResult<()> {
+    hdk::prelude::cancel_schedule(schedule_id)
+}
+
+#[hdk_extern]
+fn list_reminders(_: ()) -> ExternResult<Vec<PendingSchedule>> {
+    hdk::prelude::list_schedules()
+}
+
 fn _query(entry_types_unit: EntryTypesUnit) -> ExternResult<Vec<Record>> {
     hdk::prelude::query(
         ChainQueryFilter::default().entry_type(entry_types_unit.try_into().unwrap()),
@@ -98,6 +120,11 @@ fn query_tock(_: ()) -> ExternResult<Vec<Record>> {
     _query(EntryTypesUnit::Tock)
 }
 
+#[hdk_extern]
+fn query_reminders(_: ()) -> ExternResult<Vec<Record>> {
+    _query(EntryTypesUnit::Reminder)
+}
+
 #[hdk_extern]
 fn query_tock_init(_: ()) -> ExternResult<Vec<Record>> {
     _query(EntryTypesUnit::TockInit)
@@ -14,6 +14,9 @@ pub struct Tick;
 #[hdk_entry_helper]
 pub struct Tock;
 
+#[hdk_entry_helper]
+pub struct Reminder(pub String);
+
 #[hdk_entry_types]
 #[unit_enum(EntryTypesUnit)]
 pub enum EntryTypes {
@@ -21,4 +24,5 @@ pub enum EntryTypes {
     TockInit(TockInit),
     Tick(Tick),
     Tock(Tock),
+    Reminder(Reminder),
 }
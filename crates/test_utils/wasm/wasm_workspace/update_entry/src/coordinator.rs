@@ -29,3 +29,21 @@ fn invalid_update_entry(_: ()) -> ExternResult<ActionHash> {
     let action_hash = hdk::prelude::create_entry(&IntegrityUpdateEntry(post()))?;
     hdk::prelude::update_entry(action_hash, &msg())
 }
+
+#[hdk_extern]
+/// Creates a msg and updates it twice, returning the original and the latest action.
+fn create_msg_chain(_: ()) -> ExternResult<(ActionHash, ActionHash)> {
+    let original = hdk::prelude::create_entry(&IntegrityUpdateEntry(msg()))?;
+    let update = hdk::prelude::update_entry(original.clone(), &Msg("hello".into()))?;
+    let latest = hdk::prelude::update_entry(update, &Msg("hello again".into()))?;
+    Ok((original, latest))
+}
+
+#[hdk_extern]
+fn get_latest_msg(action_hash: ActionHash) -> ExternResult<Option<LatestRecord>> {
+    get_latest(
+        action_hash,
+        UpdateTieBreak::Timestamp,
+        GetOptions::network(),
+    )
+}
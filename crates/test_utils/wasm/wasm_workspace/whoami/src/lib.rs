@@ -33,6 +33,22 @@ fn whoami(_: ()) -> ExternResult<AgentInfo> {
     agent_info()
 }
 
+// calls "whoami" on every given agent at once, giving up after the timeout
+#[hdk_extern]
+fn whoarethey_many_within(
+    (agents, timeout): (Vec<AgentPubKey>, std::time::Duration),
+) -> ExternResult<CallRemoteManyOutput> {
+    call_remote_many(
+        agents,
+        zome_info()?.name,
+        "whoami".to_string().into(),
+        None,
+        &(),
+        timeout,
+        None,
+    )
+}
+
 // returns the agent info reported by the given pub key
 // in theory the output is the same as the input
 // it's just that the output comes _from the opinion of the remote agent_
@@ -56,6 +72,22 @@ fn whoarethey(agent_pubkey: AgentPubKey) -> ExternResult<AgentInfo> {
     }
 }
 
+// calls "whoami" on every given agent at once
+#[hdk_extern]
+fn whoarethey_many(
+    (agents, min_responses): (Vec<AgentPubKey>, Option<usize>),
+) -> ExternResult<CallRemoteManyOutput> {
+    call_remote_many(
+        agents,
+        zome_info()?.name,
+        "whoami".to_string().into(),
+        None,
+        &(),
+        std::time::Duration::from_secs(30),
+        min_responses,
+    )
+}
+
 // returns the agent info reported by the given pub key
 // in theory the output is the same as the input
 // it's just that the output comes _from the opinion of the remote agent_